@@ -4,6 +4,7 @@ use crate::intersection::{intersect_quad_bez_path, intersect_quad_subpath, Quad}
 use crate::LayerId;
 
 use glam::{DAffine2, DMat2, DVec2};
+use graphene_core::renderer::SvgIds;
 use graphene_core::vector::VectorData;
 use graphene_core::SurfaceId;
 use kurbo::{Affine, BezPath, Shape as KurboShape};
@@ -64,7 +65,7 @@ impl LayerData for LayerLayer {
 				}
 				svg.push('"');
 
-				svg.push_str(&vector_data.style.render(render_data.view_mode, svg_defs, transform, layer_bounds, transformed_bounds));
+				svg.push_str(&vector_data.style.render(render_data.view_mode, SvgIds::Random, svg_defs, transform, layer_bounds, transformed_bounds));
 				let _ = write!(svg, "/>");
 			}
 			CachedOutputData::BlobURL(blob_url) => {
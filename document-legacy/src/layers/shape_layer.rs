@@ -3,6 +3,7 @@ use super::style::{self, PathStyle, RenderData, ViewMode};
 use crate::intersection::{intersect_quad_bez_path, Quad};
 use crate::LayerId;
 
+use graphene_std::renderer::SvgIds;
 use graphene_std::vector::subpath::Subpath;
 
 use glam::{DAffine2, DMat2, DVec2};
@@ -50,7 +51,7 @@ impl LayerData for ShapeLayer {
 			svg,
 			r#"<path d="{}" {} />"#,
 			subpath.to_svg(),
-			self.style.render(render_data.view_mode, svg_defs, transform, layer_bounds, transformed_bounds)
+			self.style.render(render_data.view_mode, SvgIds::Random, svg_defs, transform, layer_bounds, transformed_bounds)
 		);
 		let _ = svg.write_str("</g>");
 
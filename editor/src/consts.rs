@@ -84,4 +84,9 @@ pub const DEFAULT_DOCUMENT_NAME: &str = "Untitled Document";
 pub const FILE_SAVE_SUFFIX: &str = ".graphite";
 pub const MAX_UNDO_HISTORY_LEN: usize = 100; // TODO: Add this to user preferences
 
+// Output node export
+pub const OUTPUT_EXPORT_SCALE_MIN: f64 = 0.01;
+pub const OUTPUT_EXPORT_SCALE_MAX: f64 = 16.;
+pub const OUTPUT_EXPORT_MAX_PIXEL_COUNT: u64 = 8192 * 8192; // Keeps the RGBA canvas used for resampling under about 256 MB
+
 pub const VIEWPORT_ZOOM_TO_FIT_PADDING_SCALE_FACTOR: f32 = 1.05;
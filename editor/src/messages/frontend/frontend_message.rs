@@ -56,6 +56,9 @@ pub enum FrontendMessage {
 		layer_name: String,
 		#[serde(rename = "blobUrl")]
 		blob_url: String,
+		mime: String,
+		quality: f64,
+		size: (u32, u32),
 	},
 	TriggerDownloadRaster {
 		svg: String,
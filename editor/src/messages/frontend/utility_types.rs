@@ -46,6 +46,7 @@ pub enum FileType {
 	Png,
 	Jpg,
 	Svg,
+	Webp,
 }
 
 impl FileType {
@@ -54,6 +55,7 @@ impl FileType {
 			FileType::Png => "image/png",
 			FileType::Jpg => "image/jpeg",
 			FileType::Svg => "image/svg+xml",
+			FileType::Webp => "image/webp",
 		}
 	}
 }
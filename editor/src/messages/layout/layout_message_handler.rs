@@ -183,6 +183,19 @@ impl<F: Fn(&MessageDiscriminant) -> Vec<KeysGroup>> MessageHandler<LayoutMessage
 								panic!("Invalid string found when updating `NumberInput`")
 							}
 						},
+						Value::Object(nudge) => {
+							let increase = nudge.get("increase").and_then(Value::as_bool).expect("NumberInput nudge was missing its direction");
+							let modifier = match nudge.get("modifier").and_then(Value::as_str) {
+								Some("Shift") => NumberInputNudgeModifier::Shift,
+								Some("Alt") => NumberInputNudgeModifier::Alt,
+								_ => NumberInputNudgeModifier::None,
+							};
+							if let Some(nudged_value) = number_input.nudged_value(increase, modifier) {
+								number_input.value = Some(nudged_value);
+								let callback_message = (number_input.on_update.callback)(number_input);
+								responses.add(callback_message);
+							}
+						}
 						_ => {} // If it's some other type we could just ignore it and leave the value as is
 					},
 					Widget::OptionalInput(optional_input) => {
@@ -49,10 +49,10 @@ pub struct ColorInput {
 	#[widget_builder(constructor)]
 	pub value: Option<Color>,
 
-	// TODO: Implement
-	// #[serde(rename = "allowTransparency")]
-	// #[derivative(Default(value = "false"))]
-	// pub allow_transparency: bool,
+	#[serde(rename = "allowTransparency")]
+	#[derivative(Default(value = "true"))]
+	pub allow_transparency: bool,
+
 	#[serde(rename = "allowNone")]
 	#[derivative(Default(value = "true"))]
 	pub allow_none: bool,
@@ -242,6 +242,10 @@ pub struct NumberInput {
 	#[serde(rename = "rangeMax")]
 	pub range_max: Option<f64>,
 
+	/// Spaces the range slider logarithmically, giving more of its length to small values when the range spans several orders of magnitude
+	#[serde(rename = "logScale")]
+	pub log_scale: bool,
+
 	// Styling
 	#[serde(rename = "minWidth")]
 	pub min_width: u32,
@@ -290,6 +294,69 @@ impl NumberInput {
 	pub fn percentage(self) -> Self {
 		self.min(0.).max(100.).mode_range().unit("%").display_decimal_places(2)
 	}
+
+	/// Maps a value to its position along the range slider, from 0 to 1. Mirrored by the frontend's `NumberInput` component.
+	pub fn slider_position(&self, value: f64) -> f64 {
+		let (range_min, range_max) = (self.range_min.unwrap_or(0.), self.range_max.unwrap_or(1.));
+		let offset = (value - range_min).clamp(0., range_max - range_min);
+		if self.log_scale {
+			offset.ln_1p() / (range_max - range_min).ln_1p()
+		} else {
+			offset / (range_max - range_min)
+		}
+	}
+
+	/// The value at a position along the range slider, the inverse of [`NumberInput::slider_position`].
+	pub fn slider_value(&self, position: f64) -> f64 {
+		let (range_min, range_max) = (self.range_min.unwrap_or(0.), self.range_max.unwrap_or(1.));
+		let position = position.clamp(0., 1.);
+		if self.log_scale {
+			range_min + (position * (range_max - range_min).ln_1p()).exp_m1()
+		} else {
+			range_min + position * (range_max - range_min)
+		}
+	}
+
+	/// The amount an arrow key press changes the value: one step, ten with Shift, or a tenth with Alt.
+	/// When the step is a multiplier, nudges add 1 instead. Integer inputs always change by at least 1.
+	pub fn nudge_step(&self, modifier: NumberInputNudgeModifier) -> f64 {
+		let step = if self.increment_behavior == NumberInputIncrementBehavior::Multiply { 1. } else { self.step };
+		let step = match modifier {
+			NumberInputNudgeModifier::None => step,
+			NumberInputNudgeModifier::Shift => step * 10.,
+			NumberInputNudgeModifier::Alt => step / 10.,
+		};
+		if self.is_integer {
+			step.round().max(1.)
+		} else {
+			step
+		}
+	}
+
+	/// The value after an arrow key press, kept within the input's bounds. `None` if there's no value to nudge.
+	pub fn nudged_value(&self, increase: bool, modifier: NumberInputNudgeModifier) -> Option<f64> {
+		let step = self.nudge_step(modifier);
+		let mut value = self.value? + if increase { step } else { -step };
+		if self.is_integer {
+			value = value.round();
+		}
+		if let Some(min) = self.min {
+			value = value.max(min);
+		}
+		if let Some(max) = self.max {
+			value = value.min(max);
+		}
+		Some(value)
+	}
+}
+
+/// The modifier key held while pressing an arrow key in a number input, which scales the nudge step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NumberInputNudgeModifier {
+	#[default]
+	None,
+	Shift,
+	Alt,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq, Eq, specta::Type)]
@@ -414,3 +481,63 @@ pub struct TextInput {
 	#[derivative(Debug = "ignore", PartialEq = "ignore")]
 	pub on_update: WidgetCallback<TextInput>,
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn nudge_step_is_scaled_by_the_modifier() {
+		let input = NumberInput::new(Some(5.)).increment_step(0.5);
+		assert_eq!(input.nudge_step(NumberInputNudgeModifier::None), 0.5);
+		assert_eq!(input.nudge_step(NumberInputNudgeModifier::Shift), 5.);
+		assert_eq!(input.nudge_step(NumberInputNudgeModifier::Alt), 0.05);
+
+		let integer = NumberInput::new(Some(5.)).int().increment_step(3.);
+		assert_eq!(integer.nudge_step(NumberInputNudgeModifier::None), 3.);
+		assert_eq!(integer.nudge_step(NumberInputNudgeModifier::Shift), 30.);
+		assert_eq!(integer.nudge_step(NumberInputNudgeModifier::Alt), 1.);
+
+		let multiplier = NumberInput::new(Some(5.)).increment_behavior(NumberInputIncrementBehavior::Multiply).increment_step(2.);
+		assert_eq!(multiplier.nudge_step(NumberInputNudgeModifier::None), 1.);
+		assert_eq!(multiplier.nudge_step(NumberInputNudgeModifier::Shift), 10.);
+	}
+
+	#[test]
+	fn nudged_value_respects_bounds() {
+		let input = NumberInput::new(Some(95.)).min(0.).max(100.);
+		assert_eq!(input.nudged_value(true, NumberInputNudgeModifier::None), Some(96.));
+		assert_eq!(input.nudged_value(true, NumberInputNudgeModifier::Shift), Some(100.));
+		assert_eq!(input.nudged_value(false, NumberInputNudgeModifier::Alt), Some(94.9));
+		assert_eq!(input.clone().value(Some(3.)).nudged_value(false, NumberInputNudgeModifier::Shift), Some(0.));
+		assert_eq!(input.value(None).nudged_value(true, NumberInputNudgeModifier::None), None);
+
+		let integer = NumberInput::new(Some(2.5)).int();
+		assert_eq!(integer.nudged_value(true, NumberInputNudgeModifier::None), Some(4.));
+	}
+
+	#[test]
+	fn log_scale_slider_round_trips_values() {
+		let sigma = NumberInput::default().min(0.).max(10000.).log_scale(true);
+		for value in [0., 0.5, 1., 10., 123.456, 9999., 10000.] {
+			let position = sigma.slider_position(value);
+			assert!((0. ..=1.).contains(&position));
+			let round_trip = sigma.slider_value(position);
+			assert!((round_trip - value).abs() < 1e-9 * value.max(1.), "{value} came back as {round_trip}");
+		}
+
+		let offset = NumberInput::default().min(-50.).max(50.).log_scale(true);
+		assert_eq!(offset.slider_position(-50.), 0.);
+		assert_eq!(offset.slider_position(50.), 1.);
+		assert!((offset.slider_value(offset.slider_position(-12.5)) + 12.5).abs() < 1e-9);
+	}
+
+	#[test]
+	fn log_scale_gives_small_values_more_of_the_slider() {
+		let linear = NumberInput::default().min(0.).max(10000.);
+		let logarithmic = linear.clone().log_scale(true);
+		assert_eq!(linear.slider_position(100.), 0.01);
+		assert!(logarithmic.slider_position(100.) > 0.5);
+		assert!((logarithmic.slider_value(0.5) - (10001_f64.sqrt() - 1.)).abs() < 1e-9);
+	}
+}
@@ -1,5 +1,6 @@
 use crate::messages::frontend::utility_types::{ExportBounds, FileType};
 use crate::messages::input_mapper::utility_types::input_keyboard::Key;
+use crate::messages::portfolio::document::node_graph::OutputExportSettings;
 use crate::messages::portfolio::document::utility_types::layer_panel::LayerMetadata;
 use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, FlipAxis};
 use crate::messages::prelude::*;
@@ -75,6 +76,7 @@ pub enum DocumentMessage {
 	DocumentStructureChanged,
 	DownloadLayerImageOutput {
 		layer_path: Vec<LayerId>,
+		settings: OutputExportSettings,
 	},
 	DuplicateSelectedLayers,
 	ExportDocument {
@@ -106,6 +108,9 @@ pub enum DocumentMessage {
 	InputFrameRasterizeRegionBelowLayer {
 		layer_path: Vec<LayerId>,
 	},
+	InputFrameRefresh {
+		layer_path: Vec<LayerId>,
+	},
 	LayerChanged {
 		affected_layer_path: Vec<LayerId>,
 	},
@@ -6,8 +6,10 @@ use crate::messages::frontend::utility_types::ExportBounds;
 use crate::messages::frontend::utility_types::FileType;
 use crate::messages::input_mapper::utility_types::macros::action_keys;
 use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::portfolio::document::node_graph::migrate_network;
 use crate::messages::portfolio::document::properties_panel::utility_types::PropertiesPanelMessageHandlerData;
 use crate::messages::portfolio::document::utility_types::clipboards::Clipboard;
+use crate::messages::portfolio::document::utility_types::input_frame_cache::{InputFrameCache, InputFrameRequest};
 use crate::messages::portfolio::document::utility_types::layer_panel::{LayerMetadata, LayerPanelEntry, RawBuffer};
 use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, DocumentMode, DocumentSave, FlipAxis};
 use crate::messages::portfolio::document::utility_types::vectorize_layer_metadata;
@@ -19,7 +21,7 @@ use crate::node_graph_executor::NodeGraphExecutor;
 use document_legacy::document::Document as DocumentLegacy;
 use document_legacy::layers::blend_mode::BlendMode;
 use document_legacy::layers::folder_layer::FolderLayer;
-use document_legacy::layers::layer_info::{LayerDataType, LayerDataTypeDiscriminant};
+use document_legacy::layers::layer_info::{Layer, LayerDataType, LayerDataTypeDiscriminant};
 use document_legacy::layers::layer_layer::CachedOutputData;
 use document_legacy::layers::style::{RenderData, ViewMode};
 use document_legacy::{DocumentError, DocumentResponse, LayerId, Operation as DocumentOperation};
@@ -64,6 +66,8 @@ pub struct DocumentMessageHandler {
 	properties_panel_message_handler: PropertiesPanelMessageHandler,
 	#[serde(skip)]
 	node_graph_handler: NodeGraphMessageHandler,
+	#[serde(skip)]
+	pub input_frame_cache: InputFrameCache,
 }
 
 impl Default for DocumentMessageHandler {
@@ -96,6 +100,7 @@ impl Default for DocumentMessageHandler {
 			artboard_message_handler: ArtboardMessageHandler::default(),
 			properties_panel_message_handler: PropertiesPanelMessageHandler::default(),
 			node_graph_handler: Default::default(),
+			input_frame_cache: InputFrameCache::default(),
 		}
 	}
 }
@@ -338,15 +343,47 @@ impl MessageHandler<DocumentMessage, (u64, &InputPreprocessorMessageHandler, &Pe
 				let data_buffer: RawBuffer = self.serialize_root().as_slice().into();
 				responses.add(FrontendMessage::UpdateDocumentLayerTreeStructure { data_buffer })
 			}
-			DownloadLayerImageOutput { layer_path } => {
+			DownloadLayerImageOutput { layer_path, settings } => {
 				let layer = self.document_legacy.layer(&layer_path).ok();
 
-				let layer_name = layer.map(|layer| layer.name.clone().unwrap_or_else(|| "Untitled Layer".to_string()));
+				// Only raster formats can be encoded from the layer's image output, so anything else falls back to PNG
+				let file_type = if settings.file_type == FileType::Svg { FileType::Png } else { settings.file_type };
+
+				let file_suffix = format!(".{file_type:?}").to_lowercase();
+				let layer_name = layer.map(|layer| layer.name.clone().unwrap_or_else(|| "Untitled Layer".to_string()) + &file_suffix);
 
 				let blob_url = layer.and_then(|layer| layer.as_layer().ok()).and_then(|layer_layer| layer_layer.as_blob_url()).cloned();
 
+				let mime = file_type.to_mime().to_string();
+				let quality = settings.quality.clamp(0., 1.);
+
+				let Some((width, height)) = executor.previous_output_dimensions(&layer_path) else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Cannot download the render output".to_string(),
+						description: "The layer hasn't rendered an image yet. Wait for its graph to finish rendering and try again.".to_string(),
+					});
+					return;
+				};
+				// Refuse to resample to a size that would be too large to allocate
+				let Some(size) = settings.scaled_dimensions((width, height)) else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Cannot download the render output".to_string(),
+						description: format!(
+							"At a scale of {}x, the {width}×{height} px render output would be too large to export, or smaller than a pixel. Choose another scale and try again.",
+							settings.scale
+						),
+					});
+					return;
+				};
+
 				if let (Some(layer_name), Some(blob_url)) = (layer_name, blob_url) {
-					responses.add(FrontendMessage::TriggerDownloadBlobUrl { layer_name, blob_url });
+					responses.add(FrontendMessage::TriggerDownloadBlobUrl {
+						layer_name,
+						blob_url,
+						mime,
+						quality,
+						size,
+					});
 				}
 			}
 			DuplicateSelectedLayers => {
@@ -501,6 +538,10 @@ impl MessageHandler<DocumentMessage, (u64, &InputPreprocessorMessageHandler, &Pe
 					responses.add(message);
 				}
 			}
+			InputFrameRefresh { layer_path } => {
+				self.input_frame_cache.invalidate(&layer_path);
+				responses.add(InputFrameRasterizeRegionBelowLayer { layer_path });
+			}
 			LayerChanged { affected_layer_path } => {
 				if let Ok(layer_entry) = self.layer_panel_entry(affected_layer_path.clone(), &render_data) {
 					responses.add(FrontendMessage::UpdateDocumentLayerDetails { data: layer_entry });
@@ -668,6 +709,7 @@ impl MessageHandler<DocumentMessage, (u64, &InputPreprocessorMessageHandler, &Pe
 				responses.add(FrontendMessage::UpdateDocumentArtwork {
 					svg: self.document_legacy.render_root(&render_data),
 				});
+				executor.render_graphic_group(document_id, self.document_legacy.root.transform, ipp.viewport_bounds.size(), responses);
 				responses.add(ArtboardMessage::RenderArtboards);
 
 				let document_transform_scale = self.navigation_handler.snapped_scale();
@@ -1009,8 +1051,19 @@ impl DocumentMessageHandler {
 
 			self.restore_document_transform(old_transforms);
 
-			// Once JS asynchronously rasterizes the SVG, it will call the `PortfolioMessage::RenderGraphUsingRasterizedRegionBelowLayer` message with the rasterized image data
-			FrontendMessage::TriggerRasterizeRegionBelowLayer { document_id, layer_path, svg, size }.into()
+			// Only rasterize again if the artwork below has changed since the last time
+			match self.input_frame_cache.request(&layer_path, InputFrameCache::fingerprint(&svg, size)) {
+				// Once JS asynchronously rasterizes the SVG, it will call the `PortfolioMessage::RasterizedRegionBelowLayer` message with the rasterized image data
+				InputFrameRequest::Rasterize => FrontendMessage::TriggerRasterizeRegionBelowLayer { document_id, layer_path, svg, size }.into(),
+				InputFrameRequest::Reuse(input_image_data, rasterized_size) => PortfolioMessage::RenderGraphUsingRasterizedRegionBelowLayer {
+					document_id,
+					layer_path,
+					input_image_data,
+					size: rasterized_size,
+				}
+				.into(),
+				InputFrameRequest::InFlight => return None,
+			}
 		}
 		// Skip taking a round trip through JS since there's nothing to rasterize, and instead directly call the message which would otherwise be called asynchronously from JS
 		else {
@@ -1115,6 +1168,7 @@ impl DocumentMessageHandler {
 		match Self::deserialize_document(&serialized_content) {
 			Ok(mut document) => {
 				document.name = name;
+				document.migrate_node_networks();
 				Ok(document)
 			}
 			Err(DocumentError::InvalidFile(msg)) => Err(EditorError::DocumentDeserialization(msg)),
@@ -1122,6 +1176,20 @@ impl DocumentMessageHandler {
 		}
 	}
 
+	/// Upgrades the nodes of a document saved by an older version of the editor to the current node inputs.
+	fn migrate_node_networks(&mut self) {
+		fn migrate_layer(layer: &mut Layer) {
+			match &mut layer.data {
+				LayerDataType::Folder(folder) => folder.layers_mut().iter_mut().for_each(migrate_layer),
+				LayerDataType::Layer(layer) => migrate_network(&mut layer.network),
+				LayerDataType::Shape(_) => {}
+			}
+		}
+
+		migrate_network(&mut self.document_legacy.document_network);
+		migrate_layer(&mut self.document_legacy.root);
+	}
+
 	pub fn is_unmodified_default(&self) -> bool {
 		self.serialize_root().len() == Self::default().serialize_root().len()
 			&& self.document_undo_history.is_empty()
@@ -1557,12 +1625,18 @@ impl DocumentMessageHandler {
 					}
 					for node in layer.network.nodes.values() {
 						for input in &node.inputs {
-							if let NodeInput::Value {
-								tagged_value: TaggedValue::Font(font),
-								..
-							} = input
-							{
-								fonts.insert(font.clone());
+							match input {
+								NodeInput::Value {
+									tagged_value: TaggedValue::Font(font),
+									..
+								} => {
+									fonts.insert(font.clone());
+								}
+								NodeInput::Value {
+									tagged_value: TaggedValue::Fonts(fallback_fonts),
+									..
+								} => fonts.extend(fallback_fonts.iter().cloned()),
+								_ => {}
 							}
 						}
 					}
@@ -1910,3 +1984,88 @@ impl DocumentMessageHandler {
 		}
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::messages::portfolio::document::node_graph::new_image_network;
+
+	use document_legacy::layers::style::{Fill, PathStyle};
+	use graphene_core::raster::color::Color;
+
+	fn square_transform(x: f64) -> [f64; 6] {
+		DAffine2::from_scale_angle_translation(DVec2::splat(10.), 0., DVec2::new(x, 0.)).to_cols_array()
+	}
+
+	fn add_square(document: &mut DocumentMessageHandler, id: LayerId) {
+		let style = PathStyle::new(None, Fill::Solid(Color::BLACK));
+		let operation = DocumentOperation::AddRect {
+			path: vec![id],
+			insert_index: -1,
+			transform: square_transform(0.),
+			style,
+		};
+		document.document_legacy.handle_operation(operation).unwrap();
+	}
+
+	fn move_square(document: &mut DocumentMessageHandler, id: LayerId, x: f64) {
+		let operation = DocumentOperation::SetLayerTransform {
+			path: vec![id],
+			transform: square_transform(x),
+		};
+		document.document_legacy.handle_operation(operation).unwrap();
+	}
+
+	#[test]
+	fn only_edits_below_the_layer_rasterize_its_input_frame_again() {
+		let mut document = DocumentMessageHandler::default();
+		add_square(&mut document, 1);
+		let frame = DocumentOperation::AddFrame {
+			path: vec![2],
+			insert_index: -1,
+			transform: DAffine2::from_scale(DVec2::splat(20.)).to_cols_array(),
+			network: new_image_network(8, 0),
+		};
+		document.document_legacy.handle_operation(frame).unwrap();
+		add_square(&mut document, 3);
+
+		let (preferences, persistent_data) = (PreferencesMessageHandler::default(), PersistentData::default());
+		let rasterize = |document: &mut DocumentMessageHandler| document.rasterize_region_below_layer(0, vec![2], &preferences, &persistent_data);
+		let is_rasterization = |message: Option<Message>| matches!(message, Some(Message::Frontend(FrontendMessage::TriggerRasterizeRegionBelowLayer { .. })));
+		let is_reuse = |message: Option<Message>| matches!(message, Some(Message::Portfolio(PortfolioMessage::RenderGraphUsingRasterizedRegionBelowLayer { .. })));
+
+		assert!(is_rasterization(rasterize(&mut document)));
+		assert_eq!(rasterize(&mut document), None, "The artwork is already being rasterized");
+		// JS sends the rasterized image data back
+		document.input_frame_cache.store(&[2], &[0; 4], (1, 1));
+
+		move_square(&mut document, 3, 5.);
+		assert!(is_reuse(rasterize(&mut document)), "Moving a layer above shouldn't rasterize the artwork below again");
+
+		move_square(&mut document, 1, 5.);
+		assert!(is_rasterization(rasterize(&mut document)));
+	}
+
+	#[test]
+	fn opening_an_old_document_migrates_nodes_inside_groups() {
+		use crate::messages::portfolio::document::node_graph::resolve_document_node_type;
+		use graph_craft::document::DocumentNodeImplementation;
+
+		let content = include_str!("../../../../test_files/brightness-contrast-in-group.graphite");
+		let document = DocumentMessageHandler::with_name_and_content("Old".to_string(), content.to_string()).unwrap();
+
+		let LayerDataType::Layer(layer) = &document.document_legacy.layer(&[12825788055422975213]).unwrap().data else {
+			panic!("The fixture should contain a node graph layer")
+		};
+		let DocumentNodeImplementation::Network(group) = &layer.network.nodes[&2].implementation else {
+			panic!("The group should be implemented by a network")
+		};
+		let node = &group.nodes[&0];
+		let node_type = resolve_document_node_type("Brightness/Contrast").unwrap();
+
+		assert_eq!(node.inputs.len(), node_type.inputs.len());
+		assert_eq!(node.inputs[1], NodeInput::value(TaggedValue::F32(20.), false), "Saved values should be kept");
+		assert_eq!(node.inputs[3], NodeInput::value(TaggedValue::Bool(true), false), "Old documents should keep the legacy algorithm");
+		assert_eq!(node.implementation, node_type.generate_implementation(), "The saved implementation only accepts the old inputs");
+	}
+}
@@ -126,6 +126,18 @@ impl<'a> ModifyInputsContext<'a> {
 				Some(NodeInput::value(TaggedValue::IVec2(artboard.dimensions), false)),
 				Some(NodeInput::value(TaggedValue::Color(artboard.background), false)),
 				Some(NodeInput::value(TaggedValue::Bool(artboard.clip), false)),
+				Some(NodeInput::value(TaggedValue::F32(artboard.opacity * 100.), false)),
+				Some(NodeInput::value(TaggedValue::Bool(artboard.label.visible), false)),
+				Some(NodeInput::value(TaggedValue::String(artboard.label.text), false)),
+				Some(NodeInput::value(TaggedValue::F64(artboard.label.font_size), false)),
+				Some(NodeInput::value(TaggedValue::Color(artboard.label.color), false)),
+				Some(NodeInput::value(TaggedValue::F64(artboard.corner_radius), false)),
+				Some(NodeInput::value(TaggedValue::FillType(artboard.background_type), false)),
+				Some(NodeInput::value(TaggedValue::GradientType(artboard.background_gradient.gradient_type), false)),
+				Some(NodeInput::value(TaggedValue::DVec2(artboard.background_gradient.start), false)),
+				Some(NodeInput::value(TaggedValue::DVec2(artboard.background_gradient.end), false)),
+				Some(NodeInput::value(TaggedValue::DAffine2(artboard.background_gradient.transform), false)),
+				Some(NodeInput::value(TaggedValue::GradientPositions(artboard.background_gradient.positions), false)),
 			],
 			Default::default(),
 		);
@@ -205,6 +217,7 @@ impl<'a> ModifyInputsContext<'a> {
 				Fill::None => FillType::None,
 				Fill::Solid(_) => FillType::Solid,
 				Fill::Gradient(_) => FillType::Gradient,
+				Fill::Pattern(_) => FillType::Pattern,
 			};
 			inputs[1] = NodeInput::value(TaggedValue::FillType(fill_type), false);
 			if Fill::None == fill {
@@ -217,6 +230,9 @@ impl<'a> ModifyInputsContext<'a> {
 				inputs[5] = NodeInput::value(TaggedValue::DVec2(gradient.end), false);
 				inputs[6] = NodeInput::value(TaggedValue::DAffine2(gradient.transform), false);
 				inputs[7] = NodeInput::value(TaggedValue::GradientPositions(gradient.positions), false);
+			} else if let Fill::Pattern(pattern) = fill {
+				inputs[8] = NodeInput::value(TaggedValue::ImageFrame(pattern.image), false);
+				inputs[9] = NodeInput::value(TaggedValue::Bool(pattern.repeat), false);
 			}
 		});
 	}
@@ -230,6 +246,15 @@ impl<'a> ModifyInputsContext<'a> {
 			inputs[5] = NodeInput::value(TaggedValue::LineCap(stroke.line_cap), false);
 			inputs[6] = NodeInput::value(TaggedValue::LineJoin(stroke.line_join), false);
 			inputs[7] = NodeInput::value(TaggedValue::F32(stroke.line_join_miter_limit as f32), false);
+			let paint_type = if stroke.gradient.is_some() { FillType::Gradient } else { FillType::Solid };
+			inputs[9] = NodeInput::value(TaggedValue::FillType(paint_type), false);
+			if let Some(gradient) = stroke.gradient {
+				inputs[10] = NodeInput::value(TaggedValue::GradientType(gradient.gradient_type), false);
+				inputs[11] = NodeInput::value(TaggedValue::DVec2(gradient.start), false);
+				inputs[12] = NodeInput::value(TaggedValue::DVec2(gradient.end), false);
+				inputs[13] = NodeInput::value(TaggedValue::DAffine2(gradient.transform), false);
+				inputs[14] = NodeInput::value(TaggedValue::GradientPositions(gradient.positions), false);
+			}
 		});
 	}
 
@@ -1,3 +1,4 @@
+use crate::messages::portfolio::document::node_graph::OutputExportSettings;
 use crate::messages::prelude::*;
 
 use document_legacy::LayerId;
@@ -10,6 +11,8 @@ use graph_craft::document::{DocumentNode, NodeId, NodeInput};
 pub enum NodeGraphMessage {
 	// Messages
 	CloseNodeGraph,
+	CollapseSelectedNodes,
+	CollapseSelectedNodesImpl,
 	ConnectNodesByLink {
 		output_node: u64,
 		output_node_connector_index: usize,
@@ -17,6 +20,10 @@ pub enum NodeGraphMessage {
 		input_node_connector_index: usize,
 	},
 	Copy,
+	CopyInputValue {
+		node_id: NodeId,
+		input_index: usize,
+	},
 	CreateNode {
 		// Having the caller generate the id means that we don't have to return it. This can be a random u64.
 		node_id: Option<NodeId>,
@@ -40,6 +47,12 @@ pub enum NodeGraphMessage {
 	ExitNestedNetwork {
 		depth_of_nesting: usize,
 	},
+	ExpandNode {
+		node_id: NodeId,
+	},
+	ExpandNodeImpl {
+		node_id: NodeId,
+	},
 	ExposeInput {
 		node_id: NodeId,
 		input_index: usize,
@@ -56,6 +69,10 @@ pub enum NodeGraphMessage {
 	OpenNodeGraph {
 		layer_path: Vec<document_legacy::LayerId>,
 	},
+	PasteInputValue {
+		node_id: NodeId,
+		input_index: usize,
+	},
 	PasteNodes {
 		serialized_nodes: String,
 	},
@@ -76,6 +93,9 @@ pub enum NodeGraphMessage {
 		input_index: usize,
 		input: NodeInput,
 	},
+	SetOutputExportSettings {
+		settings: OutputExportSettings,
+	},
 	SetQualifiedInputValue {
 		layer_path: Vec<LayerId>,
 		node_path: Vec<NodeId>,
@@ -85,6 +105,12 @@ pub enum NodeGraphMessage {
 	ShiftNode {
 		node_id: NodeId,
 	},
+	ToggleBypass {
+		node_id: NodeId,
+	},
+	ToggleBypassImpl {
+		node_id: NodeId,
+	},
 	ToggleHidden,
 	ToggleHiddenImpl,
 	TogglePreview {
@@ -1,4 +1,6 @@
 pub use self::document_node_types::*;
+use crate::consts::{OUTPUT_EXPORT_MAX_PIXEL_COUNT, OUTPUT_EXPORT_SCALE_MAX, OUTPUT_EXPORT_SCALE_MIN};
+use crate::messages::frontend::utility_types::FileType;
 use crate::messages::input_mapper::utility_types::macros::action_keys;
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::prelude::*;
@@ -34,6 +36,9 @@ pub enum FrontendGraphDataType {
 	/// Refers to the mathematical vector, with direction and magnitude.
 	#[serde(rename = "vec2")]
 	Vector,
+	/// A list of positions, such as the anchors extracted from vector data.
+	#[serde(rename = "points")]
+	Points,
 	#[serde(rename = "graphic")]
 	GraphicGroup,
 	#[serde(rename = "artboard")]
@@ -46,6 +51,7 @@ impl FrontendGraphDataType {
 			TaggedValue::F32(_) | TaggedValue::F64(_) | TaggedValue::U32(_) | TaggedValue::DAffine2(_) => Self::Number,
 			TaggedValue::Bool(_) => Self::Boolean,
 			TaggedValue::DVec2(_) | TaggedValue::IVec2(_) => Self::Vector,
+			TaggedValue::VectorPoints(_) => Self::Points,
 			TaggedValue::Image(_) => Self::Raster,
 			TaggedValue::ImageFrame(_) => Self::Raster,
 			TaggedValue::Color(_) => Self::Color,
@@ -108,23 +114,98 @@ pub struct FrontendNodeLink {
 pub struct FrontendNodeType {
 	pub name: String,
 	pub category: String,
+	pub description: String,
+	pub keywords: Vec<String>,
 }
 impl FrontendNodeType {
 	pub fn new(name: &'static str, category: &'static str) -> Self {
 		Self {
 			name: name.to_string(),
 			category: category.to_string(),
+			description: String::new(),
+			keywords: Vec::new(),
+		}
+	}
+
+	pub fn from_document_node_type(node_type: &DocumentNodeType) -> Self {
+		Self {
+			description: node_type.description.to_string(),
+			keywords: node_type.keywords.iter().map(|keyword| keyword.to_string()).collect(),
+			..Self::new(node_type.name, node_type.category)
+		}
+	}
+}
+
+/// The encoding used when downloading the rendered image output of a layer's graph from the Output node.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OutputExportSettings {
+	pub file_type: FileType,
+	/// Encoder quality in the range 0 to 1, only used by the lossy formats.
+	pub quality: f64,
+	/// Factor applied to the native resolution of the output image when it is resampled for export.
+	#[serde(default = "OutputExportSettings::default_scale")]
+	pub scale: f64,
+}
+
+impl Default for OutputExportSettings {
+	fn default() -> Self {
+		Self {
+			file_type: FileType::Png,
+			quality: 0.92,
+			scale: Self::default_scale(),
 		}
 	}
 }
 
+impl OutputExportSettings {
+	fn default_scale() -> f64 {
+		1.
+	}
+
+	/// The pixel dimensions of the exported image given the native dimensions of the output, or `None` if the result would be empty or exceed [`OUTPUT_EXPORT_MAX_PIXEL_COUNT`].
+	pub fn scaled_dimensions(&self, (width, height): (u32, u32)) -> Option<(u32, u32)> {
+		let scale = self.scale.clamp(OUTPUT_EXPORT_SCALE_MIN, OUTPUT_EXPORT_SCALE_MAX);
+		let (width, height) = ((width as f64 * scale).round(), (height as f64 * scale).round());
+
+		if width < 1. || height < 1. || width * height > OUTPUT_EXPORT_MAX_PIXEL_COUNT as f64 {
+			return None;
+		}
+		Some((width as u32, height as u32))
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub struct NodeGraphMessageHandler {
 	pub layer_path: Option<Vec<LayerId>>,
 	pub nested_path: Vec<NodeId>,
 	pub selected_nodes: Vec<NodeId>,
+	#[serde(default)]
+	pub output_export: OutputExportSettings,
 	#[serde(skip)]
 	pub widgets: [LayoutGroup; 2],
+	/// The input value last copied from the properties panel, which can be pasted into inputs of a compatible type
+	#[serde(skip)]
+	pub copied_input_value: Option<TaggedValue>,
+}
+
+/// The value to store when pasting `copied` into an input currently holding `target`, or `None` if their types don't match.
+/// Colors also paste between inputs that do and don't allow none, as long as a color is being pasted.
+pub fn pasted_input_value(target: &TaggedValue, copied: &TaggedValue) -> Option<TaggedValue> {
+	match (target, copied) {
+		(TaggedValue::OptionalColor(_), &TaggedValue::Color(color)) => Some(TaggedValue::OptionalColor(Some(color))),
+		(TaggedValue::Color(_), &TaggedValue::OptionalColor(Some(color))) => Some(TaggedValue::Color(color)),
+		_ if std::mem::discriminant(target) == std::mem::discriminant(copied) => Some(copied.clone()),
+		_ => None,
+	}
+}
+
+/// The input that replaces the primary input of a node connected as a lambda, so the node is called with the element it runs on instead of its own value.
+/// Primary inputs that are linked to another node are kept, since their data would otherwise be lost.
+pub fn lambda_call_input(primary_input: &NodeInput) -> Option<NodeInput> {
+	match primary_input {
+		NodeInput::Value { .. } | NodeInput::Network(_) => Some(NodeInput::ShortCircut(primary_input.ty())),
+		_ => None,
+	}
 }
 
 impl NodeGraphMessageHandler {
@@ -159,6 +240,20 @@ impl NodeGraphMessageHandler {
 		self.get_root_network_mut(document).nested_network_mut(&self.nested_path)
 	}
 
+	/// Whether the node can be bypassed, which replaces it with its primary input. Input and output nodes of the network can't be bypassed.
+	fn can_bypass(network: &NodeNetwork, node_id: NodeId) -> bool {
+		if network.inputs.contains(&node_id) || network.original_outputs_contain(node_id) {
+			return false;
+		}
+		let Some(node) = network.nodes.get(&node_id) else { return false };
+		document_node_types::resolve_document_node_type(&node.name).is_some_and(|node_type| node_type.can_bypass(node))
+	}
+
+	/// Whether the node is a group created by collapsing nodes, rather than a node from the library that happens to be implemented by a nested network.
+	fn is_group_node(node: &DocumentNode) -> bool {
+		node.implementation.get_network().is_some() && document_node_types::resolve_document_node_type(&node.name).is_none()
+	}
+
 	/// Send the cached layout to the frontend for the options bar at the top of the node panel
 	fn send_node_bar_layout(&self, responses: &mut VecDeque<Message>) {
 		responses.add(LayoutMessage::SendLayout {
@@ -240,9 +335,28 @@ impl NodeGraphMessageHandler {
 				widgets.push(hide_button);
 			}
 
+			// If several nodes are selected then show the button to collapse them into a group
+			if self.selected_nodes.len() > 1 {
+				let collapse_button = TextButton::new("Collapse")
+					.tooltip("Collapse the selected nodes into a single group node")
+					.on_update(move |_| NodeGraphMessage::CollapseSelectedNodes.into())
+					.widget_holder();
+				widgets.push(collapse_button);
+			}
+
 			// If only one node is selected then show the preview or stop previewing button
 			if self.selected_nodes.len() == 1 {
 				let node_id = self.selected_nodes[0];
+
+				// Show the button to expand a group node back into the nodes it contains
+				if network.nodes.get(&node_id).is_some_and(Self::is_group_node) {
+					let expand_button = TextButton::new("Expand")
+						.tooltip("Replace the group node with the nodes it contains")
+						.on_update(move |_| NodeGraphMessage::ExpandNode { node_id }.into())
+						.widget_holder();
+					widgets.push(expand_button);
+				}
+
 				// Is this node the current output
 				let is_output = network.outputs_contain(node_id);
 
@@ -271,7 +385,7 @@ impl NodeGraphMessageHandler {
 		// If empty, show all nodes in the network starting with the output
 		if self.selected_nodes.is_empty() {
 			for (document_node, node_id) in network.primary_flow().collect::<Vec<_>>().into_iter().rev() {
-				sections.push(node_properties::generate_node_properties(document_node, node_id, context));
+				sections.push(node_properties::generate_node_properties(document_node, node_id, network, context));
 			}
 		}
 		// Show properties for all selected nodes
@@ -280,7 +394,7 @@ impl NodeGraphMessageHandler {
 				continue;
 			};
 
-			sections.push(node_properties::generate_node_properties(document_node, *node_id, context));
+			sections.push(node_properties::generate_node_properties(document_node, *node_id, network, context));
 		}
 	}
 
@@ -316,33 +430,38 @@ impl NodeGraphMessageHandler {
 
 		let mut nodes = Vec::new();
 		for (id, node) in &network.nodes {
-			let Some(node_type) = document_node_types::resolve_document_node_type(&node.name) else {
-				warn!("Node '{}' does not exist in library", node.name);
-				continue;
+			let (input_types, output_types): (Vec<_>, Vec<_>) = match document_node_types::resolve_document_node_type(&node.name) {
+				Some(node_type) => (
+					node_type.inputs.iter().map(|input_type| (input_type.name.to_string(), input_type.data_type)).collect(),
+					node_type.outputs.iter().map(|output_type| (output_type.name.to_string(), output_type.data_type)).collect(),
+				),
+				// Group nodes aren't in the library, so their inputs and outputs are numbered instead
+				None if Self::is_group_node(node) => (
+					(1..=node.inputs.len()).map(|index| (format!("Input {index}"), FrontendGraphDataType::General)).collect(),
+					(1..=node.output_count()).map(|index| (format!("Output {index}"), FrontendGraphDataType::General)).collect(),
+				),
+				None => {
+					warn!("Node '{}' does not exist in library", node.name);
+					continue;
+				}
 			};
 
 			let primary_input = node
 				.inputs
 				.first()
 				.filter(|input| input.is_exposed())
-				.and_then(|_| node_type.inputs.get(0))
-				.map(|input_type| input_type.data_type);
+				.and_then(|_| input_types.first())
+				.map(|(_, data_type)| *data_type);
 			let exposed_inputs = node
 				.inputs
 				.iter()
-				.zip(node_type.inputs.iter())
+				.zip(input_types)
 				.skip(1)
 				.filter(|(input, _)| input.is_exposed())
-				.map(|(_, input_type)| NodeGraphInput {
-					data_type: input_type.data_type,
-					name: input_type.name.to_string(),
-				})
+				.map(|(_, (name, data_type))| NodeGraphInput { data_type, name })
 				.collect();
 
-			let mut outputs = node_type.outputs.iter().map(|output_type| NodeGraphOutput {
-				data_type: output_type.data_type,
-				name: output_type.name.to_string(),
-			});
+			let mut outputs = output_types.into_iter().map(|(name, data_type)| NodeGraphOutput { data_type, name });
 			let primary_output = outputs.next();
 
 			let graph_identifier = GraphIdentifier::new(layer_id);
@@ -357,7 +476,8 @@ impl NodeGraphMessageHandler {
 				exposed_outputs: outputs.collect::<Vec<_>>(),
 				position: node.metadata.position.into(),
 				previewed: network.outputs_contain(*id),
-				disabled: network.disabled.contains(id),
+				// Bypassed nodes are shown like hidden ones since neither affects the output
+				disabled: network.disabled.contains(id) || network.bypassed.contains(id),
 				thumbnail_svg,
 			})
 		}
@@ -418,22 +538,6 @@ impl NodeGraphMessageHandler {
 			false
 		}
 	}
-
-	/// Gets the default node input based on the node name and the input index
-	fn default_node_input(name: String, index: usize) -> Option<NodeInput> {
-		resolve_document_node_type(&name)
-			.and_then(|node| node.inputs.get(index))
-			.map(|input: &DocumentInputType| input.default.clone())
-	}
-
-	/// Returns an iterator of nodes to be copied and their ids, excluding output and input nodes
-	fn copy_nodes<'a>(network: &'a NodeNetwork, new_ids: &'a HashMap<NodeId, NodeId>) -> impl Iterator<Item = (NodeId, DocumentNode)> + 'a {
-		new_ids
-			.iter()
-			.filter(|&(&id, _)| !network.outputs_contain(id))
-			.filter_map(|(&id, &new)| network.nodes.get(&id).map(|node| (new, node.clone())))
-			.map(move |(new, node)| (new, node.map_ids(Self::default_node_input, new_ids)))
-	}
 }
 
 impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &str)> for NodeGraphMessageHandler {
@@ -444,6 +548,25 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 			NodeGraphMessage::CloseNodeGraph => {
 				self.update_layer_path(None, responses);
 			}
+			NodeGraphMessage::CollapseSelectedNodes => {
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(NodeGraphMessage::CollapseSelectedNodesImpl);
+			}
+			NodeGraphMessage::CollapseSelectedNodesImpl => {
+				let Some(network) = self.get_active_network_mut(document) else {
+					warn!("No network");
+					return;
+				};
+				let group_id = crate::application::generate_uuid();
+				if let Err(error) = network.collapse_nodes(&self.selected_nodes, group_id) {
+					warn!("Could not collapse the selected nodes: {error}");
+					return;
+				}
+				Self::send_graph(network, executor, &self.layer_path, responses);
+
+				self.selected_nodes = vec![group_id];
+				self.update_selected(document, responses);
+			}
 			NodeGraphMessage::ConnectNodesByLink {
 				output_node,
 				output_node_connector_index,
@@ -467,9 +590,24 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 
 				responses.add(DocumentMessage::StartTransaction);
 
-				let input = NodeInput::node(output_node, output_node_connector_index);
+				let lambda = document_node_types::resolve_document_node_type(&input_node.name).is_some_and(|node_type| node_type.lambda_inputs.contains(&input_index));
+				let input = NodeInput::Node {
+					node_id: output_node,
+					output_index: output_node_connector_index,
+					lambda,
+				};
 				responses.add(NodeGraphMessage::SetNodeInput { node_id, input_index, input });
 
+				if lambda {
+					if let Some(input) = network.nodes.get(&output_node).and_then(|node| node.inputs.first()).and_then(lambda_call_input) {
+						responses.add(NodeGraphMessage::SetNodeInput {
+							node_id: output_node,
+							input_index: 0,
+							input,
+						});
+					}
+				}
+
 				let should_rerender = network.connected_to_output(node_id);
 				responses.add(NodeGraphMessage::SendGraph { should_rerender });
 			}
@@ -480,8 +618,7 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 				};
 
 				// Collect the selected nodes
-				let new_ids = &self.selected_nodes.iter().copied().enumerate().map(|(new, old)| (old, new as NodeId)).collect();
-				let copied_nodes: Vec<_> = Self::copy_nodes(network, new_ids).collect();
+				let copied_nodes = document_node_types::copy_node_subgraph(network, &self.selected_nodes);
 
 				// Prefix to show that this is nodes
 				let mut copy_text = String::from("graphite/nodes: ");
@@ -489,10 +626,17 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 
 				responses.add(FrontendMessage::TriggerTextCopy { copy_text });
 			}
+			NodeGraphMessage::CopyInputValue { node_id, input_index } => {
+				let Some(network) = self.get_active_network(document) else { return };
+
+				if let Some(NodeInput::Value { tagged_value, .. }) = network.nodes.get(&node_id).and_then(|node| node.inputs.get(input_index)) {
+					self.copied_input_value = Some(tagged_value.clone());
+				}
+			}
 			NodeGraphMessage::CreateNode { node_id, node_type, x, y } => {
 				let node_id = node_id.unwrap_or_else(crate::application::generate_uuid);
 
-				let Some(document_node_type) = document_node_types::resolve_document_node_type(&node_type) else {
+				let Some(document_node_type) = document_node_types::resolve_by_alias(&node_type) else {
 					responses.add(DialogMessage::DisplayDialogError {
 						title: "Cannot insert node".to_string(),
 						description: format!("The document node '{node_type}' does not exist in the document node list"),
@@ -563,6 +707,23 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 				}
 				responses.add(NodeGraphMessage::SetNodeInput { node_id, input_index, input });
 
+				// A node that was connected as a lambda gets its own primary input back
+				if let &NodeInput::Node {
+					node_id: lambda_node_id,
+					lambda: true,
+					..
+				} = existing_input
+				{
+					let lambda_node = network.nodes.get(&lambda_node_id).filter(|node| matches!(node.inputs.first(), Some(NodeInput::ShortCircut(_))));
+					if let Some(input) = lambda_node.and_then(|node| resolve_document_node_type(&node.name)).and_then(|node_type| node_type.inputs.first()) {
+						responses.add(NodeGraphMessage::SetNodeInput {
+							node_id: lambda_node_id,
+							input_index: 0,
+							input: input.default.clone(),
+						});
+					}
+				}
+
 				let should_rerender = network.connected_to_output(node_id);
 				responses.add(NodeGraphMessage::SendGraph { should_rerender });
 			}
@@ -582,11 +743,11 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 				if let Some(network) = self.get_active_network(document) {
 					responses.add(DocumentMessage::StartTransaction);
 
-					let new_ids = &self.selected_nodes.iter().map(|&id| (id, crate::application::generate_uuid())).collect();
+					// Copy the selected nodes
+					let copied_nodes = document_node_types::copy_node_subgraph(network, &self.selected_nodes);
+					let copied_nodes = document_node_types::paste_node_subgraph(network, copied_nodes, crate::application::generate_uuid);
 					self.selected_nodes.clear();
 
-					// Copy the selected nodes
-					let copied_nodes = Self::copy_nodes(network, new_ids).collect::<Vec<_>>();
 					for (node_id, mut document_node) in copied_nodes {
 						// Shift duplicated node
 						document_node.metadata.position += IVec2::splat(2);
@@ -614,6 +775,31 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 				self.collect_nested_addresses(document, document_name, responses);
 				self.update_selected(document, responses);
 			}
+			NodeGraphMessage::ExpandNode { node_id } => {
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(NodeGraphMessage::ExpandNodeImpl { node_id });
+			}
+			NodeGraphMessage::ExpandNodeImpl { node_id } => {
+				let Some(network) = self.get_active_network_mut(document) else {
+					warn!("No network");
+					return;
+				};
+				if !network.nodes.get(&node_id).is_some_and(Self::is_group_node) {
+					warn!("Only group nodes can be expanded");
+					return;
+				}
+				let expanded_nodes = match network.expand_node(node_id, crate::application::generate_uuid) {
+					Ok(expanded_nodes) => expanded_nodes,
+					Err(error) => {
+						warn!("Could not expand the node: {error}");
+						return;
+					}
+				};
+				Self::send_graph(network, executor, &self.layer_path, responses);
+
+				self.selected_nodes = expanded_nodes;
+				self.update_selected(document, responses);
+			}
 			NodeGraphMessage::ExposeInput { node_id, input_index, new_exposed } => {
 				let Some(network) = self.get_active_network(document) else {
 					warn!("No network");
@@ -676,6 +862,22 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 				self.collect_nested_addresses(document, document_name, responses);
 				self.update_selected(document, responses);
 			}
+			NodeGraphMessage::PasteInputValue { node_id, input_index } => {
+				let (Some(copied), Some(network)) = (&self.copied_input_value, self.get_active_network(document)) else {
+					return;
+				};
+				let Some(NodeInput::Value { tagged_value, .. }) = network.nodes.get(&node_id).and_then(|node| node.inputs.get(input_index)) else {
+					return;
+				};
+
+				match pasted_input_value(tagged_value, copied) {
+					Some(value) => responses.add(NodeGraphMessage::SetInputValue { node_id, input_index, value }),
+					None => responses.add(DialogMessage::DisplayDialogError {
+						title: "Cannot paste value".to_string(),
+						description: format!("A copied {} value can't be pasted into this {} input", copied.ty(), tagged_value.ty()),
+					}),
+				}
+			}
 			NodeGraphMessage::PasteNodes { serialized_nodes } => {
 				let Some(network) = self.get_active_network(document) else {
 					warn!("No network");
@@ -705,20 +907,17 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 
 				responses.add(DocumentMessage::StartTransaction);
 
-				let new_ids: HashMap<_, _> = data.iter().map(|&(id, _)| (id, crate::application::generate_uuid())).collect();
-				for (old_id, mut document_node) in data {
+				// Give the pasted nodes new, non-conflicting ids
+				let pasted_nodes = document_node_types::paste_node_subgraph(network, data, crate::application::generate_uuid);
+				let nodes = pasted_nodes.iter().map(|&(node_id, _)| node_id).collect();
+				for (node_id, mut document_node) in pasted_nodes {
 					// Shift copied node
 					document_node.metadata.position += shift;
 
-					// Get the new, non-conflicting id
-					let node_id = *new_ids.get(&old_id).unwrap();
-					document_node = document_node.map_ids(Self::default_node_input, &new_ids);
-
 					// Insert node into network
 					responses.add(NodeGraphMessage::InsertNode { node_id, document_node });
 				}
 
-				let nodes = new_ids.values().copied().collect();
 				responses.add(NodeGraphMessage::SelectNodes { nodes });
 
 				responses.add(NodeGraphMessage::SendGraph { should_rerender: false });
@@ -773,6 +972,10 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 					}
 				}
 			}
+			NodeGraphMessage::SetOutputExportSettings { settings } => {
+				self.output_export = settings;
+				responses.add(PropertiesPanelMessage::ResendActiveProperties);
+			}
 			NodeGraphMessage::SetQualifiedInputValue {
 				layer_path,
 				node_path,
@@ -847,6 +1050,29 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 				}
 				responses.add(NodeGraphMessage::SendGraph { should_rerender: false });
 			}
+			NodeGraphMessage::ToggleBypass { node_id } => {
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(NodeGraphMessage::ToggleBypassImpl { node_id });
+			}
+			NodeGraphMessage::ToggleBypassImpl { node_id } => {
+				if let Some(network) = self.get_active_network_mut(document) {
+					if network.bypassed.contains(&node_id) {
+						network.bypassed.retain(|&id| id != node_id);
+					} else if Self::can_bypass(network, node_id) {
+						network.bypassed.push(node_id);
+					} else {
+						return;
+					}
+					Self::send_graph(network, executor, &self.layer_path, responses);
+
+					if network.connected_to_output(node_id) {
+						if let Some(layer_path) = self.layer_path.clone() {
+							responses.add(DocumentMessage::InputFrameRasterizeRegionBelowLayer { layer_path });
+						}
+					}
+				}
+				self.update_selection_action_buttons(document, responses);
+			}
 			NodeGraphMessage::ToggleHidden => {
 				responses.add(DocumentMessage::StartTransaction);
 				responses.add(NodeGraphMessage::ToggleHiddenImpl);
@@ -920,3 +1146,53 @@ impl MessageHandler<NodeGraphMessage, (&mut Document, &NodeGraphExecutor, u64, &
 		}
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn pasting_input_values_of_the_same_type() {
+		assert_eq!(pasted_input_value(&TaggedValue::F64(1.), &TaggedValue::F64(2.5)), Some(TaggedValue::F64(2.5)));
+		assert_eq!(pasted_input_value(&TaggedValue::Bool(false), &TaggedValue::Bool(true)), Some(TaggedValue::Bool(true)));
+		assert_eq!(
+			pasted_input_value(&TaggedValue::String(String::new()), &TaggedValue::String("Text".to_string())),
+			Some(TaggedValue::String("Text".to_string()))
+		);
+	}
+
+	#[test]
+	fn pasting_input_values_of_another_type_is_rejected() {
+		assert_eq!(pasted_input_value(&TaggedValue::F64(1.), &TaggedValue::Color(Color::BLACK)), None);
+		assert_eq!(pasted_input_value(&TaggedValue::F64(1.), &TaggedValue::F32(1.)), None);
+		assert_eq!(pasted_input_value(&TaggedValue::U32(1), &TaggedValue::F64(1.)), None);
+		assert_eq!(pasted_input_value(&TaggedValue::Color(Color::WHITE), &TaggedValue::String("#000000".to_string())), None);
+	}
+
+	#[test]
+	fn pasting_colors_between_optional_and_required_inputs() {
+		let optional = TaggedValue::OptionalColor(None);
+		let required = TaggedValue::Color(Color::WHITE);
+		assert_eq!(pasted_input_value(&optional, &TaggedValue::Color(Color::BLACK)), Some(TaggedValue::OptionalColor(Some(Color::BLACK))));
+		assert_eq!(pasted_input_value(&required, &TaggedValue::OptionalColor(Some(Color::BLACK))), Some(TaggedValue::Color(Color::BLACK)));
+		assert_eq!(pasted_input_value(&required, &TaggedValue::OptionalColor(None)), None);
+	}
+
+	#[test]
+	fn points_are_sent_to_the_frontend_apart_from_vectors() {
+		assert_eq!(serde_json::to_string(&FrontendGraphDataType::Points).unwrap(), r#""points""#);
+		assert_eq!(serde_json::from_str::<FrontendGraphDataType>(r#""points""#).unwrap(), FrontendGraphDataType::Points);
+		assert_eq!(serde_json::from_str::<FrontendGraphDataType>(r#""vec2""#).unwrap(), FrontendGraphDataType::Vector);
+	}
+
+	#[test]
+	fn lambdas_are_called_with_the_type_of_their_primary_input() {
+		use graph_craft::concrete;
+		use graphene_core::raster::ImageFrame;
+
+		let image = NodeInput::value(TaggedValue::ImageFrame(ImageFrame::empty()), true);
+		assert_eq!(lambda_call_input(&image), Some(NodeInput::ShortCircut(concrete!(ImageFrame<Color>))));
+		assert_eq!(lambda_call_input(&NodeInput::value(TaggedValue::F32(1.), false)), Some(NodeInput::ShortCircut(concrete!(f32))));
+		assert_eq!(lambda_call_input(&NodeInput::node(3, 0)), None);
+	}
+}
@@ -8,19 +8,21 @@ use graph_craft::document::value::*;
 use graph_craft::document::*;
 use graph_craft::imaginate_input::ImaginateSamplingMethod;
 use graph_craft::NodeIdentifier;
+use graphene_core::animation::{AnimationCurve, Keyframe, KeyframeInterpolation};
 #[cfg(feature = "gpu")]
 use graphene_core::application_io::SurfaceHandle;
 use graphene_core::raster::brush_cache::BrushCache;
-use graphene_core::raster::{BlendMode, Color, Image, ImageFrame, LuminanceCalculation, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
-use graphene_core::text::Font;
-use graphene_core::vector::VectorData;
+use graphene_core::raster::{BlendMode, Color, DownresFilter, HueRange, Image, ImageFrame, LuminanceCalculation, RedGreenBlue, RedGreenBlueAlpha, RelativeAbsolute, SelectiveColorChoice, ToneMapping};
+use graphene_core::text::{Font, FontAxis, TextAlign};
+use graphene_core::vector::brush_stroke::BrushMode;
+use graphene_core::vector::{VectorData, VectorPoints};
 use graphene_core::*;
 
 #[cfg(feature = "gpu")]
 use gpu_executor::*;
 use graphene_std::wasm_application_io::WasmEditorApi;
 use once_cell::sync::Lazy;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 #[cfg(feature = "gpu")]
 use wgpu_executor::WgpuExecutor;
 
@@ -71,6 +73,14 @@ pub struct NodePropertiesContext<'a> {
 	pub nested_path: &'a [NodeId],
 	pub executor: &'a mut NodeGraphExecutor,
 	pub network: &'a NodeNetwork,
+	pub output_export: super::OutputExportSettings,
+}
+
+impl NodePropertiesContext<'_> {
+	/// The type of an output of a node in the network shown in the properties panel, as resolved by the last graph evaluation.
+	pub fn output_type_of(&self, node_id: NodeId, output_index: usize) -> Option<Type> {
+		self.executor.output_type_of(self.network, self.nested_path, node_id, output_index)
+	}
 }
 
 #[derive(Clone)]
@@ -96,11 +106,21 @@ impl NodeImplementation {
 pub struct DocumentNodeType {
 	pub name: &'static str,
 	pub category: &'static str,
+	/// A short sentence explaining what the node does, shown alongside it in the node library.
+	pub description: &'static str,
+	/// Alternative terms the node can be found by when searching the node library.
+	pub keywords: &'static [&'static str],
+	/// Other names that unambiguously refer to this node, used when resolving a node by a name other than its own.
+	pub aliases: &'static [&'static str],
 	pub identifier: NodeImplementation,
 	pub inputs: Vec<DocumentInputType>,
 	pub outputs: Vec<DocumentOutputType>,
 	pub primary_output: bool,
+	/// Indices of the inputs that take the connected node itself as a function (a lambda) to call, rather than the value it outputs.
+	pub lambda_inputs: &'static [usize],
 	pub properties: fn(&DocumentNode, NodeId, &mut NodePropertiesContext) -> Vec<LayoutGroup>,
+	/// Upgrades the inputs of a node saved by an older version of the editor, before any missing trailing inputs are filled in with their defaults.
+	pub input_migration: Option<fn(&mut DocumentNode)>,
 }
 
 impl Default for DocumentNodeType {
@@ -108,15 +128,57 @@ impl Default for DocumentNodeType {
 		Self {
 			name: Default::default(),
 			category: Default::default(),
+			description: Default::default(),
+			keywords: &[],
+			aliases: &[],
 			identifier: Default::default(),
 			inputs: Default::default(),
 			outputs: Default::default(),
 			primary_output: Default::default(),
+			lambda_inputs: &[],
 			properties: node_properties::no_properties,
+			input_migration: None,
 		}
 	}
 }
 
+/// The network of a node type that computes a value from its inputs and reads several outputs off of it, like the minimum and maximum of image statistics.
+/// The value is cached so the readouts share a single computation. Such node types have no primary output, so their first output is a placeholder that holds `empty`.
+fn cached_readouts_network(compute: DocumentNode, readouts: &[(&str, &'static str)], empty: TaggedValue) -> NodeNetwork {
+	let cache = DocumentNode {
+		name: "Cache".to_string(),
+		inputs: vec![NodeInput::ShortCircut(concrete!(())), NodeInput::node(0, 0)],
+		implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::memo::MemoNode<_, _>")),
+		..Default::default()
+	};
+	let readout_nodes = readouts.iter().map(|&(name, identifier)| DocumentNode {
+		name: name.to_string(),
+		inputs: vec![NodeInput::node(1, 0)],
+		implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new(identifier)),
+		..Default::default()
+	});
+	let empty_output = DocumentNode {
+		name: "EmptyOutput".to_string(),
+		inputs: vec![NodeInput::value(empty, false)],
+		implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+		..Default::default()
+	};
+	let empty_output_id = readouts.len() as NodeId + 2;
+
+	NodeNetwork {
+		inputs: vec![0; compute.inputs.len()],
+		outputs: [empty_output_id].into_iter().chain(2..empty_output_id).map(|id| NodeOutput::new(id, 0)).collect(),
+		nodes: [compute, cache]
+			.into_iter()
+			.chain(readout_nodes)
+			.chain([empty_output])
+			.enumerate()
+			.map(|(id, node)| (id as NodeId, node))
+			.collect(),
+		..Default::default()
+	}
+}
+
 // We use the once cell for lazy initialization to avoid the overhead of reconstructing the node list every time.
 // TODO: make document nodes not require a `'static` lifetime to avoid having to split the construction into const and non-const parts.
 static DOCUMENT_NODE_TYPES: once_cell::sync::Lazy<Vec<DocumentNodeType>> = once_cell::sync::Lazy::new(static_nodes);
@@ -167,19 +229,21 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Monitor",
 			category: "Structural",
-			identifier: NodeImplementation::proto("graphene_core::ops::IdNode"),
+			identifier: NodeImplementation::proto("graphene_core::memo::MonitorNode<_>"),
 			inputs: vec![DocumentInputType {
 				name: "In",
 				data_type: FrontendGraphDataType::General,
 				default: NodeInput::value(TaggedValue::None, true),
 			}],
 			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::General)],
-			properties: |_document_node, _node_id, _context| node_properties::string_properties("The Monitor node stores the value of its last evaluation"),
+			properties: node_properties::monitor_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
 			name: "Layer",
 			category: "General",
+			description: "Adds its input as a named layer on top of a stack of graphic elements",
+			keywords: &["group", "stack", "composite"],
 			identifier: NodeImplementation::DocumentNode(NodeNetwork {
 				inputs: vec![0; 8],
 				outputs: vec![NodeOutput::new(1, 0)],
@@ -228,37 +292,157 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			properties: node_properties::layer_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Stack",
+			category: "General",
+			description: "Inserts an element as a new layer into a stack of graphic elements, counting its position from the top or bottom of the stack",
+			keywords: &["group", "insert", "append", "prepend"],
+			identifier: NodeImplementation::proto("graphene_core::StackNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Stack", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Element", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Name", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Index", TaggedValue::U32(0), false),
+				DocumentInputType::value("From Top", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::stack_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Reorder",
+			category: "General",
+			description: "Moves an element of a graphic group to a different position in its stack, which changes what is drawn on top",
+			keywords: &["z-order", "arrange", "raise", "lower", "front", "back"],
+			identifier: NodeImplementation::proto("graphene_core::ReorderElementNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("From", TaggedValue::U32(0), false),
+				DocumentInputType::value("To", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::reorder_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Clip",
+			category: "General",
+			description: "Hides the parts of a graphic group that lie outside of a vector shape",
+			keywords: &["mask", "clipping", "crop", "cutout"],
+			identifier: NodeImplementation::proto("graphene_core::ClipNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Mask", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Flatten Group",
+			category: "General",
+			description: "Combines a graphic group into a single element, merging vector shapes or rasterizing the group when it has other content",
+			keywords: &["merge", "combine", "collapse", "single"],
+			identifier: NodeImplementation::proto("graphene_core::FlattenGroupNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Raster Resolution", TaggedValue::F64(1.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::flatten_group_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Ungroup",
+			category: "General",
+			description: "Moves the elements of nested groups up into the graphic group",
+			keywords: &["unnest", "split", "separate", "explode"],
+			identifier: NodeImplementation::proto("graphene_core::UngroupNode"),
+			inputs: vec![DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true)],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Artboard",
 			category: "General",
-			identifier: NodeImplementation::proto("graphene_core::ConstructArtboardNode<_, _, _, _>"),
+			description: "Places a graphic group on a fixed-size region of the canvas with a background color",
+			keywords: &["page", "canvas", "frame", "board"],
+			identifier: NodeImplementation::proto("graphene_core::ConstructArtboardNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
 				DocumentInputType::value("Location", TaggedValue::IVec2(glam::IVec2::ZERO), false),
 				DocumentInputType::value("Dimensions", TaggedValue::IVec2(glam::IVec2::new(1920, 1080)), false),
 				DocumentInputType::value("Background", TaggedValue::Color(Color::WHITE), false),
 				DocumentInputType::value("Clip", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Opacity", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Show Label", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Label", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Label Size", TaggedValue::F64(14.), false),
+				DocumentInputType::value("Label Color", TaggedValue::Color(Color::WHITE), false),
+				DocumentInputType::value("Corner Radius", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Background Type", TaggedValue::FillType(vector::style::FillType::Solid), false),
+				DocumentInputType::value("Gradient Type", TaggedValue::GradientType(vector::style::GradientType::Linear), false),
+				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::new(0., 0.5)), false),
+				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
+				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Some(Color::BLACK)), (1., Some(Color::WHITE))]), false),
 			],
 			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::Artboard)],
 			properties: node_properties::artboard_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Export Artboards",
+			category: "Export",
+			description: "Renders every artboard in a graphic group to its own image",
+			keywords: &["png", "batch", "render", "save"],
+			identifier: NodeImplementation::proto("graphene_std::raster::ExportArtboardsNode"),
+			inputs: vec![DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true)],
+			outputs: vec![DocumentOutputType::new("Images", FrontendGraphDataType::General)],
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Rasterize",
+			category: "Raster",
+			description: "Renders the fills of vector data, or the fills and images of a graphic group, to pixels so raster nodes can process them",
+			keywords: &["pixels", "bitmap", "flatten", "render"],
+			identifier: NodeImplementation::proto("graphene_core::RasterizeNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Resolution", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Background", TaggedValue::OptionalColor(None), false),
+				DocumentInputType::value("Anti-aliasing", TaggedValue::AntiAliasing(graphene_core::renderer::AntiAliasing::default()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::rasterize_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Downres",
 			category: "Raster",
+			description: "Reduces the resolution of an image to match the size it is displayed at",
+			keywords: &["downscale", "downsample", "resolution", "resize", "shrink"],
 			identifier: NodeImplementation::DocumentNode(NodeNetwork {
-				inputs: vec![0],
-				outputs: vec![NodeOutput::new(1, 0)],
+				inputs: vec![0, 1, 1],
+				outputs: vec![NodeOutput::new(2, 0)],
 				nodes: [
+					// Keeps the image before downscaling, so the properties panel can show the original size next to the factor
 					DocumentNode {
-						name: "Downres".to_string(),
+						name: "Frame Monitor".to_string(),
 						inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>))],
-						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::raster::DownresNode<_>")),
+						implementation: DocumentNodeImplementation::proto("graphene_core::memo::MonitorNode<_>"),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Downres".to_string(),
+						inputs: vec![NodeInput::node(0, 0), NodeInput::Network(concrete!(f64)), NodeInput::Network(concrete!(DownresFilter))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::raster::DownresNode<_, _>")),
 						..Default::default()
 					},
 					DocumentNode {
 						name: "Cache".to_string(),
-						inputs: vec![NodeInput::ShortCircut(concrete!(())), NodeInput::node(0, 0)],
+						inputs: vec![NodeInput::ShortCircut(concrete!(())), NodeInput::node(1, 0)],
 						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::memo::MemoNode<_, _>")),
 						..Default::default()
 					},
@@ -276,9 +460,57 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 				.collect(),
 				..Default::default()
 			}),
-			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), false)],
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), false),
+				DocumentInputType::value("Factor", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Filter", TaggedValue::DownresFilter(DownresFilter::Average), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::downres_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Sample Pixel",
+			category: "Raster",
+			description: "Reads the color of an image at a position, blending between neighboring pixels",
+			keywords: &["color picker", "eyedropper", "read", "lookup", "uv"],
+			identifier: NodeImplementation::proto("graphene_std::raster::SamplePixelNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("UV", TaggedValue::DVec2(DVec2::splat(0.5)), false),
+			],
+			outputs: vec![DocumentOutputType::new("Color", FrontendGraphDataType::Color)],
+			properties: node_properties::sample_pixel_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Set Pixel",
+			category: "Raster",
+			description: "Replaces the color of a single pixel in an image",
+			keywords: &["write", "paint", "uv"],
+			identifier: NodeImplementation::proto("graphene_std::raster::SetPixelNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("UV", TaggedValue::DVec2(DVec2::splat(0.5)), false),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::BLACK), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::set_pixel_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Solid Color",
+			category: "Raster",
+			description: "Creates an image of the given resolution filled with a single color",
+			keywords: &["fill", "blank", "canvas", "background"],
+			identifier: NodeImplementation::proto("graphene_std::raster::SolidColorNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("None", TaggedValue::None, false),
+				DocumentInputType::value("Resolution", TaggedValue::UVec2(glam::UVec2::new(1920, 1080)), false),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::WHITE), false),
+			],
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
-			properties: |_document_node, _node_id, _context| node_properties::string_properties("Downres the image to a lower resolution"),
+			properties: node_properties::solid_color_properties,
 			..Default::default()
 		},
 		// DocumentNodeType {
@@ -293,25 +525,95 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		// 	outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::Raster)],
 		// 	properties: node_properties::input_properties,
 		// },
+		DocumentNodeType {
+			name: "Animation Frame",
+			category: "Inputs",
+			description: "Outputs the number of the animation frame being rendered, which is 0 outside of animation, to drive parameters of other nodes over time",
+			keywords: &["time", "animation", "timeline", "t"],
+			identifier: NodeImplementation::proto("graphene_core::FrameNode"),
+			inputs: vec![DocumentInputType {
+				name: "api",
+				data_type: FrontendGraphDataType::General,
+				default: NodeInput::Network(concrete!(WasmEditorApi)),
+			}],
+			outputs: vec![DocumentOutputType::new("Frame", FrontendGraphDataType::Number)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Animation Curve",
+			category: "Inputs",
+			description: "Evaluates a curve of keyframes at the given time, such as the Animation Frame, blending between them with linear, step or eased interpolation",
+			keywords: &["animation", "keyframe", "tween", "easing", "timeline"],
+			identifier: NodeImplementation::proto("graphene_core::animation::AnimationCurveNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Time", TaggedValue::F64(0.), true),
+				DocumentInputType::value(
+					"Keyframes",
+					TaggedValue::AnimationCurve(AnimationCurve::new(vec![
+						Keyframe::new(0., 0., KeyframeInterpolation::Linear),
+						Keyframe::new(60., 100., KeyframeInterpolation::Linear),
+					])),
+					false,
+				),
+			],
+			outputs: vec![DocumentOutputType::new("Value", FrontendGraphDataType::Number)],
+			properties: node_properties::animation_curve_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Input Frame",
 			category: "Ignore",
-			identifier: NodeImplementation::proto("graphene_core::ExtractImageFrame"),
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0],
+				outputs: vec![NodeOutput::new(1, 0), NodeOutput::new(2, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Extract Image Frame".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi))],
+						implementation: DocumentNodeImplementation::proto("graphene_core::ExtractImageFrame"),
+						..Default::default()
+					},
+					// Keeps the last rasterized frame, whose size and transform the properties panel shows and whose emptiness decides the Available output
+					DocumentNode {
+						name: "Frame Monitor".to_string(),
+						inputs: vec![NodeInput::node(0, 0)],
+						implementation: DocumentNodeImplementation::proto("graphene_core::memo::MonitorNode<_>"),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Frame Available".to_string(),
+						inputs: vec![NodeInput::node(1, 0)],
+						implementation: DocumentNodeImplementation::proto("graphene_core::ImageFrameAvailableNode"),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
 			inputs: vec![DocumentInputType {
 				name: "In",
 				data_type: FrontendGraphDataType::General,
 				default: NodeInput::Network(concrete!(WasmEditorApi)),
 			}],
-			outputs: vec![DocumentOutputType {
-				name: "Image Frame",
-				data_type: FrontendGraphDataType::Raster,
-			}],
+			outputs: vec![
+				DocumentOutputType {
+					name: "Image Frame",
+					data_type: FrontendGraphDataType::Raster,
+				},
+				DocumentOutputType::new("Available", FrontendGraphDataType::Boolean),
+			],
 			properties: node_properties::input_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
 			name: "Load Image",
 			category: "Structural",
+			description: "Loads an image from a file path or URL",
+			keywords: &["import", "file", "open", "picture"],
 			identifier: NodeImplementation::DocumentNode(NodeNetwork {
 				inputs: vec![0, 0],
 				outputs: vec![NodeOutput::new(1, 0)],
@@ -354,6 +656,115 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			properties: node_properties::load_image_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Palette Color",
+			category: "General",
+			description: "Picks a color by its index from a palette loaded from an Adobe Swatch Exchange (.ase) or GIMP palette (.gpl) file path or URL",
+			keywords: &["swatches", "ase", "gpl", "import", "colors"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0, 0, 2],
+				outputs: vec![NodeOutput::new(2, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Load Resource".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi)), NodeInput::Network(concrete!(String))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::wasm_application_io::LoadResourceNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Decode Palette".to_string(),
+						inputs: vec![NodeInput::node(0, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::palette::DecodePaletteNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Palette Color".to_string(),
+						inputs: vec![NodeInput::node(1, 0), NodeInput::Network(concrete!(u32))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::palette::PaletteColorNode<_>")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType {
+					name: "api",
+					data_type: FrontendGraphDataType::General,
+					default: NodeInput::Network(concrete!(WasmEditorApi)),
+				},
+				DocumentInputType::value("Palette Path", TaggedValue::String("graphite:null".to_string()), false),
+				DocumentInputType::value("Index", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Color", FrontendGraphDataType::Color)],
+			properties: node_properties::palette_color_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "CSV Column",
+			category: "Data",
+			description: "Loads a CSV file from a file path or URL and outputs the column with the given header as a list of numbers and a list of text, one element per row",
+			keywords: &["spreadsheet", "table", "import", "data driven", "rows"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0, 0, 2],
+				outputs: vec![NodeOutput::new(3, 0), NodeOutput::new(4, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Load Resource".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi)), NodeInput::Network(concrete!(String))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::wasm_application_io::LoadResourceNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Decode CSV".to_string(),
+						inputs: vec![NodeInput::node(0, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::csv::DecodeCsvNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Select Column".to_string(),
+						inputs: vec![NodeInput::node(1, 0), NodeInput::Network(concrete!(String))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::csv::CsvColumnNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Numbers".to_string(),
+						inputs: vec![NodeInput::node(2, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::csv::CsvNumbersNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Text".to_string(),
+						inputs: vec![NodeInput::node(2, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::csv::CsvTextNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType {
+					name: "api",
+					data_type: FrontendGraphDataType::General,
+					default: NodeInput::Network(concrete!(WasmEditorApi)),
+				},
+				DocumentInputType::value("CSV Path", TaggedValue::String("graphite:null".to_string()), false),
+				DocumentInputType::value("Column", TaggedValue::String(String::new()), false),
+			],
+			outputs: vec![
+				DocumentOutputType::new("Numbers", FrontendGraphDataType::General),
+				DocumentOutputType::new("Text", FrontendGraphDataType::General),
+			],
+			properties: node_properties::csv_column_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Create Canvas",
 			category: "Structural",
@@ -535,45 +946,110 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			..Default::default()
 		},
 		DocumentNodeType {
-			name: "Image Frame",
+			name: "Composite Layers",
 			category: "General",
-			identifier: NodeImplementation::proto("graphene_std::raster::ImageFrameNode<_, _>"),
-			inputs: vec![
-				DocumentInputType::value("Image", TaggedValue::Image(Image::empty()), true),
-				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), true),
-			],
-			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
-			properties: |_document_node, _node_id, _context| node_properties::string_properties("Creates an embedded image with the given transform"),
-			..Default::default()
-		},
-		DocumentNodeType {
-			name: "Mask",
-			category: "Image Adjustments",
-			identifier: NodeImplementation::proto("graphene_std::raster::MaskImageNode<_, _, _>"),
-			inputs: vec![
-				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
-				DocumentInputType::value("Stencil", TaggedValue::ImageFrame(ImageFrame::empty()), true),
-			],
-			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
-			properties: node_properties::mask_properties,
-			..Default::default()
-		},
-		DocumentNodeType {
-			name: "Insert Channel",
-			category: "Image Adjustments",
+			description: "Composites up to four images bottom-to-top into a single image, each with its own blend mode and opacity",
+			keywords: &["stack", "flatten", "merge", "blend"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0; 12],
+				outputs: vec![NodeOutput::new(1, 0)],
+				nodes: [
+					(
+						0,
+						DocumentNode {
+							inputs: vec![
+								NodeInput::Network(concrete!(ImageFrame<Color>)),
+								NodeInput::Network(concrete!(BlendMode)),
+								NodeInput::Network(concrete!(f32)),
+								NodeInput::Network(concrete!(ImageFrame<Color>)),
+								NodeInput::Network(concrete!(BlendMode)),
+								NodeInput::Network(concrete!(f32)),
+								NodeInput::Network(concrete!(ImageFrame<Color>)),
+								NodeInput::Network(concrete!(BlendMode)),
+								NodeInput::Network(concrete!(f32)),
+								NodeInput::Network(concrete!(ImageFrame<Color>)),
+								NodeInput::Network(concrete!(BlendMode)),
+								NodeInput::Network(concrete!(f32)),
+							],
+							implementation: DocumentNodeImplementation::proto("graphene_std::raster::CompositeLayerListNode<_, _, _, _, _, _, _, _, _, _, _>"),
+							..Default::default()
+						},
+					),
+					(
+						1,
+						DocumentNode {
+							inputs: vec![NodeInput::node(0, 0)],
+							implementation: DocumentNodeImplementation::proto("graphene_std::raster::CompositeLayersNode"),
+							..Default::default()
+						},
+					),
+				]
+				.into(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Bottom", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Bottom Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Bottom Opacity", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Second", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Second Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Second Opacity", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Third", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Third Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Third Opacity", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Top", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Top Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Top Opacity", TaggedValue::F32(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::composite_layers_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Image Frame",
+			category: "General",
+			identifier: NodeImplementation::proto("graphene_std::raster::ImageFrameNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::Image(Image::empty()), true),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), true),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: |_document_node, _node_id, _context| node_properties::string_properties("Creates an embedded image with the given transform"),
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Mask",
+			category: "Image Adjustments",
+			description: "Hides parts of an image using the luminance of a stencil image",
+			keywords: &["stencil", "cutout", "clip"],
+			identifier: NodeImplementation::proto("graphene_std::raster::MaskImageNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Stencil", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::mask_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Insert Channel",
+			category: "Image Adjustments",
 			identifier: NodeImplementation::proto("graphene_std::raster::InsertChannelNode<_, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
 				DocumentInputType::value("Insertion", TaggedValue::ImageFrame(ImageFrame::empty()), true),
-				DocumentInputType::value("Replace", TaggedValue::RedGreenBlue(RedGreenBlue::Red), false),
+				DocumentInputType::value("Replace", TaggedValue::RedGreenBlueAlpha(RedGreenBlueAlpha::Red), false),
 			],
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
 			properties: node_properties::insert_channel_properties,
+			input_migration: Some(migrate_insert_channel),
 			..Default::default()
 		},
 		DocumentNodeType {
 			name: "Combine Channels",
 			category: "Image Adjustments",
+			description: "Joins separate red, green, blue, and alpha images into one image",
+			keywords: &["rgba", "merge", "compose"],
 			identifier: NodeImplementation::proto("graphene_std::raster::CombineChannelsNode"),
 			inputs: vec![
 				DocumentInputType::value("None", TaggedValue::None, false),
@@ -588,9 +1064,26 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			}],
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Merge RGBA",
+			category: "Image Adjustments",
+			description: "Exactly rebuilds an image from the red, green, blue, and alpha images made by Split RGBA",
+			keywords: &["rgba", "combine", "compose", "lossless"],
+			identifier: NodeImplementation::proto("graphene_std::raster::MergeChannelsNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Red", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Green", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Blue", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Alpha", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Blend",
 			category: "Image Adjustments",
+			description: "Combines two images using a blend mode and opacity",
+			keywords: &["composite", "mix", "merge", "overlay", "layer"],
 			identifier: NodeImplementation::proto("graphene_core::raster::BlendNode<_, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
@@ -605,6 +1098,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Levels",
 			category: "Image Adjustments",
+			description: "Remaps the tonal range of an image with shadow, midtone, and highlight controls",
+			keywords: &["gamma", "black point", "white point", "tone", "curves"],
 			identifier: NodeImplementation::proto("graphene_core::raster::LevelsNode<_, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType {
@@ -642,9 +1137,106 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			properties: node_properties::levels_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Alpha Levels",
+			category: "Image Adjustments",
+			description: "Remaps the transparency of an image with shadow, midtone, and highlight controls, leaving its colors untouched",
+			keywords: &["mask", "transparency", "opacity", "contrast", "matte"],
+			identifier: NodeImplementation::proto("graphene_core::raster::AlphaChannelAdjustNode<graphene_core::raster::LevelsNode<_, _, _, _, _>>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Shadows", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Midtones", TaggedValue::F32(50.), false),
+				DocumentInputType::value("Highlights", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Output Minimums", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Output Maximums", TaggedValue::F32(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::levels_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Adjust Alpha Only",
+			category: "Image Adjustments",
+			description: "Runs the adjustment connected to the Adjustment input on the transparency of the image, seen as a grayscale image, leaving its colors untouched",
+			keywords: &["mask", "transparency", "opacity", "matte", "channel"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0, 1],
+				outputs: vec![NodeOutput::new(0, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Adjust Alpha Only".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>)), NodeInput::lambda(1, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::AlphaChannelAdjustNode<_>")),
+						..Default::default()
+					},
+					// With no adjustment connected, the alpha channel is passed back as is and the image keeps its transparency
+					DocumentNode {
+						name: "Adjustment".to_string(),
+						inputs: vec![NodeInput::ShortCircut(concrete!(ImageFrame<Color>))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::new("Adjustment", FrontendGraphDataType::General, NodeInput::ShortCircut(concrete!(ImageFrame<Color>))),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			lambda_inputs: &[1],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Adjust Colors Only",
+			category: "Image Adjustments",
+			description: "Runs the adjustment connected to the Adjustment input on the colors of the image, keeping its original transparency",
+			keywords: &["rgb", "color", "preserve", "transparency", "channel"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0, 1],
+				outputs: vec![NodeOutput::new(0, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Adjust Colors Only".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>)), NodeInput::lambda(1, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ColorChannelsAdjustNode<_>")),
+						..Default::default()
+					},
+					// With no adjustment connected, the colors come back untouched and the original alpha is restored over them
+					DocumentNode {
+						name: "Adjustment".to_string(),
+						inputs: vec![NodeInput::ShortCircut(concrete!(ImageFrame<Color>))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::new("Adjustment", FrontendGraphDataType::General, NodeInput::ShortCircut(concrete!(ImageFrame<Color>))),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			lambda_inputs: &[1],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Grayscale",
 			category: "Image Adjustments",
+			description: "Converts an image to shades of gray with a per-channel weighting",
+			keywords: &["black and white", "desaturate", "monochrome", "greyscale"],
+			aliases: &["Greyscale", "Desaturate"],
 			identifier: NodeImplementation::proto("graphene_core::raster::GrayscaleNode<_, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType {
@@ -713,6 +1305,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Luminance",
 			category: "Image Adjustments",
+			description: "Replaces each pixel with its luminance",
+			keywords: &["brightness", "lightness", "luma"],
 			identifier: NodeImplementation::proto("graphene_core::raster::LuminanceNode<_>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
@@ -722,6 +1316,156 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			properties: node_properties::luminance_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Luminance to Mask",
+			category: "Image Adjustments",
+			description: "Turns the luminance of each pixel into alpha, producing a mask ready for the Mask node's stencil",
+			keywords: &["stencil", "matte", "alpha", "luma"],
+			identifier: NodeImplementation::proto("graphene_core::raster::LuminanceToMaskNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Luminance Calc", TaggedValue::LuminanceCalculation(LuminanceCalculation::SRGB), false),
+				DocumentInputType::value("Invert", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::luminance_to_mask_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Duotone",
+			category: "Image Adjustments",
+			description: "Maps the shadows of an image to one color and the highlights to another, optionally through a third midtone color",
+			keywords: &["tritone", "gradient map", "tint", "stylize"],
+			aliases: &["Tritone"],
+			identifier: NodeImplementation::proto("graphene_core::raster::DuotoneNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Shadows", TaggedValue::Color(Color::from_rgbf32_unchecked(0.02, 0.05, 0.2)), false),
+				DocumentInputType::value("Highlights", TaggedValue::Color(Color::from_rgbf32_unchecked(1., 0.75, 0.3)), false),
+				DocumentInputType::value("Tritone", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Midtones", TaggedValue::Color(Color::from_rgbf32_unchecked(0.6, 0.2, 0.3)), false),
+				DocumentInputType::value("Luminance Calc", TaggedValue::LuminanceCalculation(LuminanceCalculation::SRGB), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::duotone_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Displace",
+			category: "Image Adjustments",
+			description: "Warps an image by offsetting its pixels with the red and green channels of a displacement map, where 50% gray leaves pixels in place",
+			keywords: &["displacement", "warp", "distort", "offset"],
+			identifier: NodeImplementation::proto("graphene_std::raster::DisplaceNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Map", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Strength", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::displace_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Bloom",
+			category: "Image Adjustments",
+			description: "Makes the bright areas of an image glow by blurring them and adding them back on top",
+			keywords: &["glow", "halo", "highlights", "blur"],
+			aliases: &["Glow"],
+			identifier: NodeImplementation::proto("graphene_std::raster::BloomNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Threshold", TaggedValue::F32(80.), false),
+				DocumentInputType::value("Radius", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Intensity", TaggedValue::F32(50.), false),
+				DocumentInputType::value("Luminance Calc", TaggedValue::LuminanceCalculation(LuminanceCalculation::SRGB), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::bloom_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Lens Distortion",
+			category: "Image Adjustments",
+			description: "Bends an image radially to correct or introduce barrel and pincushion lens distortion",
+			keywords: &["barrel", "pincushion", "fisheye", "warp", "lens correction"],
+			identifier: NodeImplementation::proto("graphene_std::raster::LensDistortionNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("K1", TaggedValue::F64(0.), false),
+				DocumentInputType::value("K2", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Center", TaggedValue::DVec2(DVec2::splat(0.5)), false),
+				DocumentInputType::value("Clamp Edges", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::lens_distortion_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Apply LUT",
+			category: "Image Adjustments",
+			description: "Grades an image with a 3D color lookup table loaded from a .cube file path or URL",
+			keywords: &["lut", "cube", "color grading", "look", "film emulation"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![2, 0, 0, 2],
+				outputs: vec![NodeOutput::new(2, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Load Resource".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi)), NodeInput::Network(concrete!(String))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::wasm_application_io::LoadResourceNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Decode LUT".to_string(),
+						inputs: vec![NodeInput::node(0, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::lut::DecodeCubeLutNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Apply LUT".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>)), NodeInput::node(1, 0), NodeInput::Network(concrete!(f32))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::lut::ApplyLutNode<_, _>")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType {
+					name: "api",
+					data_type: FrontendGraphDataType::General,
+					default: NodeInput::Network(concrete!(WasmEditorApi)),
+				},
+				DocumentInputType::value("LUT Path", TaggedValue::String("graphite:null".to_string()), false),
+				DocumentInputType::value("Intensity", TaggedValue::F32(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::apply_lut_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Film Grain",
+			category: "Image Adjustments",
+			description: "Blends seeded noise over an image, keeping the grain size fixed in document space regardless of the image resolution",
+			keywords: &["noise", "grain", "dither", "texture", "film"],
+			identifier: NodeImplementation::proto("graphene_std::raster::FilmGrainNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Intensity", TaggedValue::F32(25.), false),
+				DocumentInputType::value("Size", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+				DocumentInputType::value("Monochrome", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Overlay), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::film_grain_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Extract Channel",
 			category: "Image Adjustments",
@@ -747,12 +1491,98 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			category: "Image Adjustments",
 			identifier: NodeImplementation::proto("graphene_core::raster::ExtractOpaqueNode<>"),
 			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
-			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Premultiply Alpha",
+			category: "Image Adjustments",
+			description: "Multiplies the color channels by alpha, for images coming from tools that store straight alpha",
+			keywords: &["associate", "straight", "unassociated"],
+			identifier: NodeImplementation::proto("graphene_core::raster::PremultiplyAlphaNode<>"),
+			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Unpremultiply Alpha",
+			category: "Image Adjustments",
+			description: "Divides the color channels by alpha, for tools that expect straight alpha",
+			keywords: &["unassociate", "straight", "associated", "demultiply"],
+			identifier: NodeImplementation::proto("graphene_core::raster::UnpremultiplyAlphaNode<>"),
+			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Split Channels",
+			category: "Image Adjustments",
+			description: "Splits an image into separate red, green, blue, and alpha images",
+			keywords: &["rgba", "separate", "decompose"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0],
+				outputs: vec![NodeOutput::new(4, 0), NodeOutput::new(1, 0), NodeOutput::new(2, 0), NodeOutput::new(3, 0), NodeOutput::new(4, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Identity".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "RedNode".to_string(),
+						inputs: vec![NodeInput::node(0, 0), NodeInput::value(TaggedValue::RedGreenBlue(RedGreenBlue::Red), false)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ExtractChannelNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "GreenNode".to_string(),
+						inputs: vec![NodeInput::node(0, 0), NodeInput::value(TaggedValue::RedGreenBlue(RedGreenBlue::Green), false)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ExtractChannelNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "BlueNode".to_string(),
+						inputs: vec![NodeInput::node(0, 0), NodeInput::value(TaggedValue::RedGreenBlue(RedGreenBlue::Blue), false)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ExtractChannelNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "AlphaNode".to_string(),
+						inputs: vec![NodeInput::node(0, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ExtractAlphaNode<>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "EmptyOutput".to_string(),
+						inputs: vec![NodeInput::value(TaggedValue::ImageFrame(ImageFrame::empty()), false)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+
+				..Default::default()
+			}),
+			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
+			outputs: vec![
+				DocumentOutputType::new("Empty", FrontendGraphDataType::Raster),
+				DocumentOutputType::new("Red", FrontendGraphDataType::Raster),
+				DocumentOutputType::new("Green", FrontendGraphDataType::Raster),
+				DocumentOutputType::new("Blue", FrontendGraphDataType::Raster),
+				DocumentOutputType::new("Alpha", FrontendGraphDataType::Raster),
+			],
+			primary_output: false,
 			..Default::default()
 		},
 		DocumentNodeType {
-			name: "Split Channels",
+			name: "Split RGBA",
 			category: "Image Adjustments",
+			description: "Splits an image into gray images holding the exact red, green, blue, and alpha values, which Merge RGBA recombines without loss",
+			keywords: &["rgba", "separate", "decompose", "lossless"],
 			identifier: NodeImplementation::DocumentNode(NodeNetwork {
 				inputs: vec![0],
 				outputs: vec![NodeOutput::new(4, 0), NodeOutput::new(1, 0), NodeOutput::new(2, 0), NodeOutput::new(3, 0), NodeOutput::new(4, 0)],
@@ -766,19 +1596,19 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 					DocumentNode {
 						name: "RedNode".to_string(),
 						inputs: vec![NodeInput::node(0, 0), NodeInput::value(TaggedValue::RedGreenBlue(RedGreenBlue::Red), false)],
-						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ExtractChannelNode<_>")),
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::SplitChannelNode<_>")),
 						..Default::default()
 					},
 					DocumentNode {
 						name: "GreenNode".to_string(),
 						inputs: vec![NodeInput::node(0, 0), NodeInput::value(TaggedValue::RedGreenBlue(RedGreenBlue::Green), false)],
-						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ExtractChannelNode<_>")),
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::SplitChannelNode<_>")),
 						..Default::default()
 					},
 					DocumentNode {
 						name: "BlueNode".to_string(),
 						inputs: vec![NodeInput::node(0, 0), NodeInput::value(TaggedValue::RedGreenBlue(RedGreenBlue::Blue), false)],
-						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::ExtractChannelNode<_>")),
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::SplitChannelNode<_>")),
 						..Default::default()
 					},
 					DocumentNode {
@@ -812,20 +1642,56 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			primary_output: false,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Image Statistics",
+			category: "Image Adjustments",
+			description: "Measures the minimum, maximum, and average color of an image",
+			keywords: &["histogram", "stats", "average", "mean", "min", "max", "auto levels"],
+			identifier: NodeImplementation::DocumentNode(cached_readouts_network(
+				DocumentNode {
+					name: "Image Stats".to_string(),
+					inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>))],
+					implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_std::raster::ImageStatsNode")),
+					..Default::default()
+				},
+				&[
+					("Min", "graphene_std::raster::ImageStatsMinNode"),
+					("Max", "graphene_std::raster::ImageStatsMaxNode"),
+					("Mean", "graphene_std::raster::ImageStatsMeanNode"),
+				],
+				TaggedValue::ImageFrame(ImageFrame::empty()),
+			)),
+			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
+			outputs: vec![
+				DocumentOutputType::new("Empty", FrontendGraphDataType::Raster),
+				DocumentOutputType::new("Min", FrontendGraphDataType::Color),
+				DocumentOutputType::new("Max", FrontendGraphDataType::Color),
+				DocumentOutputType::new("Mean", FrontendGraphDataType::Color),
+			],
+			primary_output: false,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Brush",
 			category: "Brush",
-			identifier: NodeImplementation::proto("graphene_std::brush::BrushNode<_, _, _>"),
+			description: "Paints brush strokes onto an image",
+			keywords: &["paint", "draw", "stroke", "sketch"],
+			identifier: NodeImplementation::proto("graphene_std::brush::BrushNode<_, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Background", TaggedValue::ImageFrame(ImageFrame::empty()), true),
 				DocumentInputType::value("Bounds", TaggedValue::ImageFrame(ImageFrame::empty()), true),
 				DocumentInputType::value("Trace", TaggedValue::BrushStrokes(Vec::new()), false),
 				DocumentInputType::value("Cache", TaggedValue::BrushCache(BrushCache::new_proto()), false),
+				DocumentInputType::value("Mode", TaggedValue::BrushMode(BrushMode::Paint), false),
+				DocumentInputType::value("Override Style", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Spacing", TaggedValue::F64(50.), false),
+				DocumentInputType::value("Hardness", TaggedValue::F64(50.), false),
 			],
 			outputs: vec![DocumentOutputType {
 				name: "Image",
 				data_type: FrontendGraphDataType::Raster,
 			}],
+			properties: node_properties::brush_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
@@ -835,8 +1701,28 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			inputs: vec![DocumentInputType::value("VectorData", TaggedValue::VectorData(VectorData::empty()), true)],
 			outputs: vec![DocumentOutputType {
 				name: "Vector Points",
-				data_type: FrontendGraphDataType::General,
+				data_type: FrontendGraphDataType::Points,
+			}],
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Scatter",
+			category: "Brush",
+			description: "Stamps a round brush tip at every point, such as the anchors from Extract Vector Points",
+			keywords: &["stamp", "dots", "points"],
+			identifier: NodeImplementation::proto("graphene_std::brush::ScatterNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Background", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Points", TaggedValue::VectorPoints(VectorPoints::default()), true),
+				DocumentInputType::value("Diameter", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Hardness", TaggedValue::F64(70.), false),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::BLACK), false),
+			],
+			outputs: vec![DocumentOutputType {
+				name: "Image",
+				data_type: FrontendGraphDataType::Raster,
 			}],
+			properties: node_properties::scatter_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
@@ -1491,28 +2377,62 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Invert RGB",
 			category: "Image Adjustments",
-			identifier: NodeImplementation::proto("graphene_core::raster::InvertRGBNode"),
-			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
+			description: "Inverts the color channels of an image",
+			keywords: &["negative", "inverse", "invert"],
+			aliases: &["Invert"],
+			identifier: NodeImplementation::proto("graphene_core::raster::InvertRGBNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Red", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Green", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Blue", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Alpha", TaggedValue::Bool(false), false),
+			],
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::invert_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
 			name: "Hue/Saturation",
 			category: "Image Adjustments",
-			identifier: NodeImplementation::proto("graphene_core::raster::HueSaturationNode<_, _, _>"),
+			description: "Shifts the hue, saturation, and lightness of an image",
+			keywords: &["hsl", "hue", "saturation", "lightness", "color"],
+			aliases: &["Hue", "Saturation"],
+			identifier: NodeImplementation::proto("graphene_core::raster::HueSaturationNode<_, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
 				DocumentInputType::value("Hue Shift", TaggedValue::F32(0.), false),
 				DocumentInputType::value("Saturation Shift", TaggedValue::F32(0.), false),
 				DocumentInputType::value("Lightness Shift", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Colorize", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Range", TaggedValue::HueRange(HueRange::Master), false),
 			],
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
 			properties: node_properties::adjust_hsl_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "OkLCh Adjust",
+			category: "Image Adjustments",
+			description: "Shifts the lightness, chroma, and hue of an image in the perceptual OkLCh color space, giving cleaner hue shifts than Hue/Saturation",
+			keywords: &["oklab", "oklch", "perceptual", "hue", "chroma", "lightness", "color"],
+			identifier: NodeImplementation::proto("graphene_core::raster::OkLchAdjustNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Lightness Shift", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Chroma Shift", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Hue Shift", TaggedValue::F32(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::adjust_oklch_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Brightness/Contrast",
 			category: "Image Adjustments",
+			description: "Adjusts the brightness and contrast of an image",
+			keywords: &["brightness", "contrast", "lighten", "darken"],
+			aliases: &["Brightness", "Contrast"],
 			identifier: NodeImplementation::proto("graphene_core::raster::BrightnessContrastNode<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
@@ -1522,17 +2442,21 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			],
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
 			properties: node_properties::brightness_contrast_properties,
+			input_migration: Some(migrate_brightness_contrast),
 			..Default::default()
 		},
 		DocumentNodeType {
 			name: "Threshold",
 			category: "Image Adjustments",
-			identifier: NodeImplementation::proto("graphene_core::raster::ThresholdNode<_, _, _>"),
+			description: "Turns pixels within a luminance range white and the rest black",
+			keywords: &["black and white", "binarize", "cutoff"],
+			identifier: NodeImplementation::proto("graphene_core::raster::ThresholdNode<_, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
 				DocumentInputType::value("Min Luminance", TaggedValue::F32(50.), false),
 				DocumentInputType::value("Max Luminance", TaggedValue::F32(100.), false),
 				DocumentInputType::value("Luminance Calc", TaggedValue::LuminanceCalculation(LuminanceCalculation::SRGB), false),
+				DocumentInputType::value("Softness", TaggedValue::F32(0.), false),
 			],
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
 			properties: node_properties::adjust_threshold_properties,
@@ -1541,6 +2465,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Vibrance",
 			category: "Image Adjustments",
+			description: "Boosts the saturation of muted colors while protecting saturated ones",
+			keywords: &["saturation", "intensity"],
 			identifier: NodeImplementation::proto("graphene_core::raster::VibranceNode<_>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
@@ -1553,6 +2479,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Channel Mixer",
 			category: "Image Adjustments",
+			description: "Builds each color channel from a weighted mix of the others",
+			keywords: &["channels", "swap", "mix"],
 			identifier: NodeImplementation::proto("graphene_core::raster::ChannelMixerNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
@@ -1588,6 +2516,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Selective Color",
 			category: "Image Adjustments",
+			description: "Adjusts the amount of cyan, magenta, yellow, and black in specific color ranges",
+			keywords: &["cmyk", "color correction"],
 			identifier: NodeImplementation::proto(
 				"graphene_core::raster::SelectiveColorNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>",
 			),
@@ -1650,10 +2580,13 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Opacity",
 			category: "Image Adjustments",
-			identifier: NodeImplementation::proto("graphene_core::raster::OpacityNode<_>"),
+			description: "Multiplies the alpha of an image",
+			keywords: &["transparency", "alpha", "fade"],
+			identifier: NodeImplementation::proto("graphene_core::raster::OpacityNode<_, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
 				DocumentInputType::value("Factor", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Premultiplied", TaggedValue::Bool(false), false),
 			],
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
 			properties: node_properties::multiply_opacity,
@@ -1662,6 +2595,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Posterize",
 			category: "Image Adjustments",
+			description: "Reduces the number of tonal levels in each channel",
+			keywords: &["quantize", "banding", "reduce colors"],
 			identifier: NodeImplementation::proto("graphene_core::raster::PosterizeNode<_>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
@@ -1674,6 +2609,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Exposure",
 			category: "Image Adjustments",
+			description: "Adjusts exposure, offset, and gamma like a camera would",
+			keywords: &["gamma", "offset", "brighten", "stops"],
 			identifier: NodeImplementation::proto("graphene_core::raster::ExposureNode<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
@@ -1685,6 +2622,67 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			properties: node_properties::exposure_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "White Balance",
+			category: "Image Adjustments",
+			description: "Neutralizes a color cast using a color in the image that should be gray",
+			keywords: &["color cast", "temperature", "neutral", "match color"],
+			identifier: NodeImplementation::proto("graphene_core::raster::WhiteBalanceNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Neutral Reference", TaggedValue::Color(Color::from_rgbf32_unchecked(0.5, 0.5, 0.5)), false),
+				DocumentInputType::value("Strength", TaggedValue::F32(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::white_balance_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Temperature/Tint",
+			category: "Image Adjustments",
+			description: "Warms or cools an image by re-lighting it with a black body of a different color temperature, and shifts it between green and magenta",
+			keywords: &["warmth", "kelvin", "white balance", "color cast"],
+			aliases: &["Temperature", "Tint"],
+			identifier: NodeImplementation::proto("graphene_core::raster::TemperatureTintNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Temperature", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Tint", TaggedValue::F32(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::temperature_tint_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Clamp Color",
+			category: "Image Adjustments",
+			description: "Brings out-of-range color values back into range, optionally rolling off highlights smoothly",
+			keywords: &["clip", "hdr", "tone mapping", "reinhard", "filmic", "gamut"],
+			identifier: NodeImplementation::proto("graphene_core::raster::ClampColorNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Min", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Max", TaggedValue::F32(1.), false),
+				DocumentInputType::value("Rolloff", TaggedValue::ToneMapping(ToneMapping::None), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::clamp_color_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Flatten Alpha",
+			category: "Image Adjustments",
+			description: "Composites an image over a solid background color to remove its transparency",
+			keywords: &["matte", "solidify", "opaque", "background", "jpeg"],
+			identifier: NodeImplementation::proto("graphene_core::raster::FlattenAlphaNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Background", TaggedValue::Color(Color::WHITE), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::flatten_alpha_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Add",
 			category: "Math",
@@ -1802,10 +2800,29 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			properties: node_properties::no_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Switch by Index",
+			category: "Logic",
+			description: "Passes through one of its inputs, chosen by the index. An index past the last input chooses the last input",
+			keywords: &["mux", "multiplexer", "select", "choose", "branch"],
+			identifier: NodeImplementation::proto("graphene_core::logic::MuxNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Input 0", TaggedValue::F64(0.), true),
+				DocumentInputType::value("Index", TaggedValue::U32(0), false),
+				DocumentInputType::value("Input 1", TaggedValue::F64(0.), true),
+				DocumentInputType::value("Input 2", TaggedValue::F64(0.), true),
+				DocumentInputType::value("Input 3", TaggedValue::F64(0.), true),
+			],
+			outputs: vec![DocumentOutputType::new("Output", FrontendGraphDataType::General)],
+			properties: node_properties::mux_properties,
+			..Default::default()
+		},
 		(*IMAGINATE_NODE).clone(),
 		DocumentNodeType {
 			name: "Unit Circle Generator",
 			category: "Vector",
+			description: "Generates a circle with a radius of one",
+			keywords: &["ellipse", "circle", "round"],
 			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::UnitCircleGenerator"),
 			inputs: vec![DocumentInputType::none()],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
@@ -1814,6 +2831,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Shape",
 			category: "Vector",
+			description: "Creates vector data from a set of subpaths",
+			keywords: &["path", "vector", "bezier"],
 			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::PathGenerator<_>"),
 			inputs: vec![
 				DocumentInputType::value("Path Data", TaggedValue::Subpaths(vec![]), false),
@@ -1825,12 +2844,22 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Text",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::text::TextGenerator<_, _, _>"),
+			description: "Generates vector shapes from a string of text in a given font",
+			keywords: &["font", "type", "typography", "words"],
+			identifier: NodeImplementation::proto("graphene_core::text::TextGenerator<_, _, _, _, _, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::none(),
 				DocumentInputType::value("Text", TaggedValue::String("hello world".to_string()), false),
 				DocumentInputType::value("Font", TaggedValue::Font(Font::new(DEFAULT_FONT_FAMILY.into(), DEFAULT_FONT_STYLE.into())), false),
 				DocumentInputType::value("Size", TaggedValue::F64(24.), false),
+				DocumentInputType::value("Line Height", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Letter Spacing", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Max Width", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Align", TaggedValue::TextAlign(TextAlign::Left), false),
+				DocumentInputType::value("Fallback Fonts", TaggedValue::Fonts(Vec::new()), false),
+				DocumentInputType::value("Weight", TaggedValue::F64(FontAxis::Weight.regular_value()), false),
+				DocumentInputType::value("Width", TaggedValue::F64(FontAxis::Width.regular_value()), false),
+				DocumentInputType::value("Slant", TaggedValue::F64(FontAxis::Slant.regular_value()), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			properties: node_properties::node_section_font,
@@ -1839,6 +2868,8 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 		DocumentNodeType {
 			name: "Transform",
 			category: "Transform",
+			description: "Moves, rotates, scales, and skews its input",
+			keywords: &["move", "rotate", "scale", "translate", "skew"],
 			identifier: NodeImplementation::proto("graphene_core::transform::TransformNode<_, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
@@ -1848,82 +2879,364 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 				DocumentInputType::value("Skew", TaggedValue::DVec2(DVec2::ZERO), false),
 				DocumentInputType::value("Pivot", TaggedValue::DVec2(DVec2::splat(0.5)), false),
 			],
-			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
-			properties: node_properties::transform_properties,
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
+			properties: node_properties::transform_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "SetTransform",
+			category: "Transform",
+			identifier: NodeImplementation::proto("graphene_core::transform::SetTransformNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), true),
+			],
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Fill",
+			category: "Vector",
+			description: "Sets the fill of vector data to a solid color, gradient, or image pattern",
+			keywords: &["color", "gradient", "paint", "pattern", "texture"],
+			identifier: NodeImplementation::proto("graphene_core::vector::SetFillNode<_, _, _, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Fill Type", TaggedValue::FillType(vector::style::FillType::None), false),
+				DocumentInputType::value("Solid Color", TaggedValue::OptionalColor(None), false),
+				DocumentInputType::value("Gradient Type", TaggedValue::GradientType(vector::style::GradientType::Linear), false),
+				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::new(0., 0.5)), false),
+				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
+				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Some(Color::BLACK)), (1., Some(Color::WHITE))]), false),
+				DocumentInputType::value("Pattern Image", TaggedValue::ImageFrame(ImageFrame::empty()), false),
+				DocumentInputType::value("Repeat", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::fill_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Stroke",
+			category: "Vector",
+			description: "Sets the stroke of vector data to a solid color or gradient",
+			keywords: &["outline", "border", "line", "width", "gradient"],
+			identifier: NodeImplementation::proto("graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Color", TaggedValue::OptionalColor(Some(Color::BLACK)), false),
+				DocumentInputType::value("Weight", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Dash Lengths", TaggedValue::VecF32(Vec::new()), false),
+				DocumentInputType::value("Dash Offset", TaggedValue::F32(0.), false),
+				DocumentInputType::value("Line Cap", TaggedValue::LineCap(graphene_core::vector::style::LineCap::Butt), false),
+				DocumentInputType::value("Line Join", TaggedValue::LineJoin(graphene_core::vector::style::LineJoin::Miter), false),
+				DocumentInputType::value("Miter Limit", TaggedValue::F32(4.), false),
+				DocumentInputType::value("Alignment", TaggedValue::StrokeAlign(graphene_core::vector::style::StrokeAlign::Center), false),
+				DocumentInputType::value("Paint Type", TaggedValue::FillType(vector::style::FillType::Solid), false),
+				DocumentInputType::value("Gradient Type", TaggedValue::GradientType(vector::style::GradientType::Linear), false),
+				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::new(0., 0.5)), false),
+				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
+				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Some(Color::BLACK)), (1., Some(Color::WHITE))]), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::stroke_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Style Layers",
+			category: "Vector",
+			description: "Draws extra fills and strokes beneath the fill and stroke of vector data",
+			keywords: &["appearance", "multiple", "fills", "strokes", "outline"],
+			identifier: NodeImplementation::proto("graphene_core::vector::SetStyleLayersNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Layers", TaggedValue::StyleLayers(Vec::new()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::style_layers_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Repeat",
+			category: "Vector",
+			description: "Repeats vector data along a direction",
+			keywords: &["array", "duplicate", "copy", "clone"],
+			identifier: NodeImplementation::proto("graphene_core::vector::RepeatNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Direction", TaggedValue::DVec2((100., 0.).into()), false),
+				DocumentInputType::value("Count", TaggedValue::U32(10), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::repeat_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Mirror",
+			category: "Vector",
+			description: "Reflects vector data across an axis, optionally keeping the original for a symmetric shape",
+			keywords: &["symmetry", "reflect", "flip"],
+			identifier: NodeImplementation::proto("graphene_core::vector::MirrorNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Axis", TaggedValue::MirrorAxis(graphene_core::vector::MirrorAxis::Vertical), false),
+				DocumentInputType::value("Axis Position", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Axis Angle", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Keep Original", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::mirror_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Trim Path",
+			category: "Vector",
+			description: "Keeps the part of each subpath between a start and end fraction of its length",
+			keywords: &["trim paths", "reveal", "draw on", "dash", "length"],
+			identifier: NodeImplementation::proto("graphene_core::vector::TrimPathNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Start", TaggedValue::F64(0.), false),
+				DocumentInputType::value("End", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Offset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Wrap", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::trim_path_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Dash to Geometry",
+			category: "Vector",
+			description: "Cuts paths into a separate subpath per dash, so the dashes don't rely on the stroke's dash pattern",
+			keywords: &["dashes", "dashed", "stroke", "expand", "bake"],
+			identifier: NodeImplementation::proto("graphene_core::vector::DashToGeometryNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Dash Lengths", TaggedValue::VecF32(vec![10., 10.]), false),
+				DocumentInputType::value("Dash Offset", TaggedValue::F32(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::dash_to_geometry_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Bounding Box",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::BoundingBoxNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Bounds",
+			category: "Vector",
+			description: "Measures the corners, center, and size of the bounding box of vector data in document space",
+			keywords: &["bounding box", "min", "max", "center", "size", "dimensions"],
+			identifier: NodeImplementation::DocumentNode(cached_readouts_network(
+				DocumentNode {
+					name: "Bounds".to_string(),
+					inputs: vec![NodeInput::Network(concrete!(VectorData))],
+					implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::vector::BoundsNode")),
+					..Default::default()
+				},
+				&[
+					("Min", "graphene_core::vector::BoundsMinNode"),
+					("Max", "graphene_core::vector::BoundsMaxNode"),
+					("Center", "graphene_core::vector::BoundsCenterNode"),
+					("Size", "graphene_core::vector::BoundsSizeNode"),
+				],
+				TaggedValue::VectorData(VectorData::empty()),
+			)),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true)],
+			outputs: vec![
+				DocumentOutputType::new("Empty", FrontendGraphDataType::Subpath),
+				DocumentOutputType::new("Min", FrontendGraphDataType::Vector),
+				DocumentOutputType::new("Max", FrontendGraphDataType::Vector),
+				DocumentOutputType::new("Center", FrontendGraphDataType::Vector),
+				DocumentOutputType::new("Size", FrontendGraphDataType::Vector),
+			],
+			primary_output: false,
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "For Each Subpath",
+			category: "Vector",
+			description: "Runs the node connected to the Function input on each subpath of vector data separately, then combines the results",
+			keywords: &["map", "loop", "iterate", "individually"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0, 1],
+				outputs: vec![NodeOutput::new(0, 0)],
+				nodes: [
+					DocumentNode {
+						name: "For Each Subpath".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(VectorData)), NodeInput::lambda(1, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::vector::ForEachSubpathNode<_>")),
+						..Default::default()
+					},
+					// Without a connected Function, every subpath comes out as it went in, so the combined result equals the input shape
+					DocumentNode {
+						name: "Function".to_string(),
+						inputs: vec![NodeInput::ShortCircut(concrete!(VectorData))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::new("Function", FrontendGraphDataType::General, NodeInput::ShortCircut(concrete!(VectorData))),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			lambda_inputs: &[1],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeType {
+			name: "Map",
+			category: "Array",
+			description: "Runs the node connected to the Function input on each element of a list, giving a list of the results",
+			keywords: &["list", "array", "loop", "each", "batch"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0, 1],
+				outputs: vec![NodeOutput::new(0, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Map".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(Vec<ImageFrame<Color>>)), NodeInput::lambda(1, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::MapNode<_>")),
+						..Default::default()
+					},
+					// An identity placeholder for the Function input, so mapping without a connected function returns a copy of the list
+					DocumentNode {
+						name: "Function".to_string(),
+						inputs: vec![NodeInput::ShortCircut(concrete!(ImageFrame<Color>))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("List", TaggedValue::Segments(Vec::new()), true),
+				DocumentInputType::new("Function", FrontendGraphDataType::General, NodeInput::ShortCircut(concrete!(ImageFrame<Color>))),
+			],
+			outputs: vec![DocumentOutputType::new("List", FrontendGraphDataType::General)],
+			lambda_inputs: &[1],
+			properties: node_properties::no_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
-			name: "SetTransform",
-			category: "Transform",
-			identifier: NodeImplementation::proto("graphene_core::transform::SetTransformNode<_>"),
+			name: "Reduce",
+			category: "Array",
+			description: "Combines the elements of a list into one, starting from the initial value and running the node connected to the Function input on the result so far and each element",
+			keywords: &["list", "array", "fold", "combine", "accumulate"],
+			identifier: NodeImplementation::DocumentNode(NodeNetwork {
+				inputs: vec![0, 0, 1],
+				outputs: vec![NodeOutput::new(0, 0)],
+				nodes: [
+					DocumentNode {
+						name: "Reduce".to_string(),
+						inputs: vec![
+							NodeInput::Network(concrete!(Vec<ImageFrame<Color>>)),
+							NodeInput::Network(concrete!(ImageFrame<Color>)),
+							NodeInput::lambda(1, 0),
+						],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::ReduceNode<_, _>")),
+						..Default::default()
+					},
+					// Keeps the last element until a node is connected to the Function input
+					DocumentNode {
+						name: "Function".to_string(),
+						inputs: vec![NodeInput::ShortCircut(concrete!((ImageFrame<Color>, ImageFrame<Color>)))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::SndNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (id as NodeId, node))
+				.collect(),
+				..Default::default()
+			}),
 			inputs: vec![
-				DocumentInputType::value("Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), true),
+				DocumentInputType::value("List", TaggedValue::Segments(Vec::new()), true),
+				DocumentInputType::value("Initial", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::new("Function", FrontendGraphDataType::General, NodeInput::ShortCircut(concrete!((ImageFrame<Color>, ImageFrame<Color>)))),
 			],
-			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
+			outputs: vec![DocumentOutputType::new("Result", FrontendGraphDataType::General)],
+			lambda_inputs: &[2],
+			properties: node_properties::no_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
-			name: "Fill",
+			name: "Path to Points",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::SetFillNode<_, _, _, _, _, _, _>"),
-			inputs: vec![
-				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Fill Type", TaggedValue::FillType(vector::style::FillType::None), false),
-				DocumentInputType::value("Solid Color", TaggedValue::OptionalColor(None), false),
-				DocumentInputType::value("Gradient Type", TaggedValue::GradientType(vector::style::GradientType::Linear), false),
-				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::new(0., 0.5)), false),
-				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
-				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
-				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Some(Color::BLACK)), (1., Some(Color::WHITE))]), false),
-			],
-			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::fill_properties,
+			description: "Extracts the anchor points of vector data in document space",
+			keywords: &["anchors", "vertices", "extract"],
+			identifier: NodeImplementation::proto("graphene_core::vector::PathToPointsNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Points", FrontendGraphDataType::Points)],
+			properties: node_properties::no_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
-			name: "Stroke",
+			name: "Points to Path",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _>"),
+			description: "Connects points in order into a path, optionally smoothed into a Catmull-Rom spline",
+			keywords: &["connect", "polyline", "spline", "curve"],
+			identifier: NodeImplementation::proto("graphene_core::vector::PointsToPathNode<_, _>"),
 			inputs: vec![
-				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Color", TaggedValue::OptionalColor(Some(Color::BLACK)), false),
-				DocumentInputType::value("Weight", TaggedValue::F32(0.), false),
-				DocumentInputType::value("Dash Lengths", TaggedValue::VecF32(Vec::new()), false),
-				DocumentInputType::value("Dash Offset", TaggedValue::F32(0.), false),
-				DocumentInputType::value("Line Cap", TaggedValue::LineCap(graphene_core::vector::style::LineCap::Butt), false),
-				DocumentInputType::value("Line Join", TaggedValue::LineJoin(graphene_core::vector::style::LineJoin::Miter), false),
-				DocumentInputType::value("Miter Limit", TaggedValue::F32(4.), false),
+				DocumentInputType::value("Points", TaggedValue::VectorPoints(VectorPoints::default()), true),
+				DocumentInputType::value("Closed", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Smooth", TaggedValue::Bool(false), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::stroke_properties,
+			properties: node_properties::points_to_path_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
-			name: "Repeat",
+			name: "Convex Hull",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::RepeatNode<_, _>"),
-			inputs: vec![
-				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Direction", TaggedValue::DVec2((100., 0.).into()), false),
-				DocumentInputType::value("Count", TaggedValue::U32(10), false),
-			],
+			description: "Wraps vector data in the smallest convex shape containing it",
+			keywords: &["hull", "wrap", "outline", "bounds", "collision"],
+			identifier: NodeImplementation::proto("graphene_core::vector::ConvexHullNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::repeat_properties,
+			properties: node_properties::no_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
-			name: "Bounding Box",
+			name: "Delaunay Triangulation",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::BoundingBoxNode"),
-			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			description: "Connects the points of vector data into triangles, or surrounds each point with its Voronoi cell",
+			keywords: &["voronoi", "triangulate", "mesh", "cells", "generative"],
+			identifier: NodeImplementation::proto("graphene_core::vector::DelaunayNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Voronoi", TaggedValue::Bool(false), false),
+			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::no_properties,
+			properties: node_properties::delaunay_properties,
 			..Default::default()
 		},
 		DocumentNodeType {
 			name: "Circular Repeat",
 			category: "Vector",
+			description: "Repeats vector data around a circle",
+			keywords: &["radial", "array", "rotate", "duplicate"],
 			identifier: NodeImplementation::proto("graphene_core::vector::CircularRepeatNode<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
@@ -1935,6 +3248,41 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 			properties: node_properties::circle_repeat_properties,
 			..Default::default()
 		},
+		DocumentNodeType {
+			name: "Measure",
+			category: "Vector",
+			description: "Measures the length and enclosed area of vector data, in total and for each of its subpaths",
+			keywords: &["length", "area", "perimeter", "size"],
+			identifier: NodeImplementation::DocumentNode(cached_readouts_network(
+				DocumentNode {
+					name: "Measure".to_string(),
+					inputs: vec![NodeInput::Network(concrete!(VectorData)), NodeInput::Network(concrete!(bool))],
+					implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::vector::MeasureNode<_>")),
+					..Default::default()
+				},
+				&[
+					("Length", "graphene_core::vector::MeasuredLengthNode"),
+					("Area", "graphene_core::vector::MeasuredAreaNode"),
+					("Subpath Lengths", "graphene_core::vector::MeasuredSubpathLengthsNode"),
+					("Subpath Areas", "graphene_core::vector::MeasuredSubpathAreasNode"),
+				],
+				TaggedValue::VectorData(VectorData::empty()),
+			)),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Close Open Subpaths", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![
+				DocumentOutputType::new("Empty", FrontendGraphDataType::Subpath),
+				DocumentOutputType::new("Length", FrontendGraphDataType::Number),
+				DocumentOutputType::new("Area", FrontendGraphDataType::Number),
+				DocumentOutputType::new("Subpath Lengths", FrontendGraphDataType::General),
+				DocumentOutputType::new("Subpath Areas", FrontendGraphDataType::General),
+			],
+			primary_output: false,
+			properties: node_properties::measure_properties,
+			..Default::default()
+		},
 		DocumentNodeType {
 			name: "Image Segmentation",
 			category: "Image Adjustments",
@@ -1964,9 +3312,11 @@ fn static_nodes() -> Vec<DocumentNodeType> {
 pub static IMAGINATE_NODE: Lazy<DocumentNodeType> = Lazy::new(|| DocumentNodeType {
 	name: "Imaginate",
 	category: "Image Synthesis",
+	description: "Generates images with Stable Diffusion from a prompt and an optional input image",
+	keywords: &["ai", "stable diffusion", "generate", "synthesis"],
 	identifier: NodeImplementation::DocumentNode(NodeNetwork {
-		inputs: vec![0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
-		outputs: vec![NodeOutput::new(1, 0)],
+		inputs: vec![0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+		outputs: vec![NodeOutput::new(2, 0), NodeOutput::new(1, 0)],
 		nodes: [
 			(
 				0,
@@ -2000,8 +3350,23 @@ pub static IMAGINATE_NODE: Lazy<DocumentNodeType> = Lazy::new(|| DocumentNodeTyp
 						NodeInput::Network(concrete!(ImaginateMaskStartingFill)),
 						NodeInput::Network(concrete!(bool)),
 						NodeInput::Network(concrete!(bool)),
+						NodeInput::Network(concrete!(u32)),
+						NodeInput::Network(concrete!(ImageFrame<Color>)),
+						NodeInput::Network(concrete!(ImaginateControlType)),
+						NodeInput::Network(concrete!(f64)),
+						NodeInput::Network(concrete!(f32)),
 					],
-					implementation: DocumentNodeImplementation::proto("graphene_std::raster::ImaginateNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
+					implementation: DocumentNodeImplementation::proto("graphene_std::raster::ImaginateNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
+					..Default::default()
+				},
+			),
+			// The first variation of the batch, which is the only one unless a batch count is set
+			(
+				2,
+				DocumentNode {
+					name: "First Variation".into(),
+					inputs: vec![NodeInput::node(1, 0), NodeInput::value(TaggedValue::U32(0), false)],
+					implementation: DocumentNodeImplementation::proto("graphene_core::raster::IndexNode<_>"),
 					..Default::default()
 				},
 			),
@@ -2032,8 +3397,16 @@ pub static IMAGINATE_NODE: Lazy<DocumentNodeType> = Lazy::new(|| DocumentNodeTyp
 		DocumentInputType::value("Mask Starting Fill", TaggedValue::ImaginateMaskStartingFill(ImaginateMaskStartingFill::Fill), false),
 		DocumentInputType::value("Improve Faces", TaggedValue::Bool(false), false),
 		DocumentInputType::value("Tiling", TaggedValue::Bool(false), false),
+		DocumentInputType::value("Batch Count", TaggedValue::U32(1), false),
+		DocumentInputType::value("Control Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+		DocumentInputType::value("Control Type", TaggedValue::ImaginateControlType(ImaginateControlType::Depth), false),
+		DocumentInputType::value("Variation Seed", TaggedValue::F64(0.), false),
+		DocumentInputType::value("Variation Strength", TaggedValue::F32(0.), false),
+	],
+	outputs: vec![
+		DocumentOutputType::new("Image", FrontendGraphDataType::Raster),
+		DocumentOutputType::new("Variations", FrontendGraphDataType::General),
 	],
-	outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
 	properties: node_properties::imaginate_properties,
 	..Default::default()
 });
@@ -2042,15 +3415,78 @@ pub fn resolve_document_node_type(name: &str) -> Option<&DocumentNodeType> {
 	DOCUMENT_NODE_TYPES.iter().find(|node| node.name == name)
 }
 
+/// Resolves a node type by its exact name, falling back to a case-insensitive match against each node's aliases.
+pub fn resolve_by_alias(name: &str) -> Option<&DocumentNodeType> {
+	resolve_document_node_type(name).or_else(|| DOCUMENT_NODE_TYPES.iter().find(|node| node.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name.trim()))))
+}
+
 pub fn collect_node_types() -> Vec<FrontendNodeType> {
 	DOCUMENT_NODE_TYPES
 		.iter()
 		.filter(|node_type| !node_type.category.eq_ignore_ascii_case("ignore"))
-		.map(|node_type| FrontendNodeType::new(node_type.name, node_type.category))
+		.map(FrontendNodeType::from_document_node_type)
 		.collect()
 }
 
+/// Collects the node types shown in the node library, grouped by category in the order each category first appears.
+pub fn collect_node_types_by_category() -> Vec<(&'static str, Vec<FrontendNodeType>)> {
+	let mut categories: Vec<(&'static str, Vec<FrontendNodeType>)> = Vec::new();
+
+	for node_type in DOCUMENT_NODE_TYPES.iter().filter(|node_type| !node_type.category.eq_ignore_ascii_case("ignore")) {
+		let frontend_node_type = FrontendNodeType::from_document_node_type(node_type);
+		match categories.iter_mut().find(|(category, _)| *category == node_type.category) {
+			Some((_, node_types)) => node_types.push(frontend_node_type),
+			None => categories.push((node_type.category, vec![frontend_node_type])),
+		}
+	}
+
+	categories
+}
+
+/// Searches the node library by name, keyword, and category, returning the matches ordered from best to worst.
+pub fn search_node_types(query: &str) -> Vec<&'static DocumentNodeType> {
+	let query = query.trim().to_lowercase();
+	if query.is_empty() {
+		return Vec::new();
+	}
+
+	let mut matches: Vec<_> = DOCUMENT_NODE_TYPES
+		.iter()
+		.filter(|node_type| !node_type.category.eq_ignore_ascii_case("ignore"))
+		.filter_map(|node_type| node_type.search_rank(&query).map(|rank| (rank, node_type)))
+		.collect();
+	matches.sort_by_key(|&(rank, node_type)| (rank, node_type.name));
+
+	matches.into_iter().map(|(_, node_type)| node_type).collect()
+}
+
 impl DocumentNodeType {
+	/// How well this node type matches a lowercase search query, where a lower rank is a better match, or `None` if it doesn't match at all.
+	fn search_rank(&self, query: &str) -> Option<u8> {
+		let name = self.name.to_lowercase();
+		let words = || name.split(|c: char| !c.is_alphanumeric());
+
+		if name == query {
+			Some(0)
+		} else if name.starts_with(query) {
+			Some(1)
+		} else if words().any(|word| word.starts_with(query)) {
+			Some(2)
+		} else if self.aliases.iter().chain(self.keywords).any(|keyword| keyword.eq_ignore_ascii_case(query)) {
+			Some(3)
+		} else if name.contains(query) {
+			Some(4)
+		} else if self.keywords.iter().any(|keyword| keyword.to_lowercase().starts_with(query)) {
+			Some(5)
+		} else if self.category.to_lowercase().contains(query) {
+			Some(6)
+		} else if is_subsequence(query, &name) {
+			Some(7)
+		} else {
+			None
+		}
+	}
+
 	/// Generate a [`DocumentNodeImplementation`] from this node type, using a nested network.
 	pub fn generate_implementation(&self) -> DocumentNodeImplementation {
 		// let num_inputs = self.inputs.len();
@@ -2086,10 +3522,21 @@ impl DocumentNodeType {
 		DocumentNodeImplementation::Network(inner_network)
 	}
 
-	/// Converts the [DocumentNodeType] type to a [DocumentNode], based on the inputs from the graph (which must be the correct length) and the metadata
+	/// Converts the [DocumentNodeType] type to a [DocumentNode], based on the inputs from the graph and the metadata.
+	/// Inputs that don't match the required number are padded with defaults or truncated, with a warning, so a stale node can't crash the editor.
 	pub fn to_document_node(&self, inputs: impl IntoIterator<Item = NodeInput>, metadata: graph_craft::document::DocumentNodeMetadata) -> DocumentNode {
-		let inputs: Vec<_> = inputs.into_iter().collect();
-		assert_eq!(inputs.len(), self.inputs.len(), "Inputs passed from the graph must be equal to the number required");
+		let mut inputs: Vec<_> = inputs.into_iter().collect();
+		if inputs.len() != self.inputs.len() {
+			warn!(
+				"The '{}' node was given {} inputs but requires {}, so its inputs were {} to match",
+				self.name,
+				inputs.len(),
+				self.inputs.len(),
+				if inputs.len() < self.inputs.len() { "padded with defaults" } else { "truncated" }
+			);
+			inputs.truncate(self.inputs.len());
+			inputs.extend(self.inputs.iter().skip(inputs.len()).map(|input| input.default.clone()));
+		}
 		DocumentNode {
 			name: self.name.to_string(),
 			inputs,
@@ -2099,6 +3546,35 @@ impl DocumentNodeType {
 		}
 	}
 
+	/// Upgrades a node loaded from an older document to the current inputs of this node type.
+	/// Runs the node type's own migration and then appends the defaults of any inputs that were added since the node was saved.
+	/// The saved implementation can't accept the changed inputs, so it is replaced by the current one whenever the inputs were changed.
+	/// It is also replaced when its network has a different number of outputs than the node type, such as an Input Frame saved before its "Available" output was added.
+	pub fn migrate_inputs(&self, node: &mut DocumentNode) {
+		let saved_inputs = node.inputs.clone();
+		if let Some(input_migration) = self.input_migration {
+			input_migration(node);
+		}
+
+		let missing_inputs = self.inputs.iter().skip(node.inputs.len());
+		node.inputs.extend(missing_inputs.map(|input| input.default.clone()));
+
+		let outputs_changed = matches!(&node.implementation, DocumentNodeImplementation::Network(network) if network.outputs.len() != self.outputs.len());
+		if node.inputs != saved_inputs || outputs_changed {
+			node.implementation = self.generate_implementation();
+		}
+	}
+
+	/// Whether `node`, which is of this type, can be bypassed, which passes its primary input straight through to its output.
+	/// That needs a single output of the same type as the primary input, and no other inputs exposed on the node whose data would silently be dropped.
+	pub fn can_bypass(&self, node: &DocumentNode) -> bool {
+		let [output] = self.outputs.as_slice() else { return false };
+		let Some(primary) = self.inputs.first() else { return false };
+		let Some((_, secondary)) = node.inputs.split_first() else { return false };
+
+		primary.data_type == output.data_type && !secondary.iter().any(|input| input.is_exposed())
+	}
+
 	/// Converts the [DocumentNodeType] type to a [DocumentNode], using the provided `input_override` and falling back to the default inputs.
 	/// `input_override` does not have to be the correct length.
 	pub fn to_document_node_default_inputs(&self, input_override: impl IntoIterator<Item = Option<NodeInput>>, metadata: graph_craft::document::DocumentNodeMetadata) -> DocumentNode {
@@ -2108,7 +3584,88 @@ impl DocumentNodeType {
 	}
 }
 
+/// Brightness/Contrast originally only had the legacy algorithm, so nodes saved before the "Use Legacy" input existed keep using it.
+fn migrate_brightness_contrast(node: &mut DocumentNode) {
+	if node.inputs.len() == 3 {
+		node.inputs.push(NodeInput::value(TaggedValue::Bool(true), false));
+	}
+}
+
+/// Insert Channel could only target the red, green, or blue channel before alpha was added, so its channel was saved as a [RedGreenBlue].
+fn migrate_insert_channel(node: &mut DocumentNode) {
+	if let Some(&NodeInput::Value {
+		tagged_value: TaggedValue::RedGreenBlue(channel),
+		exposed,
+	}) = node.inputs.get(2)
+	{
+		node.inputs[2] = NodeInput::value(TaggedValue::RedGreenBlueAlpha(channel.into()), exposed);
+	}
+}
+
+/// Migrates every node in the network to the current inputs of its node type.
+/// The networks of nodes that aren't in the library, such as groups, hold nodes placed by the user and are migrated too.
+/// The networks of library nodes are internal to the node type's implementation, which is regenerated if its inputs or outputs changed.
+pub fn migrate_network(network: &mut NodeNetwork) {
+	for node in network.nodes.values_mut() {
+		if let Some(node_type) = resolve_document_node_type(&node.name) {
+			node_type.migrate_inputs(node);
+		} else if let DocumentNodeImplementation::Network(nested_network) = &mut node.implementation {
+			migrate_network(nested_network);
+		}
+	}
+}
+
+/// The default value of an input of a node type, used in place of links to nodes that weren't copied along with the node.
+/// Falls back to an empty value for nodes that aren't in the library, such as groups.
+fn default_node_input(name: String, index: usize) -> Option<NodeInput> {
+	let default = resolve_document_node_type(&name).and_then(|node_type| node_type.inputs.get(index)).map(|input| input.default.clone());
+	Some(default.unwrap_or_else(|| NodeInput::value(TaggedValue::None, false)))
+}
+
+/// Copies the given nodes out of the network along with the links between them, keyed by their ids in the network.
+/// Links to nodes outside of the copied set are replaced by the default input. The network's input and output nodes can't be copied.
+pub fn copy_node_subgraph(network: &NodeNetwork, node_ids: &[NodeId]) -> Vec<(NodeId, DocumentNode)> {
+	let copied_ids = node_ids
+		.iter()
+		.copied()
+		.filter(|&id| !network.inputs.contains(&id) && !network.original_outputs_contain(id))
+		.filter(|id| network.nodes.contains_key(id))
+		.map(|id| (id, id))
+		.collect::<HashMap<_, _>>();
+
+	let mut copied_nodes = copied_ids
+		.keys()
+		.map(|id| (*id, network.nodes[id].clone().map_ids(default_node_input, &copied_ids)))
+		.collect::<Vec<_>>();
+	copied_nodes.sort_by_key(|&(id, _)| id);
+	copied_nodes
+}
+
+/// Gives copied nodes new ids from `gen_id` that don't collide with the nodes already in the network, remapping the links between them.
+/// Links to nodes that aren't part of the pasted nodes are replaced by the default input.
+pub fn paste_node_subgraph(network: &NodeNetwork, nodes: Vec<(NodeId, DocumentNode)>, gen_id: impl Fn() -> NodeId) -> Vec<(NodeId, DocumentNode)> {
+	let mut new_ids = HashMap::new();
+	for &(old_id, _) in &nodes {
+		let new_id = std::iter::repeat_with(&gen_id)
+			.find(|new_id| !network.nodes.contains_key(new_id) && !new_ids.values().any(|used_id| used_id == new_id))
+			.unwrap();
+		new_ids.insert(old_id, new_id);
+	}
+
+	nodes.into_iter().map(|(old_id, node)| (new_ids[&old_id], node.map_ids(default_node_input, &new_ids))).collect()
+}
+
+/// Whether every character of `query` appears in `text` in the same order, allowing for gaps (e.g. "hsat" in "hue/saturation").
+fn is_subsequence(query: &str, text: &str) -> bool {
+	let mut text = text.chars();
+	query.chars().filter(|c| !c.is_whitespace()).all(|query_char| text.any(|text_char| text_char == query_char))
+}
+
 pub fn wrap_network_in_scope(mut network: NodeNetwork) -> NodeNetwork {
+	for diagnostic in network.break_cycles() {
+		warn!("{} (node {} in network {:?})", diagnostic.message, diagnostic.node_id, diagnostic.network_path);
+	}
+
 	network.generate_node_paths(&[]);
 
 	let node_ids = network.nodes.keys().copied().collect::<Vec<_>>();
@@ -2218,12 +3775,12 @@ pub fn new_text_network(text: String, font: Font, size: f64) -> NodeNetwork {
 		..Default::default()
 	};
 	network.push_node(
-		text_generator.to_document_node(
+		text_generator.to_document_node_default_inputs(
 			[
-				NodeInput::Network(concrete!(WasmEditorApi)),
-				NodeInput::value(TaggedValue::String(text), false),
-				NodeInput::value(TaggedValue::Font(font), false),
-				NodeInput::value(TaggedValue::F64(size), false),
+				Some(NodeInput::Network(concrete!(WasmEditorApi))),
+				Some(NodeInput::value(TaggedValue::String(text), false)),
+				Some(NodeInput::value(TaggedValue::Font(font), false)),
+				Some(NodeInput::value(TaggedValue::F64(size), false)),
 			],
 			DocumentNodeMetadata::position((0, 4)),
 		),
@@ -2235,3 +3792,370 @@ pub fn new_text_network(text: String, font: Font, size: f64) -> NodeNetwork {
 	network.push_node(output.to_document_node_default_inputs([None], Default::default()), true);
 	network
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn imaginate_network_json_round_trip() {
+		let NodeImplementation::DocumentNode(network) = &IMAGINATE_NODE.identifier else {
+			panic!("The Imaginate node should be implemented by a network");
+		};
+
+		let json = network.to_json().expect("Failed to serialize the Imaginate network");
+		let deserialized = NodeNetwork::from_json(&json).expect("Failed to deserialize the Imaginate network");
+
+		assert_eq!(&deserialized, network);
+		assert_eq!(deserialized.to_json().unwrap(), json, "Serializing the same network twice should produce identical JSON");
+	}
+
+	#[test]
+	fn search_finds_nodes_by_keyword() {
+		let results = search_node_types("gamma");
+		assert_eq!(results.first().map(|node_type| node_type.name), Some("Levels"));
+	}
+
+	#[test]
+	fn search_ranks_exact_names_before_keywords() {
+		let names: Vec<_> = search_node_types("layer").into_iter().map(|node_type| node_type.name).collect();
+		let position = |name| names.iter().position(|&result| result == name).unwrap();
+
+		assert_eq!(names[0], "Layer");
+		assert!(position("Layer") < position("Blend"), "Matching a keyword should rank below matching the name");
+	}
+
+	#[test]
+	fn aliases_resolve_to_the_combined_node() {
+		assert_eq!(resolve_by_alias("brightness").map(|node_type| node_type.name), Some("Brightness/Contrast"));
+		assert_eq!(resolve_by_alias("Contrast").map(|node_type| node_type.name), Some("Brightness/Contrast"));
+		assert!(resolve_by_alias("Not A Node").is_none());
+	}
+
+	#[test]
+	fn exact_names_win_over_aliases() {
+		for node_type in DOCUMENT_NODE_TYPES.iter() {
+			assert_eq!(resolve_by_alias(node_type.name).map(|resolved| resolved.name), Some(node_type.name));
+		}
+	}
+
+	#[test]
+	fn migrate_brightness_contrast_missing_use_legacy() {
+		let node_type = resolve_document_node_type("Brightness/Contrast").unwrap();
+		let mut node = node_type.to_document_node_default_inputs([], DocumentNodeMetadata::default());
+		node.inputs.truncate(3);
+
+		node_type.migrate_inputs(&mut node);
+
+		assert_eq!(node.inputs.len(), node_type.inputs.len());
+		assert_eq!(node.inputs[3], NodeInput::value(TaggedValue::Bool(true), false), "Old documents should keep the legacy algorithm");
+	}
+
+	#[test]
+	fn migrate_network_fills_missing_inputs_with_defaults() {
+		let node_type = resolve_document_node_type("Levels").unwrap();
+		let mut node = node_type.to_document_node_default_inputs([], DocumentNodeMetadata::default());
+		node.inputs.truncate(1);
+		let mut network = NodeNetwork {
+			nodes: [(0, node)].into_iter().collect(),
+			..Default::default()
+		};
+
+		migrate_network(&mut network);
+
+		let defaults: Vec<_> = node_type.inputs.iter().map(|input| input.default.clone()).collect();
+		assert_eq!(network.nodes[&0].inputs, defaults);
+	}
+
+	#[test]
+	fn migrate_network_regenerates_outdated_input_frame() {
+		let node_type = resolve_document_node_type("Input Frame").unwrap();
+		let mut node = node_type.to_document_node_default_inputs([], DocumentNodeMetadata::default());
+		let DocumentNodeImplementation::Network(saved_network) = &mut node.implementation else {
+			panic!("Input Frame should be implemented by a network")
+		};
+		saved_network.outputs.truncate(1);
+		saved_network.nodes.remove(&2);
+		let mut network = NodeNetwork {
+			nodes: [(0, node)].into_iter().collect(),
+			..Default::default()
+		};
+
+		migrate_network(&mut network);
+
+		assert_eq!(network.nodes[&0].implementation, node_type.generate_implementation());
+	}
+
+	#[test]
+	fn cached_readouts_share_one_computation() {
+		let network = cached_readouts_network(DocumentNode::default(), &[("First", "first"), ("Second", "second")], TaggedValue::None);
+
+		assert_eq!(network.outputs, vec![NodeOutput::new(4, 0), NodeOutput::new(2, 0), NodeOutput::new(3, 0)]);
+		assert_eq!(network.nodes[&1].name, "Cache");
+		assert!([2, 3].iter().all(|id| network.nodes[id].inputs == vec![NodeInput::node(1, 0)]));
+	}
+
+	#[test]
+	fn to_document_node_pads_missing_inputs() {
+		let node_type = resolve_document_node_type("Brightness/Contrast").unwrap();
+		let image = NodeInput::node(1, 0);
+		let node = node_type.to_document_node([image.clone()], DocumentNodeMetadata::default());
+
+		let mut expected: Vec<_> = node_type.inputs.iter().map(|input| input.default.clone()).collect();
+		expected[0] = image;
+		assert_eq!(node.inputs, expected);
+	}
+
+	#[test]
+	fn to_document_node_truncates_extra_inputs() {
+		let node_type = resolve_document_node_type("Opacity").unwrap();
+		let mut inputs: Vec<_> = node_type.inputs.iter().map(|input| input.default.clone()).collect();
+		let expected = inputs.clone();
+		inputs.push(NodeInput::value(TaggedValue::F32(1.), false));
+
+		let node = node_type.to_document_node(inputs, DocumentNodeMetadata::default());
+		assert_eq!(node.inputs, expected);
+	}
+
+	#[test]
+	fn wrap_network_in_scope_breaks_cycles() {
+		let identity = |input| DocumentNode {
+			name: "Identity".into(),
+			inputs: vec![input],
+			..Default::default()
+		};
+		let network = NodeNetwork {
+			inputs: vec![0],
+			outputs: vec![NodeOutput::new(1, 0)],
+			nodes: [(0, identity(NodeInput::node(1, 0))), (1, identity(NodeInput::node(0, 0)))].into_iter().collect(),
+			..Default::default()
+		};
+
+		let mut broken = network.clone();
+		let diagnostics = broken.break_cycles();
+		assert_eq!(diagnostics.len(), 1, "Disconnecting a single input should break a two node cycle");
+		assert_eq!((diagnostics[0].node_id, diagnostics[0].input_index), (0, Some(0)));
+		assert!(broken.nodes_in_cycles().is_empty());
+
+		let wrapped = wrap_network_in_scope(network);
+		assert!(wrapped.nodes_in_cycles().is_empty(), "The wrapped network should no longer contain the cycle");
+	}
+
+	#[test]
+	fn search_excludes_ignored_nodes() {
+		assert!(search_node_types("").is_empty());
+		assert!(search_node_types("scope").iter().all(|node_type| !node_type.category.eq_ignore_ascii_case("ignore")));
+	}
+
+	#[test]
+	fn bypass_requires_a_pass_through_input() {
+		let can_bypass = |name, input_override: Vec<Option<NodeInput>>| {
+			let node_type = resolve_document_node_type(name).unwrap();
+			node_type.can_bypass(&node_type.to_document_node_default_inputs(input_override, Default::default()))
+		};
+
+		assert!(can_bypass("Invert RGB", Vec::new()));
+		assert!(!can_bypass("Blend", Vec::new()), "Blending two exposed images has no single input to pass through");
+		assert!(!can_bypass("Extract Vector Points", Vec::new()), "The output type differs from the input type");
+
+		// The inputs exposed on the node itself count, rather than those of the type
+		let unexposed_second = NodeInput::value(TaggedValue::ImageFrame(ImageFrame::empty()), false);
+		assert!(can_bypass("Blend", vec![None, Some(unexposed_second)]));
+		let exposed_red = NodeInput::value(TaggedValue::Bool(true), true);
+		assert!(!can_bypass("Invert RGB", vec![None, Some(exposed_red)]), "The exposed Red input would be dropped");
+	}
+
+	/// A chain of Input → Invert RGB → Opacity → Output nodes with ids 0 to 3.
+	fn chain_network() -> NodeNetwork {
+		let node = |name, input: Option<NodeId>| {
+			let node_type = resolve_document_node_type(name).unwrap();
+			node_type.to_document_node_default_inputs([input.map(|id| NodeInput::node(id, 0))], DocumentNodeMetadata::default())
+		};
+		NodeNetwork {
+			inputs: vec![0],
+			outputs: vec![NodeOutput::new(3, 0)],
+			nodes: [
+				(0, node("Input Frame", None)),
+				(1, node("Invert RGB", Some(0))),
+				(2, node("Opacity", Some(1))),
+				(3, node("Output", Some(2))),
+			]
+			.into_iter()
+			.collect(),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn copy_node_subgraph_drops_external_links() {
+		let network = chain_network();
+		let copied = copy_node_subgraph(&network, &[1, 2, 3]);
+
+		assert_eq!(copied.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![1, 2], "The output node shouldn't be copied");
+		let invert_default = resolve_document_node_type("Invert RGB").unwrap().inputs[0].default.clone();
+		assert_eq!(copied[0].1.inputs[0], invert_default, "The link to the input node should be replaced by the default input");
+		assert_eq!(copied[1].1.inputs[0], NodeInput::node(1, 0), "The link between copied nodes should be kept");
+	}
+
+	#[test]
+	fn paste_node_subgraph_remaps_ids() {
+		let network = chain_network();
+		let copied = copy_node_subgraph(&network, &[1, 2]);
+
+		// The first ids would collide with the existing nodes, so they should be skipped
+		let next_id = std::cell::Cell::new(0);
+		let pasted = paste_node_subgraph(&network, copied, || next_id.replace(next_id.get() + 1));
+
+		let ids = pasted.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+		assert_eq!(ids, vec![4, 5]);
+		assert!(ids.iter().all(|id| !network.nodes.contains_key(id)));
+		assert_eq!(pasted[0].1.name, "Invert RGB");
+		assert_eq!(
+			pasted[1].1.inputs[0],
+			NodeInput::node(4, 0),
+			"The pasted Opacity node should be connected to the pasted Invert RGB node"
+		);
+	}
+
+	/// Compiles the network the way the editor does before running it, then evaluates it with `input` as the argument of the network.
+	fn execute_network<I: dyn_any::StaticType>(network: NodeNetwork, input: I) -> TaggedValue {
+		use futures::executor::block_on;
+		use graph_craft::graphene_compiler::{Compiler, Executor};
+		use interpreted_executor::dynamic_executor::DynamicExecutor;
+
+		let proto_network = Compiler {}.compile_single(network).expect("The network should compile");
+		let executor = block_on(DynamicExecutor::new(proto_network)).unwrap_or_else(|error| panic!("The network should type check: {error}"));
+		block_on((&executor).execute(input)).unwrap()
+	}
+
+	/// A network running the named node on the argument of the network, with `function` connected as the lambda input at `lambda_index` when given.
+	fn lambda_network(name: &str, argument: Type, lambda_index: usize, function: Option<DocumentNode>) -> NodeNetwork {
+		let node_type = resolve_document_node_type(name).unwrap();
+		let mut inputs = vec![Some(NodeInput::Network(argument))];
+		inputs.resize(lambda_index, None);
+		inputs.push(function.as_ref().map(|_| NodeInput::lambda(1, 0)));
+		let node = node_type.to_document_node_default_inputs(inputs, DocumentNodeMetadata::default());
+
+		NodeNetwork {
+			inputs: vec![0],
+			outputs: vec![NodeOutput::new(0, 0)],
+			nodes: [(0, node)].into_iter().chain(function.map(|function| (1, function))).collect(),
+			..Default::default()
+		}
+	}
+
+	/// The named library node with its default inputs, with its primary input replaced the way connecting it as a lambda does.
+	fn lambda_node(name: &str) -> DocumentNode {
+		let mut node = resolve_document_node_type(name).unwrap().to_document_node_default_inputs([], DocumentNodeMetadata::default());
+		node.inputs[0] = super::super::lambda_call_input(&node.inputs[0]).unwrap();
+		node
+	}
+
+	fn two_subpaths() -> VectorData {
+		let corner = bezier_rs::Subpath::from_anchors([DVec2::ZERO, DVec2::new(2., 0.), DVec2::new(2., 2.)], false);
+		let square = bezier_rs::Subpath::new_rect(DVec2::new(10., 10.), DVec2::new(13., 13.));
+		let mut shape = VectorData::from_subpaths(vec![corner, square]);
+		shape.transform = DAffine2::from_scale_angle_translation(DVec2::splat(2.), 1., DVec2::new(5., -3.));
+		shape
+	}
+
+	#[test]
+	fn for_each_subpath_defaults_to_leaving_subpaths_unchanged() {
+		let shape = two_subpaths();
+		let network = lambda_network("For Each Subpath", concrete!(VectorData), 1, None);
+		assert_eq!(execute_network(network, shape.clone()), TaggedValue::VectorData(shape));
+	}
+
+	#[test]
+	fn for_each_subpath_runs_the_connected_node_on_each_subpath() {
+		let mut transform = lambda_node("Transform");
+		transform.inputs[3] = NodeInput::value(TaggedValue::DVec2(DVec2::splat(2.)), false);
+
+		let shape = two_subpaths();
+		let network = lambda_network("For Each Subpath", concrete!(VectorData), 1, Some(transform));
+		let TaggedValue::VectorData(result) = execute_network(network, shape.clone()) else {
+			panic!("For Each Subpath should output vector data");
+		};
+
+		// Each subpath is scaled about its own center, so the corner and the square grow in place instead of spreading apart
+		assert_eq!(result.transform, shape.transform);
+		let bounds = |subpath: &bezier_rs::Subpath<_>| subpath.bounding_box().unwrap();
+		assert!(bounds(&result.subpaths[0])[0].abs_diff_eq(DVec2::new(-1., -1.), 1e-9));
+		assert!(bounds(&result.subpaths[0])[1].abs_diff_eq(DVec2::new(3., 3.), 1e-9));
+		assert!(bounds(&result.subpaths[1])[0].abs_diff_eq(DVec2::new(8.5, 8.5), 1e-9));
+		assert!(bounds(&result.subpaths[1])[1].abs_diff_eq(DVec2::new(14.5, 14.5), 1e-9));
+	}
+
+	fn images() -> Vec<ImageFrame<Color>> {
+		let image = |color| ImageFrame {
+			image: Image::new(1, 1, color),
+			transform: DAffine2::IDENTITY,
+		};
+		vec![image(Color::RED), image(Color::BLACK)]
+	}
+
+	#[test]
+	fn map_defaults_to_leaving_elements_unchanged() {
+		let network = lambda_network("Map", concrete!(Vec<ImageFrame<Color>>), 1, None);
+		assert_eq!(execute_network(network, images()), TaggedValue::Segments(images()));
+	}
+
+	#[test]
+	fn map_runs_a_connected_image_adjustment_on_each_image() {
+		let network = lambda_network("Map", concrete!(Vec<ImageFrame<Color>>), 1, Some(lambda_node("Invert RGB")));
+		let TaggedValue::Segments(result) = execute_network(network, images()) else {
+			panic!("Map should output a list of images");
+		};
+
+		let colors = result.iter().map(|image| image.image.data[0].to_rgba8_srgb()).collect::<Vec<_>>();
+		assert_eq!(colors, vec![[0, 255, 255, 255], [255, 255, 255, 255]]);
+	}
+
+	#[test]
+	fn adjust_alpha_only_leaves_the_colors_untouched() {
+		let image = ImageFrame {
+			image: Image::new(1, 1, Color::from_rgbaf32_unchecked(0.2, 0.4, 0.6, 0.25)),
+			transform: DAffine2::IDENTITY,
+		};
+		let network = lambda_network("Adjust Alpha Only", concrete!(ImageFrame<Color>), 1, Some(lambda_node("Invert RGB")));
+		let TaggedValue::ImageFrame(result) = execute_network(network, image) else {
+			panic!("Adjust Alpha Only should output an image");
+		};
+
+		let pixel = result.image.data[0];
+		assert_eq!((pixel.r(), pixel.g(), pixel.b()), (0.2, 0.4, 0.6));
+		assert!((pixel.a() - 0.75).abs() < 1e-4, "Inverting the alpha should make it 0.75, not {}", pixel.a());
+
+		let network = lambda_network("Adjust Alpha Only", concrete!(ImageFrame<Color>), 1, None);
+		assert_eq!(
+			execute_network(network, images()[0].clone()),
+			TaggedValue::ImageFrame(images()[0].clone()),
+			"Without an adjustment the image is unchanged"
+		);
+	}
+
+	#[test]
+	fn adjust_colors_only_keeps_the_alpha() {
+		let mut opacity = lambda_node("Opacity");
+		opacity.inputs[1] = NodeInput::value(TaggedValue::F32(50.), false);
+		let network = lambda_network("Adjust Colors Only", concrete!(ImageFrame<Color>), 1, Some(opacity));
+		assert_eq!(execute_network(network, images()[0].clone()), TaggedValue::ImageFrame(images()[0].clone()));
+
+		let network = lambda_network("Adjust Colors Only", concrete!(ImageFrame<Color>), 1, Some(lambda_node("Invert RGB")));
+		let TaggedValue::ImageFrame(result) = execute_network(network, images()[0].clone()) else {
+			panic!("Adjust Colors Only should output an image");
+		};
+		assert_eq!(result.image.data[0].to_rgba8_srgb(), [0, 255, 255, 255]);
+	}
+
+	#[test]
+	fn reduce_defaults_to_keeping_the_last_element() {
+		let network = lambda_network("Reduce", concrete!(Vec<ImageFrame<Color>>), 2, None);
+		assert_eq!(execute_network(network.clone(), images()), TaggedValue::ImageFrame(images()[1].clone()));
+		assert_eq!(
+			execute_network(network, Vec::<ImageFrame<Color>>::new()),
+			TaggedValue::ImageFrame(ImageFrame::empty()),
+			"An empty list gives the initial value"
+		);
+	}
+}
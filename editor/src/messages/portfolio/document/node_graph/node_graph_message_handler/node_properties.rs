@@ -1,21 +1,27 @@
 #![allow(clippy::too_many_arguments)]
 
 use super::document_node_types::NodePropertiesContext;
-use super::FrontendGraphDataType;
+use super::{FrontendGraphDataType, OutputExportSettings};
+use crate::consts::{DEFAULT_FONT_FAMILY, DEFAULT_FONT_STYLE, OUTPUT_EXPORT_SCALE_MAX, OUTPUT_EXPORT_SCALE_MIN};
+use crate::messages::frontend::utility_types::FileType;
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::prelude::*;
 
 use document_legacy::{layers::layer_info::LayerDataTypeDiscriminant, Operation};
 use graph_craft::concrete;
 use graph_craft::document::value::TaggedValue;
-use graph_craft::document::{DocumentNode, NodeId, NodeInput};
-use graph_craft::imaginate_input::{ImaginateMaskStartingFill, ImaginateSamplingMethod, ImaginateServerStatus, ImaginateStatus};
-use graphene_core::raster::{BlendMode, Color, ImageFrame, LuminanceCalculation, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
-use graphene_core::text::Font;
-use graphene_core::vector::style::{FillType, GradientType, LineCap, LineJoin};
-use graphene_core::{Cow, Type, TypeDescriptor};
-
-use glam::{DVec2, IVec2};
+use graph_craft::document::{DocumentNode, NodeId, NodeInput, NodeNetwork};
+use graph_craft::imaginate_input::{ImaginateControlType, ImaginateMaskStartingFill, ImaginateSamplingMethod, ImaginateServerStatus, ImaginateStatus};
+use graphene_core::animation::{AnimationCurve, Keyframe, KeyframeInterpolation};
+use graphene_core::raster::{BlendMode, Color, DownresFilter, HueRange, ImageFrame, LuminanceCalculation, RedGreenBlue, RedGreenBlueAlpha, RelativeAbsolute, SelectiveColorChoice, ToneMapping};
+use graphene_core::renderer::AntiAliasing;
+use graphene_core::text::{load_face, Font, FontAxis, TextAlign};
+use graphene_core::vector::brush_stroke::BrushMode;
+use graphene_core::vector::style::{Fill, FillType, GradientType, LineCap, LineJoin, Stroke, StrokeAlign, StyleLayer};
+use graphene_core::vector::MirrorAxis;
+use graphene_core::{Cow, ImageFrameAvailableNode, Type, TypeDescriptor};
+
+use glam::{DVec2, IVec2, UVec2};
 
 pub fn string_properties(text: impl Into<String>) -> Vec<LayoutGroup> {
 	let widget = TextLabel::new(text).widget_holder();
@@ -68,6 +74,19 @@ fn start_widgets(document_node: &DocumentNode, node_id: NodeId, index: usize, na
 		Separator::new(SeparatorType::Unrelated).widget_holder(),
 		TextLabel::new(name).widget_holder(),
 	];
+	if let NodeInput::Value { exposed: false, .. } = input {
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("Copy", 16)
+				.tooltip("Copy this input's value")
+				.on_update(move |_| NodeGraphMessage::CopyInputValue { node_id, input_index: index }.into())
+				.widget_holder(),
+			IconButton::new("Paste", 16)
+				.tooltip("Paste a copied value of the same type into this input")
+				.on_update(move |_| NodeGraphMessage::PasteInputValue { node_id, input_index: index }.into())
+				.widget_holder(),
+		]);
+	}
 	if blank_assist {
 		add_blank_assist(&mut widgets);
 	}
@@ -162,8 +181,8 @@ fn vec2_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name
 		exposed: false,
 	} = document_node.inputs[index]
 	{
-		let update_x = move |input: &NumberInput| TaggedValue::IVec2(IVec2::new(input.value.unwrap() as i32, vec2.y));
-		let update_y = move |input: &NumberInput| TaggedValue::IVec2(IVec2::new(vec2.x, input.value.unwrap() as i32));
+		let update_x = move |input: &NumberInput| TaggedValue::IVec2(IVec2::new(to_i32(input.value.unwrap()), vec2.y));
+		let update_y = move |input: &NumberInput| TaggedValue::IVec2(IVec2::new(vec2.x, to_i32(input.value.unwrap())));
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			NumberInput::new(Some(vec2.x as f64))
@@ -184,11 +203,48 @@ fn vec2_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name
 				.on_update(update_value(update_y, node_id, index))
 				.widget_holder(),
 		]);
+	} else if let NodeInput::Value {
+		tagged_value: TaggedValue::UVec2(vec2),
+		exposed: false,
+	} = document_node.inputs[index]
+	{
+		let update_x = move |input: &NumberInput| TaggedValue::UVec2(UVec2::new(to_u32(input.value.unwrap()), vec2.y));
+		let update_y = move |input: &NumberInput| TaggedValue::UVec2(UVec2::new(vec2.x, to_u32(input.value.unwrap())));
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(vec2.x as f64))
+				.int()
+				.label(x)
+				.unit(unit)
+				.min(0.)
+				.max(u32::MAX as f64)
+				.on_update(update_value(update_x, node_id, index))
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			NumberInput::new(Some(vec2.y as f64))
+				.int()
+				.label(y)
+				.unit(unit)
+				.min(0.)
+				.max(u32::MAX as f64)
+				.on_update(update_value(update_y, node_id, index))
+				.widget_holder(),
+		]);
 	}
 
 	LayoutGroup::Row { widgets }
 }
 
+/// Rounds an edited number to the nearest value a `u32` input can hold, as its widget can still be dragged or typed past its limits.
+fn to_u32(value: f64) -> u32 {
+	value.round().clamp(0., u32::MAX as f64) as u32
+}
+
+/// Rounds an edited number to the nearest value an `i32` input can hold.
+fn to_i32(value: f64) -> i32 {
+	value.round().clamp(i32::MIN as f64, i32::MAX as f64) as i32
+}
+
 fn vec_f32_input(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, text_props: TextInput, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::Color, blank_assist);
 
@@ -217,23 +273,31 @@ fn vec_f32_input(document_node: &DocumentNode, node_id: NodeId, index: usize, na
 	}
 	widgets
 }
+/// The font family and font style pickers for a font, with `to_value` turning a picked font into the new value of the input
+fn font_pickers(font: &Font, to_value: impl Fn(Font) -> TaggedValue + Clone + 'static + Send + Sync, node_id: NodeId, index: usize) -> (WidgetHolder, WidgetHolder) {
+	let from_font_input = move |font: &FontInput| to_value(Font::new(font.font_family.clone(), font.font_style.clone()));
+
+	let family = FontInput::new(font.font_family.clone(), font.font_style.clone())
+		.on_update(update_value(from_font_input.clone(), node_id, index))
+		.widget_holder();
+	let style = FontInput::new(font.font_family.clone(), font.font_style.clone())
+		.is_style_picker(true)
+		.on_update(update_value(from_font_input, node_id, index))
+		.widget_holder();
+	(family, style)
+}
+
 fn font_inputs(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> (Vec<WidgetHolder>, Option<Vec<WidgetHolder>>) {
 	let mut first_widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
 	let mut second_widgets = None;
 
-	let from_font_input = |font: &FontInput| TaggedValue::Font(Font::new(font.font_family.clone(), font.font_style.clone()));
-
 	if let NodeInput::Value {
 		tagged_value: TaggedValue::Font(font),
 		exposed: false,
 	} = &document_node.inputs[index]
 	{
-		first_widgets.extend_from_slice(&[
-			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			FontInput::new(font.font_family.clone(), font.font_style.clone())
-				.on_update(update_value(from_font_input, node_id, index))
-				.widget_holder(),
-		]);
+		let (family, style) = font_pickers(font, TaggedValue::Font, node_id, index);
+		first_widgets.extend_from_slice(&[Separator::new(SeparatorType::Unrelated).widget_holder(), family]);
 		second_widgets = Some(vec![
 			TextLabel::new("").widget_holder(),
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
@@ -241,15 +305,88 @@ fn font_inputs(document_node: &DocumentNode, node_id: NodeId, index: usize, name
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			FontInput::new(font.font_family.clone(), font.font_style.clone())
-				.is_style_picker(true)
-				.on_update(update_value(from_font_input, node_id, index))
-				.widget_holder(),
+			style,
 		]);
 	}
 	(first_widgets, second_widgets)
 }
 
+/// A row for each font of a list of fallback fonts, with pickers to change it and a button to remove it, followed by a button to add another
+fn fallback_font_inputs(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> Vec<LayoutGroup> {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	let NodeInput::Value {
+		tagged_value: TaggedValue::Fonts(fonts),
+		exposed: false,
+	} = &document_node.inputs[index]
+	else {
+		return vec![LayoutGroup::Row { widgets }];
+	};
+
+	let mut rows = Vec::with_capacity(fonts.len() + 1);
+	for (font_index, font) in fonts.iter().enumerate() {
+		if font_index != 0 {
+			widgets.push(TextLabel::new("").widget_holder());
+			add_blank_assist(&mut widgets);
+		}
+
+		let replace = {
+			let fonts = fonts.clone();
+			move |font: Font| {
+				let mut new_fonts = fonts.clone();
+				new_fonts[font_index] = font;
+				TaggedValue::Fonts(new_fonts)
+			}
+		};
+		let remove = {
+			let fonts = fonts.clone();
+			move |_: &IconButton| {
+				let mut new_fonts = fonts.clone();
+				new_fonts.remove(font_index);
+				TaggedValue::Fonts(new_fonts)
+			}
+		};
+
+		let (family, style) = font_pickers(font, replace, node_id, index);
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			family,
+			Separator::new(SeparatorType::Related).widget_holder(),
+			style,
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("Remove", 16)
+				.tooltip("Remove this fallback font")
+				.on_update(update_value(remove, node_id, index))
+				.widget_holder(),
+		]);
+
+		let widgets = std::mem::take(&mut widgets);
+		rows.push(LayoutGroup::Row { widgets });
+	}
+
+	let add = {
+		let fonts = fonts.clone();
+		move |_: &TextButton| {
+			let mut new_fonts = fonts.clone();
+			new_fonts.push(Font::new(DEFAULT_FONT_FAMILY.into(), DEFAULT_FONT_STYLE.into()));
+			TaggedValue::Fonts(new_fonts)
+		}
+	};
+	if !fonts.is_empty() {
+		widgets.push(TextLabel::new("").widget_holder());
+		add_blank_assist(&mut widgets);
+	}
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextButton::new("Add Fallback")
+			.icon(Some("Add".into()))
+			.tooltip("Add a font to take characters from when the fonts above don't have them")
+			.on_update(update_value(add, node_id, index))
+			.widget_holder(),
+	]);
+	rows.push(LayoutGroup::Row { widgets });
+	rows
+}
+
 fn number_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, number_props: NumberInput, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::Number, blank_assist);
 
@@ -270,11 +407,15 @@ fn number_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, na
 		exposed: false,
 	} = document_node.inputs[index]
 	{
+		// Unsigned inputs are always whole numbers that can't go below zero, even if the caller didn't say so
+		let min = number_props.min.map_or(0., |min| min.max(0.));
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			number_props
+				.int()
+				.min(min)
 				.value(Some(x as f64))
-				.on_update(update_value(move |x: &NumberInput| TaggedValue::U32((x.value.unwrap()) as u32), node_id, index))
+				.on_update(update_value(move |x: &NumberInput| TaggedValue::U32(to_u32(x.value.unwrap())), node_id, index))
 				.widget_holder(),
 		])
 	} else if let NodeInput::Value {
@@ -316,6 +457,29 @@ fn color_channel(document_node: &DocumentNode, node_id: u64, index: usize, name:
 	LayoutGroup::Row { widgets }.with_tooltip("Color Channel")
 }
 
+/// Like [color_channel] but also offering the alpha channel, for nodes that can target it.
+fn color_channel_with_alpha(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::RedGreenBlueAlpha(mode),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let calculation_modes = [RedGreenBlueAlpha::Red, RedGreenBlueAlpha::Green, RedGreenBlueAlpha::Blue, RedGreenBlueAlpha::Alpha];
+		let mut entries = Vec::with_capacity(calculation_modes.len());
+		for method in calculation_modes {
+			entries.push(DropdownEntryData::new(method.to_string()).on_update(update_value(move |_| TaggedValue::RedGreenBlueAlpha(method), node_id, index)));
+		}
+		let entries = vec![entries];
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(entries).selected_index(Some(mode as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Color Channel")
+}
+
 //TODO Use generalized Version of this as soon as it's available
 fn blend_mode(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
@@ -365,6 +529,26 @@ fn luminance_calculation(document_node: &DocumentNode, node_id: u64, index: usiz
 	LayoutGroup::Row { widgets }.with_tooltip("Formula used to calculate the luminance of a pixel")
 }
 
+fn anti_aliasing_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::AntiAliasing(anti_aliasing),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = AntiAliasing::list()
+			.into_iter()
+			.map(|quality| DropdownEntryData::new(quality.to_string()).on_update(update_value(move |_| TaggedValue::AntiAliasing(quality), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(anti_aliasing as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Smooths the edges of shapes by averaging several samples per pixel, which is slower at higher quality")
+}
+
 fn line_cap_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
 	if let &NodeInput::Value {
@@ -385,6 +569,46 @@ fn line_cap_widget(document_node: &DocumentNode, node_id: u64, index: usize, nam
 	LayoutGroup::Row { widgets }
 }
 
+fn stroke_align_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::StrokeAlign(stroke_align),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = StrokeAlign::list()
+			.into_iter()
+			.map(|align| RadioEntryData::new(align.to_string()).on_update(update_value(move |_| TaggedValue::StrokeAlign(align), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(stroke_align as u32).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Where the stroke lies relative to closed shapes, which are offset to draw it inside or outside")
+}
+
+fn mirror_axis_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::MirrorAxis(mirror_axis),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = MirrorAxis::list()
+			.into_iter()
+			.map(|axis| RadioEntryData::new(axis.to_string()).on_update(update_value(move |_| TaggedValue::MirrorAxis(axis), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(mirror_axis as u32).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
 fn line_join_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
 	if let &NodeInput::Value {
@@ -405,26 +629,43 @@ fn line_join_widget(document_node: &DocumentNode, node_id: u64, index: usize, na
 	LayoutGroup::Row { widgets }
 }
 
-fn fill_type_widget(document_node: &DocumentNode, node_id: u64, index: usize) -> LayoutGroup {
-	let mut widgets = start_widgets(document_node, node_id, index, "Fill Type", FrontendGraphDataType::General, true);
+fn text_align_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::TextAlign(align),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [TextAlign::Left, TextAlign::Center, TextAlign::Right]
+			.into_iter()
+			.map(|align| RadioEntryData::new(align.to_string()).on_update(update_value(move |_| TaggedValue::TextAlign(align), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(align as u32).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+/// Chooses between the given fill types and their labels. A fill type that isn't among them selects the first.
+fn fill_type_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, fill_types: &[(FillType, &str)]) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, true);
 	if let &NodeInput::Value {
 		tagged_value: TaggedValue::FillType(fill_type),
 		exposed: false,
 	} = &document_node.inputs[index]
 	{
-		let entries = vec![
-			RadioEntryData::new("Solid").on_update(update_value(move |_| TaggedValue::FillType(FillType::Solid), node_id, index)),
-			RadioEntryData::new("Gradient").on_update(update_value(move |_| TaggedValue::FillType(FillType::Gradient), node_id, index)),
-		];
+		let entries = fill_types
+			.iter()
+			.map(|&(option, label)| RadioEntryData::new(label).on_update(update_value(move |_| TaggedValue::FillType(option), node_id, index)))
+			.collect();
+		let selected_index = fill_types.iter().position(|&(option, _)| option == fill_type).unwrap_or_default();
 
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			RadioInput::new(entries)
-				.selected_index(match fill_type {
-					FillType::None | FillType::Solid => 0,
-					FillType::Gradient => 1,
-				})
-				.widget_holder(),
+			RadioInput::new(entries).selected_index(selected_index as u32).widget_holder(),
 		]);
 	}
 	LayoutGroup::Row { widgets }
@@ -565,16 +806,20 @@ fn gradient_positions(rows: &mut Vec<LayoutGroup>, document_node: &DocumentNode,
 	}
 }
 
+/// A color picker for a `Color` or `OptionalColor` input. When the props don't allow transparency, the picker hides its alpha controls and every picked color is stored fully opaque.
 fn color_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, color_props: ColorInput, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::Number, blank_assist);
 
+	let allow_transparency = color_props.allow_transparency;
+	let opaque = move |color: Color| if allow_transparency { color } else { color.with_alpha(1.) };
+
 	if let NodeInput::Value { tagged_value, exposed: false } = &document_node.inputs[index] {
 		if let &TaggedValue::Color(x) = tagged_value {
 			widgets.extend_from_slice(&[
 				Separator::new(SeparatorType::Unrelated).widget_holder(),
 				color_props
 					.value(Some(x as Color))
-					.on_update(update_value(|x: &ColorInput| TaggedValue::Color(x.value.unwrap()), node_id, index))
+					.on_update(update_value(move |x: &ColorInput| TaggedValue::Color(opaque(x.value.unwrap())), node_id, index))
 					.widget_holder(),
 			])
 		} else if let &TaggedValue::OptionalColor(x) = tagged_value {
@@ -582,7 +827,7 @@ fn color_widget(document_node: &DocumentNode, node_id: u64, index: usize, name:
 				Separator::new(SeparatorType::Unrelated).widget_holder(),
 				color_props
 					.value(x)
-					.on_update(update_value(|x: &ColorInput| TaggedValue::OptionalColor(x.value), node_id, index))
+					.on_update(update_value(move |x: &ColorInput| TaggedValue::OptionalColor(x.value.map(opaque)), node_id, index))
 					.widget_holder(),
 			])
 		}
@@ -590,14 +835,171 @@ fn color_widget(document_node: &DocumentNode, node_id: u64, index: usize, name:
 	LayoutGroup::Row { widgets }
 }
 /// Properties for the input node, with information describing how frames work and a refresh button
-pub fn input_properties(_document_node: &DocumentNode, _node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+/// A read-only row describing an image frame cached by a Monitor node inside the node's network, showing "—" until it has been evaluated.
+fn image_frame_info(node_id: NodeId, context: &mut NodePropertiesContext, find_monitor: impl FnOnce(&NodeNetwork) -> Option<NodeId>) -> LayoutGroup {
+	let node_path = [context.nested_path, &[node_id]].concat();
+	let describe = |frame: &ImageFrame<Color>| {
+		let (width, height) = (frame.image.width, frame.image.height);
+		if width == 0 || height == 0 {
+			return "Empty image".to_string();
+		}
+
+		let scale = DVec2::new(frame.transform.matrix2.x_axis.length(), frame.transform.matrix2.y_axis.length());
+		if (scale.x - scale.y).abs() < 1e-6 {
+			format!("{width}×{height}, transform scale {:.1}", scale.x)
+		} else {
+			format!("{width}×{height}, transform scale {:.1}×{:.1}", scale.x, scale.y)
+		}
+	};
+	let info = context
+		.executor
+		.introspect_node_in_network(context.network, &node_path, find_monitor, describe)
+		.unwrap_or_else(|| "—".to_string());
+
+	let widgets = vec![
+		TextLabel::new("Image").widget_holder(),
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextLabel::new(info).tooltip("Dimensions and transform scale of the image from the last evaluation").widget_holder(),
+	];
+	LayoutGroup::Row { widgets }
+}
+
+pub fn input_properties(_document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let information = TextLabel::new("The graph's input frame is the rasterized artwork under the layer").widget_holder();
 	let layer_path = context.layer_path.to_vec();
 	let refresh_button = TextButton::new("Refresh Input")
-		.tooltip("Refresh the artwork under the layer")
-		.on_update(move |_| DocumentMessage::InputFrameRasterizeRegionBelowLayer { layer_path: layer_path.clone() }.into())
+		.tooltip("Rasterize the artwork under the layer again, even if it hasn't changed")
+		.on_update(move |_| DocumentMessage::InputFrameRefresh { layer_path: layer_path.clone() }.into())
 		.widget_holder();
-	vec![LayoutGroup::Row { widgets: vec![information] }, LayoutGroup::Row { widgets: vec![refresh_button] }]
+	let info = image_frame_info(node_id, context, |network| network.outputs.first().map(|output| output.node_id));
+
+	// The frame monitor holds the frame from the last evaluation, which is empty when there was no artwork under the layer
+	let node_path = [context.nested_path, &[node_id]].concat();
+	let available = context.executor.introspect_node_in_network(
+		context.network,
+		&node_path,
+		|network| network.outputs.first().map(|output| output.node_id),
+		ImageFrameAvailableNode::is_available,
+	);
+	let status = match available {
+		Some(true) => "Available",
+		Some(false) => "No artwork under the layer",
+		None => "—",
+	};
+	let status = vec![
+		TextLabel::new("Status").widget_holder(),
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextLabel::new(status)
+			.tooltip("Whether the last evaluation had artwork under the layer to use as the input frame, which is also given by the Available output")
+			.widget_holder(),
+	];
+
+	vec![
+		LayoutGroup::Row { widgets: vec![information] },
+		LayoutGroup::Row { widgets: status },
+		info,
+		LayoutGroup::Row { widgets: vec![refresh_button] },
+	]
+}
+
+pub fn sample_pixel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let uv = vec2_widget(document_node, node_id, 1, "UV", "U", "V", "", add_blank_assist);
+
+	vec![uv.with_tooltip("The position in the image, from (0, 0) at the top left to (1, 1) at the bottom right")]
+}
+
+pub fn set_pixel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let uv = vec2_widget(document_node, node_id, 1, "UV", "U", "V", "", add_blank_assist);
+	let color = color_widget(document_node, node_id, 2, "Color", ColorInput::default().allow_none(false), true);
+
+	vec![uv.with_tooltip("The position in the image, from (0, 0) at the top left to (1, 1) at the bottom right"), color]
+}
+
+pub fn solid_color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let resolution = vec2_widget(document_node, node_id, 1, "Resolution", "W", "H", " px", add_blank_assist);
+	let color = color_widget(document_node, node_id, 2, "Color", ColorInput::default().allow_none(false), true);
+
+	vec![resolution, color]
+}
+
+pub fn brush_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let mode_index = 4;
+	let mut mode = start_widgets(document_node, node_id, mode_index, "Mode", FrontendGraphDataType::General, true);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::BrushMode(brush_mode),
+		exposed: false,
+	} = &document_node.inputs[mode_index]
+	{
+		let entries = [BrushMode::Paint, BrushMode::Erase]
+			.into_iter()
+			.map(|brush_mode| RadioEntryData::new(brush_mode.to_string()).on_update(update_value(move |_| TaggedValue::BrushMode(brush_mode), node_id, mode_index)))
+			.collect();
+		mode.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(brush_mode as u32).widget_holder(),
+		]);
+	}
+
+	let override_index = 5;
+	let override_style = bool_widget(document_node, node_id, override_index, "Override Style", true);
+	let is_override = if let &NodeInput::Value {
+		tagged_value: TaggedValue::Bool(override_choice),
+		..
+	} = &document_node.inputs[override_index]
+	{
+		override_choice
+	} else {
+		false
+	};
+
+	let mut layout = vec![
+		LayoutGroup::Row { widgets: mode }.with_tooltip("Paint uses the blend mode of each stroke, Erase turns every stroke into an eraser"),
+		LayoutGroup::Row { widgets: override_style }.with_tooltip("Draw every stroke with the spacing and hardness below instead of the ones it was painted with"),
+	];
+	if is_override {
+		let spacing_props = NumberInput::default().min(1.).max(1000.).unit("%").mode_range().log_scale(true);
+		let spacing = number_widget(document_node, node_id, 6, "Spacing", spacing_props, true);
+		let hardness = number_widget(document_node, node_id, 7, "Hardness", NumberInput::default().min(0.).max(100.).unit("%"), true);
+		layout.push(LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance between stamps as a percentage of the diameter, lower values are smoother but slower"));
+		layout.push(LayoutGroup::Row { widgets: hardness }.with_tooltip("How sharp the edge of each stamp is, 100% gives crisp edges"));
+	}
+	layout
+}
+
+pub fn scatter_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let diameter = number_widget(document_node, node_id, 2, "Diameter", NumberInput::default().min(1.).unit(" px"), true);
+	let hardness = number_widget(document_node, node_id, 3, "Hardness", NumberInput::default().min(0.).max(100.).unit("%"), true);
+	let color = color_widget(document_node, node_id, 4, "Color", ColorInput::default().allow_none(false), true);
+
+	vec![LayoutGroup::Row { widgets: diameter }, LayoutGroup::Row { widgets: hardness }, color]
+}
+
+pub fn downres_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let factor = number_widget(document_node, node_id, 1, "Factor", NumberInput::default().min(1.).max(64.).unit("x"), true);
+
+	let filter_index = 2;
+	let mut filter = start_widgets(document_node, node_id, filter_index, "Filter", FrontendGraphDataType::General, true);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::DownresFilter(downres_filter),
+		exposed: false,
+	} = &document_node.inputs[filter_index]
+	{
+		let entries = [DownresFilter::Average, DownresFilter::Nearest]
+			.into_iter()
+			.map(|filter| RadioEntryData::new(filter.to_string()).on_update(update_value(move |_| TaggedValue::DownresFilter(filter), node_id, filter_index)))
+			.collect();
+		filter.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(downres_filter as u32).widget_holder(),
+		]);
+	}
+
+	let info = image_frame_info(node_id, context, |network| network.inputs.first().copied());
+	vec![
+		LayoutGroup::Row { widgets: factor }.with_tooltip("Divides the resolution the image is displayed at, the image is never upscaled"),
+		LayoutGroup::Row { widgets: filter }.with_tooltip("Average blends each block of pixels for quality, Nearest keeps a single pixel for speed"),
+		info,
+	]
 }
 
 pub fn levels_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -620,7 +1022,7 @@ pub fn grayscale_properties(document_node: &DocumentNode, node_id: NodeId, _cont
 	const MIN: f64 = -200.;
 	const MAX: f64 = 300.;
 	// TODO: Add tint color (blended above using the "Color" blend mode)
-	let tint = color_widget(document_node, node_id, 1, "Tint", ColorInput::default(), true);
+	let tint = color_widget(document_node, node_id, 1, "Tint", ColorInput::default().allow_none(false), true);
 	let r_weight = number_widget(document_node, node_id, 2, "Reds", NumberInput::default().min(MIN).max(MAX).unit("%"), true);
 	let y_weight = number_widget(document_node, node_id, 3, "Yellows", NumberInput::default().min(MIN).max(MAX).unit("%"), true);
 	let g_weight = number_widget(document_node, node_id, 4, "Greens", NumberInput::default().min(MIN).max(MAX).unit("%"), true);
@@ -640,7 +1042,7 @@ pub fn grayscale_properties(document_node: &DocumentNode, node_id: NodeId, _cont
 }
 
 pub fn blend_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let backdrop = color_widget(document_node, node_id, 1, "Backdrop", ColorInput::default(), true);
+	let backdrop = color_widget(document_node, node_id, 1, "Backdrop", ColorInput::default().allow_none(false), true);
 	let blend_mode = blend_mode(document_node, node_id, 2, "Blend Mode", true);
 	let opacity = number_widget(document_node, node_id, 3, "Opacity", NumberInput::default().min(0.).max(100.).unit("%"), true);
 
@@ -680,8 +1082,41 @@ pub fn load_image_properties(document_node: &DocumentNode, node_id: NodeId, _con
 	vec![LayoutGroup::Row { widgets: url }]
 }
 
-pub fn output_properties(_document_node: &DocumentNode, _node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let output_type = context.executor.previous_output_type(context.layer_path);
+pub fn monitor_properties(_document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let node_path = [context.nested_path, &[node_id]].concat();
+	let value = context.executor.read_monitor_value(&node_path);
+
+	let value_text = match &value {
+		None => "—".to_string(),
+		Some(TaggedValue::None) => "Nothing".to_string(),
+		Some(TaggedValue::String(text)) => format!("\"{text}\""),
+		Some(TaggedValue::ImageFrame(frame)) => format!("Image {}×{}", frame.image.width, frame.image.height),
+		Some(TaggedValue::VectorData(vector_data)) => format!("Vector data with {} subpaths", vector_data.subpaths.len()),
+		Some(TaggedValue::GraphicGroup(group)) => format!("Graphic group with {} elements", group.len()),
+		Some(TaggedValue::Artboard(artboard)) => format!("Artboard {}×{}", artboard.dimensions.x, artboard.dimensions.y),
+		Some(value) => {
+			let mut text = format!("{value:?}");
+			if text.chars().count() > 100 {
+				text = text.chars().take(100).chain(std::iter::once('…')).collect();
+			}
+			text
+		}
+	};
+
+	let widgets = vec![
+		TextLabel::new("Last Value").widget_holder(),
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextLabel::new(value_text).tooltip("The value the Monitor node received during its last evaluation").widget_holder(),
+	];
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn output_properties(document_node: &DocumentNode, _node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let upstream_type = match document_node.inputs.first() {
+		Some(&NodeInput::Node { node_id, output_index, .. }) => context.output_type_of(node_id, output_index),
+		_ => None,
+	};
+	let output_type = upstream_type.or_else(|| context.executor.previous_output_type(context.layer_path));
 	let raster_output_type = concrete!(ImageFrame<Color>);
 	let disabled = match output_type {
 		Some(output_type) => output_type != raster_output_type,
@@ -690,12 +1125,72 @@ pub fn output_properties(_document_node: &DocumentNode, _node_id: NodeId, contex
 
 	let layer_path_1 = context.layer_path.to_vec();
 	let layer_path_2 = context.layer_path.to_vec();
+	let settings = context.output_export;
 
 	let label = TextLabel::new("The graph's output is drawn in the layer").widget_holder();
-	let download_button = TextButton::new("Download Render Output")
-		.tooltip("Download the rendered image output as a PNG file")
+
+	let file_types = [(FileType::Png, "PNG"), (FileType::Jpg, "JPEG"), (FileType::Webp, "WebP")];
+	let entries = file_types
+		.iter()
+		.map(|&(file_type, name)| {
+			DropdownEntryData::new(name).on_update(move |_| {
+				let settings = OutputExportSettings { file_type, ..settings };
+				NodeGraphMessage::SetOutputExportSettings { settings }.into()
+			})
+		})
+		.collect();
+	let selected_index = file_types.iter().position(|&(file_type, _)| file_type == settings.file_type).map(|index| index as u32);
+	let format_dropdown = DropdownInput::new(vec![entries]).selected_index(selected_index).disabled(disabled).widget_holder();
+
+	let quality = NumberInput::new(Some(settings.quality * 100.))
+		.label("Quality")
+		.percentage()
+		.disabled(disabled || settings.file_type == FileType::Png)
+		.on_update(move |number_input: &NumberInput| {
+			let quality = number_input.value.unwrap_or(settings.quality * 100.) / 100.;
+			let settings = OutputExportSettings { quality, ..settings };
+			NodeGraphMessage::SetOutputExportSettings { settings }.into()
+		})
+		.widget_holder();
+
+	let scale = NumberInput::new(Some(settings.scale))
+		.label("Scale")
+		.unit("x")
+		.min(OUTPUT_EXPORT_SCALE_MIN)
+		.max(OUTPUT_EXPORT_SCALE_MAX)
+		.increment_step(0.5)
 		.disabled(disabled)
-		.on_update(move |_| DocumentMessage::DownloadLayerImageOutput { layer_path: layer_path_1.clone() }.into())
+		.on_update(move |number_input: &NumberInput| {
+			let scale = number_input.value.unwrap_or(settings.scale);
+			let settings = OutputExportSettings { scale, ..settings };
+			NodeGraphMessage::SetOutputExportSettings { settings }.into()
+		})
+		.widget_holder();
+
+	let scaled_dimensions = context.executor.previous_output_dimensions(context.layer_path).map(|dimensions| settings.scaled_dimensions(dimensions));
+	let dimensions_text = match scaled_dimensions {
+		Some(Some((width, height))) => format!("{width}×{height} px"),
+		Some(None) => "Too large or small to export".to_string(),
+		None => "—".to_string(),
+	};
+	let dimensions = TextLabel::new(dimensions_text).tooltip("Pixel dimensions of the exported image").widget_holder();
+	let download_disabled = disabled || !matches!(scaled_dimensions, Some(Some(_)));
+	let download_tooltip = match scaled_dimensions {
+		Some(Some(_)) => "Download the rendered image output in the chosen file format and scale",
+		Some(None) => "The image is too large to export at this scale, or smaller than a pixel. Choose another scale to download it.",
+		None => "The layer hasn't rendered an image to download yet",
+	};
+
+	let download_button = TextButton::new("Download Render Output")
+		.tooltip(download_tooltip)
+		.disabled(download_disabled)
+		.on_update(move |_| {
+			DocumentMessage::DownloadLayerImageOutput {
+				layer_path: layer_path_1.clone(),
+				settings,
+			}
+			.into()
+		})
 		.widget_holder();
 	let copy_button = TextButton::new("Copy Render Output")
 		.tooltip("Copy the rendered image output to the clipboard")
@@ -705,6 +1200,12 @@ pub fn output_properties(_document_node: &DocumentNode, _node_id: NodeId, contex
 
 	vec![
 		LayoutGroup::Row { widgets: vec![label] },
+		LayoutGroup::Row {
+			widgets: vec![format_dropdown, Separator::new(SeparatorType::Related).widget_holder(), quality],
+		},
+		LayoutGroup::Row {
+			widgets: vec![scale, Separator::new(SeparatorType::Unrelated).widget_holder(), dimensions],
+		},
 		LayoutGroup::Row {
 			widgets: vec![download_button, Separator::new(SeparatorType::Related).widget_holder(), copy_button],
 		},
@@ -712,7 +1213,7 @@ pub fn output_properties(_document_node: &DocumentNode, _node_id: NodeId, contex
 }
 
 pub fn mask_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let mask = color_widget(document_node, node_id, 1, "Stencil", ColorInput::default(), true);
+	let mask = color_widget(document_node, node_id, 1, "Stencil", ColorInput::default().allow_none(false), true);
 
 	vec![mask]
 }
@@ -741,8 +1242,126 @@ pub fn luminance_properties(document_node: &DocumentNode, node_id: NodeId, _cont
 	vec![luminance_calc]
 }
 
+pub fn luminance_to_mask_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let luminance_calc = luminance_calculation(document_node, node_id, 1, "Luminance Calc", true);
+	let invert = bool_widget(document_node, node_id, 2, "Invert", true);
+
+	vec![luminance_calc, LayoutGroup::Row { widgets: invert }]
+}
+
+pub fn duotone_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let shadows = color_widget(document_node, node_id, 1, "Shadows", ColorInput::default().allow_transparency(false).allow_none(false), true);
+	let highlights = color_widget(document_node, node_id, 2, "Highlights", ColorInput::default().allow_transparency(false).allow_none(false), true);
+	let tritone_index = 3;
+	let tritone = bool_widget(document_node, node_id, tritone_index, "Tritone", true);
+	let is_tritone = if let &NodeInput::Value {
+		tagged_value: TaggedValue::Bool(tritone_choice),
+		..
+	} = &document_node.inputs[tritone_index]
+	{
+		tritone_choice
+	} else {
+		false
+	};
+	let luminance_calc = luminance_calculation(document_node, node_id, 5, "Luminance Calc", true);
+
+	let mut layout = vec![shadows, highlights, LayoutGroup::Row { widgets: tritone }.with_tooltip("Pass through a third color for the midtones")];
+	if is_tritone {
+		layout.push(color_widget(
+			document_node,
+			node_id,
+			4,
+			"Midtones",
+			ColorInput::default().allow_transparency(false).allow_none(false),
+			true,
+		));
+	}
+	layout.push(luminance_calc);
+	layout
+}
+
+pub fn displace_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let strength = number_widget(document_node, node_id, 2, "Strength", NumberInput::default().unit(" px"), true);
+
+	vec![LayoutGroup::Row { widgets: strength }.with_tooltip("How many pixels a fully red or green map value moves the image, negative values reverse the direction")]
+}
+
+pub fn bloom_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let threshold = number_widget(document_node, node_id, 1, "Threshold", NumberInput::default().min(0.).max(100.).unit("%"), true);
+	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default().min(0.).unit(" px"), true);
+	let intensity = number_widget(document_node, node_id, 3, "Intensity", NumberInput::default().min(0.).max(400.).unit("%"), true);
+	let luminance_calc = luminance_calculation(document_node, node_id, 4, "Luminance Calc", true);
+
+	vec![
+		LayoutGroup::Row { widgets: threshold }.with_tooltip("Only pixels at least this bright contribute to the glow"),
+		LayoutGroup::Row { widgets: radius },
+		LayoutGroup::Row { widgets: intensity },
+		luminance_calc,
+	]
+}
+
+pub fn lens_distortion_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let k1 = number_widget(document_node, node_id, 1, "K1", NumberInput::default().min(-1.).max(1.), true);
+	let k2 = number_widget(document_node, node_id, 2, "K2", NumberInput::default().min(-1.).max(1.), true);
+	let center = vec2_widget(document_node, node_id, 3, "Center", "U", "V", "", add_blank_assist);
+	let clamp_edges = bool_widget(document_node, node_id, 4, "Clamp Edges", true);
+
+	vec![
+		LayoutGroup::Row { widgets: k1 }.with_tooltip("Positive values give pincushion distortion and negative values barrel distortion"),
+		LayoutGroup::Row { widgets: k2 }.with_tooltip("Like K1, but mostly affects the edges of the image"),
+		center.with_tooltip("The optical center, from (0, 0) at the top left to (1, 1) at the bottom right"),
+		LayoutGroup::Row { widgets: clamp_edges }.with_tooltip("Fill areas sampled from outside of the image with its edge pixels instead of leaving them transparent"),
+	]
+}
+
+pub fn palette_color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let path = text_widget(document_node, node_id, 1, "Palette Path", true);
+	let index = number_widget(document_node, node_id, 2, "Index", NumberInput::default().min(0.).int(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: path }.with_tooltip("The file path or URL of an Adobe Swatch Exchange (.ase) or GIMP palette (.gpl) file"),
+		LayoutGroup::Row { widgets: index }.with_tooltip("Position of the color in the palette, starting from 0. An index past the last color picks the last color"),
+	]
+}
+
+pub fn csv_column_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let path = text_widget(document_node, node_id, 1, "CSV Path", true);
+	let column = text_widget(document_node, node_id, 2, "Column", true);
+
+	vec![
+		LayoutGroup::Row { widgets: path }.with_tooltip("The file path or URL of a CSV file whose first row names the columns"),
+		LayoutGroup::Row { widgets: column }.with_tooltip("The name of the column in the first row of the CSV file. Missing values become 0 in the numbers and empty in the text"),
+	]
+}
+
+pub fn apply_lut_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let path = text_widget(document_node, node_id, 2, "LUT Path", true);
+	let intensity = number_widget(document_node, node_id, 3, "Intensity", NumberInput::default().min(0.).max(100.).unit("%"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: path }.with_tooltip("The file path or URL of a .cube file containing a 3D LUT"),
+		LayoutGroup::Row { widgets: intensity },
+	]
+}
+
+pub fn film_grain_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let intensity = number_widget(document_node, node_id, 1, "Intensity", NumberInput::default().min(0.).max(100.).unit("%"), true);
+	let size = number_widget(document_node, node_id, 2, "Size", NumberInput::default().min(0.01).unit(" px"), true);
+	let seed = number_widget(document_node, node_id, 3, "Seed", NumberInput::default().min(0.).int(), true);
+	let monochrome = bool_widget(document_node, node_id, 4, "Monochrome", true);
+	let blend_mode = blend_mode(document_node, node_id, 5, "Blend Mode", true);
+
+	vec![
+		LayoutGroup::Row { widgets: intensity },
+		LayoutGroup::Row { widgets: size }.with_tooltip("The width of each grain in document units"),
+		LayoutGroup::Row { widgets: seed },
+		LayoutGroup::Row { widgets: monochrome }.with_tooltip("Use the same noise for every channel instead of colored noise"),
+		blend_mode,
+	]
+}
+
 pub fn insert_channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let color_channel = color_channel(document_node, node_id, 2, "Into", true);
+	let color_channel = color_channel_with_alpha(document_node, node_id, 2, "Into", true);
 
 	vec![color_channel]
 }
@@ -754,17 +1373,82 @@ pub fn extract_channel_properties(document_node: &DocumentNode, node_id: NodeId,
 }
 
 pub fn adjust_hsl_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let hue_shift = number_widget(document_node, node_id, 1, "Hue Shift", NumberInput::default().min(-180.).max(180.).unit("°"), true);
-	let saturation_shift = number_widget(document_node, node_id, 2, "Saturation Shift", NumberInput::default().min(-100.).max(100.).unit("%"), true);
+	let colorize_index = 4;
+	let colorize = bool_widget(document_node, node_id, colorize_index, "Colorize", true);
+	let is_colorize = if let &NodeInput::Value {
+		tagged_value: TaggedValue::Bool(colorize_choice),
+		..
+	} = &document_node.inputs[colorize_index]
+	{
+		colorize_choice
+	} else {
+		false
+	};
+
+	// In colorize mode the hue and saturation are absolute values rather than shifts
+	let (hue_name, hue_range, saturation_name, saturation_min) = if is_colorize {
+		("Hue", (0., 360.), "Saturation", 0.)
+	} else {
+		("Hue Shift", (-180., 180.), "Saturation Shift", -100.)
+	};
+	let hue_shift = number_widget(document_node, node_id, 1, hue_name, NumberInput::default().min(hue_range.0).max(hue_range.1).unit("°"), true);
+	let saturation_shift = number_widget(document_node, node_id, 2, saturation_name, NumberInput::default().min(saturation_min).max(100.).unit("%"), true);
 	let lightness_shift = number_widget(document_node, node_id, 3, "Lightness Shift", NumberInput::default().min(-100.).max(100.).unit("%"), true);
 
-	vec![
+	let mut layout = vec![
+		LayoutGroup::Row { widgets: colorize }.with_tooltip("Tint the whole image with a single hue instead of shifting its existing hues"),
 		LayoutGroup::Row { widgets: hue_shift },
 		LayoutGroup::Row { widgets: saturation_shift },
 		LayoutGroup::Row { widgets: lightness_shift },
+	];
+	if !is_colorize {
+		layout.insert(1, hue_range_widget(document_node, node_id, 5, "Range", true));
+	}
+	layout
+}
+
+pub fn adjust_oklch_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let lightness_shift = number_widget(document_node, node_id, 1, "Lightness Shift", NumberInput::default().min(-100.).max(100.).unit("%"), true);
+	let chroma_shift = number_widget(document_node, node_id, 2, "Chroma Shift", NumberInput::default().min(-100.).max(100.).unit("%"), true);
+	let hue_shift = number_widget(document_node, node_id, 3, "Hue Shift", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: lightness_shift },
+		LayoutGroup::Row { widgets: chroma_shift },
+		LayoutGroup::Row { widgets: hue_shift },
 	]
 }
 
+//TODO Use generalized Version of this as soon as it's available
+fn hue_range_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::HueRange(range),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let ranges = [
+			HueRange::Master,
+			HueRange::Reds,
+			HueRange::Yellows,
+			HueRange::Greens,
+			HueRange::Cyans,
+			HueRange::Blues,
+			HueRange::Magentas,
+		];
+		let entries = ranges
+			.into_iter()
+			.map(|range| DropdownEntryData::new(range.to_string()).on_update(update_value(move |_| TaggedValue::HueRange(range), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(range as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Restrict the adjustment to colors with hues in this range")
+}
+
 pub fn brightness_contrast_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let brightness = number_widget(document_node, node_id, 1, "Brightness", NumberInput::default().min(-150.).max(150.), true);
 	let contrast = number_widget(document_node, node_id, 2, "Contrast", NumberInput::default().min(-100.).max(100.), true);
@@ -779,17 +1463,37 @@ pub fn brightness_contrast_properties(document_node: &DocumentNode, node_id: Nod
 
 pub fn _blur_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).max(20.).int(), true);
-	let sigma = number_widget(document_node, node_id, 2, "Sigma", NumberInput::default().min(0.).max(10000.), true);
+	let sigma = number_widget(document_node, node_id, 2, "Sigma", NumberInput::default().min(0.).max(10000.).mode_range().log_scale(true), true);
 
 	vec![LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: sigma }]
 }
 
+pub fn invert_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let red = bool_widget(document_node, node_id, 1, "Red", true);
+	let green = bool_widget(document_node, node_id, 2, "Green", true);
+	let blue = bool_widget(document_node, node_id, 3, "Blue", true);
+	let alpha = bool_widget(document_node, node_id, 4, "Alpha", true);
+
+	vec![
+		LayoutGroup::Row { widgets: red },
+		LayoutGroup::Row { widgets: green },
+		LayoutGroup::Row { widgets: blue },
+		LayoutGroup::Row { widgets: alpha }.with_tooltip("Invert the transparency, which is useful for flipping masks"),
+	]
+}
+
 pub fn adjust_threshold_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let thereshold_min = number_widget(document_node, node_id, 1, "Min Luminance", NumberInput::default().min(0.).max(100.).unit("%"), true);
 	let thereshold_max = number_widget(document_node, node_id, 2, "Max Luminance", NumberInput::default().min(0.).max(100.).unit("%"), true);
 	let luminance_calc = luminance_calculation(document_node, node_id, 3, "Luminance Calc", true);
+	let softness = number_widget(document_node, node_id, 4, "Softness", NumberInput::default().min(0.).max(100.).unit("%"), true);
 
-	vec![LayoutGroup::Row { widgets: thereshold_min }, LayoutGroup::Row { widgets: thereshold_max }, luminance_calc]
+	vec![
+		LayoutGroup::Row { widgets: thereshold_min },
+		LayoutGroup::Row { widgets: thereshold_max },
+		luminance_calc,
+		LayoutGroup::Row { widgets: softness }.with_tooltip("Blend smoothly across each edge of the luminance range instead of cutting hard between black and white"),
+	]
 }
 
 pub fn adjust_vibrance_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -955,8 +1659,12 @@ pub fn _gpu_map_properties(document_node: &DocumentNode, node_id: NodeId, _conte
 
 pub fn multiply_opacity(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let gamma = number_widget(document_node, node_id, 1, "Factor", NumberInput::default().min(0.).max(100.).unit("%"), true);
+	let premultiplied = bool_widget(document_node, node_id, 2, "Premultiplied", true);
 
-	vec![LayoutGroup::Row { widgets: gamma }]
+	vec![
+		LayoutGroup::Row { widgets: gamma },
+		LayoutGroup::Row { widgets: premultiplied }.with_tooltip("Also scale the color channels by the opacity, for images whose colors are multiplied by their alpha"),
+	]
 }
 
 pub fn posterize_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -967,21 +1675,75 @@ pub fn posterize_properties(document_node: &DocumentNode, node_id: NodeId, _cont
 
 #[cfg(feature = "quantization")]
 pub fn quantize_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let value = number_widget(document_node, node_id, 1, "Levels", NumberInput::default().min(1.).max(1000.).int(), true);
+	let value = number_widget(document_node, node_id, 1, "Levels", NumberInput::default().min(1.).max(1000.).int().mode_range().log_scale(true), true);
 	let index = number_widget(document_node, node_id, 1, "Fit Fn Index", NumberInput::default().min(0.).max(2.).int(), true);
 
-	vec![LayoutGroup::Row { widgets: value }, LayoutGroup::Row { widgets: index }]
+	vec![LayoutGroup::Row { widgets: value }, LayoutGroup::Row { widgets: index }]
+}
+pub fn exposure_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let exposure = number_widget(document_node, node_id, 1, "Exposure", NumberInput::default().min(-20.).max(20.), true);
+	let offset = number_widget(document_node, node_id, 2, "Offset", NumberInput::default().min(-0.5).max(0.5), true);
+	let gamma_input = NumberInput::default().min(0.01).max(9.99).mode_increment().increment_step(0.1);
+	let gamma_correction = number_widget(document_node, node_id, 3, "Gamma Correction", gamma_input, true);
+
+	vec![
+		LayoutGroup::Row { widgets: exposure },
+		LayoutGroup::Row { widgets: offset },
+		LayoutGroup::Row { widgets: gamma_correction },
+	]
+}
+
+pub fn clamp_color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let min = number_widget(document_node, node_id, 1, "Min", NumberInput::default().mode_increment().increment_step(0.1), true);
+	let max = number_widget(document_node, node_id, 2, "Max", NumberInput::default().mode_increment().increment_step(0.1), true);
+
+	let rolloff_index = 3;
+	let mut rolloff = start_widgets(document_node, node_id, rolloff_index, "Rolloff", FrontendGraphDataType::General, true);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::ToneMapping(tone_mapping),
+		exposed: false,
+	} = &document_node.inputs[rolloff_index]
+	{
+		let entries = [ToneMapping::None, ToneMapping::Reinhard, ToneMapping::Filmic]
+			.into_iter()
+			.map(|mode| RadioEntryData::new(mode.to_string()).on_update(update_value(move |_| TaggedValue::ToneMapping(mode), node_id, rolloff_index)))
+			.collect();
+		rolloff.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(tone_mapping as u32).widget_holder(),
+		]);
+	}
+
+	vec![
+		LayoutGroup::Row { widgets: min },
+		LayoutGroup::Row { widgets: max },
+		LayoutGroup::Row { widgets: rolloff }.with_tooltip("Compress bright values smoothly before clamping instead of clipping them"),
+	]
+}
+
+pub fn flatten_alpha_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let background = color_widget(document_node, node_id, 1, "Background", ColorInput::default().allow_transparency(false).allow_none(false), true);
+
+	vec![background]
+}
+
+pub fn white_balance_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let reference = color_widget(document_node, node_id, 1, "Neutral Reference", ColorInput::default().allow_transparency(false).allow_none(false), true);
+	let strength = number_widget(document_node, node_id, 2, "Strength", NumberInput::default().min(0.).max(100.).unit("%"), true);
+
+	vec![
+		reference.with_tooltip("A color sampled from the image that should appear gray, such as a white wall or gray card"),
+		LayoutGroup::Row { widgets: strength },
+	]
 }
-pub fn exposure_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let exposure = number_widget(document_node, node_id, 1, "Exposure", NumberInput::default().min(-20.).max(20.), true);
-	let offset = number_widget(document_node, node_id, 2, "Offset", NumberInput::default().min(-0.5).max(0.5), true);
-	let gamma_input = NumberInput::default().min(0.01).max(9.99).mode_increment().increment_step(0.1);
-	let gamma_correction = number_widget(document_node, node_id, 3, "Gamma Correction", gamma_input, true);
+
+pub fn temperature_tint_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let temperature = number_widget(document_node, node_id, 1, "Temperature", NumberInput::default().min(-4000.).max(4000.).unit(" K"), true);
+	let tint = number_widget(document_node, node_id, 2, "Tint", NumberInput::default().min(-100.).max(100.).unit("%"), true);
 
 	vec![
-		LayoutGroup::Row { widgets: exposure },
-		LayoutGroup::Row { widgets: offset },
-		LayoutGroup::Row { widgets: gamma_correction },
+		LayoutGroup::Row { widgets: temperature }.with_tooltip("Offset from daylight white in Kelvin, positive values warm the image and negative values cool it"),
+		LayoutGroup::Row { widgets: tint }.with_tooltip("Positive values shift toward magenta and negative values toward green"),
 	]
 }
 
@@ -1119,19 +1881,64 @@ pub fn transform_properties(document_node: &DocumentNode, node_id: NodeId, _cont
 	vec![translation, rotation, scale]
 }
 
-pub fn node_section_font(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+pub fn node_section_font(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let text = text_area_widget(document_node, node_id, 1, "Text", true);
 	let (font, style) = font_inputs(document_node, node_id, 2, "Font", true);
 	let size = number_widget(document_node, node_id, 3, "Size", NumberInput::default().unit(" px").min(1.), true);
+	let line_height = number_widget(document_node, node_id, 4, "Line Height", NumberInput::default().unit("x").min(0.).step(0.1), true);
+	let letter_spacing = number_widget(document_node, node_id, 5, "Letter Spacing", NumberInput::default().unit(" px"), true);
+	let max_width = number_widget(document_node, node_id, 6, "Max Width", NumberInput::default().unit(" px").min(0.), true);
+	let align = text_align_widget(document_node, node_id, 7, "Align", true);
 
 	let mut result = vec![LayoutGroup::Row { widgets: text }, LayoutGroup::Row { widgets: font }];
 	if let Some(style) = style {
 		result.push(LayoutGroup::Row { widgets: style });
 	}
+	result.extend(fallback_font_inputs(document_node, node_id, 8, "Fallback Fonts", true));
 	result.push(LayoutGroup::Row { widgets: size });
+	result.push(LayoutGroup::Row { widgets: line_height });
+	result.push(LayoutGroup::Row { widgets: letter_spacing });
+	result.push(LayoutGroup::Row { widgets: max_width }.with_tooltip("Wraps lines longer than this width, or leaves them unwrapped when zero"));
+	result.push(align);
+
+	// Variable font axes are only shown for the axes the chosen font varies along, once it has loaded
+	let font_data = match &document_node.inputs[2] {
+		NodeInput::Value {
+			tagged_value: TaggedValue::Font(font),
+			..
+		} => context.persistent_data.font_cache.get(font),
+		_ => None,
+	};
+	if let Some(buzz_face) = font_data.map(|data| load_face(data)) {
+		let axes = [(9, "Weight", FontAxis::Weight, ""), (10, "Width", FontAxis::Width, "%"), (11, "Slant", FontAxis::Slant, "°")];
+		for (index, name, axis, unit) in axes {
+			let Some([min, max]) = axis.range(&buzz_face) else { continue };
+			let number_props = NumberInput::default().unit(unit).min(min).max(max).mode_range();
+			result.push(LayoutGroup::Row {
+				widgets: number_widget(document_node, node_id, index, name, number_props, true),
+			});
+		}
+	}
 	result
 }
 
+/// Dropdown of common resolutions, each switching the resolution to manual. The current resolution is selected if it matches a preset.
+fn imaginate_resolution_presets(resolution: DVec2, node_id: NodeId, index: usize) -> WidgetHolder {
+	use graphene_std::imaginate::{pick_safe_imaginate_resolution, IMAGINATE_RESOLUTION_PRESETS};
+
+	let presets = IMAGINATE_RESOLUTION_PRESETS.map(|(name, (width, height))| {
+		let (width, height) = pick_safe_imaginate_resolution((width as f64, height as f64));
+		(name, DVec2::new(width as f64, height as f64))
+	});
+	let selected_index = presets.iter().position(|&(_, preset)| preset == resolution).map(|index| index as u32);
+	let entries = presets
+		.into_iter()
+		.map(|(name, preset)| DropdownEntryData::new(name).on_update(update_value(move |_| TaggedValue::OptionalDVec2(Some(preset)), node_id, index)))
+		.collect();
+
+	DropdownInput::new(vec![entries]).selected_index(selected_index).tooltip("Common resolutions").widget_holder()
+}
+
 pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let imaginate_node = [context.nested_path, &[node_id]].concat();
 
@@ -1157,6 +1964,11 @@ pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, conte
 	let mask_fill_index = resolve_input("Mask Starting Fill");
 	let faces_index = resolve_input("Improve Faces");
 	let tiling_index = resolve_input("Tiling");
+	let batch_count_index = resolve_input("Batch Count");
+	let control_image_index = resolve_input("Control Image");
+	let control_type_index = resolve_input("Control Type");
+	let variation_seed_index = resolve_input("Variation Seed");
+	let variation_strength_index = resolve_input("Variation Strength");
 
 	let controller = &document_node.inputs[resolve_input("Controller")];
 
@@ -1359,6 +2171,32 @@ pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, conte
 		LayoutGroup::Row { widgets }.with_tooltip("Seed determines the random outcome, enabling limitless unique variations")
 	};
 
+	let variation_seed = {
+		let props = NumberInput::default()
+			.int()
+			.min(-((1u64 << f64::MANTISSA_DIGITS) as f64))
+			.max((1u64 << f64::MANTISSA_DIGITS) as f64)
+			.mode(NumberInputMode::Increment);
+		let widgets = number_widget(document_node, node_id, variation_seed_index, "Variation Seed", props, true);
+		LayoutGroup::Row { widgets }.with_tooltip("Secondary seed whose random outcome is blended into the seed's by the variation strength")
+	};
+
+	let variation_strength = {
+		let widgets = number_widget(document_node, node_id, variation_strength_index, "Variation Strength", NumberInput::default().percentage(), true);
+		LayoutGroup::Row { widgets }.with_tooltip(
+			"Amount the variation seed changes the result, for exploring close variations of a good seed. The seed is reproduced exactly at 0% and replaced by the variation seed at 100%.",
+		)
+	};
+
+	let batch_count = {
+		let widgets = number_widget(document_node, node_id, batch_count_index, "Batch Count", NumberInput::default().min(1.).max(16.), true);
+		LayoutGroup::Row { widgets }.with_tooltip(
+			"Number of variations to generate one after another, with seeds counting up from this seed.\n\
+			\n\
+			The first variation is the 'Image' output, and all of them are listed in the 'Variations' output for use with the Index node.",
+		)
+	};
+
 	let transform = context
 		.executor
 		.introspect_node_in_network(context.network, &imaginate_node, |network| network.inputs.first().copied(), |frame: &ImageFrame<Color>| frame.transform)
@@ -1443,6 +2281,8 @@ pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, conte
 						resolution_index,
 					))
 					.widget_holder(),
+				Separator::new(SeparatorType::Related).widget_holder(),
+				imaginate_resolution_presets(vec2, node_id, resolution_index),
 			])
 		}
 		LayoutGroup::Row { widgets }.with_tooltip(
@@ -1564,6 +2404,9 @@ pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, conte
 		progress,
 		image_controls,
 		seed,
+		variation_seed,
+		variation_strength,
+		batch_count,
 		resolution,
 		sampling_steps,
 		sampling_method,
@@ -1654,6 +2497,38 @@ pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, conte
 	};
 	layout.extend_from_slice(&[improve_faces, tiling]);
 
+	let control_image = {
+		let widgets = start_widgets(document_node, node_id, control_image_index, "Control Image", FrontendGraphDataType::Raster, true);
+		LayoutGroup::Row { widgets }.with_tooltip("Connect a depth map, edge drawing, or pose skeleton to guide the composition of the generated image using ControlNet")
+	};
+	layout.push(control_image);
+
+	if let NodeInput::Node { .. } = document_node.inputs[control_image_index] {
+		let control_type = {
+			let mut widgets = start_widgets(document_node, node_id, control_type_index, "Control Type", FrontendGraphDataType::General, true);
+
+			if let &NodeInput::Value {
+				tagged_value: TaggedValue::ImaginateControlType(control_type),
+				exposed: false,
+			} = &document_node.inputs[control_type_index]
+			{
+				let control_types = ImaginateControlType::list();
+				let mut entries = Vec::with_capacity(control_types.len());
+				for control_type in control_types {
+					entries.push(DropdownEntryData::new(control_type.to_string()).on_update(update_value(move |_| TaggedValue::ImaginateControlType(control_type), node_id, control_type_index)));
+				}
+				let entries = vec![entries];
+
+				widgets.extend_from_slice(&[
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					DropdownInput::new(entries).selected_index(Some(control_type as u32)).widget_holder(),
+				]);
+			}
+			LayoutGroup::Row { widgets }.with_tooltip("What the control image depicts, which decides how the server interprets it to guide the generation")
+		};
+		layout.push(control_type);
+	}
+
 	layout
 }
 
@@ -1671,10 +2546,158 @@ pub fn index_node_properties(document_node: &DocumentNode, node_id: NodeId, _con
 	vec![LayoutGroup::Row { widgets: index }]
 }
 
-pub fn generate_node_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> LayoutGroup {
+pub fn mux_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let max_index = (graphene_core::logic::MUX_INPUT_COUNT - 1) as f64;
+	let index = number_widget(document_node, node_id, 1, "Index", NumberInput::default().min(0.).max(max_index).int(), true);
+
+	vec![LayoutGroup::Row { widgets: index }]
+}
+
+/// Wraps an edit of a curve's keyframes into an update of the input, keeping the keyframes sorted by time.
+fn update_keyframes<T>(curve: &AnimationCurve, edit: impl Fn(&mut Vec<Keyframe>, &T) + 'static + Send + Sync, node_id: NodeId, input_index: usize) -> impl Fn(&T) -> Message + 'static + Send + Sync {
+	let keyframes = curve.keyframes.clone();
+	update_value(
+		move |input: &T| {
+			let mut keyframes = keyframes.clone();
+			edit(&mut keyframes, input);
+			TaggedValue::AnimationCurve(AnimationCurve::new(keyframes))
+		},
+		node_id,
+		input_index,
+	)
+}
+
+fn keyframe_row(curve: &AnimationCurve, index: usize, node_id: NodeId, input_index: usize) -> LayoutGroup {
+	let keyframe = curve.keyframes[index];
+	let mut widgets = vec![TextLabel::new(format!("Keyframe {}", index + 1)).widget_holder()];
+	add_blank_assist(&mut widgets);
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		NumberInput::new(Some(keyframe.time))
+			.label("Time")
+			.on_update(update_keyframes(
+				curve,
+				move |keyframes, input: &NumberInput| keyframes[index].time = input.value.unwrap(),
+				node_id,
+				input_index,
+			))
+			.widget_holder(),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		NumberInput::new(Some(keyframe.value))
+			.label("Value")
+			.on_update(update_keyframes(
+				curve,
+				move |keyframes, input: &NumberInput| keyframes[index].value = input.value.unwrap(),
+				node_id,
+				input_index,
+			))
+			.widget_holder(),
+	]);
+
+	// The interpolation of the last keyframe has no following keyframe to interpolate towards
+	if index + 1 != curve.keyframes.len() {
+		let entries = KeyframeInterpolation::list()
+			.into_iter()
+			.map(|interpolation| {
+				DropdownEntryData::new(interpolation.to_string()).on_update(update_keyframes(curve, move |keyframes, _| keyframes[index].interpolation = interpolation, node_id, input_index))
+			})
+			.collect();
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Related).widget_holder(),
+			DropdownInput::new(vec![entries])
+				.selected_index(Some(keyframe.interpolation as u32))
+				.tooltip("How the value changes on the way to the next keyframe")
+				.widget_holder(),
+		]);
+	}
+
+	if curve.keyframes.len() > 1 {
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("Remove", 16)
+				.tooltip("Remove this keyframe")
+				.on_update(update_keyframes(
+					curve,
+					move |keyframes, _: &IconButton| {
+						keyframes.remove(index);
+					},
+					node_id,
+					input_index,
+				))
+				.widget_holder(),
+		]);
+	}
+
+	// A new keyframe goes halfway to the next keyframe, or 10 frames later after the last one, keeping the current shape of the curve
+	let time = curve.keyframes.get(index + 1).map_or(keyframe.time + 10., |next| (keyframe.time + next.time) / 2.);
+	let new_keyframe = Keyframe::new(time, curve.evaluate(time), keyframe.interpolation);
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Related).widget_holder(),
+		IconButton::new("Add", 16)
+			.tooltip("Add a keyframe after this")
+			.on_update(update_keyframes(
+				curve,
+				move |keyframes, _: &IconButton| keyframes.insert(index + 1, new_keyframe),
+				node_id,
+				input_index,
+			))
+			.widget_holder(),
+	]);
+
+	LayoutGroup::Row { widgets }
+}
+
+pub fn animation_curve_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = start_widgets(document_node, node_id, 1, "Keyframes", FrontendGraphDataType::General, true);
+	let mut rows = vec![LayoutGroup::Row { widgets }.with_tooltip("The value at each point in time, which is held before the first keyframe and after the last")];
+
+	if let NodeInput::Value {
+		tagged_value: TaggedValue::AnimationCurve(curve),
+		exposed: false,
+	} = &document_node.inputs[1]
+	{
+		rows.extend((0..curve.keyframes.len()).map(|index| keyframe_row(curve, index, node_id, 1)));
+		if curve.keyframes.is_empty() {
+			let add = IconButton::new("Add", 16)
+				.tooltip("Add a keyframe")
+				.on_update(update_keyframes(curve, |keyframes, _: &IconButton| keyframes.push(Keyframe::default()), node_id, 1))
+				.widget_holder();
+			rows.push(LayoutGroup::Row { widgets: vec![add] });
+		}
+	}
+	rows
+}
+
+/// The row at the top of a node's properties that toggles whether the node is bypassed, which is disabled for nodes that can't pass their primary input through.
+fn bypass_properties(network: &NodeNetwork, node_id: NodeId) -> LayoutGroup {
+	let can_bypass = super::NodeGraphMessageHandler::can_bypass(network, node_id);
+	let tooltip = if can_bypass {
+		"Pass the primary input straight through to the output, skipping this node"
+	} else {
+		"This node can't be bypassed because it has no input of the same type as its output to pass through"
+	};
+
+	let widgets = vec![
+		TextLabel::new("Bypass").tooltip(tooltip).widget_holder(),
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		CheckboxInput::new(network.bypassed.contains(&node_id))
+			.tooltip(tooltip)
+			.disabled(!can_bypass)
+			.on_update(move |_: &CheckboxInput| NodeGraphMessage::ToggleBypass { node_id }.into())
+			.widget_holder(),
+	];
+	LayoutGroup::Row { widgets }
+}
+
+/// Generates the properties section of a node in the given network, which is the network shown in the node graph rather than necessarily the document's root network.
+pub fn generate_node_properties(document_node: &DocumentNode, node_id: NodeId, network: &NodeNetwork, context: &mut NodePropertiesContext) -> LayoutGroup {
 	let name = document_node.name.clone();
 	let layout = match super::document_node_types::resolve_document_node_type(&name) {
-		Some(document_node_type) => (document_node_type.properties)(document_node, node_id, context),
+		Some(document_node_type) => {
+			let mut layout = vec![bypass_properties(network, node_id)];
+			layout.extend((document_node_type.properties)(document_node, node_id, context));
+			layout
+		}
 		None => unknown_node_properties(document_node),
 	};
 	LayoutGroup::Section { name, layout }
@@ -1688,24 +2711,177 @@ pub fn stroke_properties(document_node: &DocumentNode, node_id: NodeId, _context
 	let line_cap_index = 5;
 	let line_join_index = 6;
 	let miter_limit_index = 7;
+	let align_index = 8;
+	let paint_type_index = 9;
+	let gradient_type_index = 10;
+	let positions_index = 14;
+
+	let paint_type = if let &NodeInput::Value {
+		tagged_value: TaggedValue::FillType(paint_type),
+		..
+	} = &document_node.inputs[paint_type_index]
+	{
+		Some(paint_type)
+	} else {
+		None
+	};
+	let gradient = paint_type == Some(graphene_core::vector::style::FillType::Gradient);
+
+	let mut widgets = vec![fill_type_widget(
+		document_node,
+		node_id,
+		paint_type_index,
+		"Paint Type",
+		&[(FillType::Solid, "Solid"), (FillType::Gradient, "Gradient")],
+	)];
+	if !gradient {
+		widgets.push(color_widget(document_node, node_id, color_index, "Color", ColorInput::default(), true));
+	}
+	if paint_type.is_none() || gradient {
+		widgets.push(gradient_type_widget(document_node, node_id, gradient_type_index));
+		gradient_positions(&mut widgets, document_node, "Gradient Positions", node_id, positions_index);
+	}
 
-	let color = color_widget(document_node, node_id, color_index, "Color", ColorInput::default(), true);
 	let weight = number_widget(document_node, node_id, weight_index, "Weight", NumberInput::default().unit("px").min(0.), true);
 	let dash_lengths = vec_f32_input(document_node, node_id, dash_lengths_index, "Dash Lengths", TextInput::default().centered(true), true);
 	let dash_offset = number_widget(document_node, node_id, dash_offset_index, "Dash Offset", NumberInput::default().unit("px").min(0.), true);
 	let line_cap = line_cap_widget(document_node, node_id, line_cap_index, "Line Cap", true);
 	let line_join = line_join_widget(document_node, node_id, line_join_index, "Line Join", true);
 	let miter_limit = number_widget(document_node, node_id, miter_limit_index, "Miter Limit", NumberInput::default().min(0.), true);
+	let align = stroke_align_widget(document_node, node_id, align_index, "Alignment", true);
 
-	vec![
-		color,
+	widgets.extend([
 		LayoutGroup::Row { widgets: weight },
 		LayoutGroup::Row { widgets: dash_lengths },
 		LayoutGroup::Row { widgets: dash_offset },
 		line_cap,
 		line_join,
 		LayoutGroup::Row { widgets: miter_limit },
-	]
+		align,
+	]);
+	widgets
+}
+
+/// A row for each style layer, from back to front, with its color, the weight of strokes, and a button to remove it, followed by buttons to add another fill or stroke
+pub fn style_layers_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let index = 1;
+	let mut widgets = start_widgets(document_node, node_id, index, "Layers", FrontendGraphDataType::General, true);
+	let NodeInput::Value {
+		tagged_value: TaggedValue::StyleLayers(layers),
+		exposed: false,
+	} = &document_node.inputs[index]
+	else {
+		return vec![LayoutGroup::Row { widgets }];
+	};
+
+	let mut rows = Vec::with_capacity(layers.len() + 1);
+	for (layer_index, layer) in layers.iter().enumerate() {
+		if layer_index != 0 {
+			widgets.push(TextLabel::new("").widget_holder());
+			add_blank_assist(&mut widgets);
+		}
+
+		let replace = {
+			let layers = layers.clone();
+			move |layer: StyleLayer| {
+				let mut new_layers = layers.clone();
+				new_layers[layer_index] = layer;
+				TaggedValue::StyleLayers(new_layers)
+			}
+		};
+		let remove = {
+			let layers = layers.clone();
+			move |_: &IconButton| {
+				let mut new_layers = layers.clone();
+				new_layers.remove(layer_index);
+				TaggedValue::StyleLayers(new_layers)
+			}
+		};
+
+		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		match layer {
+			StyleLayer::Fill(Fill::Gradient(_)) => widgets.push(TextLabel::new("Gradient Fill").widget_holder()),
+			StyleLayer::Fill(Fill::Pattern(_)) => widgets.push(TextLabel::new("Pattern Fill").widget_holder()),
+			StyleLayer::Fill(fill) => {
+				let color = match fill {
+					Fill::Solid(color) => Some(*color),
+					_ => None,
+				};
+				let replace = replace.clone();
+				let on_update = move |color_input: &ColorInput| replace(StyleLayer::Fill(color_input.value.map_or(Fill::None, Fill::Solid)));
+				widgets.extend_from_slice(&[
+					TextLabel::new("Fill").widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					ColorInput::new(color).on_update(update_value(on_update, node_id, index)).widget_holder(),
+				]);
+			}
+			StyleLayer::Stroke(stroke) => {
+				let update_color = {
+					let (replace, stroke) = (replace.clone(), stroke.clone());
+					move |color_input: &ColorInput| {
+						replace(StyleLayer::Stroke(Stroke {
+							color: color_input.value,
+							..stroke.clone()
+						}))
+					}
+				};
+				let update_weight = {
+					let (replace, stroke) = (replace.clone(), stroke.clone());
+					move |number_input: &NumberInput| replace(StyleLayer::Stroke(stroke.clone().with_weight(number_input.value.unwrap_or_default())))
+				};
+				widgets.extend_from_slice(&[
+					TextLabel::new("Stroke").widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					ColorInput::new(stroke.color).on_update(update_value(update_color, node_id, index)).widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					NumberInput::new(Some(stroke.weight))
+						.unit("px")
+						.min(0.)
+						.on_update(update_value(update_weight, node_id, index))
+						.widget_holder(),
+				]);
+			}
+		}
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("Remove", 16)
+				.tooltip("Remove this style layer")
+				.on_update(update_value(remove, node_id, index))
+				.widget_holder(),
+		]);
+
+		let widgets = std::mem::take(&mut widgets);
+		rows.push(LayoutGroup::Row { widgets });
+	}
+
+	let add = |layer: StyleLayer| {
+		let layers = layers.clone();
+		move |_: &TextButton| {
+			let mut new_layers = layers.clone();
+			new_layers.push(layer.clone());
+			TaggedValue::StyleLayers(new_layers)
+		}
+	};
+	if !layers.is_empty() {
+		widgets.push(TextLabel::new("").widget_holder());
+		add_blank_assist(&mut widgets);
+	}
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextButton::new("Add Fill")
+			.icon(Some("Add".into()))
+			.tooltip("Add a fill in front of the other style layers")
+			.on_update(update_value(add(StyleLayer::Fill(Fill::Solid(Color::BLACK))), node_id, index))
+			.widget_holder(),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		TextButton::new("Add Stroke")
+			.icon(Some("Add".into()))
+			.tooltip("Add a stroke in front of the other style layers")
+			.on_update(update_value(add(StyleLayer::Stroke(Stroke::new(Some(Color::BLACK), 2.))), node_id, index))
+			.widget_holder(),
+	]);
+	rows.push(LayoutGroup::Row { widgets });
+	rows
 }
 
 pub fn repeat_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -1723,12 +2899,76 @@ pub fn circle_repeat_properties(document_node: &DocumentNode, node_id: NodeId, _
 	vec![LayoutGroup::Row { widgets: angle_radius }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: count }]
 }
 
+pub fn points_to_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let closed = bool_widget(document_node, node_id, 1, "Closed", true);
+	let smooth = bool_widget(document_node, node_id, 2, "Smooth", true);
+
+	vec![LayoutGroup::Row { widgets: closed }, LayoutGroup::Row { widgets: smooth }]
+}
+
+pub fn delaunay_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let voronoi = bool_widget(document_node, node_id, 1, "Voronoi", true);
+
+	vec![LayoutGroup::Row { widgets: voronoi }]
+}
+
+pub fn mirror_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let axis = mirror_axis_widget(document_node, node_id, 1, "Axis", true);
+	let position = vec2_widget(document_node, node_id, 2, "Axis Position", "X", "Y", " px", add_blank_assist);
+	let angle = number_widget(document_node, node_id, 3, "Axis Angle", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+	let keep_original = bool_widget(document_node, node_id, 4, "Keep Original", true);
+
+	let custom_axis = matches!(
+		&document_node.inputs[1],
+		NodeInput::Value {
+			tagged_value: TaggedValue::MirrorAxis(MirrorAxis::Custom),
+			..
+		}
+	);
+	let mut layout = vec![axis, position];
+	if custom_axis {
+		layout.push(LayoutGroup::Row { widgets: angle });
+	}
+	layout.push(LayoutGroup::Row { widgets: keep_original });
+	layout
+}
+
+pub fn trim_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let fraction = NumberInput::default().min(0.).max(1.).step(0.01).mode_range();
+	let start = number_widget(document_node, node_id, 1, "Start", fraction.clone(), true);
+	let end = number_widget(document_node, node_id, 2, "End", fraction, true);
+	let offset = number_widget(document_node, node_id, 3, "Offset", NumberInput::default().step(0.01), true);
+	let wrap = bool_widget(document_node, node_id, 4, "Wrap", true);
+
+	vec![
+		LayoutGroup::Row { widgets: start },
+		LayoutGroup::Row { widgets: end },
+		LayoutGroup::Row { widgets: offset },
+		LayoutGroup::Row { widgets: wrap }.with_tooltip("When the start comes after the end, keep the part running around through the ends of the path instead of the part between them"),
+	]
+}
+
+pub fn dash_to_geometry_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let dash_lengths = vec_f32_input(document_node, node_id, 1, "Dash Lengths", TextInput::default().centered(true), true);
+	let dash_offset = number_widget(document_node, node_id, 2, "Dash Offset", NumberInput::default().unit("px").min(0.), true);
+
+	vec![LayoutGroup::Row { widgets: dash_lengths }, LayoutGroup::Row { widgets: dash_offset }]
+}
+
+pub fn measure_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let close_open_subpaths = bool_widget(document_node, node_id, 1, "Close Open Subpaths", true);
+
+	vec![LayoutGroup::Row { widgets: close_open_subpaths }]
+}
+
 /// Fill Node Widgets LayoutGroup
 pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let fill_type_index = 1;
 	let solid_color_index = 2;
 	let gradient_type_index = 3;
 	let positions_index = 7;
+	let pattern_image_index = 8;
+	let repeat_index = 9;
 
 	let fill_type = if let &NodeInput::Value {
 		tagged_value: TaggedValue::FillType(fill_type),
@@ -1743,8 +2983,15 @@ pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context:
 	let mut widgets = Vec::new();
 	let gradient = fill_type == Some(graphene_core::vector::style::FillType::Gradient);
 	let solid = fill_type == Some(graphene_core::vector::style::FillType::Solid);
-
-	let fill_type_switch = fill_type_widget(document_node, node_id, fill_type_index);
+	let pattern = fill_type == Some(graphene_core::vector::style::FillType::Pattern);
+
+	let fill_type_switch = fill_type_widget(
+		document_node,
+		node_id,
+		fill_type_index,
+		"Fill Type",
+		&[(FillType::Solid, "Solid"), (FillType::Gradient, "Gradient"), (FillType::Pattern, "Pattern")],
+	);
 	widgets.push(fill_type_switch);
 
 	if fill_type.is_none() || solid {
@@ -1758,6 +3005,13 @@ pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context:
 		gradient_positions(&mut widgets, document_node, "Gradient Positions", node_id, positions_index);
 	}
 
+	if fill_type.is_none() || pattern {
+		let pattern_image = start_widgets(document_node, node_id, pattern_image_index, "Pattern Image", FrontendGraphDataType::Raster, true);
+		let repeat = bool_widget(document_node, node_id, repeat_index, "Repeat", true);
+		widgets.push(LayoutGroup::Row { widgets: pattern_image });
+		widgets.push(LayoutGroup::Row { widgets: repeat });
+	}
+
 	widgets
 }
 
@@ -1778,6 +3032,48 @@ pub fn layer_properties(document_node: &DocumentNode, node_id: NodeId, _context:
 		LayoutGroup::Row { widgets: collapsed },
 	]
 }
+
+pub fn composite_layers_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	["Bottom", "Second", "Third", "Top"]
+		.into_iter()
+		.enumerate()
+		.flat_map(|(layer, name)| {
+			let blend_mode = blend_mode(document_node, node_id, layer * 3 + 1, &format!("{name} Blend Mode"), true);
+			let opacity = number_widget(document_node, node_id, layer * 3 + 2, &format!("{name} Opacity"), NumberInput::default().percentage(), true);
+			[blend_mode, LayoutGroup::Row { widgets: opacity }]
+		})
+		.collect()
+}
+
+pub fn rasterize_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let resolution = number_widget(document_node, node_id, 1, "Resolution", NumberInput::default().min(0.01).max(16.).unit("x"), true);
+	let background = color_widget(document_node, node_id, 2, "Background", ColorInput::default(), true);
+	let anti_aliasing = anti_aliasing_widget(document_node, node_id, 3, "Anti-aliasing", true);
+
+	vec![LayoutGroup::Row { widgets: resolution }, background, anti_aliasing]
+}
+
+pub fn flatten_group_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let resolution = number_widget(document_node, node_id, 1, "Raster Resolution", NumberInput::default().min(0.01).max(16.).unit("x"), true);
+
+	vec![LayoutGroup::Row { widgets: resolution }]
+}
+
+pub fn stack_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 2, "Name", true);
+	let index = number_widget(document_node, node_id, 3, "Index", NumberInput::default().min(0.).int(), true);
+	let from_top = bool_widget(document_node, node_id, 4, "From Top", true);
+
+	vec![LayoutGroup::Row { widgets: name }, LayoutGroup::Row { widgets: index }, LayoutGroup::Row { widgets: from_top }]
+}
+
+pub fn reorder_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let from = number_widget(document_node, node_id, 1, "From", NumberInput::default().min(0.).int(), true);
+	let to = number_widget(document_node, node_id, 2, "To", NumberInput::default().min(0.).int(), true);
+
+	vec![LayoutGroup::Row { widgets: from }, LayoutGroup::Row { widgets: to }]
+}
+
 pub fn artboard_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let location = vec2_widget(document_node, node_id, 1, "Location", "X", "Y", " px", add_blank_assist);
 	let dimensions = vec2_widget(document_node, node_id, 2, "Dimensions", "W", "H", " px", add_blank_assist);
@@ -1785,5 +3081,175 @@ pub fn artboard_properties(document_node: &DocumentNode, node_id: NodeId, _conte
 	let clip = LayoutGroup::Row {
 		widgets: bool_widget(document_node, node_id, 4, "Clip", true),
 	};
-	vec![location, dimensions, background, clip]
+	let corner_radius = LayoutGroup::Row {
+		widgets: number_widget(document_node, node_id, 10, "Corner Radius", NumberInput::default().unit(" px").min(0.), true),
+	};
+	let opacity = LayoutGroup::Row {
+		widgets: number_widget(document_node, node_id, 5, "Opacity", NumberInput::default().percentage(), true),
+	};
+	let mut layout = vec![location, dimensions];
+
+	let background_type_index = 11;
+	let background_type = if let &NodeInput::Value {
+		tagged_value: TaggedValue::FillType(background_type),
+		..
+	} = &document_node.inputs[background_type_index]
+	{
+		Some(background_type)
+	} else {
+		None
+	};
+	let background_types = [(FillType::None, "Transparent"), (FillType::Solid, "Solid"), (FillType::Gradient, "Gradient")];
+	layout.push(fill_type_widget(document_node, node_id, background_type_index, "Background Type", &background_types));
+	if background_type.is_none() || background_type == Some(FillType::Solid) {
+		layout.push(background);
+	}
+	if background_type.is_none() || background_type == Some(FillType::Gradient) {
+		layout.push(gradient_type_widget(document_node, node_id, 12));
+		gradient_positions(&mut layout, document_node, "Gradient Positions", node_id, 16);
+	}
+
+	layout.extend([clip, corner_radius, opacity]);
+
+	let show_label_index = 6;
+	layout.push(LayoutGroup::Row {
+		widgets: bool_widget(document_node, node_id, show_label_index, "Show Label", true),
+	});
+	let label_hidden = matches!(
+		document_node.inputs[show_label_index],
+		NodeInput::Value {
+			tagged_value: TaggedValue::Bool(false),
+			..
+		}
+	);
+	if !label_hidden {
+		let label = text_widget(document_node, node_id, 7, "Label", true);
+		let label_size = number_widget(document_node, node_id, 8, "Label Size", NumberInput::default().unit(" px").min(1.), true);
+		let label_color = color_widget(document_node, node_id, 9, "Label Color", ColorInput::default().allow_none(false), true);
+		layout.extend([LayoutGroup::Row { widgets: label }, LayoutGroup::Row { widgets: label_size }, label_color]);
+	}
+	layout
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::document_node_types::resolve_document_node_type;
+	use super::*;
+
+	use graph_craft::document::DocumentNodeMetadata;
+
+	/// Types the value into the `nth` number input among the widgets, returning the message its update sends
+	fn edit_number(widgets: &[WidgetHolder], nth: usize, value: f64) -> Message {
+		let mut number_inputs = widgets.iter().filter_map(|holder| match &holder.widget {
+			Widget::NumberInput(number_input) => Some(number_input.clone()),
+			_ => None,
+		});
+		let mut number_input = number_inputs.nth(nth).expect("The number input should exist");
+		number_input.value = Some(value);
+		(number_input.on_update.callback)(&number_input)
+	}
+
+	/// Picks the color in the row's color input, returning the message its update sends
+	fn pick_color(row: &LayoutGroup, color: Option<Color>) -> Message {
+		let LayoutGroup::Row { widgets } = row else { panic!("The widget should be a single row") };
+		let mut color_input = widgets
+			.iter()
+			.find_map(|holder| match &holder.widget {
+				Widget::ColorInput(color_input) => Some(color_input.clone()),
+				_ => None,
+			})
+			.expect("The color input should exist");
+		color_input.value = color;
+		(color_input.on_update.callback)(&color_input)
+	}
+
+	fn set_input_value(input_index: usize, value: TaggedValue) -> Message {
+		NodeGraphMessage::SetInputValue { node_id: 0, input_index, value }.into()
+	}
+
+	#[test]
+	fn editing_u32_input_stores_rounded_non_negative_value() {
+		let node = resolve_document_node_type("Repeat").unwrap().to_document_node_default_inputs([], DocumentNodeMetadata::default());
+		let widgets = number_widget(&node, 0, 2, "Count", NumberInput::default(), true);
+
+		for (edited, stored) in [(4.6, 5), (2.4, 2), (-3., 0)] {
+			assert_eq!(edit_number(&widgets, 0, edited), set_input_value(2, TaggedValue::U32(stored)));
+		}
+	}
+
+	#[test]
+	fn editing_uvec2_input_stores_rounded_non_negative_values() {
+		let node = DocumentNode {
+			inputs: vec![NodeInput::value(TaggedValue::UVec2(UVec2::new(1920, 1080)), false)],
+			..Default::default()
+		};
+		let LayoutGroup::Row { widgets } = vec2_widget(&node, 0, 0, "Resolution", "W", "H", " px", add_blank_assist) else {
+			panic!("The widget should be a single row")
+		};
+
+		assert_eq!(edit_number(&widgets, 0, -5.5), set_input_value(0, TaggedValue::UVec2(UVec2::new(0, 1080))));
+		assert_eq!(edit_number(&widgets, 1, 719.5), set_input_value(0, TaggedValue::UVec2(UVec2::new(1920, 720))));
+	}
+
+	#[test]
+	fn alpha_disabled_color_picker_stores_opaque_colors() {
+		let translucent = Color::from_rgbaf32_unchecked(0.2, 0.4, 0.6, 0.5);
+		let opaque = Color::from_rgbaf32_unchecked(0.2, 0.4, 0.6, 1.);
+		let node = |value| DocumentNode {
+			inputs: vec![NodeInput::value(value, false)],
+			..Default::default()
+		};
+
+		let color = node(TaggedValue::Color(Color::WHITE));
+		let without_alpha = color_widget(&color, 0, 0, "Color", ColorInput::default().allow_transparency(false).allow_none(false), true);
+		let with_alpha = color_widget(&color, 0, 0, "Color", ColorInput::default().allow_none(false), true);
+		assert_eq!(pick_color(&without_alpha, Some(translucent)), set_input_value(0, TaggedValue::Color(opaque)));
+		assert_eq!(pick_color(&with_alpha, Some(translucent)), set_input_value(0, TaggedValue::Color(translucent)));
+
+		let optional_color = node(TaggedValue::OptionalColor(None));
+		let row = color_widget(&optional_color, 0, 0, "Color", ColorInput::default().allow_transparency(false), true);
+		assert_eq!(pick_color(&row, Some(translucent)), set_input_value(0, TaggedValue::OptionalColor(Some(opaque))));
+		assert_eq!(pick_color(&row, None), set_input_value(0, TaggedValue::OptionalColor(None)));
+	}
+
+	#[test]
+	fn selecting_a_resolution_preset_sets_its_dimensions() {
+		let Widget::DropdownInput(dropdown) = imaginate_resolution_presets(DVec2::new(512., 512.), 0, 4).widget else {
+			panic!("The presets should be a dropdown")
+		};
+		assert_eq!(dropdown.selected_index, Some(0));
+
+		let select = |label: &str| {
+			let entry = dropdown.entries[0].iter().find(|entry| entry.label == label).expect("The preset should exist");
+			(entry.on_update.callback)(&())
+		};
+		assert_eq!(select("512×768 Portrait"), set_input_value(4, TaggedValue::OptionalDVec2(Some(DVec2::new(512., 768.)))));
+		assert_eq!(select("1024×576 Wide Landscape"), set_input_value(4, TaggedValue::OptionalDVec2(Some(DVec2::new(1024., 576.)))));
+
+		let Widget::DropdownInput(custom) = imaginate_resolution_presets(DVec2::new(640., 448.), 0, 4).widget else {
+			panic!("The presets should be a dropdown")
+		};
+		assert_eq!(custom.selected_index, None, "A custom resolution matches no preset");
+
+		for (name, (width, height)) in graphene_std::imaginate::IMAGINATE_RESOLUTION_PRESETS {
+			let rounded = graphene_std::imaginate::pick_safe_imaginate_resolution((width as f64, height as f64));
+			assert_eq!(rounded, (width, height), "The {name} preset should already be a safe resolution");
+		}
+	}
+
+	#[test]
+	fn color_picker_options_reach_the_widget() {
+		let node = DocumentNode {
+			inputs: vec![NodeInput::value(TaggedValue::Color(Color::WHITE), false)],
+			..Default::default()
+		};
+		let LayoutGroup::Row { widgets } = color_widget(&node, 0, 0, "Background", ColorInput::default().allow_transparency(false).allow_none(false), true) else {
+			panic!("The widget should be a single row")
+		};
+		let Some(Widget::ColorInput(color_input)) = widgets.last().map(|holder| &holder.widget) else {
+			panic!("The color input should be the last widget")
+		};
+		assert!(!color_input.allow_transparency);
+		assert!(!color_input.allow_none);
+	}
 }
@@ -152,6 +152,7 @@ impl<'a> MessageHandler<PropertiesPanelMessage, (&PersistentData, PropertiesPane
 						layer_path: &[],
 						executor,
 						network: &artwork_document.document_network,
+						output_export: node_graph_message_handler.output_export,
 					};
 					register_document_graph_properties(context, node_graph_message_handler, document_name);
 				}
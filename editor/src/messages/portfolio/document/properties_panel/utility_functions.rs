@@ -245,6 +245,7 @@ pub fn register_artwork_layer_properties(
 				layer_path: &layer_path,
 				executor,
 				network: &layer.network,
+				output_export: node_graph_message_handler.output_export,
 			};
 			node_graph_message_handler.collate_properties(&mut context, &mut properties_sections);
 
@@ -648,6 +649,8 @@ fn node_section_fill(fill: &Fill) -> Option<LayoutGroup> {
 				layout
 			},
 		}),
+		// Patterns are only made by the Fill node, whose own properties edit them
+		Fill::Pattern(_) => None,
 	}
 }
 
@@ -0,0 +1,124 @@
+use document_legacy::LayerId;
+
+use glam::DVec2;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+
+/// Remembers the last rasterized artwork below each layer, so its Input Frame is only rasterized again once that artwork changes.
+#[derive(Debug, Clone, Default)]
+pub struct InputFrameCache {
+	entries: HashMap<Vec<LayerId>, InputFrameCacheEntry>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct InputFrameCacheEntry {
+	/// The fingerprint of the last rasterized SVG, along with its image data and size
+	frame: Option<(u64, Vec<u8>, (u32, u32))>,
+	/// Fingerprints of the SVGs sent off to be rasterized, in the order their results are expected back
+	pending: VecDeque<u64>,
+}
+
+/// What to do about a request to refresh a layer's Input Frame
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputFrameRequest {
+	/// The artwork below the layer changed, so it has to be rasterized again
+	Rasterize,
+	/// The artwork below the layer is unchanged, so the previously rasterized image data and size can be used
+	Reuse(Vec<u8>, (u32, u32)),
+	/// The same artwork is already being rasterized, and the graph will run once that finishes
+	InFlight,
+}
+
+impl InputFrameCache {
+	/// Identifies the SVG of the artwork below a layer together with the size it gets rasterized at
+	pub fn fingerprint(svg: &str, size: DVec2) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		svg.hash(&mut hasher);
+		size.x.to_bits().hash(&mut hasher);
+		size.y.to_bits().hash(&mut hasher);
+		hasher.finish()
+	}
+
+	pub fn request(&mut self, layer_path: &[LayerId], fingerprint: u64) -> InputFrameRequest {
+		let entry = self.entries.entry(layer_path.to_vec()).or_default();
+
+		if let Some((_, image_data, size)) = entry.frame.as_ref().filter(|(rasterized, ..)| *rasterized == fingerprint) {
+			return InputFrameRequest::Reuse(image_data.clone(), *size);
+		}
+		if entry.pending.contains(&fingerprint) {
+			return InputFrameRequest::InFlight;
+		}
+
+		entry.pending.push_back(fingerprint);
+		InputFrameRequest::Rasterize
+	}
+
+	/// Keeps the rasterized result of the layer's oldest pending request. Image data that wasn't requested through the cache is ignored.
+	pub fn store(&mut self, layer_path: &[LayerId], image_data: &[u8], size: (u32, u32)) {
+		let Some(entry) = self.entries.get_mut(layer_path) else { return };
+
+		if let Some(fingerprint) = entry.pending.pop_front() {
+			entry.frame = Some((fingerprint, image_data.to_vec(), size));
+		}
+	}
+
+	/// Forgets the layer's rasterized artwork so its next request is rasterized again.
+	/// Rasterizations already in flight are kept, so refreshing repeatedly before one finishes waits for it instead of rasterizing the same artwork again.
+	pub fn invalidate(&mut self, layer_path: &[LayerId]) {
+		if let Some(entry) = self.entries.get_mut(layer_path) {
+			entry.frame = None;
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn repeated_requests_wait_for_the_pending_rasterization() {
+		let mut cache = InputFrameCache::default();
+		let fingerprint = InputFrameCache::fingerprint("<rect />", DVec2::splat(10.));
+
+		assert_eq!(cache.request(&[1], fingerprint), InputFrameRequest::Rasterize);
+		assert_eq!(cache.request(&[1], fingerprint), InputFrameRequest::InFlight);
+
+		cache.store(&[1], &[255; 4], (1, 1));
+		assert_eq!(cache.request(&[1], fingerprint), InputFrameRequest::Reuse(vec![255; 4], (1, 1)));
+		assert_eq!(cache.request(&[2], fingerprint), InputFrameRequest::Rasterize, "Other layers have their own frames");
+	}
+
+	#[test]
+	fn changed_or_invalidated_artwork_is_rasterized_again() {
+		let mut cache = InputFrameCache::default();
+		let small = InputFrameCache::fingerprint("<rect />", DVec2::splat(10.));
+		let large = InputFrameCache::fingerprint("<rect />", DVec2::splat(20.));
+		assert_ne!(small, large);
+
+		cache.request(&[1], small);
+		cache.store(&[1], &[0; 4], (1, 1));
+		assert_eq!(cache.request(&[1], large), InputFrameRequest::Rasterize);
+
+		cache.store(&[1], &[0; 16], (2, 2));
+		assert_eq!(cache.request(&[1], large), InputFrameRequest::Reuse(vec![0; 16], (2, 2)));
+
+		cache.invalidate(&[1]);
+		assert_eq!(cache.request(&[1], large), InputFrameRequest::Rasterize);
+	}
+
+	#[test]
+	fn rapid_refreshes_wait_for_the_pending_rasterization() {
+		let mut cache = InputFrameCache::default();
+		let fingerprint = InputFrameCache::fingerprint("<rect />", DVec2::splat(10.));
+
+		assert_eq!(cache.request(&[1], fingerprint), InputFrameRequest::Rasterize);
+		for _ in 0..3 {
+			cache.invalidate(&[1]);
+			assert_eq!(cache.request(&[1], fingerprint), InputFrameRequest::InFlight);
+		}
+
+		cache.store(&[1], &[255; 4], (1, 1));
+		assert_eq!(cache.request(&[1], fingerprint), InputFrameRequest::Reuse(vec![255; 4], (1, 1)));
+	}
+}
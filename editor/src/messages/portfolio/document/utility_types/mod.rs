@@ -1,5 +1,6 @@
 pub mod clipboards;
 pub mod error;
+pub mod input_frame_cache;
 pub mod layer_panel;
 pub mod misc;
 pub mod transformation;
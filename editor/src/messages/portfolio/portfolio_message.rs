@@ -95,6 +95,12 @@ pub enum PortfolioMessage {
 		data: String,
 	},
 	PrevDocument,
+	RasterizedRegionBelowLayer {
+		document_id: u64,
+		layer_path: Vec<LayerId>,
+		input_image_data: Vec<u8>,
+		size: (u32, u32),
+	},
 	RenderGraphUsingRasterizedRegionBelowLayer {
 		document_id: u64,
 		layer_path: Vec<LayerId>,
@@ -107,6 +113,9 @@ pub enum PortfolioMessage {
 	SetActiveDocument {
 		document_id: u64,
 	},
+	SetAnimationFrame {
+		animation_frame: f64,
+	},
 	SetImageBlobUrl {
 		document_id: u64,
 		layer_path: Vec<LayerId>,
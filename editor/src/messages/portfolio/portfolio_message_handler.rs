@@ -13,7 +13,7 @@ use document_legacy::layers::layer_info::LayerDataType;
 use document_legacy::layers::style::RenderData;
 use document_legacy::Operation as DocumentOperation;
 use graph_craft::document::value::TaggedValue;
-use graph_craft::document::{NodeId, NodeInput};
+use graph_craft::document::{NodeId, NodeInput, NodeNetwork};
 use graphene_core::text::Font;
 
 use glam::DAffine2;
@@ -419,6 +419,24 @@ impl MessageHandler<PortfolioMessage, (&InputPreprocessorMessageHandler, &Prefer
 					responses.add(PortfolioMessage::SelectDocument { document_id: prev_id });
 				}
 			}
+			PortfolioMessage::RasterizedRegionBelowLayer {
+				document_id,
+				layer_path,
+				input_image_data,
+				size,
+			} => {
+				// Only image data rasterized by JS answers a pending request of the Input Frame cache, unlike reused or empty frames
+				if let Some(document) = self.documents.get_mut(&document_id) {
+					document.input_frame_cache.store(&layer_path, &input_image_data, size);
+				}
+
+				responses.add(PortfolioMessage::RenderGraphUsingRasterizedRegionBelowLayer {
+					document_id,
+					layer_path,
+					input_image_data,
+					size,
+				});
+			}
 			PortfolioMessage::RenderGraphUsingRasterizedRegionBelowLayer {
 				document_id,
 				layer_path,
@@ -474,6 +492,13 @@ impl MessageHandler<PortfolioMessage, (&InputPreprocessorMessageHandler, &Prefer
 				self.active_document_id = Some(document_id);
 				responses.add(MenuBarMessage::SendLayout);
 			}
+			PortfolioMessage::SetAnimationFrame { animation_frame } => {
+				self.executor.update_animation_frame(animation_frame);
+
+				if let Some(document) = self.active_document() {
+					Self::rerender_animated_layers(document, responses);
+				}
+			}
 			PortfolioMessage::SetImageBlobUrl {
 				document_id,
 				layer_path,
@@ -646,15 +671,16 @@ impl PortfolioMessageHandler {
 					x
 				}))),
 				LayerDataType::Layer(layer) => {
-					let input_is_font = |input: &NodeInput| {
-						let NodeInput::Value {
+					let input_is_font = |input: &NodeInput| match input {
+						NodeInput::Value {
 							tagged_value: TaggedValue::Font(font),
 							..
-						} = input
-						else {
-							return false;
-						};
-						font == target_font
+						} => font == target_font,
+						NodeInput::Value {
+							tagged_value: TaggedValue::Fonts(fallback_fonts),
+							..
+						} => fallback_fonts.contains(target_font),
+						_ => false,
 					};
 					let should_rerender = layer.network.nodes.values().any(|node| node.inputs.iter().any(input_is_font));
 					if should_rerender {
@@ -666,6 +692,28 @@ impl PortfolioMessageHandler {
 		}
 	}
 
+	/// Re-evaluates the graphs that contain an Animation Frame node so they show the current frame.
+	fn rerender_animated_layers(document: &DocumentMessageHandler, responses: &mut VecDeque<Message>) {
+		let is_animated = |network: &NodeNetwork| network.recursive_nodes().any(|node| node.name == "Animation Frame");
+
+		if is_animated(&document.document_legacy.document_network) {
+			responses.add(NodeGraphMessage::RunDocumentGraph);
+		}
+
+		let mut stack = vec![(&document.document_legacy.root, Vec::new())];
+		while let Some((layer, layer_path)) = stack.pop() {
+			match &layer.data {
+				LayerDataType::Folder(folder) => stack.extend(folder.layers.iter().zip(folder.layer_ids.iter().map(|id| {
+					let mut x = layer_path.clone();
+					x.push(*id);
+					x
+				}))),
+				LayerDataType::Layer(layer) if is_animated(&layer.network) => responses.add(DocumentMessage::InputFrameRasterizeRegionBelowLayer { layer_path }),
+				_ => {}
+			}
+		}
+	}
+
 	pub fn poll_node_graph_evaluation(&mut self, responses: &mut VecDeque<Message>) {
 		let transform = self.active_document().map(|document| document.document_legacy.root.transform).unwrap_or(DAffine2::IDENTITY);
 		self.executor.poll_node_graph_evaluation(transform, responses).unwrap_or_else(|e| {
@@ -309,13 +309,7 @@ impl Fsm for ArtboardToolFsmState {
 						});
 						responses.add(GraphOperationMessage::NewArtboard {
 							id,
-							artboard: graphene_core::Artboard {
-								graphic_group: graphene_core::GraphicGroup::EMPTY,
-								location: start.round().as_ivec2(),
-								dimensions: IVec2::splat(1),
-								background: graphene_core::Color::WHITE,
-								clip: false,
-							},
+							artboard: graphene_core::Artboard::new(start.round().as_ivec2(), IVec2::splat(1)),
 						})
 					}
 
@@ -383,7 +383,7 @@ impl Fsm for BrushToolFsmState {
 						DrawMode::Restore => BlendMode::Restore,
 					};
 					tool_data.strokes.push(BrushStroke {
-						trace: vec![BrushInputSample { position: layer_position }],
+						trace: vec![BrushInputSample::new(layer_position)],
 						style: BrushStyle {
 							color: tool_options.color.active_color().unwrap_or_default(),
 							diameter: tool_options.diameter / layer_scale,
@@ -404,7 +404,7 @@ impl Fsm for BrushToolFsmState {
 
 				(BrushToolFsmState::Drawing, BrushToolMessage::PointerMove) => {
 					if let Some(stroke) = tool_data.strokes.last_mut() {
-						stroke.trace.push(BrushInputSample { position: layer_position })
+						stroke.trace.push(BrushInputSample::new(layer_position))
 					}
 					tool_data.update_strokes(responses);
 
@@ -18,7 +18,7 @@ use document_legacy::LayerId;
 use document_legacy::Operation;
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{DocumentNode, NodeId, NodeInput, NodeNetwork};
-use graphene_core::text::{load_face, Font};
+use graphene_core::text::{load_face, Font, TypesettingConfig};
 use graphene_core::Color;
 
 use glam::{DAffine2, DVec2};
@@ -220,7 +220,7 @@ enum TextToolFsmState {
 pub struct EditingText {
 	text: String,
 	font: Font,
-	font_size: f64,
+	typesetting: TypesettingConfig,
 	color: Option<Color>,
 	transform: DAffine2,
 }
@@ -242,8 +242,8 @@ impl TextToolData {
 		if let Some(editing_text) = self.editing_text.as_ref().filter(|_| editable) {
 			responses.add(FrontendMessage::DisplayEditableTextbox {
 				text: editing_text.text.clone(),
-				line_width: None,
-				font_size: editing_text.font_size,
+				line_width: editing_text.typesetting.max_width,
+				font_size: editing_text.typesetting.font_size,
 				color: editing_text.color.unwrap_or(Color::BLACK),
 				url: render_data.font_cache.get_preview_url(&editing_text.font).cloned().unwrap_or_default(),
 				transform: editing_text.transform.to_cols_array(),
@@ -265,11 +265,11 @@ impl TextToolData {
 		let node_id = get_text_node_id(network)?;
 		let node = network.nodes.get(&node_id)?;
 
-		let (text, font, font_size) = Self::extract_text_node_inputs(node)?;
+		let (text, font, typesetting) = Self::extract_text_node_inputs(node)?;
 		self.editing_text = Some(EditingText {
 			text: text.clone(),
 			font: font.clone(),
-			font_size,
+			typesetting,
 			color: Some(color),
 			transform,
 		});
@@ -293,7 +293,8 @@ impl TextToolData {
 		responses.add(DocumentMessage::SetSelectedLayers { replacement_selected_layers });
 	}
 
-	fn extract_text_node_inputs(node: &DocumentNode) -> Option<(&String, &Font, f64)> {
+	/// Reads the text, font and layout options from the inputs of a text node, falling back to the defaults for layout inputs that aren't plain values
+	fn extract_text_node_inputs(node: &DocumentNode) -> Option<(&String, &Font, TypesettingConfig)> {
 		let NodeInput::Value {
 			tagged_value: TaggedValue::String(text),
 			..
@@ -315,7 +316,29 @@ impl TextToolData {
 		else {
 			return None;
 		};
-		Some((text, font, *font_size))
+		let f64_input = |index: usize| match node.inputs.get(index) {
+			Some(NodeInput::Value {
+				tagged_value: TaggedValue::F64(value),
+				..
+			}) => Some(*value),
+			_ => None,
+		};
+		let defaults = TypesettingConfig::default();
+		let align = match node.inputs.get(7) {
+			Some(NodeInput::Value {
+				tagged_value: TaggedValue::TextAlign(align),
+				..
+			}) => *align,
+			_ => defaults.align,
+		};
+		let typesetting = TypesettingConfig {
+			font_size: *font_size,
+			line_height_ratio: f64_input(4).unwrap_or(defaults.line_height_ratio),
+			letter_spacing: f64_input(5).unwrap_or(defaults.letter_spacing),
+			max_width: f64_input(6).filter(|&max_width| max_width > 0.),
+			align,
+		};
+		Some((text, font, typesetting))
 	}
 
 	fn interact(&mut self, state: TextToolFsmState, mouse: DVec2, document: &DocumentMessageHandler, render_data: &RenderData, responses: &mut VecDeque<Message>) -> TextToolFsmState {
@@ -332,7 +355,7 @@ impl TextToolData {
 		else if let Some(editing_text) = self.editing_text.as_ref().filter(|_| state == TextToolFsmState::Ready) {
 			responses.add(DocumentMessage::StartTransaction);
 
-			let network = new_text_network(String::new(), editing_text.font.clone(), editing_text.font_size);
+			let network = new_text_network(String::new(), editing_text.font.clone(), editing_text.typesetting.font_size);
 
 			responses.add(Operation::AddFrame {
 				path: self.layer_path.clone(),
@@ -372,8 +395,9 @@ impl TextToolData {
 		resize_overlays(&mut self.overlays, responses, 1);
 
 		let editing_text = self.editing_text.as_ref()?;
-		let buzz_face = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data));
-		let far = graphene_core::text::bounding_box(&self.new_text, buzz_face, editing_text.font_size, None);
+		let buzz_faces = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data)).into_iter().collect::<Vec<_>>();
+		let typesetting = editing_text.typesetting;
+		let far = graphene_core::text::bounding_box(&self.new_text, &buzz_faces, typesetting);
 		let quad = Quad::from_box([DVec2::ZERO, far]);
 
 		let transformed_quad = document.document_legacy.multiply_transforms(&self.layer_path).ok()? * quad;
@@ -389,8 +413,9 @@ impl TextToolData {
 
 	fn get_bounds(&self, text: &str, render_data: &RenderData) -> Option<[DVec2; 2]> {
 		let editing_text = self.editing_text.as_ref()?;
-		let buzz_face = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data));
-		let subpaths = graphene_core::text::to_path(text, buzz_face, editing_text.font_size, None);
+		let buzz_faces = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data)).into_iter().collect::<Vec<_>>();
+		let typesetting = editing_text.typesetting;
+		let subpaths = graphene_core::text::to_path(text, &buzz_faces, typesetting);
 		let bounds = subpaths.iter().filter_map(|subpath| subpath.bounding_box());
 		let combined_bounds = bounds.reduce(|a, b| [a[0].min(b[0]), a[1].max(b[1])]).unwrap_or_default();
 		Some(combined_bounds)
@@ -434,9 +459,9 @@ fn update_overlays(document: &DocumentMessageHandler, tool_data: &mut TextToolDa
 		let node_graph = layer.as_layer_network().ok()?;
 		let node_id = get_text_node_id(node_graph)?;
 		let document_node = node_graph.nodes.get(&node_id)?;
-		let (text, font, font_size) = TextToolData::extract_text_node_inputs(document_node)?;
-		let buzz_face = render_data.font_cache.get(font).map(|data| load_face(data));
-		let far = graphene_core::text::bounding_box(text, buzz_face, font_size, None);
+		let (text, font, typesetting) = TextToolData::extract_text_node_inputs(document_node)?;
+		let buzz_faces = render_data.font_cache.get(font).map(|data| load_face(data)).into_iter().collect::<Vec<_>>();
+		let far = graphene_core::text::bounding_box(text, &buzz_faces, typesetting);
 		let quad = Quad::from_box([DVec2::ZERO, far]);
 		let multiplied = document.document_legacy.multiply_transforms(path).ok()? * quad;
 		Some(multiplied.bounding_box())
@@ -521,7 +546,10 @@ impl Fsm for TextToolFsmState {
 					tool_data.editing_text = Some(EditingText {
 						text: String::new(),
 						transform: DAffine2::from_translation(input.mouse.position),
-						font_size: tool_options.font_size as f64,
+						typesetting: TypesettingConfig {
+							font_size: tool_options.font_size as f64,
+							..Default::default()
+						},
 						font: Font::new(tool_options.font_name.clone(), tool_options.font_style.clone()),
 						color: tool_options.fill.active_color(),
 					});
@@ -52,6 +52,7 @@ pub struct NodeRuntime {
 	sender: InternalNodeGraphUpdateSender,
 	wasm_io: Option<WasmApplicationIo>,
 	imaginate_preferences: ImaginatePreferences,
+	animation_frame: f64,
 	pub(crate) thumbnails: HashMap<GraphIdentifier, HashMap<NodeId, SvgSegmentList>>,
 	canvas_cache: HashMap<Vec<LayerId>, SurfaceId>,
 }
@@ -60,6 +61,7 @@ enum NodeRuntimeMessage {
 	GenerationRequest(GenerationRequest),
 	FontCacheUpdate(FontCache),
 	ImaginatePreferencesUpdate(ImaginatePreferences),
+	AnimationFrameUpdate(f64),
 }
 
 pub(crate) struct GenerationRequest {
@@ -108,6 +110,7 @@ impl NodeRuntime {
 			sender: InternalNodeGraphUpdateSender(sender),
 			font_cache: FontCache::default(),
 			imaginate_preferences: Default::default(),
+			animation_frame: 0.,
 			thumbnails: Default::default(),
 			wasm_io: None,
 			canvas_cache: Default::default(),
@@ -127,6 +130,7 @@ impl NodeRuntime {
 			match request {
 				NodeRuntimeMessage::FontCacheUpdate(font_cache) => self.font_cache = font_cache,
 				NodeRuntimeMessage::ImaginatePreferencesUpdate(preferences) => self.imaginate_preferences = preferences,
+				NodeRuntimeMessage::AnimationFrameUpdate(animation_frame) => self.animation_frame = animation_frame,
 				NodeRuntimeMessage::GenerationRequest(GenerationRequest {
 					generation_id,
 					graph,
@@ -168,6 +172,7 @@ impl NodeRuntime {
 			application_io: self.wasm_io.as_ref().unwrap(),
 			node_graph_message_sender: &self.sender,
 			imaginate_preferences: &self.imaginate_preferences,
+			animation_frame: self.animation_frame,
 		};
 
 		// We assume only one output
@@ -224,7 +229,7 @@ impl NodeRuntime {
 			render.format_svg(min, max);
 			debug!("SVG {}", render.svg);
 
-			if let Some(node_id) = node_path.get(node_path.len() - 2).copied() {
+			if let Some(node_id) = node_path.len().checked_sub(2).and_then(|index| node_path.get(index)).copied() {
 				let graph_identifier = GraphIdentifier::new(layer_path.last().copied());
 				let old_thumbnail = self.thumbnails.entry(graph_identifier).or_default().entry(node_id).or_default();
 				if *old_thumbnail != render.svg {
@@ -257,6 +262,20 @@ pub fn introspect_node(path: &[NodeId]) -> Option<Arc<dyn std::any::Any>> {
 		.unwrap_or(None)
 }
 
+pub fn node_output_type(path: &[NodeId]) -> Option<Type> {
+	NODE_RUNTIME
+		.try_with(|runtime| {
+			let runtime = runtime.try_borrow();
+			if let Ok(ref runtime) = runtime {
+				if let Some(ref runtime) = runtime.as_ref() {
+					return runtime.executor.output_type_of_path(path);
+				}
+			}
+			None
+		})
+		.unwrap_or(None)
+}
+
 pub async fn run_node_graph() {
 	let result = NODE_RUNTIME.try_with(|runtime| {
 		let runtime = runtime.clone();
@@ -280,7 +299,13 @@ pub struct NodeGraphExecutor {
 	receiver: Receiver<NodeGraphUpdate>,
 	// TODO: This is a memory leak since layers are never removed
 	pub(crate) last_output_type: HashMap<Vec<LayerId>, Option<Type>>,
+	/// The pixel dimensions of the last raster output of each layer, used to size exports of the render output
+	pub(crate) last_output_dimensions: HashMap<Vec<LayerId>, (u32, u32)>,
 	pub(crate) thumbnails: HashMap<GraphIdentifier, HashMap<NodeId, SvgSegmentList>>,
+	/// The last graphic group output by a graph along with the id of its document, rendered again whenever the document is so only what's in view gets drawn
+	last_graphic_group: Option<(u64, graphene_core::GraphicGroup)>,
+	/// The SVG of the elements of the last rendered graphic group, so only the elements that changed are rendered again
+	render_cache: graphene_core::renderer::RenderCache,
 	futures: HashMap<u64, ExecutionContext>,
 }
 
@@ -303,7 +328,10 @@ impl Default for NodeGraphExecutor {
 			sender: request_sender,
 			receiver: response_receiver,
 			last_output_type: Default::default(),
+			last_output_dimensions: Default::default(),
 			thumbnails: Default::default(),
+			last_graphic_group: None,
+			render_cache: Default::default(),
 		}
 	}
 }
@@ -337,10 +365,41 @@ impl NodeGraphExecutor {
 			.expect("Failed to send imaginate preferences");
 	}
 
+	/// Sets the animation frame output by Animation Frame nodes in the following renders, which `PortfolioMessage::SetAnimationFrame` follows by re-evaluating the graphs that use it.
+	pub fn update_animation_frame(&self, animation_frame: f64) {
+		self.sender.send(NodeRuntimeMessage::AnimationFrameUpdate(animation_frame)).expect("Failed to send animation frame");
+	}
+
+	/// Reads the value cached by the Monitor node at `node_path` during its last evaluation, or `None` if it hasn't been evaluated yet.
+	pub fn read_monitor_value(&self, node_path: &[NodeId]) -> Option<TaggedValue> {
+		let introspection = self.introspect_node(node_path)?;
+		TaggedValue::try_from_std_any_ref(introspection.as_ref()).ok()
+	}
+
+	/// Resolves the type of an output of a node in `network`, where `nested_path` is the path to `network` within the layer's graph.
+	/// Returns `None` if the node wasn't part of the last compiled graph (for example because it's disconnected) or its type wasn't inferred.
+	pub fn output_type_of(&self, network: &NodeNetwork, nested_path: &[NodeId], node_id: NodeId, output_index: usize) -> Option<Type> {
+		let node = network.nodes.get(&node_id)?;
+		let node_path = [nested_path, &[node_id]].concat();
+
+		match &node.implementation {
+			DocumentNodeImplementation::Network(inner_network) => {
+				let output = inner_network.outputs.get(output_index)?;
+				self.output_type_of(inner_network, &node_path, output.node_id, output.node_output_index)
+			}
+			_ if output_index == 0 => node_output_type(&node_path),
+			_ => None,
+		}
+	}
+
 	pub fn previous_output_type(&self, path: &[LayerId]) -> Option<Type> {
 		self.last_output_type.get(path).cloned().flatten()
 	}
 
+	pub fn previous_output_dimensions(&self, path: &[LayerId]) -> Option<(u32, u32)> {
+		self.last_output_dimensions.get(path).copied()
+	}
+
 	pub fn introspect_node_in_network<T: std::any::Any + core::fmt::Debug, U, F1: FnOnce(&NodeNetwork) -> Option<NodeId>, F2: FnOnce(&T) -> U>(
 		&mut self,
 		network: &NodeNetwork,
@@ -467,6 +526,7 @@ impl NodeGraphExecutor {
 
 	fn process_node_graph_output(&mut self, node_graph_output: TaggedValue, layer_path: Vec<LayerId>, _transform: DAffine2, responses: &mut VecDeque<Message>, document_id: u64) -> Result<(), String> {
 		self.last_output_type.insert(layer_path.clone(), Some(node_graph_output.ty()));
+		self.last_output_dimensions.remove(&layer_path);
 		match node_graph_output {
 			TaggedValue::VectorData(vector_data) => {
 				// Update the cached vector data on the layer
@@ -492,6 +552,8 @@ impl NodeGraphExecutor {
 						responses.add(Operation::SetLayerTransform { path: layer_path, transform });
 					}
 				} else {
+					self.last_output_dimensions.insert(layer_path.clone(), (image.width, image.height));
+
 					// Update the image data
 					let image_data = vec![Self::to_frontend_image_data(image, transform, &layer_path, None, None)?];
 					responses.add(FrontendMessage::UpdateImageData { document_id, image_data });
@@ -503,24 +565,9 @@ impl NodeGraphExecutor {
 			}
 			TaggedValue::GraphicGroup(graphic_group) => {
 				debug!("{graphic_group:#?}");
-				use graphene_core::renderer::{GraphicElementRendered, RenderParams, SvgRender};
-
-				// Setup rendering
-				let mut render = SvgRender::new();
-				let render_params = RenderParams::new(ViewMode::Normal, None, false);
-
-				// Render svg
-				graphic_group.render_svg(&mut render, &render_params);
 
-				// Conctenate the defs and the svg into one string
-				let mut svg = "<defs>".to_string();
-				svg.push_str(&render.svg_defs);
-				svg.push_str("</defs>");
-				use std::fmt::Write;
-				write!(svg, "{}", render.svg).unwrap();
-
-				// Send to frontend
-				responses.add(FrontendMessage::UpdateDocumentNodeRender { svg });
+				// The group is rendered by the `DocumentMessage::RenderDocument` that follows, which knows the viewport to cull it to
+				self.last_graphic_group = Some((document_id, graphic_group));
 
 				//return Err("Graphic group (see console)".to_string());
 			}
@@ -531,6 +578,32 @@ impl NodeGraphExecutor {
 		Ok(())
 	}
 
+	/// Renders the last graphic group output by the document's graph, leaving out the elements that lie outside of the viewport.
+	pub fn render_graphic_group(&mut self, document_id: u64, document_to_viewport: DAffine2, viewport_size: DVec2, responses: &mut VecDeque<Message>) {
+		let Some((_, graphic_group)) = self.last_graphic_group.as_ref().filter(|(id, _)| *id == document_id) else {
+			return;
+		};
+		use graphene_core::renderer::{viewport_culling_bounds, RenderParams, SvgRender};
+
+		// Setup rendering
+		let mut render = SvgRender::new();
+		// The ids are derived from the content so rendering the same document, including for export, always gives the same SVG
+		let render_params = RenderParams::new(ViewMode::Normal, viewport_culling_bounds(document_to_viewport, viewport_size), false).with_deterministic_ids();
+
+		// Render svg, reusing the elements that are unchanged since the last render
+		self.render_cache.render_group(graphic_group, &mut render, &render_params, None);
+
+		// Conctenate the defs and the svg into one string
+		let mut svg = "<defs>".to_string();
+		svg.push_str(&render.svg_defs);
+		svg.push_str("</defs>");
+		use std::fmt::Write;
+		write!(svg, "{}", render.svg).unwrap();
+
+		// Send to frontend
+		responses.add(FrontendMessage::UpdateDocumentNodeRender { svg });
+	}
+
 	/// When a blob url for a thumbnail is loaded, update the state and the UI.
 	pub fn insert_thumbnail_blob_url(&mut self, blob_url: String, layer_id: Option<LayerId>, node_id: NodeId, responses: &mut VecDeque<Message>) {
 		if let Some(layer) = self.thumbnails.get_mut(&GraphIdentifier::new(layer_id)) {
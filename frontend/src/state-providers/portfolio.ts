@@ -3,7 +3,7 @@
 import { writable } from "svelte/store";
 
 import { downloadFileText, downloadFileBlob, upload, downloadFileURL } from "@graphite/utility-functions/files";
-import { extractPixelData, imageToPNG, rasterizeSVG, rasterizeSVGCanvas } from "@graphite/utility-functions/rasterization";
+import { extractPixelData, imageToFormat, rasterizeSVG, rasterizeSVGCanvas } from "@graphite/utility-functions/rasterization";
 import { type Editor } from "@graphite/wasm-communication/editor";
 import {
 	type FrontendDocumentDetails,
@@ -62,10 +62,11 @@ export function createPortfolioState(editor: Editor) {
 		const data = await fetch(triggerDownloadBlobUrl.blobUrl);
 		const blob = await data.blob();
 
-		// TODO: Remove this if/when we end up returning PNG directly from the backend
-		const pngBlob = await imageToPNG(blob);
+		// TODO: Remove this if/when we end up returning the encoded image directly from the backend
+		const { mime, quality, size } = triggerDownloadBlobUrl;
+		const encodedBlob = await imageToFormat(blob, mime, quality, size);
 
-		downloadFileBlob(triggerDownloadBlobUrl.layerName, pngBlob);
+		downloadFileBlob(triggerDownloadBlobUrl.layerName, encodedBlob);
 	});
 	editor.subscriptions.subscribeJsMessage(TriggerCopyToClipboardBlobUrl, (triggerDownloadBlobUrl) => {
 		copyToClipboardFileURL(triggerDownloadBlobUrl.blobUrl);
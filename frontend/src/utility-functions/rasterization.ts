@@ -1,4 +1,5 @@
 import { replaceBlobURLsWithBase64 } from "@graphite/utility-functions/files";
+import { type XY } from "@graphite/wasm-communication/messages";
 
 // Rasterize the string of an SVG document at a given width and height and return the canvas it was drawn onto during the rasterization process
 export async function rasterizeSVGCanvas(svg: string, width: number, height: number, backgroundColor?: string): Promise<HTMLCanvasElement> {
@@ -65,17 +66,34 @@ export async function extractPixelData(imageData: ImageBitmapSource): Promise<Im
 
 /// Convert an image source (e.g. BMP document) into a PNG blob
 export async function imageToPNG(imageData: ImageBitmapSource): Promise<Blob> {
-	const canvasContext = await imageToCanvasContext(imageData);
+	return imageToFormat(imageData, "image/png");
+}
+
+/// Convert an image source (e.g. BMP document) into a blob of the given MIME type, with an optional encoder quality in the range 0 to 1 for lossy formats
+/// and an optional pixel size to resample the image to
+export async function imageToFormat(imageData: ImageBitmapSource, mime: string, quality?: number, size?: XY): Promise<Blob> {
+	const canvasContext = await imageToCanvasContext(imageData, size);
+
+	// Flatten onto white if it'll be a JPEG (which does not support transparency and defaults to black)
+	if (mime.endsWith("jpeg")) {
+		canvasContext.globalCompositeOperation = "destination-over";
+		canvasContext.fillStyle = "white";
+		canvasContext.fillRect(0, 0, canvasContext.canvas.width, canvasContext.canvas.height);
+	}
 
 	return new Promise((resolve, reject) => {
-		canvasContext.canvas.toBlob((pngBlob) => {
-			if (pngBlob) resolve(pngBlob);
-			else reject("Converting canvas to blob data failed in imageToPNG()");
-		}, "image/png");
+		canvasContext.canvas.toBlob(
+			(blob) => {
+				if (blob) resolve(blob);
+				else reject("Converting canvas to blob data failed in imageToFormat()");
+			},
+			mime,
+			quality
+		);
 	});
 }
 
-export async function imageToCanvasContext(imageData: ImageBitmapSource): Promise<CanvasRenderingContext2D> {
+export async function imageToCanvasContext(imageData: ImageBitmapSource, size?: XY): Promise<CanvasRenderingContext2D> {
 	// Special handling to rasterize an SVG file
 	let svgImageData;
 	if (imageData instanceof File && imageData.type === "image/svg+xml") {
@@ -99,7 +117,7 @@ export async function imageToCanvasContext(imageData: ImageBitmapSource): Promis
 	// Decode the image file binary data
 	const image = await createImageBitmap(svgImageData || imageData);
 
-	let { width, height } = image;
+	let { width, height } = size ? { width: size.x, height: size.y } : image;
 	width = Math.floor(width);
 	height = Math.floor(height);
 
@@ -73,7 +73,7 @@ export class FrontendDocumentDetails extends DocumentDetails {
 	readonly id!: bigint;
 }
 
-export type FrontendGraphDataType = "general" | "raster" | "color" | "vector" | "vec2" | "graphic" | "artboard";
+export type FrontendGraphDataType = "general" | "raster" | "color" | "vector" | "vec2" | "points" | "graphic" | "artboard";
 
 export class NodeGraphInput {
 	readonly dataType!: FrontendGraphDataType;
@@ -124,6 +124,10 @@ export class FrontendNodeType {
 	readonly name!: string;
 
 	readonly category!: string;
+
+	readonly description!: string;
+
+	readonly keywords!: string[];
 }
 
 export class IndexedDbDocumentDetails extends DocumentDetails {
@@ -527,6 +531,13 @@ export class TriggerDownloadBlobUrl extends JsMessage {
 	readonly layerName!: string;
 
 	readonly blobUrl!: string;
+
+	readonly mime!: string;
+
+	readonly quality!: number;
+
+	@TupleToVec2
+	readonly size!: XY;
 }
 
 export class TriggerDownloadRaster extends JsMessage {
@@ -766,8 +777,9 @@ export class ColorInput extends WidgetProps {
 
 	allowNone!: boolean;
 
+	allowTransparency!: boolean;
+
 	// TODO: Implement
-	// allowTransparency!: boolean;
 	// disabled!: boolean;
 
 	@Transform(({ value }: { value: string }) => value || undefined)
@@ -913,6 +925,8 @@ export class NumberInput extends WidgetProps {
 
 	rangeMax!: number | undefined;
 
+	logScale!: boolean;
+
 	// Styling
 
 	minWidth!: number;
@@ -589,7 +589,7 @@ impl JsEditorHandle {
 	/// Sends the blob URL generated by JS to the Imaginate layer in the respective document
 	#[wasm_bindgen(js_name = renderGraphUsingRasterizedRegionBelowLayer)]
 	pub fn render_graph_using_rasterized_region_below_layer(&self, document_id: u64, layer_path: Vec<LayerId>, input_image_data: Vec<u8>, width: u32, height: u32) {
-		let message = PortfolioMessage::RenderGraphUsingRasterizedRegionBelowLayer {
+		let message = PortfolioMessage::RasterizedRegionBelowLayer {
 			document_id,
 			layer_path,
 			input_image_data,
@@ -675,6 +675,13 @@ impl JsEditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Sets the animation frame read by Animation Frame nodes and re-renders the graphs that use it
+	#[wasm_bindgen(js_name = setAnimationFrame)]
+	pub fn set_animation_frame(&self, animation_frame: f64) {
+		let message = PortfolioMessage::SetAnimationFrame { animation_frame };
+		self.dispatch(message);
+	}
+
 	/// Toggle preview on node
 	#[wasm_bindgen(js_name = togglePreview)]
 	pub fn toggle_preview(&self, node_id: NodeId) {
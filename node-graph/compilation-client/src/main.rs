@@ -42,6 +42,7 @@ fn add_network() -> NodeNetwork {
 		inputs: vec![],
 		outputs: vec![NodeOutput::new(0, 0)],
 		disabled: vec![],
+		bypassed: vec![],
 		previous_outputs: None,
 		nodes: [DocumentNode {
 			name: "Blend Image".into(),
@@ -0,0 +1,56 @@
+//! Compares rendering a document with many elements on one thread and on the whole rayon thread pool.
+//! Run with `cargo bench -p graphene-core --features rayon --bench render`.
+
+use graphene_core::raster::BlendMode;
+use graphene_core::renderer::{GraphicElementRendered, RenderParams, SvgRender};
+use graphene_core::vector::style::ViewMode;
+use graphene_core::vector::VectorData;
+use graphene_core::{GraphicElement, GraphicGroup};
+
+use glam::DVec2;
+use std::time::{Duration, Instant};
+
+const ELEMENTS: usize = 50_000;
+const RUNS: u32 = 10;
+
+fn many_element_document() -> GraphicGroup {
+	let mut group = GraphicGroup::EMPTY;
+	group.extend((0..ELEMENTS).map(|index| {
+		let center = DVec2::new((index % 250) as f64, (index / 250) as f64) * 20.;
+		GraphicElement {
+			name: index.to_string(),
+			blend_mode: BlendMode::Normal,
+			opacity: 1.,
+			visible: true,
+			locked: false,
+			collapsed: false,
+			graphic_element_data: VectorData::from_subpath(bezier_rs::Subpath::new_ellipse(center - 8., center + 8.)).into(),
+			clip: None,
+		}
+	}));
+	group
+}
+
+fn time(name: &str, threads: usize, group: &GraphicGroup, params: &RenderParams) -> Duration {
+	let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().expect("Failed to build the thread pool");
+	let start = Instant::now();
+	let mut length = 0;
+	for _ in 0..RUNS {
+		let mut render = SvgRender::new();
+		pool.install(|| group.render_svg(&mut render, params));
+		length = render.svg.to_string().len();
+	}
+	let average = start.elapsed() / RUNS;
+	println!("{name}: {average:?} per render of {ELEMENTS} elements ({length} bytes)");
+	average
+}
+
+fn main() {
+	let group = many_element_document();
+	let params = RenderParams::new(ViewMode::Normal, None, false).with_deterministic_ids();
+	let threads = rayon::current_num_threads();
+
+	let sequential = time("One thread", 1, &group, &params);
+	let parallel = time("Thread pool", threads, &group, &params);
+	println!("Speedup: {:.2}x on {threads} threads", sequential.as_secs_f64() / parallel.as_secs_f64());
+}
@@ -0,0 +1,161 @@
+//! Keyframed values that change over time.
+
+use crate::Node;
+
+use alloc::vec::Vec;
+use dyn_any::{DynAny, StaticType};
+use serde::{Deserialize, Serialize};
+
+/// How the value of an [`AnimationCurve`] changes between a keyframe and the next one.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash, Serialize, Deserialize, DynAny, specta::Type)]
+pub enum KeyframeInterpolation {
+	#[default]
+	Linear,
+	/// Holds the value of the keyframe until the next keyframe is reached.
+	Step,
+	/// Eases in and out of the keyframes, like the CSS `ease-in-out` timing function.
+	Bezier,
+}
+
+impl core::fmt::Display for KeyframeInterpolation {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			KeyframeInterpolation::Linear => write!(f, "Linear"),
+			KeyframeInterpolation::Step => write!(f, "Step"),
+			KeyframeInterpolation::Bezier => write!(f, "Bezier"),
+		}
+	}
+}
+
+impl KeyframeInterpolation {
+	pub fn list() -> [KeyframeInterpolation; 3] {
+		[KeyframeInterpolation::Linear, KeyframeInterpolation::Step, KeyframeInterpolation::Bezier]
+	}
+
+	/// Maps the progress through a segment, from 0 to 1, to the fraction of the way from the value of its first keyframe to the value of its last.
+	fn ease(self, progress: f64) -> f64 {
+		match self {
+			KeyframeInterpolation::Linear => progress,
+			KeyframeInterpolation::Step => 0.,
+			KeyframeInterpolation::Bezier => ease_in_out(progress),
+		}
+	}
+}
+
+/// Evaluates the `cubic-bezier(0.42, 0, 0.58, 1)` timing function by finding the bezier parameter whose x coordinate is `x` with bisection,
+/// which converges reliably since x increases monotonically with the parameter.
+fn ease_in_out(x: f64) -> f64 {
+	let bezier = |t: f64, p1: f64, p2: f64| 3. * (1. - t) * (1. - t) * t * p1 + 3. * (1. - t) * t * t * p2 + t * t * t;
+
+	let (mut low, mut high) = (0., 1.);
+	for _ in 0..32 {
+		let mid = (low + high) / 2.;
+		if bezier(mid, 0.42, 0.58) < x {
+			low = mid;
+		} else {
+			high = mid;
+		}
+	}
+	bezier((low + high) / 2., 0., 1.)
+}
+
+/// A value at a point in time, along with how the curve continues from it to the next keyframe.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, DynAny, specta::Type)]
+pub struct Keyframe {
+	pub time: f64,
+	pub value: f64,
+	pub interpolation: KeyframeInterpolation,
+}
+
+impl Keyframe {
+	pub fn new(time: f64, value: f64, interpolation: KeyframeInterpolation) -> Self {
+		Self { time, value, interpolation }
+	}
+}
+
+/// A value that changes over time, defined by keyframes sorted by their time.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, DynAny, specta::Type)]
+pub struct AnimationCurve {
+	pub keyframes: Vec<Keyframe>,
+}
+
+impl core::hash::Hash for AnimationCurve {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.keyframes.len().hash(state);
+		for keyframe in &self.keyframes {
+			keyframe.time.to_bits().hash(state);
+			keyframe.value.to_bits().hash(state);
+			keyframe.interpolation.hash(state);
+		}
+	}
+}
+
+impl AnimationCurve {
+	/// Constructs a curve from keyframes in any order.
+	pub fn new(mut keyframes: Vec<Keyframe>) -> Self {
+		keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+		Self { keyframes }
+	}
+
+	/// The value of the curve at `time`, which is held at the first and last keyframes before and after them. A curve without keyframes is always 0.
+	pub fn evaluate(&self, time: f64) -> f64 {
+		let next = self.keyframes.partition_point(|keyframe| keyframe.time <= time);
+		match (next.checked_sub(1).and_then(|previous| self.keyframes.get(previous)), self.keyframes.get(next)) {
+			(Some(start), Some(end)) => {
+				let progress = (time - start.time) / (end.time - start.time);
+				start.value + (end.value - start.value) * start.interpolation.ease(progress)
+			}
+			(Some(keyframe), None) | (None, Some(keyframe)) => keyframe.value,
+			(None, None) => 0.,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationCurveNode<Curve> {
+	curve: Curve,
+}
+
+#[node_macro::node_fn(AnimationCurveNode)]
+fn animation_curve(time: f64, curve: AnimationCurve) -> f64 {
+	curve.evaluate(time)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	fn curve(interpolation: KeyframeInterpolation) -> AnimationCurve {
+		AnimationCurve::new(vec![Keyframe::new(30., 50., KeyframeInterpolation::Linear), Keyframe::new(10., 10., interpolation)])
+	}
+
+	fn evaluate(interpolation: KeyframeInterpolation, time: f64) -> f64 {
+		AnimationCurveNode::new(ClonedNode::new(curve(interpolation))).eval(time)
+	}
+
+	#[test]
+	fn linear_interpolation_between_two_keyframes() {
+		assert_eq!([10., 15., 20., 30.].map(|time| evaluate(KeyframeInterpolation::Linear, time)), [10., 20., 30., 50.]);
+	}
+
+	#[test]
+	fn step_interpolation_holds_until_the_next_keyframe() {
+		assert_eq!([10., 20., 29.9, 30.].map(|time| evaluate(KeyframeInterpolation::Step, time)), [10., 10., 10., 50.]);
+	}
+
+	#[test]
+	fn bezier_interpolation_eases_in_and_out() {
+		let [start, early, middle, late, end] = [10., 12., 20., 28., 30.].map(|time| evaluate(KeyframeInterpolation::Bezier, time));
+		assert_eq!((start, end), (10., 50.));
+		assert!((middle - 30.).abs() < 1e-6, "The curve is symmetric, so it should be halfway at the middle, not {middle}");
+		assert!(early < 14. && late > 46., "The curve should start and end slower than linear interpolation, not {early} and {late}");
+	}
+
+	#[test]
+	fn time_outside_the_keyframes_is_clamped() {
+		assert_eq!(evaluate(KeyframeInterpolation::Linear, -100.), 10.);
+		assert_eq!(evaluate(KeyframeInterpolation::Linear, 100.), 50.);
+		assert_eq!(AnimationCurve::default().evaluate(5.), 0.);
+	}
+}
@@ -146,6 +146,8 @@ pub struct EditorApi<'a, Io> {
 	pub application_io: &'a Io,
 	pub node_graph_message_sender: &'a dyn NodeGraphUpdateSender,
 	pub imaginate_preferences: &'a dyn GetImaginatePreferences,
+	/// The animation frame being rendered, which is 0 unless the editor is rendering an animation.
+	pub animation_frame: f64,
 }
 
 impl<'a, Io> Clone for EditorApi<'a, Io> {
@@ -156,13 +158,14 @@ impl<'a, Io> Clone for EditorApi<'a, Io> {
 			application_io: self.application_io,
 			node_graph_message_sender: self.node_graph_message_sender,
 			imaginate_preferences: self.imaginate_preferences,
+			animation_frame: self.animation_frame,
 		}
 	}
 }
 
 impl<'a, T> PartialEq for EditorApi<'a, T> {
 	fn eq(&self, other: &Self) -> bool {
-		self.image_frame == other.image_frame && self.font_cache == other.font_cache
+		self.image_frame == other.image_frame && self.font_cache == other.font_cache && self.animation_frame == other.animation_frame
 	}
 }
 
@@ -170,6 +173,7 @@ impl<'a, T> Hash for EditorApi<'a, T> {
 	fn hash<H: Hasher>(&self, state: &mut H) {
 		self.image_frame.hash(state);
 		self.font_cache.hash(state);
+		self.animation_frame.to_bits().hash(state);
 	}
 }
 
@@ -204,3 +208,100 @@ impl ExtractImageFrame {
 		Self
 	}
 }
+
+/// Outputs whether an image frame has any pixels. [`ExtractImageFrame`] yields an empty frame when there is no artwork under the layer,
+/// so this tells a real input frame apart from a missing one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImageFrameAvailableNode;
+
+impl<'input> Node<'input, ImageFrame<Color>> for ImageFrameAvailableNode {
+	type Output = bool;
+	fn eval(&'input self, image_frame: ImageFrame<Color>) -> Self::Output {
+		Self::is_available(&image_frame)
+	}
+}
+
+impl ImageFrameAvailableNode {
+	pub fn new() -> Self {
+		Self
+	}
+
+	/// Whether the frame holds an image, since an empty frame is supplied when no image has been rendered below the layer yet.
+	pub fn is_available(image_frame: &ImageFrame<Color>) -> bool {
+		image_frame.image.width > 0 && image_frame.image.height > 0
+	}
+}
+
+/// Outputs the animation frame the editor is rendering, for driving parameters of animated graphs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameNode;
+
+impl<'a: 'input, 'input, T> Node<'input, EditorApi<'a, T>> for FrameNode {
+	type Output = f64;
+	fn eval(&'input self, editor_api: EditorApi<'a, T>) -> Self::Output {
+		editor_api.animation_frame
+	}
+}
+
+impl FrameNode {
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	struct NoPreferences;
+	impl GetImaginatePreferences for NoPreferences {
+		fn get_host_name(&self) -> &str {
+			""
+		}
+	}
+
+	struct NoUpdates;
+	impl NodeGraphUpdateSender for NoUpdates {
+		fn send(&self, _message: NodeGraphUpdateMessage) {}
+	}
+
+	fn editor_api(font_cache: &FontCache, animation_frame: f64) -> EditorApi<'_, ()> {
+		EditorApi {
+			image_frame: None,
+			font_cache,
+			application_io: &(),
+			node_graph_message_sender: &NoUpdates,
+			imaginate_preferences: &NoPreferences,
+			animation_frame,
+		}
+	}
+
+	#[test]
+	fn frame_node_outputs_the_injected_frame() {
+		let font_cache = FontCache::default();
+		assert_eq!(FrameNode::new().eval(editor_api(&font_cache, 0.)), 0.);
+		assert_eq!(FrameNode::new().eval(editor_api(&font_cache, 42.)), 42.);
+		assert_ne!(editor_api(&font_cache, 1.), editor_api(&font_cache, 2.), "Graphs should rerender when the frame changes");
+	}
+
+	#[test]
+	fn image_frame_availability() {
+		let font_cache = FontCache::default();
+		let available = |editor_api| ImageFrameAvailableNode::new().eval(ExtractImageFrame::new().eval(editor_api));
+
+		assert!(!available(editor_api(&font_cache, 0.)), "A missing input frame should not be available");
+		let empty = EditorApi {
+			image_frame: Some(ImageFrame::identity()),
+			..editor_api(&font_cache, 0.)
+		};
+		assert!(!available(empty), "An empty input frame should not be available");
+		let artwork = EditorApi {
+			image_frame: Some(ImageFrame {
+				image: crate::raster::Image::new(1, 1, Color::WHITE),
+				transform: DAffine2::IDENTITY,
+			}),
+			..editor_api(&font_cache, 0.)
+		};
+		assert!(available(artwork));
+	}
+}
@@ -1,11 +1,12 @@
 use crate::raster::{BlendMode, ImageFrame};
+use crate::vector::style::{Fill, FillType, Gradient};
 use crate::vector::VectorData;
 use crate::{Color, Node};
 
 use dyn_any::{DynAny, StaticType};
 
 use core::ops::{Deref, DerefMut};
-use glam::IVec2;
+use glam::{DAffine2, DVec2, IVec2};
 use node_macro::node_fn;
 
 pub mod renderer;
@@ -38,18 +39,67 @@ pub struct GraphicElement {
 	pub locked: bool,
 	pub collapsed: bool,
 	pub graphic_element_data: GraphicElementData,
+	/// A shape that the element is clipped to, in the same space as the element
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub clip: Option<Box<VectorData>>,
 }
 
 /// Some [`ArtboardData`] with some optional clipping bounds that can be exported.
 /// Similar to an Inkscape page: https://media.inkscape.org/media/doc/release_notes/1.2/Inkscape_1.2.html#Page_tool
-#[derive(Clone, Debug, Hash, PartialEq, DynAny)]
+#[derive(Clone, Debug, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Artboard {
 	pub graphic_group: GraphicGroup,
 	pub location: IVec2,
 	pub dimensions: IVec2,
+	/// The color of a solid background
 	pub background: Color,
+	/// Whether the background is transparent, a solid color, or a gradient. Image patterns aren't supported, so they count as solid.
+	#[cfg_attr(feature = "serde", serde(default = "solid"))]
+	pub background_type: FillType,
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub background_gradient: Gradient,
 	pub clip: bool,
+	/// The opacity of the contents, in range 0..=1. The background stays opaque.
+	#[cfg_attr(feature = "serde", serde(default = "opaque"))]
+	pub opacity: f32,
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub label: ArtboardLabel,
+	/// Rounds the corners of the clip. Zero keeps them square.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub corner_radius: f64,
+}
+
+#[cfg(feature = "serde")]
+fn opaque() -> f32 {
+	1.
+}
+
+#[cfg(feature = "serde")]
+fn solid() -> FillType {
+	FillType::Solid
+}
+
+/// The text drawn above the top left corner of an [`Artboard`] in the editor. It's left out of the artboard's bounds, so it never changes what gets exported.
+#[derive(Clone, Debug, PartialEq, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArtboardLabel {
+	pub visible: bool,
+	/// Shown in place of the name of the artboard's layer, unless empty
+	pub text: String,
+	pub font_size: f64,
+	pub color: Color,
+}
+
+impl Default for ArtboardLabel {
+	fn default() -> Self {
+		Self {
+			visible: true,
+			text: String::new(),
+			font_size: 14.,
+			color: Color::WHITE,
+		}
+	}
 }
 
 impl Artboard {
@@ -59,7 +109,21 @@ impl Artboard {
 			location: location.min(location + dimensions),
 			dimensions: dimensions.abs(),
 			background: Color::WHITE,
+			background_type: FillType::Solid,
+			background_gradient: Gradient::default(),
 			clip: false,
+			opacity: 1.,
+			label: ArtboardLabel::default(),
+			corner_radius: 0.,
+		}
+	}
+
+	/// The fill the background is drawn with
+	pub fn background_fill(&self) -> Fill {
+		match self.background_type {
+			FillType::None => Fill::None,
+			FillType::Solid | FillType::Pattern => Fill::Solid(self.background),
+			FillType::Gradient => Fill::Gradient(self.background_gradient.clone()),
 		}
 	}
 }
@@ -93,25 +157,267 @@ fn construct_layer<Data: Into<GraphicElementData>>(
 		locked,
 		collapsed,
 		graphic_element_data: graphic_element_data.into(),
+		clip: None,
 	});
 	stack
 }
 
-pub struct ConstructArtboardNode<Location, Dimensions, Background, Clip> {
+pub struct ClipNode<Mask> {
+	mask: Mask,
+}
+
+/// Wraps the group in a single element that is clipped to the mask. A mask without any subpaths leaves the group unclipped.
+#[node_fn(ClipNode)]
+fn clip(graphic_group: GraphicGroup, mask: VectorData) -> GraphicGroup {
+	if mask.subpaths.is_empty() {
+		return graphic_group;
+	}
+	GraphicGroup(vec![GraphicElement {
+		name: "Clip".to_string(),
+		blend_mode: BlendMode::Normal,
+		opacity: 1.,
+		visible: true,
+		locked: false,
+		collapsed: false,
+		graphic_element_data: graphic_group.into(),
+		clip: Some(Box::new(mask)),
+	}])
+}
+
+pub struct ReorderElementNode<From, To> {
+	from: From,
+	to: To,
+}
+
+#[node_fn(ReorderElementNode)]
+fn reorder_element(mut graphic_group: GraphicGroup, from: u32, to: u32) -> GraphicGroup {
+	graphic_group.move_element(from as usize, to as usize);
+	graphic_group
+}
+
+pub struct StackNode<Element, Name, Index, FromTop> {
+	element: Element,
+	name: Name,
+	index: Index,
+	from_top: FromTop,
+}
+
+/// Inserts the element into the stack as a new layer, `index` positions from the top (or from the bottom when `from_top` is off).
+/// An index of 0 therefore appends the element on top of the stack, or prepends it beneath everything else. Indices past the end are clamped.
+#[node_fn(StackNode)]
+fn stack<Data: Into<GraphicElementData>>(mut stack: GraphicGroup, element: Data, name: String, index: u32, from_top: bool) -> GraphicGroup {
+	let index = (index as usize).min(stack.len());
+	let index = if from_top { stack.len() - index } else { index };
+	stack.insert_element(
+		index,
+		GraphicElement {
+			name,
+			blend_mode: BlendMode::Normal,
+			opacity: 1.,
+			visible: true,
+			locked: false,
+			collapsed: false,
+			graphic_element_data: element.into(),
+			clip: None,
+		},
+	);
+	stack
+}
+
+pub struct RasterizeNode<Scale, Background, AntiAliasing> {
+	scale: Scale,
+	background: Background,
+	anti_aliasing: AntiAliasing,
+}
+
+/// Renders the fills and images to pixels covering their bounding box, with `scale` pixels per unit of document space, over an optional background color.
+/// Content without bounds, like empty vector data, produces an empty image.
+#[node_fn(RasterizeNode)]
+fn rasterize_node<Data: renderer::GraphicElementRendered>(data: Data, scale: f64, background: Option<Color>, anti_aliasing: renderer::AntiAliasing) -> ImageFrame<Color> {
+	let Some(bounds) = data.bounding_box(DAffine2::IDENTITY).filter(|_| scale > 0.) else {
+		return ImageFrame::empty();
+	};
+
+	let mut image_frame = renderer::rasterize(&data, bounds, scale, anti_aliasing);
+	if let Some(background) = background {
+		let background = background.to_linear_srgb().premultiply_alpha();
+		image_frame.image.data.iter_mut().for_each(|pixel| *pixel = background.alpha_blend(*pixel));
+	}
+	image_frame
+}
+
+pub struct FlattenGroupNode<Resolution> {
+	resolution: Resolution,
+}
+
+/// Combines the visible elements of the group, including those of nested groups, into a single element.
+/// Plain vector shapes are merged into one shape with the style of the bottom one. Anything else, like images or elements with their own opacity, blend mode, or clip, makes the whole group get rasterized with `resolution` pixels per unit of document space.
+#[node_fn(FlattenGroupNode)]
+fn flatten_group(graphic_group: GraphicGroup, resolution: f64) -> GraphicGroup {
+	let mut shapes = Vec::new();
+	let graphic_element_data = if collect_plain_shapes(&graphic_group, &mut shapes) {
+		let Some(bottom) = shapes.first() else { return GraphicGroup::EMPTY };
+		let mut combined = VectorData::empty();
+		combined.style = bottom.style.clone();
+		combined.subpaths = shapes
+			.iter()
+			.flat_map(|shape| {
+				shape.subpaths.iter().map(|subpath| {
+					let mut subpath = subpath.clone();
+					subpath.apply_transform(shape.transform);
+					subpath
+				})
+			})
+			.collect();
+		combined.into()
+	} else {
+		let Some(bounds) = renderer::GraphicElementRendered::bounding_box(&graphic_group, DAffine2::IDENTITY).filter(|_| resolution > 0.) else {
+			return GraphicGroup::EMPTY;
+		};
+		renderer::rasterize(&graphic_group, bounds, resolution, renderer::AntiAliasing::default()).into()
+	};
+
+	GraphicGroup(vec![GraphicElement {
+		name: "Flattened".to_string(),
+		blend_mode: BlendMode::Normal,
+		opacity: 1.,
+		visible: true,
+		locked: false,
+		collapsed: false,
+		graphic_element_data,
+		clip: None,
+	}])
+}
+
+/// Gathers the visible vector shapes of the group and its nested groups from bottom to top, returning false if anything else would be drawn
+fn collect_plain_shapes<'a>(graphic_group: &'a GraphicGroup, shapes: &mut Vec<&'a VectorData>) -> bool {
+	graphic_group.iter().filter(|element| element.visible).all(|element| {
+		let plain = element.blend_mode == BlendMode::Normal && element.opacity >= 1. && element.clip.is_none();
+		plain
+			&& match &element.graphic_element_data {
+				GraphicElementData::VectorShape(vector_data) => {
+					shapes.push(vector_data);
+					true
+				}
+				GraphicElementData::GraphicGroup(graphic_group) => collect_plain_shapes(graphic_group, shapes),
+				_ => false,
+			}
+	})
+}
+
+pub struct UngroupNode;
+
+/// Moves the elements of each nested group up into the group, in place of the nested group.
+/// The nested group's opacity and visibility carry over to its elements, as do its blend mode and clip where an element doesn't have its own. Nested groups with a blend mode or clip that their elements can't take on are left as they are.
+#[node_fn(UngroupNode)]
+fn ungroup(graphic_group: GraphicGroup) -> GraphicGroup {
+	let mut ungrouped = GraphicGroup::EMPTY;
+	for element in graphic_group.0 {
+		let GraphicElementData::GraphicGroup(nested) = &element.graphic_element_data else {
+			ungrouped.push(element);
+			continue;
+		};
+
+		let blended = element.blend_mode != BlendMode::Normal;
+		let conflicting = |child: &GraphicElement| (blended && child.blend_mode != BlendMode::Normal) || (element.clip.is_some() && child.clip.is_some());
+		if nested.iter().any(conflicting) {
+			ungrouped.push(element);
+			continue;
+		}
+
+		ungrouped.extend(nested.iter().cloned().map(|mut child| {
+			child.opacity *= element.opacity;
+			child.visible &= element.visible;
+			if blended {
+				child.blend_mode = element.blend_mode;
+			}
+			if child.clip.is_none() {
+				child.clip = element.clip.clone();
+			}
+			child
+		}));
+	}
+	ungrouped
+}
+
+pub struct ConstructArtboardNode<
+	Location,
+	Dimensions,
+	Background,
+	Clip,
+	Opacity,
+	ShowLabel,
+	Label,
+	LabelSize,
+	LabelColor,
+	CornerRadius,
+	BackgroundType,
+	BackgroundGradientType,
+	GradientStart,
+	GradientEnd,
+	GradientTransform,
+	GradientPositions,
+> {
 	location: Location,
 	dimensions: Dimensions,
 	background: Background,
 	clip: Clip,
+	opacity: Opacity,
+	show_label: ShowLabel,
+	label: Label,
+	label_size: LabelSize,
+	label_color: LabelColor,
+	corner_radius: CornerRadius,
+	background_type: BackgroundType,
+	gradient_type: BackgroundGradientType,
+	start: GradientStart,
+	end: GradientEnd,
+	transform: GradientTransform,
+	positions: GradientPositions,
 }
 
 #[node_fn(ConstructArtboardNode)]
-fn construct_artboard(graphic_group: GraphicGroup, location: IVec2, dimensions: IVec2, background: Color, clip: bool) -> Artboard {
+fn construct_artboard(
+	graphic_group: GraphicGroup,
+	location: IVec2,
+	dimensions: IVec2,
+	background: Color,
+	clip: bool,
+	opacity: f32,
+	show_label: bool,
+	label: String,
+	label_size: f64,
+	label_color: Color,
+	corner_radius: f64,
+	background_type: FillType,
+	gradient_type: crate::vector::style::GradientType,
+	start: DVec2,
+	end: DVec2,
+	transform: DAffine2,
+	positions: Vec<(f64, Option<Color>)>,
+) -> Artboard {
 	Artboard {
 		graphic_group,
 		location: location.min(location + dimensions),
 		dimensions: dimensions.abs(),
 		background,
+		background_type,
+		background_gradient: Gradient {
+			start,
+			end,
+			transform,
+			positions,
+			gradient_type,
+		},
 		clip,
+		opacity: (opacity / 100.).clamp(0., 1.),
+		label: ArtboardLabel {
+			visible: show_label,
+			text: label,
+			font_size: label_size.max(0.),
+			color: label_color,
+		},
+		corner_radius: corner_radius.max(0.),
 	}
 }
 
@@ -151,6 +457,24 @@ impl DerefMut for GraphicGroup {
 
 impl GraphicGroup {
 	pub const EMPTY: Self = Self(Vec::new());
+
+	/// Moves the element at index `from` to index `to`, shifting the elements in between.
+	/// Elements are rendered in order, so later ones are drawn on top. Indices past the end are clamped to the last element.
+	pub fn move_element(&mut self, from: usize, to: usize) {
+		let Some(last) = self.0.len().checked_sub(1) else { return };
+		let (from, to) = (from.min(last), to.min(last));
+		if from < to {
+			self.0[from..=to].rotate_left(1);
+		} else {
+			self.0[to..=from].rotate_right(1);
+		}
+	}
+
+	/// Inserts the element at `index`, shifting the elements above it up. Indices past the end place the element on top.
+	pub fn insert_element(&mut self, index: usize, element: GraphicElement) {
+		let index = index.min(self.0.len());
+		self.0.insert(index, element);
+	}
 }
 
 impl core::hash::Hash for GraphicElement {
@@ -162,5 +486,146 @@ impl core::hash::Hash for GraphicElement {
 		self.locked.hash(state);
 		self.collapsed.hash(state);
 		self.graphic_element_data.hash(state);
+		self.clip.hash(state);
+	}
+}
+
+impl core::hash::Hash for Artboard {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.graphic_group.hash(state);
+		self.location.hash(state);
+		self.dimensions.hash(state);
+		self.background.hash(state);
+		self.background_type.hash(state);
+		self.background_gradient.hash(state);
+		self.clip.hash(state);
+		self.opacity.to_bits().hash(state);
+		self.label.hash(state);
+		self.corner_radius.to_bits().hash(state);
+	}
+}
+
+impl core::hash::Hash for ArtboardLabel {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.visible.hash(state);
+		self.text.hash(state);
+		self.font_size.to_bits().hash(state);
+		self.color.hash(state);
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+	use bezier_rs::Subpath;
+
+	fn element(name: &str, graphic_element_data: impl Into<GraphicElementData>) -> GraphicElement {
+		GraphicElement {
+			name: name.to_string(),
+			blend_mode: BlendMode::Normal,
+			opacity: 1.,
+			visible: true,
+			locked: false,
+			collapsed: false,
+			graphic_element_data: graphic_element_data.into(),
+			clip: None,
+		}
+	}
+
+	#[test]
+	fn artboard_opacity_applies_to_its_contents() {
+		let artboard = |opacity: f32| {
+			let node = ConstructArtboardNode::new(
+				ClonedNode::new(IVec2::ZERO),
+				ClonedNode::new(IVec2::splat(100)),
+				ClonedNode::new(Color::WHITE),
+				ClonedNode::new(false),
+				ClonedNode::new(opacity),
+				ClonedNode::new(true),
+				ClonedNode::new(String::new()),
+				ClonedNode::new(14.),
+				ClonedNode::new(Color::WHITE),
+				ClonedNode::new(0.),
+				ClonedNode::new(FillType::Solid),
+				ClonedNode::new(crate::vector::style::GradientType::Linear),
+				ClonedNode::new(DVec2::ZERO),
+				ClonedNode::new(DVec2::X),
+				ClonedNode::new(DAffine2::IDENTITY),
+				ClonedNode::new(Vec::new()),
+			);
+			node.eval(GraphicGroup(vec![element("Square", VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)))]))
+		};
+		let render = |artboard: &Artboard| {
+			let mut render = renderer::SvgRender::new();
+			renderer::GraphicElementRendered::render_svg(artboard, &mut render, &renderer::RenderParams::new(crate::vector::style::ViewMode::Normal, None, false));
+			render.svg.to_string()
+		};
+
+		let svg = render(&artboard(50.));
+		assert!(svg.contains(r#"<g class="artboard" opacity="0.5">"#), "{svg}");
+		assert_eq!(svg.matches("opacity").count(), 1, "The background stays opaque");
+
+		assert!(!render(&artboard(100.)).contains("opacity"));
+		assert_eq!(artboard(150.).opacity, 1.);
+		assert_eq!(artboard(-20.).opacity, 0.);
+	}
+
+	#[test]
+	fn stacking_inserts_at_the_chosen_position() {
+		let square = |position: DVec2| VectorData::from_subpath(Subpath::new_rect(position, position + DVec2::ONE));
+		let stack = |group: GraphicGroup, name: &str, index: u32, from_top: bool| {
+			StackNode::new(
+				ClonedNode::new(square(DVec2::new(4., 2.))),
+				ClonedNode::new(name.to_string()),
+				ClonedNode::new(index),
+				ClonedNode::new(from_top),
+			)
+			.eval(group)
+		};
+		let names = |group: &GraphicGroup| group.iter().map(|element| element.name.clone()).collect::<Vec<_>>();
+
+		let group = GraphicGroup(vec![element("Bottom", square(DVec2::ZERO))]);
+		let group = stack(group, "Top", 0, true);
+		assert_eq!(names(&group), vec!["Bottom", "Top"]);
+		let group = stack(group, "Beneath", 0, false);
+		assert_eq!(names(&group), vec!["Beneath", "Bottom", "Top"]);
+		let group = stack(group, "Under Top", 1, true);
+		assert_eq!(names(&group), vec!["Beneath", "Bottom", "Under Top", "Top"]);
+		let group = stack(group, "Clamped", 10, true);
+		assert_eq!(names(&group)[0], "Clamped");
+
+		let pair = stack(GraphicGroup(vec![element("Bottom", square(DVec2::ZERO))]), "Top", 0, true);
+		let bounds = renderer::GraphicElementRendered::bounding_box(&pair, DAffine2::IDENTITY);
+		assert_eq!(bounds, Some([DVec2::ZERO, DVec2::new(5., 3.)]));
+	}
+
+	#[test]
+	fn flattening_shapes_merges_their_subpaths() {
+		let square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let mut moved_square = square.clone();
+		moved_square.transform = DAffine2::from_translation(DVec2::new(5., 0.));
+		let group = GraphicGroup(vec![element("Square", square), element("Moved Square", moved_square)]);
+
+		let flattened = FlattenGroupNode::new(ClonedNode::new(1.)).eval(group);
+		assert_eq!(flattened.len(), 1);
+		let GraphicElementData::VectorShape(vector_data) = &flattened[0].graphic_element_data else {
+			panic!("Flattening shapes should give a shape")
+		};
+		assert_eq!(vector_data.transform, DAffine2::IDENTITY);
+		let bounds: Vec<_> = vector_data.subpaths.iter().map(|subpath| subpath.bounding_box().unwrap()).collect();
+		assert_eq!(bounds, vec![[DVec2::ZERO, DVec2::ONE], [DVec2::new(5., 0.), DVec2::new(6., 1.)]]);
+	}
+
+	#[test]
+	fn ungrouping_lifts_nested_elements() {
+		let square = || VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let mut nested = element("Nested", GraphicGroup(vec![element("A", square()), element("B", square())]));
+		nested.opacity = 0.5;
+		let group = GraphicGroup(vec![element("Bottom", square()), nested]);
+
+		let ungrouped = UngroupNode::new().eval(group);
+		assert_eq!(ungrouped.iter().map(|element| element.name.as_str()).collect::<Vec<_>>(), vec!["Bottom", "A", "B"]);
+		assert_eq!(ungrouped.iter().map(|element| element.opacity).collect::<Vec<_>>(), vec![1., 0.5, 0.5]);
 	}
 }
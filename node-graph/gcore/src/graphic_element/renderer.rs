@@ -1,46 +1,90 @@
-use crate::raster::{Image, ImageFrame};
-use crate::{uuid::generate_uuid, vector::VectorData, Artboard, Color, GraphicElementData, GraphicGroup};
+use crate::raster::{BlendMode, Image, ImageFrame};
+use crate::uuid::{generate_uuid, ManipulatorGroupId};
+use crate::vector::style::{Fill, PathStyle, Pattern, StyleLayer, ViewMode};
+use crate::{vector::VectorData, Artboard, Color, GraphicElement, GraphicElementData, GraphicGroup};
 use quad::Quad;
 
+use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2};
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
 mod quad;
+mod rasterizer;
+
+pub use rasterizer::{rasterize, Canvas};
 
 /// Mutable state used whilst rendering to an SVG
+#[derive(Debug)]
 pub struct SvgRender {
 	pub svg: SvgSegmentList,
 	pub svg_defs: String,
 	pub transform: DAffine2,
 	pub image_data: Vec<(u64, Image<Color>)>,
+	/// The number of decimal places that coordinates, transforms and the `viewBox` are rounded to, or `None` for full precision
+	pub precision: Option<usize>,
 	indent: usize,
 }
 
 impl SvgRender {
+	pub const DEFAULT_PRECISION: usize = 3;
+
 	pub fn new() -> Self {
 		Self {
 			svg: SvgSegmentList::default(),
 			svg_defs: String::new(),
 			transform: DAffine2::IDENTITY,
 			image_data: Vec::new(),
+			precision: Some(Self::DEFAULT_PRECISION),
 			indent: 0,
 		}
 	}
 
+	/// An empty render with the same transform, precision, and indentation, used to render part of the SVG on its own
+	fn fork(&self) -> Self {
+		Self {
+			transform: self.transform,
+			precision: self.precision,
+			indent: self.indent,
+			..Self::new()
+		}
+	}
+
+	/// Appends the SVG, defs, and images of a forked render
+	fn append(&mut self, other: &SvgRender) {
+		other.svg.iter().for_each(|segment| self.svg.push(segment.clone()));
+		self.svg_defs.push_str(&other.svg_defs);
+		self.image_data.extend(other.image_data.iter().cloned());
+	}
+
+	/// Format a number rounded to the [`SvgRender::precision`]
+	pub fn number(&self, value: f64) -> SvgNumber {
+		SvgNumber { value, precision: self.precision }
+	}
+
 	pub fn indent(&mut self) {
-		self.svg.push("\n");
-		self.svg.push("\t".repeat(self.indent));
+		let text = self.svg.text_mut();
+		text.push('\n');
+		text.extend(core::iter::repeat('\t').take(self.indent));
 	}
 
 	/// Add an outer `<svg />` tag with a `viewBox` and the `<defs />`
 	pub fn format_svg(&mut self, bounds_min: DVec2, bounds_max: DVec2) {
-		let (x, y) = bounds_min.into();
-		let (size_x, size_y) = (bounds_max - bounds_min).into();
+		let (x, y) = (self.number(bounds_min.x), self.number(bounds_min.y));
+		let (size_x, size_y) = (self.number(bounds_max.x - bounds_min.x), self.number(bounds_max.y - bounds_min.y));
 		let defs = &self.svg_defs;
 		let svg_header = format!(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{x} {y} {size_x} {size_y}"><defs>{defs}</defs>"#,);
 		self.svg.insert(0, svg_header.into());
 		self.svg.push("</svg>");
 	}
 
+	/// Add text content within the current tag, escaped so that it can't form markup
+	pub fn push_text(&mut self, text: &str) {
+		self.svg.text_mut().push_str(&escape_xml(text));
+	}
+
 	pub fn leaf_tag(&mut self, name: impl Into<SvgSegment>, attributes: impl FnOnce(&mut SvgRenderAttrs)) {
 		self.indent();
 		self.svg.push("<");
@@ -57,17 +101,18 @@ impl SvgRender {
 		self.svg.push(name.clone());
 		attributes(&mut SvgRenderAttrs(self));
 		self.svg.push(">");
-		let length = self.svg.len();
+		let position = self.svg.write_position();
 		self.indent += 1;
 		inner(self);
 		self.indent -= 1;
-		if self.svg.len() != length {
+		if self.svg.write_position() != position {
 			self.indent();
 			self.svg.push("</");
 			self.svg.push(name);
 			self.svg.push(">");
 		} else {
-			self.svg.pop();
+			// Nothing was written inside, so the `>` just pushed becomes a self-closing `/>`
+			self.svg.text_mut().pop();
 			self.svg.push("/>");
 		}
 	}
@@ -82,42 +127,490 @@ impl Default for SvgRender {
 /// Static state used whilst rendering
 pub struct RenderParams {
 	pub view_mode: crate::vector::style::ViewMode,
+	/// Elements of a graphic group that lie entirely outside of these bounds, in the space the SVG is rendered in, are left out
 	pub culling_bounds: Option<[DVec2; 2]>,
 	pub thumbnail: bool,
+	/// Draws a checkerboard behind images to visualize their transparent regions. Off by default so exports stay clean.
+	pub transparency_checkerboard: Option<Checkerboard>,
+	/// How the ids of the clip paths, gradients, patterns, and images in the SVG are chosen
+	pub ids: SvgIds,
+	pub image_output: ImageOutput,
+	/// How smooth the edges of vector shapes are. Without antialiasing, the SVG asks for crisp edges instead.
+	pub anti_aliasing: AntiAliasing,
 }
 
 impl RenderParams {
 	pub fn new(view_mode: crate::vector::style::ViewMode, culling_bounds: Option<[DVec2; 2]>, thumbnail: bool) -> Self {
-		Self { view_mode, culling_bounds, thumbnail }
+		Self {
+			view_mode,
+			culling_bounds,
+			thumbnail,
+			transparency_checkerboard: None,
+			ids: SvgIds::Random,
+			image_output: ImageOutput::BlobUrl,
+			anti_aliasing: AntiAliasing::default(),
+		}
+	}
+
+	pub fn with_transparency_checkerboard(mut self, checkerboard: Checkerboard) -> Self {
+		self.transparency_checkerboard = Some(checkerboard);
+		self
+	}
+
+	/// Derive the ids in the SVG from the content they refer to, so that rendering the same content always gives the same SVG
+	pub fn with_deterministic_ids(mut self) -> Self {
+		self.ids = SvgIds::Deterministic;
+		self
+	}
+
+	/// Reference images by the relative path of a PNG file written next to the SVG, for exports where embedding the images would make the SVG too large
+	pub fn with_linked_images(mut self) -> Self {
+		self.image_output = ImageOutput::LinkedFile;
+		self
+	}
+
+	pub fn with_anti_aliasing(mut self, anti_aliasing: AntiAliasing) -> Self {
+		self.anti_aliasing = anti_aliasing;
+		self
 	}
 }
 
-pub fn format_transform_matrix(transform: DAffine2) -> String {
-	use std::fmt::Write;
-	let mut result = "matrix(".to_string();
-	let cols = transform.to_cols_array();
-	for (index, item) in cols.iter().enumerate() {
-		write!(result, "{}", item).unwrap();
-		if index != cols.len() - 1 {
-			result.push_str(", ");
+/// The antialiasing of content rendered to pixels. Each pixel is supersampled by rendering at a multiple of the resolution and averaging the samples.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub enum AntiAliasing {
+	/// Hard edges, with each pixel sampled at its center
+	None,
+	/// 2×2 samples per pixel
+	#[default]
+	Supersample2x,
+	/// 4×4 samples per pixel, which is slower but gives smoother edges
+	Supersample4x,
+}
+
+impl core::fmt::Display for AntiAliasing {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			AntiAliasing::None => write!(f, "None"),
+			AntiAliasing::Supersample2x => write!(f, "2× Supersampling"),
+			AntiAliasing::Supersample4x => write!(f, "4× Supersampling"),
+		}
+	}
+}
+
+impl AntiAliasing {
+	pub fn list() -> [AntiAliasing; 3] {
+		[AntiAliasing::None, AntiAliasing::Supersample2x, AntiAliasing::Supersample4x]
+	}
+
+	/// The number of samples along each side of a pixel
+	pub fn factor(self) -> u32 {
+		match self {
+			AntiAliasing::None => 1,
+			AntiAliasing::Supersample2x => 2,
+			AntiAliasing::Supersample4x => 4,
+		}
+	}
+}
+
+/// How images are referenced from within an SVG. Either way, their pixels are collected in [`SvgRender::image_data`] under the id they are referenced by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ImageOutput {
+	/// A [`SvgSegment::BlobUrl`], swapped for the url of the image once the frontend has loaded it
+	#[default]
+	BlobUrl,
+	/// The relative path of a PNG file named by [`linked_image_file_name`], which whoever saves the SVG must write alongside it.
+	/// With [`SvgIds::Deterministic`] the name is a hash of the pixels, so identical images share a file.
+	LinkedFile,
+}
+
+/// The name of the file that an image with this id is linked to when rendering with [`ImageOutput::LinkedFile`]
+pub fn linked_image_file_name(id: u64) -> String {
+	format!("image-{id:016x}.png")
+}
+
+/// The `href` of the image with this id
+fn image_href(id: u64, image_output: ImageOutput) -> SvgSegment {
+	match image_output {
+		ImageOutput::BlobUrl => SvgSegment::BlobUrl(id),
+		ImageOutput::LinkedFile => linked_image_file_name(id).into(),
+	}
+}
+
+/// The way ids are chosen for the definitions and images referenced within an SVG
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SvgIds {
+	/// Random ids, which stay unique when SVGs from separate renders are combined. Used by the editor.
+	#[default]
+	Random,
+	/// Ids hashed from the content they refer to, for reproducible output in exports and tests.
+	/// Identical definitions share an id, which is harmless since either of them gives the same result.
+	Deterministic,
+}
+
+impl SvgIds {
+	/// Choose the id for a definition or image, where `content` is what it holds
+	pub fn generate(self, content: impl Hash) -> u64 {
+		match self {
+			Self::Random => generate_uuid(),
+			Self::Deterministic => {
+				let mut hasher = DefaultHasher::new();
+				content.hash(&mut hasher);
+				hasher.finish()
+			}
+		}
+	}
+}
+
+/// The part of the document visible in a viewport of the given size, found by mapping the viewport's corners back through the document-to-viewport transform.
+/// Used as the culling bounds when rendering in document space. A transform that collapses the document gives no bounds, so nothing gets culled.
+pub fn viewport_culling_bounds(document_to_viewport: DAffine2, viewport_size: DVec2) -> Option<[DVec2; 2]> {
+	if document_to_viewport.matrix2.determinant() == 0. {
+		return None;
+	}
+	Some((document_to_viewport.inverse() * Quad::from_box([DVec2::ZERO, viewport_size])).bounding_box())
+}
+
+/// Whether two axis aligned rectangles, each defined by two corner points, share any area
+fn bounds_overlap(a: [DVec2; 2], b: [DVec2; 2]) -> bool {
+	a[0].x < b[1].x && b[0].x < a[1].x && a[0].y < b[1].y && b[0].y < a[1].y
+}
+
+/// The pattern drawn behind transparent regions, made of alternating squares of two colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Checkerboard {
+	/// The width of each square in document space
+	pub size: f64,
+	pub colors: [Color; 2],
+}
+
+impl Default for Checkerboard {
+	fn default() -> Self {
+		Self {
+			size: 8.,
+			colors: [Color::WHITE, Color::from_rgbf32_unchecked(0.8, 0.8, 0.8)],
 		}
 	}
-	result.push(')');
+}
+
+impl Checkerboard {
+	/// Add the `<pattern />` to the defs, returning its id
+	fn push_pattern(&self, render: &mut SvgRender, ids: SvgIds) -> String {
+		use std::fmt::Write;
+		let (size, double) = (render.number(self.size), render.number(self.size * 2.));
+		let [first, second] = self.colors.map(|color| color.rgba_hex());
+		let id = format!("checkerboard-{}", ids.generate((size.to_string(), &first, &second)));
+		write!(
+			render.svg_defs,
+			r##"<pattern id="{id}" patternUnits="userSpaceOnUse" width="{double}" height="{double}"><rect width="{double}" height="{double}" fill="#{first}"/><rect width="{size}" height="{size}" fill="#{second}"/><rect x="{size}" y="{size}" width="{size}" height="{size}" fill="#{second}"/></pattern>"##,
+		)
+		.unwrap();
+		id
+	}
+}
+
+pub fn format_transform_matrix(transform: DAffine2) -> String {
+	let mut result = String::new();
+	write_transform_matrix(&mut result, transform, None).unwrap();
 	result
 }
 
+/// Write the transform as `matrix(a, b, c, d, e, f)` without allocating an intermediate string
+pub fn write_transform_matrix(writer: &mut impl core::fmt::Write, transform: DAffine2, precision: Option<usize>) -> core::fmt::Result {
+	let [a, b, c, d, e, f] = transform.to_cols_array().map(|value| SvgNumber { value, precision });
+	write!(writer, "matrix({a}, {b}, {c}, {d}, {e}, {f})")
+}
+
+/// A number written to an SVG, rounded to `precision` decimal places (if any) with trailing zeros left out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgNumber {
+	pub value: f64,
+	pub precision: Option<usize>,
+}
+
+impl core::fmt::Display for SvgNumber {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let Some(precision) = self.precision else { return write!(f, "{}", self.value) };
+		let factor = 10_f64.powi(precision as i32);
+		// Adding zero turns a rounded `-0` into `0`
+		let rounded = (self.value * factor).round() / factor + 0.;
+		write!(f, "{rounded}")
+	}
+}
+
+/// Write the `d` attribute of a subpath's path, rounding the coordinates to the precision
+fn write_subpath(path: &mut String, subpath: &bezier_rs::Subpath<ManipulatorGroupId>, transform: DAffine2, precision: Option<usize>) -> core::fmt::Result {
+	use core::fmt::Write;
+	let Some(first) = subpath.manipulator_groups().first() else { return Ok(()) };
+	let point = |point: DVec2| {
+		let point = transform.transform_point2(point);
+		(SvgNumber { value: point.x, precision }, SvgNumber { value: point.y, precision })
+	};
+
+	let (x, y) = point(first.anchor);
+	write!(path, "M{x},{y}")?;
+	for bezier in subpath.iter() {
+		match (bezier.handle_start(), bezier.handle_end()) {
+			(Some(handle_start), Some(handle_end)) => {
+				let ((x1, y1), (x2, y2)) = (point(handle_start), point(handle_end));
+				write!(path, "C{x1},{y1} {x2},{y2}")?;
+			}
+			(Some(handle), None) => {
+				let (x1, y1) = point(handle);
+				write!(path, "Q{x1},{y1}")?;
+			}
+			_ => path.push('L'),
+		}
+		let (x, y) = point(bezier.end());
+		write!(path, " {x},{y} ")?;
+	}
+	if subpath.closed() {
+		path.push('Z');
+	}
+	Ok(())
+}
+
 pub trait GraphicElementRendered {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams);
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]>;
+	/// Paint the element over the pixels of the canvas, with `transform` mapping the element into document space
+	fn rasterize_into(&self, canvas: &mut Canvas, transform: DAffine2);
 }
 
+/// A rough guess of the markup written per element, used to reserve the buffer up front
+const ESTIMATED_BYTES_PER_ELEMENT: usize = 128;
+
+/// Groups with fewer elements than this are rendered on the current thread, since splitting them up would cost more than it saves
+#[cfg(feature = "rayon")]
+const MIN_PARALLEL_ELEMENTS: usize = 256;
+
 impl GraphicElementRendered for GraphicGroup {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
-		self.iter().for_each(|element| element.graphic_element_data.render_svg(render, render_params))
+		#[cfg(feature = "rayon")]
+		if self.len() >= MIN_PARALLEL_ELEMENTS {
+			render_elements_parallel(self, render, render_params);
+			return;
+		}
+		render_elements(self, render, render_params);
 	}
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
 		self.iter().filter_map(|element| element.graphic_element_data.bounding_box(transform)).reduce(Quad::combine_bounds)
 	}
+	fn rasterize_into(&self, canvas: &mut Canvas, transform: DAffine2) {
+		for element in self.iter().filter(|element| element.visible) {
+			if element.blend_mode == BlendMode::Normal && element.opacity >= 1. && element.clip.is_none() {
+				element.graphic_element_data.rasterize_into(canvas, transform);
+				continue;
+			}
+
+			// The element is painted on its own first so its blend mode, opacity and clip apply to it as a whole
+			let mut layer = canvas.empty_layer();
+			element.graphic_element_data.rasterize_into(&mut layer, transform);
+			let mask = element.clip.as_ref().map(|clip| canvas.coverage(clip, transform));
+			canvas.blend_layer(&layer, element.blend_mode, element.opacity, mask.as_deref());
+		}
+	}
+}
+
+/// Renders the elements of the group that aren't culled one after another into the shared render
+fn render_elements(graphic_group: &GraphicGroup, render: &mut SvgRender, render_params: &RenderParams) {
+	render.svg.reserve_text(graphic_group.len() * ESTIMATED_BYTES_PER_ELEMENT);
+	for element in graphic_group.iter() {
+		if !is_culled(render_params, element.graphic_element_data.bounding_box(render.transform)) {
+			render_element(element, render, render_params);
+		}
+	}
+}
+
+/// Renders each element of the group into its own buffer on the rayon thread pool, since elements only share the defs and images they add.
+/// The buffers, with their defs and images, are then appended in the order of the elements, so the output matches [`render_elements`].
+#[cfg(feature = "rayon")]
+fn render_elements_parallel(graphic_group: &GraphicGroup, render: &mut SvgRender, render_params: &RenderParams) {
+	use rayon::prelude::*;
+
+	let forked = render.fork();
+	let element_renders: Vec<SvgRender> = graphic_group
+		.par_iter()
+		.filter(|element| !is_culled(render_params, element.graphic_element_data.bounding_box(forked.transform)))
+		.map(|element| {
+			let mut element_render = forked.fork();
+			render_element(element, &mut element_render, render_params);
+			element_render
+		})
+		.collect();
+
+	render.svg.reserve_text(graphic_group.len() * ESTIMATED_BYTES_PER_ELEMENT);
+	element_renders.iter().for_each(|element_render| render.append(element_render));
+}
+
+/// Whether an element with these bounds lies entirely outside of the culling bounds.
+/// Elements without bounds are never culled, which includes text since its bounds aren't known without laying it out.
+fn is_culled(render_params: &RenderParams, bounds: Option<[DVec2; 2]>) -> bool {
+	render_params.culling_bounds.zip(bounds).is_some_and(|(culling_bounds, bounds)| !bounds_overlap(culling_bounds, bounds))
+}
+
+/// Renders the element's data, wrapped in a group for its opacity, blend mode, and clip when it has any
+fn render_element(element: &GraphicElement, render: &mut SvgRender, render_params: &RenderParams) {
+	let blended = element.blend_mode != BlendMode::Normal;
+	let translucent = element.opacity < 1.;
+	// Artboards are labelled with the name of the layer they're in
+	let render_data = |render: &mut SvgRender| match &element.graphic_element_data {
+		GraphicElementData::Artboard(artboard) => artboard.render_svg_named(render, render_params, &element.name),
+		graphic_element_data => graphic_element_data.render_svg(render, render_params),
+	};
+	if !blended && !translucent && element.clip.is_none() {
+		render_data(render);
+		return;
+	}
+
+	let opacity = render.number(element.opacity as f64);
+	let clip_id = element.clip.as_ref().map(|clip| push_clip_path(render, clip, render_params.ids));
+	render.parent_tag(
+		"g",
+		|attributes| {
+			if translucent {
+				attributes.push_display("opacity", opacity);
+			}
+			if blended {
+				attributes.push_display("style", format_args!("mix-blend-mode: {};", element.blend_mode.to_svg_style_name()));
+			}
+			if let Some(clip_id) = clip_id {
+				attributes.push_display("clip-path", format_args!("url(#{clip_id})"));
+			}
+		},
+		render_data,
+	);
+}
+
+/// The SVG emitted for each element of a graphic group, kept so that later renders of the group only have to emit the elements that changed.
+/// Elements are identified by a hash of their contents, so an edited element counts as a new one.
+#[derive(Debug, Default)]
+pub struct RenderCache {
+	/// The transform, view mode, id mode, image output, antialiasing, transparency checkerboard, precision, and indentation the cached SVG was rendered with, which must all match for it to be reused
+	context: Option<(DAffine2, ViewMode, SvgIds, ImageOutput, AntiAliasing, Option<Checkerboard>, Option<usize>, usize)>,
+	elements: HashMap<u64, SvgRender>,
+}
+
+impl RenderCache {
+	/// Forgets the SVG of every element, so the next render emits the whole group again
+	pub fn clear(&mut self) {
+		self.context = None;
+		self.elements.clear();
+	}
+
+	/// Renders the group like [`GraphicElementRendered::render_svg`], reusing the cached SVG of the elements that haven't changed since the last render.
+	/// Elements whose bounds intersect the `dirty_region` are emitted again even if they haven't changed. Returns the indices of the elements that were emitted again.
+	pub fn render_group(&mut self, graphic_group: &GraphicGroup, render: &mut SvgRender, render_params: &RenderParams, dirty_region: Option<[DVec2; 2]>) -> Vec<usize> {
+		let context = Some((
+			render.transform,
+			render_params.view_mode,
+			render_params.ids,
+			render_params.image_output,
+			render_params.anti_aliasing,
+			render_params.transparency_checkerboard,
+			render.precision,
+			render.indent,
+		));
+		if self.context != context {
+			self.clear();
+			self.context = context;
+		}
+
+		// Only the elements that are still in the group stay cached
+		let mut previous = core::mem::take(&mut self.elements);
+		let mut emitted = Vec::new();
+		render.svg.reserve_text(graphic_group.len() * ESTIMATED_BYTES_PER_ELEMENT);
+
+		for (index, element) in graphic_group.iter().enumerate() {
+			let bounds = element.graphic_element_data.bounding_box(render.transform);
+			if is_culled(render_params, bounds) {
+				continue;
+			}
+
+			let key = {
+				let mut hasher = DefaultHasher::new();
+				element.hash(&mut hasher);
+				hasher.finish()
+			};
+			let dirty = dirty_region.zip(bounds).is_some_and(|(dirty_region, bounds)| bounds_overlap(dirty_region, bounds));
+			// Identical elements share an entry, which may already have been moved over by an earlier one
+			let cached = previous.remove(&key).or_else(|| self.elements.remove(&key)).filter(|_| !dirty);
+
+			let cached = cached.unwrap_or_else(|| {
+				emitted.push(index);
+				let mut element_render = render.fork();
+				render_element(element, &mut element_render, render_params);
+				element_render
+			});
+
+			render.append(&cached);
+			self.elements.insert(key, cached);
+		}
+
+		emitted
+	}
+}
+
+/// Add a `<clipPath />` with the shape of the vector data to the defs, returning its id.
+/// The path is in the same space as the clipped content, so nested clips intersect.
+fn push_clip_path(render: &mut SvgRender, clip: &VectorData, ids: SvgIds) -> String {
+	use std::fmt::Write;
+	let mut path = String::new();
+	for subpath in &clip.subpaths {
+		let _ = write_subpath(&mut path, subpath, clip.transform * render.transform, render.precision);
+	}
+	let id = format!("clip-{}", ids.generate(&path));
+	write!(render.svg_defs, r#"<clipPath id="{id}"><path d="{path}"/></clipPath>"#).unwrap();
+	id
+}
+
+/// Add a `<pattern />` tiling the image, returning its id. Unlike the other defs, it's written into the SVG just before the shape it fills, since the image may be referenced by a blob url.
+/// The `transform` maps the layer space of the shape, in which the transform of the image places a tile, to the space the SVG is rendered in.
+fn push_image_pattern(render: &mut SvgRender, pattern: &Pattern, transform: DAffine2, layer_bounds: [DVec2; 2], render_params: &RenderParams) -> Option<String> {
+	let tile_transform = transform * pattern.image.transform;
+	if tile_transform.matrix2.determinant() == 0. {
+		return None;
+	}
+
+	// SVG patterns always repeat, so a pattern that doesn't gets a tile large enough to cover the whole shape, leaving the rest of the tile empty
+	let unit = [DVec2::ZERO, DVec2::ONE];
+	let [min, max] = if pattern.repeat {
+		unit
+	} else {
+		Quad::combine_bounds(unit, (pattern.image.transform.inverse() * Quad::from_box(layer_bounds)).bounding_box())
+	};
+	let [x, y, width, height] = [min.x, min.y, max.x - min.x, max.y - min.y].map(|value| render.number(value));
+
+	let image_id = render_params.ids.generate(&pattern.image.image);
+	let tile = [x, y, width, height].map(|number| number.to_string());
+	let id = format!("pattern-{}", render_params.ids.generate((image_id, tile_transform.to_cols_array().map(f64::to_bits), tile)));
+	render.parent_tag(
+		"defs",
+		|_| {},
+		|render| {
+			render.parent_tag(
+				"pattern",
+				|attributes| {
+					attributes.push("id", id.clone());
+					attributes.push("patternUnits", "userSpaceOnUse");
+					attributes.push_display("x", x);
+					attributes.push_display("y", y);
+					attributes.push_display("width", width);
+					attributes.push_display("height", height);
+					attributes.push_complex("patternTransform", |render| write_transform_matrix(&mut render.svg, tile_transform, render.precision).unwrap());
+				},
+				|render| {
+					render.leaf_tag("image", |attributes| {
+						attributes.push_display("width", 1);
+						attributes.push_display("height", 1);
+						attributes.push("preserveAspectRatio", "none");
+						attributes.push("href", image_href(image_id, render_params.image_output));
+					})
+				},
+			)
+		},
+	);
+	render.image_data.push((image_id, pattern.image.image.clone()));
+	Some(id)
 }
 
 impl GraphicElementRendered for VectorData {
@@ -127,61 +620,142 @@ impl GraphicElementRendered for VectorData {
 
 		let mut path = String::new();
 		for subpath in &self.subpaths {
-			let _ = subpath.subpath_to_svg(&mut path, self.transform * render.transform);
+			let _ = write_subpath(&mut path, subpath, self.transform * render.transform, render.precision);
+		}
+
+		let (view_mode, ids) = (render_params.view_mode, render_params.ids);
+		let crisp_edges = render_params.anti_aliasing == AntiAliasing::None;
+
+		// The styles leave out the `fill` attribute of image patterns, which are added here along with their defs
+		let pattern_fill = |render: &mut SvgRender, fill: &Fill| {
+			let pattern = fill.as_pattern().filter(|_| view_mode != ViewMode::Outline)?;
+			let id = push_image_pattern(render, pattern, self.transform * render.transform, layer_bounds, render_params);
+			Some(id.map_or_else(|| r#" fill="none""#.to_string(), |id| format!(r#" fill="url(#{id})""#)))
+		};
+		let layer_pattern_fills = self
+			.style
+			.layers()
+			.iter()
+			.map(|layer| match layer {
+				StyleLayer::Fill(fill) => pattern_fill(render, fill),
+				StyleLayer::Stroke(_) => None,
+			})
+			.collect::<Vec<_>>();
+		let main_pattern_fill = pattern_fill(render, self.style.fill());
+
+		let style_layers = self.style.render_layers(view_mode, ids, &mut render.svg_defs, render.transform, layer_bounds, transformed_bounds);
+		for (style_layer, layer_pattern_fill) in style_layers.into_iter().zip(layer_pattern_fills) {
+			render.leaf_tag("path", |attributes| {
+				attributes.push("class", "vector-data");
+				attributes.push("d", path.clone());
+				attributes.push_val(style_layer);
+				attributes.push_val(layer_pattern_fill.unwrap_or_default());
+				if crisp_edges {
+					attributes.push("shape-rendering", "crispEdges");
+				}
+			});
 		}
 		render.leaf_tag("path", |attributes| {
 			attributes.push("class", "vector-data");
 			attributes.push("d", path);
 			let render = &mut attributes.0;
-			let style = self.style.render(render_params.view_mode, &mut render.svg_defs, render.transform, layer_bounds, transformed_bounds);
+			let style = self.style.render(view_mode, ids, &mut render.svg_defs, render.transform, layer_bounds, transformed_bounds);
 			attributes.push_val(style);
+			attributes.push_val(main_pattern_fill.unwrap_or_default());
+			if crisp_edges {
+				attributes.push("shape-rendering", "crispEdges");
+			}
 		});
 	}
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
 		self.bounding_box_with_transform(self.transform * transform)
 	}
+	fn rasterize_into(&self, canvas: &mut Canvas, transform: DAffine2) {
+		for layer in self.style.layers() {
+			match layer {
+				StyleLayer::Fill(fill) => canvas.fill_vector(self, transform, fill),
+				StyleLayer::Stroke(stroke) => canvas.stroke_vector(self, transform, stroke),
+			}
+		}
+		canvas.fill_vector(self, transform, self.style.fill());
+		if let Some(stroke) = self.style.stroke() {
+			canvas.stroke_vector(self, transform, &stroke);
+		}
+	}
 }
 
-impl GraphicElementRendered for Artboard {
-	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
-		// Background
-		render.leaf_tag("rect", |attributes| {
-			attributes.push("class", "artboard-bg");
-			attributes.push("fill", format!("#{}", self.background.rgba_hex()));
-			attributes.push("x", self.location.x.min(self.location.x + self.dimensions.x).to_string());
-			attributes.push("y", self.location.y.min(self.location.y + self.dimensions.y).to_string());
-			attributes.push("width", self.dimensions.x.abs().to_string());
-			attributes.push("height", self.dimensions.y.abs().to_string());
-		});
+impl Artboard {
+	/// Renders the artboard like [`GraphicElementRendered::render_svg`], labelled with the name of its layer unless the label has its own text
+	pub fn render_svg_named(&self, render: &mut SvgRender, render_params: &RenderParams, name: &str) {
+		// Background, which is left out when transparent except for its outline
+		let background = self.background_fill();
+		if background.is_some() || render_params.view_mode == ViewMode::Outline {
+			let bounds = [self.location.min(self.location + self.dimensions), self.location.max(self.location + self.dimensions)].map(|corner| corner.as_dvec2());
+			let gradient = background.as_gradient().is_some().then(|| {
+				let transformed_bounds = (render.transform * Quad::from_box(bounds)).bounding_box();
+				background.render(render_params.ids, &mut render.svg_defs, render.transform, bounds, transformed_bounds)
+			});
+			render.leaf_tag("rect", |attributes| {
+				attributes.push("class", "artboard-bg");
+				if render_params.view_mode == ViewMode::Outline {
+					attributes.push_val(PathStyle::render_outline());
+				} else if let Some(gradient) = gradient {
+					attributes.push_val(gradient);
+				} else {
+					attributes.push("fill", format!("#{}", self.background.rgba_hex()));
+				}
+				attributes.push_display("x", self.location.x.min(self.location.x + self.dimensions.x));
+				attributes.push_display("y", self.location.y.min(self.location.y + self.dimensions.y));
+				attributes.push_display("width", self.dimensions.x.abs());
+				attributes.push_display("height", self.dimensions.y.abs());
+			});
+		}
 
 		// Label
-		render.parent_tag(
-			"text",
-			|attributes| {
-				attributes.push("class", "artboard-label");
-				attributes.push("fill", "white");
-				attributes.push("x", (self.location.x.min(self.location.x + self.dimensions.x)).to_string());
-				attributes.push("y", (self.location.y.min(self.location.y + self.dimensions.y) - 4).to_string());
-				attributes.push("font-size", "14px");
-			},
-			|render| {
-				render.svg.push("Artboard");
-			},
-		);
+		let label = &self.label;
+		if label.visible {
+			let text = [label.text.as_str(), name].into_iter().find(|text| !text.is_empty()).unwrap_or("Artboard");
+			let font_size = render.number(label.font_size);
+			render.parent_tag(
+				"text",
+				|attributes| {
+					attributes.push("class", "artboard-label");
+					attributes.push("fill", format!("#{}", label.color.rgba_hex()));
+					attributes.push_display("x", self.location.x.min(self.location.x + self.dimensions.x));
+					attributes.push_display("y", self.location.y.min(self.location.y + self.dimensions.y) - 4);
+					attributes.push_display("font-size", format_args!("{font_size}px"));
+				},
+				|render| {
+					render.push_text(text);
+				},
+			);
+		}
 
 		// Contents group
 		render.parent_tag(
 			"g",
 			|attributes| {
 				attributes.push("class", "artboard");
+				if self.opacity < 1. {
+					let opacity = attributes.0.number(self.opacity as f64);
+					attributes.push_display("opacity", opacity);
+				}
 				if self.clip {
-					let id = format!("artboard-{}", generate_uuid());
+					let (location, dimensions) = (self.location.min(self.location + self.dimensions), self.dimensions.abs());
+					let radius = self.clip_corner_radius();
+					let id = format!("artboard-{}", render_params.ids.generate((location, dimensions, radius.to_bits())));
 					let selector = format!("url(#{id})");
+					let corners = if radius > 0. {
+						let radius = attributes.0.number(radius);
+						format!(r#" rx="{radius}" ry="{radius}""#)
+					} else {
+						String::new()
+					};
 					use std::fmt::Write;
 					write!(
 						&mut attributes.0.svg_defs,
-						r##"<clipPath id="{id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"##,
-						self.location.x, self.location.y, self.dimensions.x, self.dimensions.y
+						r##"<clipPath id="{id}"><rect x="{}" y="{}" width="{}" height="{}"{corners}/></clipPath>"##,
+						location.x, location.y, dimensions.x, dimensions.y
 					)
 					.unwrap();
 					attributes.push("clip-path", selector);
@@ -193,22 +767,106 @@ impl GraphicElementRendered for Artboard {
 			},
 		);
 	}
+
+	/// The corner radius of the clip, limited to half the width and height like SVG does for `rx` and `ry`
+	fn clip_corner_radius(&self) -> f64 {
+		let dimensions = self.dimensions.abs().as_dvec2();
+		self.corner_radius.min(dimensions.x / 2.).min(dimensions.y / 2.).max(0.)
+	}
+
+	/// The area the contents are clipped to, which is the artboard's rectangle with its corners rounded
+	fn clip_area(&self) -> VectorData {
+		let (min, max) = (
+			self.location.min(self.location + self.dimensions).as_dvec2(),
+			self.location.max(self.location + self.dimensions).as_dvec2(),
+		);
+		let radius = self.clip_corner_radius();
+		if radius <= 0. {
+			return VectorData::from_subpath(bezier_rs::Subpath::new_rect(min, max));
+		}
+
+		// Each corner is a quarter circle between the anchors on either side of it, based on https://pomax.github.io/bezierinfo/#circles_cubic
+		let handle = radius * 0.551784777779014;
+		let group = |anchor: DVec2, in_handle: Option<DVec2>, out_handle: Option<DVec2>| {
+			bezier_rs::ManipulatorGroup::new(anchor, in_handle.map(|offset| anchor + offset), out_handle.map(|offset| anchor + offset))
+		};
+		let manipulator_groups = vec![
+			group(DVec2::new(min.x + radius, min.y), Some(-DVec2::X * handle), None),
+			group(DVec2::new(max.x - radius, min.y), None, Some(DVec2::X * handle)),
+			group(DVec2::new(max.x, min.y + radius), Some(-DVec2::Y * handle), None),
+			group(DVec2::new(max.x, max.y - radius), None, Some(DVec2::Y * handle)),
+			group(DVec2::new(max.x - radius, max.y), Some(DVec2::X * handle), None),
+			group(DVec2::new(min.x + radius, max.y), None, Some(-DVec2::X * handle)),
+			group(DVec2::new(min.x, max.y - radius), Some(DVec2::Y * handle), None),
+			group(DVec2::new(min.x, min.y + radius), None, Some(-DVec2::Y * handle)),
+		];
+		VectorData::from_subpath(bezier_rs::Subpath::new(manipulator_groups, true))
+	}
+}
+
+impl GraphicElementRendered for Artboard {
+	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
+		self.render_svg_named(render, render_params, "");
+	}
+	/// The label is left out, so it never changes the size of exports
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
 		let artboard_bounds = (transform * Quad::from_box([self.location.as_dvec2(), self.location.as_dvec2() + self.dimensions.as_dvec2()])).bounding_box();
 		[self.graphic_group.bounding_box(transform), Some(artboard_bounds)].into_iter().flatten().reduce(Quad::combine_bounds)
 	}
+	fn rasterize_into(&self, canvas: &mut Canvas, transform: DAffine2) {
+		let area = VectorData::from_subpath(bezier_rs::Subpath::new_rect(self.location.as_dvec2(), (self.location + self.dimensions).as_dvec2()));
+		canvas.fill_vector(&area, transform, &self.background_fill());
+
+		if self.clip || self.opacity < 1. {
+			let mut layer = canvas.empty_layer();
+			self.graphic_group.rasterize_into(&mut layer, transform);
+			let mask = self.clip.then(|| canvas.coverage(&self.clip_area(), transform));
+			canvas.blend_layer(&layer, BlendMode::Normal, self.opacity, mask.as_deref());
+		} else {
+			self.graphic_group.rasterize_into(canvas, transform);
+		}
+	}
 }
 
 impl GraphicElementRendered for ImageFrame<Color> {
-	fn render_svg(&self, render: &mut SvgRender, _render_params: &RenderParams) {
-		let transform: String = format_transform_matrix(self.transform * render.transform);
-		let uuid = generate_uuid();
+	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
+		// The corners are transformed here rather than with a `transform` attribute so neither the pattern nor the outline is stretched along with the image
+		let corners = |render: &SvgRender| {
+			let transform = self.transform * render.transform;
+			[DVec2::ZERO, DVec2::X, DVec2::ONE, DVec2::Y]
+				.map(|corner| transform.transform_point2(corner))
+				.map(|point| format!("{},{}", render.number(point.x), render.number(point.y)))
+				.join(" ")
+		};
+
+		if render_params.view_mode == ViewMode::Outline {
+			let points = corners(render);
+			render.leaf_tag("polygon", |attributes| {
+				attributes.push("class", "image-outline");
+				attributes.push("points", points);
+				attributes.push_val(PathStyle::render_outline());
+			});
+			return;
+		}
+
+		if let Some(checkerboard) = render_params.transparency_checkerboard {
+			let points = corners(render);
+			let id = checkerboard.push_pattern(render, render_params.ids);
+			render.leaf_tag("polygon", |attributes| {
+				attributes.push("class", "transparency-checkerboard");
+				attributes.push("points", points);
+				attributes.push("fill", format!("url(#{id})"));
+			});
+		}
+
+		let transform = self.transform * render.transform;
+		let uuid = render_params.ids.generate(&self.image);
 		render.leaf_tag("image", |attributes| {
-			attributes.push("width", 1.to_string());
-			attributes.push("height", 1.to_string());
+			attributes.push_display("width", 1);
+			attributes.push_display("height", 1);
 			attributes.push("preserveAspectRatio", "none");
-			attributes.push("transform", transform);
-			attributes.push("href", SvgSegment::BlobUrl(uuid))
+			attributes.push_transform(transform);
+			attributes.push("href", image_href(uuid, render_params.image_output));
 		});
 		render.image_data.push((uuid, self.image.clone()))
 	}
@@ -216,6 +874,9 @@ impl GraphicElementRendered for ImageFrame<Color> {
 		let transform = self.transform * transform;
 		(transform.matrix2 != glam::DMat2::ZERO).then(|| (transform * Quad::from_box([DVec2::ZERO, DVec2::ONE])).bounding_box())
 	}
+	fn rasterize_into(&self, canvas: &mut Canvas, transform: DAffine2) {
+		canvas.draw_image(&self.image, transform * self.transform);
+	}
 }
 
 impl GraphicElementRendered for GraphicElementData {
@@ -233,11 +894,22 @@ impl GraphicElementRendered for GraphicElementData {
 		match self {
 			GraphicElementData::VectorShape(vector_data) => GraphicElementRendered::bounding_box(&**vector_data, transform),
 			GraphicElementData::ImageFrame(image_frame) => image_frame.bounding_box(transform),
-			GraphicElementData::Text(_) => todo!("Bounds of a text GraphicElementData"),
+			// Text isn't laid out here, so it has no bounds and is left out of culling and of the bounds of its group
+			GraphicElementData::Text(_) => None,
 			GraphicElementData::GraphicGroup(graphic_group) => graphic_group.bounding_box(transform),
 			GraphicElementData::Artboard(artboard) => artboard.bounding_box(transform),
 		}
 	}
+
+	fn rasterize_into(&self, canvas: &mut Canvas, transform: DAffine2) {
+		match self {
+			GraphicElementData::VectorShape(vector_data) => vector_data.rasterize_into(canvas, transform),
+			GraphicElementData::ImageFrame(image_frame) => image_frame.rasterize_into(canvas, transform),
+			GraphicElementData::Text(_) => warn!("Text can't be rasterized yet and was skipped"),
+			GraphicElementData::GraphicGroup(graphic_group) => graphic_group.rasterize_into(canvas, transform),
+			GraphicElementData::Artboard(artboard) => artboard.rasterize_into(canvas, transform),
+		}
+	}
 }
 
 /// A segment of an svg string to allow for embedding blob urls
@@ -294,23 +966,731 @@ impl core::fmt::Display for SvgSegmentList {
 pub struct SvgRenderAttrs<'a>(&'a mut SvgRender);
 
 impl<'a> SvgRenderAttrs<'a> {
+	/// Push an attribute whose value is written by the closure. Any text it writes is escaped, so it can't end the attribute early.
 	pub fn push_complex(&mut self, name: impl Into<SvgSegment>, value: impl FnOnce(&mut SvgRender)) {
 		self.0.svg.push(" ");
 		self.0.svg.push(name);
 		self.0.svg.push("=\"");
+		let position = self.0.svg.write_position();
 		value(self.0);
+		self.0.svg.escape_since(position);
 		self.0.svg.push("\"");
 	}
 	pub fn push(&mut self, name: impl Into<SvgSegment>, value: impl Into<SvgSegment>) {
 		self.push_complex(name, move |renderer| renderer.svg.push(value));
 	}
+	/// Push an attribute whose value is formatted straight into the buffer, avoiding an intermediate `String`.
+	pub fn push_display(&mut self, name: &str, value: impl core::fmt::Display) {
+		use core::fmt::Write;
+		write!(self.0.svg, r#" {name}=""#).unwrap();
+		let position = self.0.svg.write_position();
+		write!(self.0.svg, "{value}").unwrap();
+		self.0.svg.escape_since(position);
+		self.0.svg.push("\"");
+	}
+	/// Push a `transform` attribute, which is left out entirely for the identity matrix.
+	pub fn push_transform(&mut self, transform: DAffine2) {
+		if transform == DAffine2::IDENTITY {
+			return;
+		}
+		self.push_complex("transform", |render| write_transform_matrix(&mut render.svg, transform, render.precision).unwrap());
+	}
 	pub fn push_val(&mut self, value: impl Into<SvgSegment>) {
 		self.0.svg.push(value);
 	}
 }
 
 impl SvgSegmentList {
+	/// Appends a segment. Text is merged into the trailing [`SvgSegment::String`], so only blob urls split the list.
 	pub fn push(&mut self, value: impl Into<SvgSegment>) {
-		self.0.push(value.into());
+		match value.into() {
+			SvgSegment::Slice(text) => self.text_mut().push_str(text),
+			SvgSegment::String(text) => match self.0.last_mut() {
+				Some(SvgSegment::String(last)) => last.push_str(&text),
+				_ => self.0.push(SvgSegment::String(text)),
+			},
+			blob_url => self.0.push(blob_url),
+		}
+	}
+
+	/// The trailing text buffer, started anew after a blob url.
+	pub fn text_mut(&mut self) -> &mut String {
+		if !matches!(self.0.last(), Some(SvgSegment::String(_))) {
+			self.0.push(SvgSegment::String(String::new()));
+		}
+		let Some(SvgSegment::String(text)) = self.0.last_mut() else { unreachable!() };
+		text
+	}
+
+	/// Reserves space for at least `additional` more bytes of text.
+	pub fn reserve_text(&mut self, additional: usize) {
+		self.text_mut().reserve(additional);
+	}
+
+	/// Changes whenever anything is pushed, since segments and text are only ever appended.
+	fn write_position(&self) -> (usize, usize) {
+		let text_length = match self.0.last() {
+			Some(SvgSegment::String(text)) => text.len(),
+			_ => 0,
+		};
+		(self.0.len(), text_length)
+	}
+
+	/// Escapes the text written after the [`SvgSegmentList::write_position`], leaving blob urls as they are
+	fn escape_since(&mut self, (segment_count, text_length): (usize, usize)) {
+		for (index, segment) in self.0.iter_mut().enumerate().skip(segment_count.saturating_sub(1)) {
+			let SvgSegment::String(text) = segment else { continue };
+			// The text buffer that was last when the position was taken already held `text_length` bytes
+			let start = if index + 1 == segment_count { text_length } else { 0 };
+			if let Cow::Owned(escaped) = escape_xml(&text[start..]) {
+				text.truncate(start);
+				text.push_str(&escaped);
+			}
+		}
+	}
+}
+
+/// Replaces the characters that have a meaning in XML text and double quoted attribute values with their entities
+pub fn escape_xml(text: &str) -> Cow<'_, str> {
+	if !text.contains(['&', '<', '>', '"']) {
+		return Cow::Borrowed(text);
+	}
+	let mut escaped = String::with_capacity(text.len() + 16);
+	for character in text.chars() {
+		match character {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			_ => escaped.push(character),
+		}
+	}
+	Cow::Owned(escaped)
+}
+
+impl core::fmt::Write for SvgSegmentList {
+	fn write_str(&mut self, text: &str) -> core::fmt::Result {
+		self.text_mut().push_str(text);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::vector::style::{FillType, Gradient, GradientType, Stroke};
+	use crate::GraphicElement;
+
+	fn render_image(render_params: &RenderParams) -> SvgRender {
+		let image_frame = ImageFrame {
+			image: Image::new(2, 2, Color::TRANSPARENT),
+			transform: DAffine2::from_scale(DVec2::new(20., 10.)),
+		};
+		let mut render = SvgRender::new();
+		image_frame.render_svg(&mut render, render_params);
+		render
+	}
+
+	#[test]
+	fn text_is_merged_between_blob_urls() {
+		let mut list = SvgSegmentList::default();
+		list.push("<image href=\"");
+		list.push(SvgSegment::BlobUrl(7));
+		list.push("\"/>");
+		list.push(String::from("<g/>"));
+		assert_eq!(
+			*list,
+			vec![SvgSegment::String("<image href=\"".to_string()), SvgSegment::BlobUrl(7), SvgSegment::String("\"/><g/>".to_string())]
+		);
+	}
+
+	#[test]
+	fn empty_parent_tag_self_closes() {
+		let mut render = SvgRender::new();
+		render.parent_tag("g", |attributes| attributes.push_display("opacity", 0.5), |_| {});
+		render.parent_tag("g", |_| {}, |render| render.leaf_tag("rect", |attributes| attributes.push_display("width", 2)));
+		assert_eq!(render.svg.to_string(), "\n<g opacity=\"0.5\"/>\n<g>\n\t<rect width=\"2\"/>\n</g>");
+	}
+
+	#[test]
+	fn user_text_is_escaped() {
+		let name = "A & B <c>";
+		let mut render = SvgRender::new();
+		render.parent_tag(
+			"text",
+			|attributes| {
+				attributes.push("data-name", name.to_string());
+				attributes.push_display("title", r#""quoted""#);
+			},
+			|render| render.push_text(name),
+		);
+		assert_eq!(
+			render.svg.to_string(),
+			"\n<text data-name=\"A &amp; B &lt;c&gt;\" title=\"&quot;quoted&quot;\">A &amp; B &lt;c&gt;\n</text>"
+		);
+
+		// Text on either side of a blob url is escaped without touching the markup written before the attribute
+		let mut render = SvgRender::new();
+		render.leaf_tag("image", |attributes| {
+			attributes.push_complex("href", |render| {
+				render.svg.push("<");
+				render.svg.push(SvgSegment::BlobUrl(7));
+				render.svg.push(">");
+			})
+		});
+		assert_eq!(
+			*render.svg,
+			vec![
+				SvgSegment::String("\n<image href=\"&lt;".to_string()),
+				SvgSegment::BlobUrl(7),
+				SvgSegment::String("&gt;\"/>".to_string())
+			]
+		);
+	}
+
+	fn element(name: &str, graphic_element_data: impl Into<GraphicElementData>) -> GraphicElement {
+		GraphicElement {
+			name: name.to_string(),
+			blend_mode: BlendMode::Normal,
+			opacity: 1.,
+			visible: true,
+			locked: false,
+			collapsed: false,
+			graphic_element_data: graphic_element_data.into(),
+			clip: None,
+		}
+	}
+
+	#[test]
+	fn large_group_renders_into_one_buffer() {
+		let image = |index: usize| {
+			let image_frame = ImageFrame {
+				image: Image::new(1, 1, Color::BLACK),
+				transform: DAffine2::from_translation(DVec2::splat(index as f64)),
+			};
+			element(&index.to_string(), image_frame)
+		};
+		let mut group = GraphicGroup::EMPTY;
+		group.extend((0..10_000).map(image));
+
+		let mut render = SvgRender::new();
+		group.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+
+		// Each image's blob url splits the text, so there is one text segment per image plus the trailing one
+		assert_eq!(render.svg.len(), 10_000 * 2 + 1);
+		assert_eq!(render.image_data.len(), 10_000);
+	}
+
+	#[test]
+	fn identity_transform_is_omitted() {
+		let mut render = SvgRender::new();
+		render.leaf_tag("image", |attributes| attributes.push_transform(DAffine2::IDENTITY));
+		render.leaf_tag("image", |attributes| attributes.push_transform(DAffine2::from_cols_array(&[2., 0., 0.5, 3., 10., -4.])));
+		assert_eq!(render.svg.to_string(), "\n<image/>\n<image transform=\"matrix(2, 0, 0.5, 3, 10, -4)\"/>");
+
+		let transform = DAffine2::from_scale_angle_translation(DVec2::new(1.5, 0.25), 0.3, DVec2::new(-7., 12.));
+		assert_eq!(format_transform_matrix(transform), format!("matrix({})", transform.to_cols_array().map(|x| x.to_string()).join(", ")));
+	}
+
+	#[test]
+	fn precision_shrinks_output() {
+		let render_at = |precision| {
+			let mut vector_data = VectorData::from_subpath(bezier_rs::Subpath::new_ellipse(DVec2::new(1. / 3., 2. / 7.), DVec2::new(100. / 3., 50. / 7.)));
+			vector_data.transform = DAffine2::from_angle(0.1);
+			let mut render = SvgRender::new();
+			render.precision = precision;
+			vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+			render.format_svg(DVec2::new(-1. / 3., 0.), DVec2::new(200. / 3., 100.));
+			render.svg.to_string()
+		};
+		let (rounded, full) = (render_at(Some(2)), render_at(None));
+
+		assert!(rounded.len() * 2 < full.len(), "{} bytes at precision 2 vs {} at full precision", rounded.len(), full.len());
+		assert!(rounded.contains(r#"viewBox="-0.33 0 67 100""#));
+		assert!(full.contains(&format!(r#"viewBox="{} 0 {} 100""#, -1. / 3., 200. / 3. + 1. / 3.)));
+	}
+
+	#[test]
+	fn numbers_drop_trailing_zeros() {
+		let number = |value, precision| SvgNumber { value, precision }.to_string();
+		assert_eq!(number(1.23456, Some(3)), "1.235");
+		assert_eq!(number(2.5, Some(3)), "2.5");
+		assert_eq!(number(-0.0001, Some(2)), "0");
+		assert_eq!(number(0.1 + 0.2, Some(3)), "0.3");
+		assert_eq!(number(0.1 + 0.2, None), (0.1_f64 + 0.2).to_string());
+	}
+
+	#[test]
+	fn reordering_changes_render_order_but_not_bounds() {
+		let square = |corner: DVec2, size: f64| VectorData::from_subpath(bezier_rs::Subpath::new_rect(corner, corner + size));
+		let mut group = GraphicGroup::EMPTY;
+		group.push(element("a", square(DVec2::ZERO, 1.)));
+		group.push(element("b", square(DVec2::new(5., -2.), 2.)));
+		group.push(element("c", square(DVec2::new(-3., 4.), 3.)));
+
+		let render_order = |group: &GraphicGroup| {
+			let mut render = SvgRender::new();
+			group.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+			let svg = render.svg.to_string();
+			let mut starts = ["M0,0", "M5,-2", "M-3,4"].map(|start| (svg.find(start).unwrap(), start));
+			starts.sort();
+			starts.map(|(_, start)| start)
+		};
+		let bounds = group.bounding_box(DAffine2::IDENTITY);
+		assert_eq!(render_order(&group), ["M0,0", "M5,-2", "M-3,4"]);
+
+		group.move_element(0, 2);
+		assert_eq!(group.iter().map(|element| element.name.as_str()).collect::<Vec<_>>(), ["b", "c", "a"]);
+		assert_eq!(render_order(&group), ["M5,-2", "M-3,4", "M0,0"]);
+		assert_eq!(group.bounding_box(DAffine2::IDENTITY), bounds);
+
+		group.move_element(10, 0);
+		assert_eq!(group.iter().map(|element| element.name.as_str()).collect::<Vec<_>>(), ["a", "b", "c"]);
+		assert_eq!(group.bounding_box(DAffine2::IDENTITY), bounds);
+	}
+
+	#[test]
+	fn elements_outside_the_viewport_are_culled() {
+		let square = |corner: DVec2| VectorData::from_subpath(bezier_rs::Subpath::new_rect(corner, corner + 10.));
+		let mut group = GraphicGroup::EMPTY;
+		group.push(element("visible", square(DVec2::new(40., 40.))));
+		group.push(element("partly visible", square(DVec2::new(95., 20.))));
+		group.push(element("off-screen", square(DVec2::new(150., 20.))));
+
+		// Zoomed in 2x, so the document from (0, 0) to (100, 75) fills a 200 by 150 viewport
+		let document_to_viewport = DAffine2::from_scale(DVec2::splat(2.));
+		let culling_bounds = viewport_culling_bounds(document_to_viewport, DVec2::new(200., 150.));
+		assert_eq!(culling_bounds, Some([DVec2::ZERO, DVec2::new(100., 75.)]));
+
+		let mut render = SvgRender::new();
+		group.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, culling_bounds, false));
+		let svg = render.svg.to_string();
+		assert!(svg.contains("M40,40"));
+		assert!(svg.contains("M95,20"));
+		assert!(!svg.contains("M150,20"), "Content outside the viewport should be left out of {svg}");
+
+		assert_eq!(viewport_culling_bounds(DAffine2::ZERO, DVec2::new(200., 150.)), None);
+
+		// Text has no bounds, so it's never culled and doesn't change the bounds of its group
+		let text = GraphicElementData::Text("Off-screen?".to_string());
+		assert_eq!(text.bounding_box(DAffine2::IDENTITY), None);
+		assert!(!is_culled(&RenderParams::new(ViewMode::Normal, culling_bounds, false), text.bounding_box(DAffine2::IDENTITY)));
+		let bounds = group.bounding_box(DAffine2::IDENTITY);
+		group.push(element("text", text));
+		assert_eq!(group.bounding_box(DAffine2::IDENTITY), bounds);
+	}
+
+	#[test]
+	fn incremental_render_only_emits_changed_elements() {
+		let square = |corner: DVec2| VectorData::from_subpath(bezier_rs::Subpath::new_rect(corner, corner + 10.));
+		let mut group = GraphicGroup::EMPTY;
+		group.push(element("a", square(DVec2::ZERO)));
+		group.push(element("b", square(DVec2::new(20., 0.))));
+		group.push(element("c", square(DVec2::new(40., 0.))));
+
+		let params = RenderParams::new(ViewMode::Normal, None, false);
+		let mut cache = RenderCache::default();
+		let mut render_incrementally = |group: &GraphicGroup, dirty_region| {
+			let mut render = SvgRender::new();
+			let emitted = cache.render_group(group, &mut render, &params, dirty_region);
+			let mut full_render = SvgRender::new();
+			group.render_svg(&mut full_render, &params);
+			assert_eq!(render.svg, full_render.svg, "Reusing cached elements should give the same SVG as a full render");
+			emitted
+		};
+
+		assert_eq!(render_incrementally(&group, None), vec![0, 1, 2]);
+		assert_eq!(render_incrementally(&group, None), Vec::<usize>::new());
+
+		group[1].opacity = 0.5;
+		assert_eq!(render_incrementally(&group, None), vec![1]);
+
+		group.move_element(2, 0);
+		assert_eq!(render_incrementally(&group, None), Vec::<usize>::new(), "Reordering reuses every element");
+		assert_eq!(render_incrementally(&group, Some([DVec2::new(5., 5.), DVec2::new(25., 6.)])), vec![1, 2]);
+	}
+
+	#[test]
+	fn incremental_render_reemits_elements_when_the_render_params_change() {
+		let mut group = GraphicGroup::EMPTY;
+		group.push(element("a", VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)))));
+		let mut cache = RenderCache::default();
+		let mut render_with = |group: &GraphicGroup, params: &RenderParams| {
+			let mut render = SvgRender::new();
+			let emitted = cache.render_group(group, &mut render, params, None);
+			(emitted, render.svg.to_string())
+		};
+
+		let params = RenderParams::new(ViewMode::Normal, None, false);
+		assert_eq!(render_with(&group, &params).0, vec![0]);
+		let (emitted, svg) = render_with(&group, &params.with_anti_aliasing(AntiAliasing::None));
+		assert_eq!(emitted, vec![0]);
+		assert!(svg.contains("crispEdges"));
+
+		group.push(element(
+			"b",
+			ImageFrame {
+				image: Image::new(1, 1, Color::RED),
+				transform: DAffine2::from_scale(DVec2::splat(10.)),
+			},
+		));
+		let params = RenderParams::new(ViewMode::Normal, None, false);
+		assert_eq!(render_with(&group, &params).0, vec![0, 1]);
+		let (emitted, svg) = render_with(&group, &RenderParams::new(ViewMode::Normal, None, false).with_transparency_checkerboard(Checkerboard::default()));
+		assert_eq!(emitted, vec![0, 1]);
+		assert!(svg.contains("transparency-checkerboard"));
+	}
+
+	#[test]
+	fn layer_blend_mode_and_opacity() {
+		let square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let mut group = GraphicGroup::EMPTY;
+		group.push(GraphicElement {
+			blend_mode: BlendMode::Multiply,
+			opacity: 0.5,
+			..element("multiplied", square.clone())
+		});
+		group.push(element("normal", square.clone()));
+		group.push(GraphicElement {
+			blend_mode: BlendMode::LinearBurn,
+			..element("fallback", square)
+		});
+
+		let mut render = SvgRender::new();
+		group.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+		let svg = render.svg.to_string();
+
+		assert!(svg.starts_with("\n<g opacity=\"0.5\" style=\"mix-blend-mode: multiply;\">\n\t<path"), "{svg}");
+		assert_eq!(svg.matches("<g").count(), 2, "Only elements with a blend mode or opacity should be wrapped in a group");
+		assert!(svg.contains("<g style=\"mix-blend-mode: color-burn;\">"));
+	}
+
+	#[test]
+	fn circle_clipped_rectangle() {
+		let rectangle = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::new(10., 10.)));
+		let mut circle = VectorData::from_subpath(bezier_rs::Subpath::new_ellipse(DVec2::ZERO, DVec2::ONE));
+		circle.transform = DAffine2::from_scale_angle_translation(DVec2::splat(4.), 0., DVec2::new(3., 3.));
+		let clipped = GraphicElement {
+			clip: Some(Box::new(circle)),
+			..element("clipped", rectangle)
+		};
+
+		let mut render = SvgRender::new();
+		GraphicGroup(vec![clipped]).render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+		let svg = render.svg.to_string();
+
+		let id = render.svg_defs.strip_prefix(r#"<clipPath id=""#).and_then(|defs| defs.split('"').next()).unwrap();
+		assert!(svg.starts_with(&format!("\n<g clip-path=\"url(#{id})\">\n\t<path")), "{svg}");
+		// The top of the unit circle, under the mask's transform
+		assert!(render.svg_defs.contains(r#"<path d="M5,3C"#), "{}", render.svg_defs);
+		assert!(render.svg_defs.ends_with("Z\"/></clipPath>"));
+	}
+
+	#[test]
+	fn nested_clips_are_separate_groups() {
+		let square = |size: f64| VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::splat(size)));
+		let inner = GraphicElement {
+			clip: Some(Box::new(square(2.))),
+			..element("inner", square(10.))
+		};
+		let outer = GraphicElement {
+			clip: Some(Box::new(square(5.))),
+			..element("outer", GraphicGroup(vec![inner]))
+		};
+
+		let mut render = SvgRender::new();
+		GraphicGroup(vec![outer]).render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+
+		assert_eq!(render.svg_defs.matches("<clipPath").count(), 2);
+		assert_eq!(render.svg.to_string().matches("clip-path=\"url(#clip-").count(), 2);
+	}
+
+	#[test]
+	fn deterministic_ids_give_reproducible_svg() {
+		let image = Image::new(1, 1, Color::BLUE);
+		let image_frame = ImageFrame {
+			image: image.clone(),
+			transform: DAffine2::from_scale(DVec2::new(4., 2.)),
+		};
+		let clipped = GraphicElement {
+			clip: Some(Box::new(VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::ONE)))),
+			..element("clipped", image_frame)
+		};
+		let group = GraphicGroup(vec![clipped]);
+
+		let render_with = |render_params: &RenderParams| {
+			let mut render = SvgRender::new();
+			group.render_svg(&mut render, render_params);
+			render
+		};
+		let params = RenderParams::new(ViewMode::Normal, None, false).with_deterministic_ids();
+		let render = render_with(&params);
+
+		let path = "M0,0C0,0 1,0 1,0 C1,0 1,1 1,1 C1,1 0,1 0,1 C0,1 0,0 0,0 Z";
+		let clip_id = SvgIds::Deterministic.generate(path);
+		let image_id = SvgIds::Deterministic.generate(&image);
+		assert_eq!(render.svg_defs, format!(r#"<clipPath id="clip-{clip_id}"><path d="{path}"/></clipPath>"#));
+		assert_eq!(
+			render.svg.to_string(),
+			format!("\n<g clip-path=\"url(#clip-{clip_id})\">\n\t<image width=\"1\" height=\"1\" preserveAspectRatio=\"none\" transform=\"matrix(4, 0, 0, 2, 0, 0)\" href=\"<!-- Blob url not yet loaded -->\"/>\n</g>")
+		);
+		assert!(render.svg.iter().any(|segment| *segment == SvgSegment::BlobUrl(image_id)));
+		assert_eq!(render.image_data.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![image_id]);
+
+		// Random ids differ between renders, but deterministic ones don't
+		let random = RenderParams::new(ViewMode::Normal, None, false);
+		assert_ne!(render_with(&random).svg_defs, render_with(&random).svg_defs);
+		let again = render_with(&params);
+		assert_eq!((again.svg, again.svg_defs), (render.svg, render.svg_defs));
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn parallel_render_matches_sequential_render() {
+		let mut group = GraphicGroup::EMPTY;
+		for index in 0..1000 {
+			let corner = DVec2::new(index as f64, -(index as f64));
+			let square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(corner, corner + 1.));
+			let mut graphic_element = match index % 4 {
+				0 => element(
+					&index.to_string(),
+					ImageFrame {
+						image: Image::new(1, 1, Color::from_rgbaf32_unchecked(index as f32 / 1000., 0., 0., 1.)),
+						transform: DAffine2::from_translation(corner),
+					},
+				),
+				_ => element(&index.to_string(), square.clone()),
+			};
+			graphic_element.opacity = if index % 3 == 0 { 0.5 } else { 1. };
+			graphic_element.clip = (index % 5 == 0).then(|| Box::new(square));
+			group.push(graphic_element);
+		}
+		let params = RenderParams::new(ViewMode::Normal, None, false).with_deterministic_ids();
+
+		let mut sequential = SvgRender::new();
+		render_elements(&group, &mut sequential, &params);
+		let mut parallel = SvgRender::new();
+		render_elements_parallel(&group, &mut parallel, &params);
+
+		assert_eq!(parallel.svg, sequential.svg);
+		assert_eq!(parallel.svg_defs, sequential.svg_defs);
+		assert_eq!(parallel.image_data, sequential.image_data);
+	}
+
+	#[test]
+	fn style_layers_are_drawn_beneath_the_main_style() {
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		square.style.set_stroke(Stroke::new(Some(Color::WHITE), 2.));
+		square.style.set_layers(vec![StyleLayer::Stroke(Stroke::new(Some(Color::BLACK), 6.))]);
+		let group = GraphicGroup(vec![element("square", square)]);
+
+		let render_in = |view_mode| {
+			let mut render = SvgRender::new();
+			group.render_svg(&mut render, &RenderParams::new(view_mode, None, false));
+			render.svg.to_string()
+		};
+
+		let svg = render_in(ViewMode::Normal);
+		assert_eq!(svg.matches("<path").count(), 2, "{svg}");
+		let (wide, narrow) = (svg.find(r#"stroke-width="6""#).unwrap(), svg.find(r#"stroke-width="2""#).unwrap());
+		assert!(wide < narrow, "The style layer should be drawn first, beneath the main stroke");
+		assert!(svg[..narrow].contains(r#"fill="none""#));
+
+		assert_eq!(render_in(ViewMode::Outline).matches("<path").count(), 1);
+	}
+
+	#[test]
+	fn gradient_stroke_references_its_def() {
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let mut gradient = Gradient::new(DVec2::ZERO, Color::BLACK, DVec2::ONE, Color::WHITE, DAffine2::IDENTITY, 0, GradientType::Radial);
+		gradient.transform = DAffine2::from_angle(0.5);
+		square.style.set_stroke(Stroke {
+			gradient: Some(gradient),
+			..Stroke::new(Some(Color::RED), 2.)
+		});
+
+		let mut render = SvgRender::new();
+		square.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+		let svg = render.svg.to_string();
+
+		let id = render.svg_defs.strip_prefix(r#"<radialGradient id=""#).and_then(|defs| defs.split('"').next()).unwrap();
+		assert!(svg.contains(&format!(r#"stroke="url('#{id}')""#)), "{svg}");
+		assert!(svg.contains(r#"stroke-width="2""#));
+		assert!(!svg.contains(&format!("#{}", Color::RED.rgb_hex())), "The gradient replaces the stroke color");
+	}
+
+	#[test]
+	fn crisp_edges_without_anti_aliasing() {
+		let square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let render_with = |anti_aliasing| {
+			let mut render = SvgRender::new();
+			square.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false).with_anti_aliasing(anti_aliasing));
+			render.svg.to_string()
+		};
+
+		assert!(render_with(AntiAliasing::None).contains(r#"shape-rendering="crispEdges""#));
+		assert!(!render_with(AntiAliasing::default()).contains("shape-rendering"));
+	}
+
+	#[test]
+	fn pattern_fill_emits_pattern_def() {
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+		let image = ImageFrame {
+			image: Image::new(2, 2, Color::RED),
+			transform: DAffine2::from_scale(DVec2::splat(10.)),
+		};
+		let render_with = |repeat: bool, square: &mut VectorData| {
+			square.style.set_fill(Fill::Pattern(Pattern { image: image.clone(), repeat }));
+			let mut render = SvgRender::new();
+			square.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false).with_deterministic_ids());
+			render
+		};
+
+		let render = render_with(true, &mut square);
+		let svg = render.svg.to_string();
+		let id = svg.split(r#"<pattern id=""#).nth(1).and_then(|pattern| pattern.split('"').next()).unwrap();
+		assert!(svg.contains(&format!(r#"fill="url(#{id})""#)), "{svg}");
+		assert!(
+			svg.contains(r#"width="1" height="1" patternTransform="matrix(10, 0, 0, 10, 0, 0)""#),
+			"A repeating tile holds one image"
+		);
+		assert!(svg.find("<pattern").unwrap() < svg.find("<path").unwrap(), "The pattern is defined before the shape");
+		assert_eq!(render.image_data.len(), 1);
+
+		// Without repeating, the tile covers the whole square so the image is only drawn once within it
+		let svg = render_with(false, &mut square).svg.to_string();
+		assert!(svg.contains(r#"x="0" y="0" width="10" height="10""#), "{svg}");
+
+		let mut outline = SvgRender::new();
+		square.render_svg(&mut outline, &RenderParams::new(ViewMode::Outline, None, false));
+		assert!(!outline.svg.to_string().contains("<pattern"));
+	}
+
+	#[test]
+	fn artboard_label_options() {
+		let mut artboard = Artboard::new(glam::IVec2::ZERO, glam::IVec2::splat(100));
+		let render = |artboard: &Artboard| {
+			let mut render = SvgRender::new();
+			GraphicGroup(vec![element("Cover & Back", artboard.clone())]).render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+			render.svg.to_string()
+		};
+
+		assert!(render(&artboard).contains(r#"font-size="14px">Cover &amp; Back"#), "The label defaults to the layer's name");
+		artboard.label.text = "Front".to_string();
+		artboard.label.font_size = 20.;
+		artboard.label.color = Color::RED;
+		let svg = render(&artboard);
+		assert!(svg.contains(&format!(r##"fill="#{}""##, Color::RED.rgba_hex())) && svg.contains(r#"font-size="20px">Front"#), "{svg}");
+
+		let bounds = artboard.bounding_box(DAffine2::IDENTITY);
+		artboard.label.visible = false;
+		assert!(!render(&artboard).contains("<text"));
+		assert_eq!(artboard.bounding_box(DAffine2::IDENTITY), bounds, "The label never affects the bounds");
+	}
+
+	#[test]
+	fn artboard_clip_corner_radius() {
+		let clip_path = |corner_radius: f64| {
+			// Dimensions going left of the location, which the clip has to flip around like the background does
+			let artboard = Artboard {
+				location: glam::IVec2::new(10, 10),
+				dimensions: glam::IVec2::new(-100, 40),
+				clip: true,
+				corner_radius,
+				..Artboard::new(glam::IVec2::ZERO, glam::IVec2::ZERO)
+			};
+			let mut render = SvgRender::new();
+			artboard.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false));
+			(render.svg_defs, artboard.clip_area())
+		};
+
+		let (defs, area) = clip_path(0.);
+		assert!(defs.contains(r#"<rect x="-90" y="10" width="100" height="40"/>"#), "{defs}");
+		assert_eq!(area.bounding_box(), Some([DVec2::new(-90., 10.), DVec2::new(10., 50.)]));
+
+		let (defs, area) = clip_path(8.);
+		assert!(defs.contains(r#"width="100" height="40" rx="8" ry="8"/>"#), "{defs}");
+		assert_eq!(area.subpaths[0].len(), 8);
+		assert!(area.bounding_box().unwrap()[0].abs_diff_eq(DVec2::new(-90., 10.), 1e-9));
+
+		// A radius past half the height is limited to it, as SVG does
+		assert!(clip_path(50.).0.contains(r#"rx="20" ry="20""#));
+
+		// The radius is rounded to the precision of the render like the other numbers
+		assert!(clip_path(1. / 3.).0.contains(r#"rx="0.333" ry="0.333""#), "{}", clip_path(1. / 3.).0);
+	}
+
+	#[test]
+	fn artboard_background_types() {
+		let mut artboard = Artboard::new(glam::IVec2::ZERO, glam::IVec2::splat(100));
+		let render = |artboard: &Artboard, view_mode| {
+			let mut render = SvgRender::new();
+			artboard.render_svg(&mut render, &RenderParams::new(view_mode, None, false));
+			render
+		};
+
+		assert!(render(&artboard, ViewMode::Normal).svg.to_string().contains(r##"class="artboard-bg" fill="#FFFFFFFF""##));
+
+		artboard.background_type = FillType::None;
+		assert!(!render(&artboard, ViewMode::Normal).svg.to_string().contains("artboard-bg"), "A transparent background has no rect");
+		assert!(render(&artboard, ViewMode::Outline).svg.to_string().contains("artboard-bg"), "The outline is still drawn");
+
+		artboard.background_type = FillType::Gradient;
+		artboard.background_gradient = Gradient::new(DVec2::ZERO, Color::BLACK, DVec2::ONE, Color::WHITE, DAffine2::IDENTITY, 0, GradientType::Linear);
+		let gradient = render(&artboard, ViewMode::Normal);
+		let id = gradient.svg_defs.strip_prefix(r#"<linearGradient id=""#).and_then(|defs| defs.split('"').next()).unwrap();
+		assert!(gradient.svg.to_string().contains(&format!(r#"class="artboard-bg" fill="url('#{id}')""#)));
+	}
+
+	#[test]
+	fn outline_mode_suppresses_fills() {
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		square.style.set_fill(Fill::Solid(Color::RED));
+		let image_frame = ImageFrame {
+			image: Image::new(1, 1, Color::BLUE),
+			transform: DAffine2::from_scale(DVec2::new(4., 2.)),
+		};
+		let group = GraphicGroup(vec![element("square", square), element("image", image_frame)]);
+
+		let render_in = |view_mode| {
+			let mut render = SvgRender::new();
+			group.render_svg(&mut render, &RenderParams::new(view_mode, None, false));
+			render
+		};
+
+		let normal = render_in(ViewMode::Normal);
+		assert!(normal.svg.to_string().contains(r##"fill="#FF0000""##));
+		assert_eq!(normal.image_data.len(), 1);
+
+		let outline = render_in(ViewMode::Outline);
+		let svg = outline.svg.to_string();
+		assert!(!svg.contains(r##"fill="#"##), "{svg}");
+		assert!(!svg.contains("<image"), "Images should be drawn as their bounds");
+		assert!(outline.image_data.is_empty());
+		assert!(svg.contains(r#"<polygon class="image-outline" points="0,0 4,0 4,2 0,2" fill="none""#), "{svg}");
+		assert_eq!(svg.matches(r#"vector-effect="non-scaling-stroke""#).count(), 2);
+	}
+
+	#[test]
+	fn checkerboard_is_off_by_default() {
+		let render = render_image(&RenderParams::new(ViewMode::Normal, None, false));
+		assert!(render.svg_defs.is_empty());
+		assert!(!render.svg.to_string().contains("checkerboard"));
+	}
+
+	#[test]
+	fn checkerboard_behind_image() {
+		let checkerboard = Checkerboard {
+			size: 4.,
+			colors: [Color::WHITE, Color::BLACK],
+		};
+		let render = render_image(&RenderParams::new(ViewMode::Normal, None, false).with_transparency_checkerboard(checkerboard));
+		let svg = render.svg.to_string();
+
+		assert!(render.svg_defs.starts_with(r#"<pattern id="checkerboard-"#));
+		assert!(render.svg_defs.contains(r#"width="8" height="8""#));
+		assert!(render.svg_defs.contains("#FFFFFFFF") && render.svg_defs.contains("#000000FF"));
+		assert!(svg.contains(r#"points="0,0 20,0 20,10 0,10""#));
+		assert!(svg.find("<polygon").unwrap() < svg.find("<image").unwrap(), "The checkerboard should be drawn below the image");
 	}
 }
@@ -0,0 +1,676 @@
+use super::{AntiAliasing, GraphicElementRendered};
+use crate::raster::{blend_colors, BlendMode, Image, ImageFrame};
+use crate::uuid::ManipulatorGroupId;
+use crate::vector::style::{Fill, Gradient, GradientType, LineCap, Pattern, Stroke};
+use crate::vector::VectorData;
+use crate::Color;
+
+use glam::{DAffine2, DVec2};
+
+/// The number of scanlines sampled per row of pixels. Coverage along each scanline is exact, so this only limits the antialiasing of horizontal-ish edges.
+/// Strokes are sampled on a grid of this many samples along each side of a pixel.
+const SUBSAMPLES: usize = 4;
+
+/// The number of line segments a curve is flattened into
+const CURVE_STEPS: usize = 16;
+
+/// The most pixels the canvas is allowed to have, the same as the limit on exported images
+const MAX_PIXEL_COUNT: f64 = 8192. * 8192.;
+
+/// Renders the element's content within `bounds` to pixels, with `scale` pixels per unit of document space.
+/// Edges are antialiased by the fraction of each pixel that shapes cover, and supersampling draws at a multiple of the resolution which is then averaged down on top of that.
+/// Without antialiasing, pixels are either covered or not. Text isn't rasterized yet.
+/// The scale is lowered when the drawing would need more than [`MAX_PIXEL_COUNT`] pixels.
+pub fn rasterize(element: &impl GraphicElementRendered, bounds: [DVec2; 2], scale: f64, anti_aliasing: AntiAliasing) -> ImageFrame<Color> {
+	let factor = anti_aliasing.factor();
+	let scale = capped_scale(bounds[1] - bounds[0], scale, factor);
+	let size = ((bounds[1] - bounds[0]) * scale).ceil().max(DVec2::ZERO);
+	let (Some(width), Some(height)) = ((size.x as u32).checked_mul(factor), (size.y as u32).checked_mul(factor)) else {
+		warn!("The rasterized image of {size} pixels is too large and was left empty");
+		return ImageFrame::empty();
+	};
+	let document_to_pixel = DAffine2::from_scale(DVec2::splat(scale * factor as f64)) * DAffine2::from_translation(-bounds[0]);
+	let mut canvas = Canvas::new(width, height, document_to_pixel, anti_aliasing);
+	element.rasterize_into(&mut canvas, DAffine2::IDENTITY);
+
+	ImageFrame {
+		image: downsample(canvas.image, factor),
+		transform: DAffine2::from_translation(bounds[0]) * DAffine2::from_scale(size / scale),
+	}
+}
+
+/// The scale, lowered as needed so the canvas drawn for an area of `size` stays at about [`MAX_PIXEL_COUNT`] pixels
+fn capped_scale(size: DVec2, scale: f64, factor: u32) -> f64 {
+	let pixels = (size.max(DVec2::ZERO) * scale * factor as f64).ceil();
+	let pixel_count = pixels.x * pixels.y;
+	if pixel_count > MAX_PIXEL_COUNT {
+		scale * (MAX_PIXEL_COUNT / pixel_count).sqrt()
+	} else {
+		scale
+	}
+}
+
+/// Averages each square of `factor`×`factor` premultiplied pixels into one pixel
+fn downsample(image: Image<Color>, factor: u32) -> Image<Color> {
+	if factor == 1 {
+		return image;
+	}
+	let (width, height) = (image.width / factor, image.height / factor);
+	let samples = (factor * factor) as f32;
+
+	let mut data = Vec::with_capacity((width * height) as usize);
+	for y in 0..height {
+		for x in 0..width {
+			let [mut r, mut g, mut b, mut a] = [0.; 4];
+			for sample_y in y * factor..(y + 1) * factor {
+				for sample_x in x * factor..(x + 1) * factor {
+					let sample = image.data[(sample_y * image.width + sample_x) as usize];
+					(r, g, b, a) = (r + sample.r(), g + sample.g(), b + sample.b(), a + sample.a());
+				}
+			}
+			data.push(Color::from_rgbaf32_unchecked(r / samples, g / samples, b / samples, a / samples));
+		}
+	}
+	Image { width, height, data }
+}
+
+/// Premultiplied linear pixels being painted, along with the mapping from document space to pixel coordinates.
+pub struct Canvas {
+	pub image: Image<Color>,
+	pub document_to_pixel: DAffine2,
+	/// Without antialiasing, the coverage of each pixel is rounded to whether it's covered or not
+	pub anti_aliasing: AntiAliasing,
+}
+
+impl Canvas {
+	pub fn new(width: u32, height: u32, document_to_pixel: DAffine2, anti_aliasing: AntiAliasing) -> Self {
+		Self {
+			image: Image::new(width, height, Color::TRANSPARENT),
+			document_to_pixel,
+			anti_aliasing,
+		}
+	}
+
+	/// A transparent canvas of the same size, mapping, and antialiasing, used to composite an element as a whole
+	pub fn empty_layer(&self) -> Self {
+		Self::new(self.image.width, self.image.height, self.document_to_pixel, self.anti_aliasing)
+	}
+
+	/// Fills the shape, treating open subpaths as closed like SVG does
+	pub fn fill_vector(&mut self, vector_data: &VectorData, transform: DAffine2, fill: &Fill) {
+		let layer_to_pixel = self.document_to_pixel * transform * vector_data.transform;
+		let Some(paint) = Paint::from_fill(fill, layer_to_pixel, vector_data.bounding_box().unwrap_or_default()) else {
+			return;
+		};
+		let coverage = self.coverage(vector_data, transform);
+		self.paint(&paint, &coverage);
+	}
+
+	/// Strokes the subpaths of the shape. Joins are always drawn round, whatever the line join of the stroke is.
+	pub fn stroke_vector(&mut self, vector_data: &VectorData, transform: DAffine2, stroke: &Stroke) {
+		let layer_to_pixel = self.document_to_pixel * transform * vector_data.transform;
+		let Some(paint) = Paint::from_stroke(stroke, layer_to_pixel, vector_data.bounding_box().unwrap_or_default()) else {
+			return;
+		};
+		let coverage = self.stroke_coverage(vector_data, transform, stroke);
+		self.paint(&paint, &coverage);
+	}
+
+	/// Blends the paint over the pixels in proportion to their coverage
+	fn paint(&mut self, paint: &Paint, coverage: &[f32]) {
+		let width = self.image.width as usize;
+		for (index, (pixel, &coverage)) in self.image.data.iter_mut().zip(coverage).enumerate() {
+			if coverage > 0. {
+				let center = DVec2::new((index % width) as f64 + 0.5, (index / width) as f64 + 0.5);
+				*pixel = pixel.alpha_blend(paint.color_at(center).apply_opacity(coverage.min(1.)));
+			}
+		}
+	}
+
+	/// Draws the image, whose unit square is mapped into document space by `transform`, sampling the nearest pixel
+	pub fn draw_image(&mut self, image: &Image<Color>, transform: DAffine2) {
+		if image.width == 0 || image.height == 0 {
+			return;
+		}
+		let pixel_to_image = DAffine2::from_scale(DVec2::new(image.width as f64, image.height as f64)) * (self.document_to_pixel * transform).inverse();
+		if !pixel_to_image.is_finite() {
+			return;
+		}
+
+		let width = self.image.width as usize;
+		for (index, pixel) in self.image.data.iter_mut().enumerate() {
+			let center = DVec2::new((index % width) as f64 + 0.5, (index / width) as f64 + 0.5);
+			let source = pixel_to_image.transform_point2(center).floor();
+			if source.x < 0. || source.y < 0. || source.x >= image.width as f64 || source.y >= image.height as f64 {
+				continue;
+			}
+			let source = image.data[source.y as usize * image.width as usize + source.x as usize];
+			*pixel = pixel.alpha_blend(source);
+		}
+	}
+
+	/// Composites a layer of the same size onto the canvas, optionally multiplying it by a coverage mask
+	pub fn blend_layer(&mut self, layer: &Canvas, blend_mode: BlendMode, opacity: f32, mask: Option<&[f32]>) {
+		for (index, (background, &foreground)) in self.image.data.iter_mut().zip(&layer.image.data).enumerate() {
+			let foreground = match mask {
+				Some(mask) => foreground.apply_opacity(mask[index].min(1.)),
+				None => foreground,
+			};
+			if foreground.a() > 0. || blend_mode != BlendMode::Normal {
+				*background = blend_colors(foreground, *background, blend_mode, opacity);
+			}
+		}
+	}
+
+	/// The fraction of each pixel covered by the shape under the nonzero winding rule
+	pub fn coverage(&self, vector_data: &VectorData, transform: DAffine2) -> Vec<f32> {
+		let (width, height) = (self.image.width as usize, self.image.height as usize);
+		let mut coverage = vec![0.; width * height];
+		let edges = flatten(&vector_data.subpaths, self.document_to_pixel * transform * vector_data.transform);
+
+		let mut crossings = Vec::new();
+		for row in 0..height {
+			for sample in 0..SUBSAMPLES {
+				let y = row as f64 + (sample as f64 + 0.5) / SUBSAMPLES as f64;
+				crossings.clear();
+				for &(start, end) in &edges {
+					let (top, bottom, winding) = if start.y < end.y { (start, end, 1) } else { (end, start, -1) };
+					if y < top.y || y >= bottom.y {
+						continue;
+					}
+					let x = top.x + (y - top.y) / (bottom.y - top.y) * (bottom.x - top.x);
+					crossings.push((x, winding));
+				}
+				crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+				let row_coverage = &mut coverage[row * width..(row + 1) * width];
+				let mut winding = 0;
+				for pair in crossings.windows(2) {
+					winding += pair[0].1;
+					if winding != 0 {
+						add_span(row_coverage, pair[0].0, pair[1].0);
+					}
+				}
+			}
+		}
+		self.finish_coverage(coverage)
+	}
+
+	/// The fraction of each pixel covered by the stroke, from a grid of [`SUBSAMPLES`] × [`SUBSAMPLES`] samples
+	pub fn stroke_coverage(&self, vector_data: &VectorData, transform: DAffine2, stroke: &Stroke) -> Vec<f32> {
+		let width = self.image.width as usize;
+		// A bit for each sample of the pixel that's covered, so the parts of the stroke that overlap aren't counted twice
+		let mut samples = vec![0_u32; width * self.image.height as usize];
+
+		// Like in SVG, the weight and dashes are measured in document space, so they aren't scaled by the transform of the shape
+		let pixels_per_unit = self.document_to_pixel.matrix2.determinant().abs().sqrt();
+		let half_width = stroke.weight * pixels_per_unit / 2.;
+		if half_width.is_nan() || half_width <= 0. {
+			return vec![0.; samples.len()];
+		}
+		let dash_lengths: Vec<_> = stroke.dash_lengths.iter().map(|&length| length as f64 * pixels_per_unit).collect();
+
+		for (points, closed) in polylines(&vector_data.subpaths, self.document_to_pixel * transform * vector_data.transform) {
+			// Closed subpaths only have ends where their dashes do
+			let pieces = match dash(&points, &dash_lengths, stroke.dash_offset * pixels_per_unit) {
+				Some(dashes) => dashes.into_iter().map(|dash| (dash, true)).collect(),
+				None => vec![(points, !closed)],
+			};
+			for (mut piece, ends) in pieces {
+				if piece.len() < 2 {
+					continue;
+				}
+				let last = piece.len() - 1;
+				if ends && stroke.line_cap == LineCap::Square {
+					piece[0] += (piece[0] - piece[1]).normalize_or_zero() * half_width;
+					piece[last] += (piece[last] - piece[last - 1]).normalize_or_zero() * half_width;
+				}
+				// Round caps are the ends of the segments left as they are, while the others stop at the ends
+				let clip_ends = ends && stroke.line_cap != LineCap::Round;
+				for (index, segment) in piece.windows(2).enumerate() {
+					let clip = (clip_ends && index == 0, clip_ends && index == last - 1);
+					cover_segment(&mut samples, width, segment[0], segment[1], half_width, clip);
+				}
+			}
+		}
+		let coverage = samples.into_iter().map(|samples| samples.count_ones() as f32 / (SUBSAMPLES * SUBSAMPLES) as f32).collect();
+		self.finish_coverage(coverage)
+	}
+
+	/// Rounds the coverage to whether each pixel is covered or not when there's no antialiasing
+	fn finish_coverage(&self, mut coverage: Vec<f32>) -> Vec<f32> {
+		if self.anti_aliasing == AntiAliasing::None {
+			coverage.iter_mut().for_each(|coverage| *coverage = if *coverage >= 0.5 { 1. } else { 0. });
+		}
+		coverage
+	}
+}
+
+/// What a shape is painted with, positioned in pixel coordinates
+enum Paint<'a> {
+	/// A premultiplied linear color
+	Solid(Color),
+	Gradient {
+		gradient: &'a Gradient,
+		start: DVec2,
+		end: DVec2,
+	},
+	/// An image pattern, along with the mapping from pixel coordinates to the unit square of its tile
+	Pattern {
+		pattern: &'a Pattern,
+		pixel_to_tile: DAffine2,
+	},
+}
+
+impl<'a> Paint<'a> {
+	/// The paint of a fill, with `layer_to_pixel` mapping the space of the shape, where it has `bounds`, to pixel coordinates
+	fn from_fill(fill: &'a Fill, layer_to_pixel: DAffine2, bounds: [DVec2; 2]) -> Option<Self> {
+		match fill {
+			Fill::None => None,
+			Fill::Solid(color) => Some(Self::solid(*color)),
+			Fill::Gradient(gradient) => Some(Self::gradient(gradient, layer_to_pixel, bounds)),
+			Fill::Pattern(pattern) => {
+				let pixel_to_tile = (layer_to_pixel * pattern.image.transform).inverse();
+				pixel_to_tile.is_finite().then_some(Self::Pattern { pattern, pixel_to_tile })
+			}
+		}
+	}
+
+	/// The paint of a stroke, which is its gradient when it has one like in SVG
+	fn from_stroke(stroke: &'a Stroke, layer_to_pixel: DAffine2, bounds: [DVec2; 2]) -> Option<Self> {
+		match (&stroke.gradient, stroke.color) {
+			(Some(gradient), _) => Some(Self::gradient(gradient, layer_to_pixel, bounds)),
+			(None, Some(color)) => Some(Self::solid(color)),
+			(None, None) => None,
+		}
+	}
+
+	/// SVG colors are gamma encoded with straight alpha, unlike the premultiplied linear pixels
+	fn solid(color: Color) -> Self {
+		Self::Solid(color.to_linear_srgb().premultiply_alpha())
+	}
+
+	/// The start and end of gradients are relative to the bounds of the shape, like in the SVG renderer
+	fn gradient(gradient: &'a Gradient, layer_to_pixel: DAffine2, bounds: [DVec2; 2]) -> Self {
+		let bound_to_pixel = layer_to_pixel * DAffine2::from_scale_angle_translation(bounds[1] - bounds[0], 0., bounds[0]);
+		Self::Gradient {
+			gradient,
+			start: bound_to_pixel.transform_point2(gradient.start),
+			end: bound_to_pixel.transform_point2(gradient.end),
+		}
+	}
+
+	/// The premultiplied linear color at a point in pixel coordinates
+	fn color_at(&self, point: DVec2) -> Color {
+		match self {
+			Self::Solid(color) => *color,
+			Self::Gradient { gradient, start, end } => {
+				let length_squared = start.distance_squared(*end);
+				// A gradient without length is painted with its last stop, like SVG does
+				let t = match gradient.gradient_type {
+					_ if length_squared == 0. => 1.,
+					GradientType::Linear => (point - *start).dot(*end - *start) / length_squared,
+					GradientType::Radial => point.distance(*start) / length_squared.sqrt(),
+				};
+				gradient_color(gradient, t.clamp(0., 1.)).to_linear_srgb().premultiply_alpha()
+			}
+			Self::Pattern { pattern, pixel_to_tile } => {
+				let image = &pattern.image.image;
+				let mut tile = pixel_to_tile.transform_point2(point);
+				if pattern.repeat {
+					tile -= tile.floor();
+				}
+				if image.width == 0 || image.height == 0 || tile.cmplt(DVec2::ZERO).any() || tile.cmpge(DVec2::ONE).any() {
+					return Color::TRANSPARENT;
+				}
+				let x = ((tile.x * image.width as f64) as usize).min(image.width as usize - 1);
+				let y = ((tile.y * image.height as f64) as usize).min(image.height as usize - 1);
+				image.data[y * image.width as usize + x]
+			}
+		}
+	}
+}
+
+/// The color of the gradient at `t` between 0 and 1, blending the gamma encoded straight colors of the stops on either side like SVG does
+fn gradient_color(gradient: &Gradient, t: f64) -> Color {
+	let mut stops = gradient.positions.iter().filter_map(|&(position, color)| Some((position, color?)));
+	let Some(mut previous) = stops.next() else { return Color::TRANSPARENT };
+	if t <= previous.0 {
+		return previous.1;
+	}
+	for stop in stops {
+		if t <= stop.0 {
+			let span = stop.0 - previous.0;
+			let factor = if span > 0. { (t - previous.0) / span } else { 1. };
+			return previous.1.lerp(stop.1, factor as f32);
+		}
+		previous = stop;
+	}
+	previous.1
+}
+
+/// Each subpath as a polyline in pixel coordinates, which returns to its start when the subpath is closed, along with whether it's closed
+fn polylines(subpaths: &[bezier_rs::Subpath<ManipulatorGroupId>], transform: DAffine2) -> Vec<(Vec<DVec2>, bool)> {
+	subpaths
+		.iter()
+		.filter_map(|subpath| {
+			let first = subpath.manipulator_groups().first()?;
+			let mut points = vec![first.anchor];
+			for bezier in subpath.iter() {
+				match bezier.handle_start() {
+					Some(_) => points.extend(bezier.compute_lookup_table(Some(CURVE_STEPS), None).into_iter().skip(1)),
+					None => points.push(bezier.end()),
+				}
+			}
+			Some((points.into_iter().map(|point| transform.transform_point2(point)).collect(), subpath.closed()))
+		})
+		.collect()
+}
+
+/// The non-horizontal edges of the subpaths as line segments in pixel coordinates, with each subpath closed
+fn flatten(subpaths: &[bezier_rs::Subpath<ManipulatorGroupId>], transform: DAffine2) -> Vec<(DVec2, DVec2)> {
+	let mut edges = Vec::new();
+	for (points, _) in polylines(subpaths, transform) {
+		let closing = (points[points.len() - 1], points[0]);
+		edges.extend(
+			points
+				.windows(2)
+				.map(|pair| (pair[0], pair[1]))
+				.chain(core::iter::once(closing))
+				.filter(|(start, end)| start.y != end.y),
+		);
+	}
+	edges
+}
+
+/// Splits the polyline into the dashes of a pattern of alternating dash and gap lengths, starting `offset` into the pattern.
+/// Returns `None` when the pattern leaves the line solid, which like in SVG is when it's empty, has a negative length, or adds up to nothing.
+fn dash(points: &[DVec2], lengths: &[f64], offset: f64) -> Option<Vec<Vec<DVec2>>> {
+	if lengths.iter().any(|length| length.is_nan() || *length < 0.) || !points.iter().all(|point| point.is_finite()) {
+		return None;
+	}
+	// An odd number of lengths is repeated, so each length is used as a dash and then as a gap
+	let lengths = if lengths.len() % 2 == 1 { lengths.repeat(2) } else { lengths.to_vec() };
+	let total: f64 = lengths.iter().sum();
+	if !total.is_finite() || total <= 0. {
+		return None;
+	}
+
+	// Skip through the pattern to the offset
+	let (mut index, mut remaining) = (0, lengths[0]);
+	let mut skipped = offset.rem_euclid(total);
+	while skipped >= remaining {
+		skipped -= remaining;
+		index = (index + 1) % lengths.len();
+		remaining = lengths[index];
+	}
+	remaining -= skipped;
+
+	// Even indices are dashes, and odd ones are gaps
+	let mut dashes = Vec::new();
+	let mut dash = if index % 2 == 0 { vec![points[0]] } else { Vec::new() };
+	for pair in points.windows(2) {
+		let (mut start, end) = (pair[0], pair[1]);
+		let direction = (end - start).normalize_or_zero();
+		let mut length = start.distance(end);
+		while length >= remaining {
+			start += direction * remaining;
+			length -= remaining;
+			dash.push(start);
+			if index % 2 == 0 {
+				dashes.push(std::mem::take(&mut dash));
+			}
+			index = (index + 1) % lengths.len();
+			remaining = lengths[index];
+		}
+		remaining -= length;
+		if index % 2 == 0 {
+			dash.push(end);
+		}
+	}
+	if dash.len() > 1 {
+		dashes.push(dash);
+	}
+	Some(dashes)
+}
+
+/// Marks the samples within `radius` of the segment, leaving out those past its start or end when `clip` says so
+fn cover_segment(samples: &mut [u32], width: usize, start: DVec2, end: DVec2, radius: f64, clip: (bool, bool)) {
+	if width == 0 {
+		return;
+	}
+	let height = samples.len() / width;
+	let direction = end - start;
+	let length_squared = direction.length_squared();
+	if length_squared == 0. && (clip.0 || clip.1) {
+		return;
+	}
+
+	// Only the pixels near the segment are visited
+	let lower = (start.min(end) - radius).floor().max(DVec2::ZERO);
+	let upper = (start.max(end) + radius).ceil().min(DVec2::new(width as f64, height as f64));
+	for y in lower.y as usize..upper.y as usize {
+		for x in lower.x as usize..upper.x as usize {
+			for sample in 0..SUBSAMPLES * SUBSAMPLES {
+				let offset = DVec2::new((sample % SUBSAMPLES) as f64 + 0.5, (sample / SUBSAMPLES) as f64 + 0.5) / SUBSAMPLES as f64;
+				let point = DVec2::new(x as f64, y as f64) + offset;
+				let t = if length_squared > 0. { (point - start).dot(direction) / length_squared } else { 0. };
+				if (clip.0 && t < 0.) || (clip.1 && t > 1.) {
+					continue;
+				}
+				if point.distance_squared(start + direction * t.clamp(0., 1.)) <= radius * radius {
+					samples[y * width + x] |= 1 << sample;
+				}
+			}
+		}
+	}
+}
+
+/// Adds the horizontal overlap of `[start, end)` with each pixel, weighted as one of the subsampled scanlines
+fn add_span(row: &mut [f32], start: f64, end: f64) {
+	let (start, end) = (start.max(0.), end.min(row.len() as f64));
+	if start >= end {
+		return;
+	}
+	for x in start.floor() as usize..(end.ceil() as usize).min(row.len()) {
+		let overlap = end.min(x as f64 + 1.) - start.max(x as f64);
+		row[x] += overlap.max(0.) as f32 / SUBSAMPLES as f32;
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::vector::style::Fill;
+
+	fn black_square(corner: DVec2, size: f64) -> VectorData {
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(corner, corner + size));
+		square.style.set_fill(Fill::Solid(Color::BLACK));
+		square
+	}
+
+	fn alpha_rows(image_frame: &ImageFrame<Color>) -> Vec<Vec<f32>> {
+		image_frame
+			.image
+			.data
+			.chunks(image_frame.image.width as usize)
+			.map(|row| row.iter().map(|pixel| pixel.a()).collect())
+			.collect()
+	}
+
+	#[test]
+	fn black_square_rasterizes_to_opaque_block() {
+		let image_frame = rasterize(&black_square(DVec2::splat(2.), 4.), [DVec2::ZERO, DVec2::splat(8.)], 1., AntiAliasing::default());
+		assert_eq!((image_frame.image.width, image_frame.image.height), (8, 8));
+		assert_eq!(image_frame.transform, DAffine2::from_scale(DVec2::splat(8.)));
+
+		for (index, pixel) in image_frame.image.data.iter().enumerate() {
+			let (x, y) = (index % 8, index / 8);
+			let inside = (2..6).contains(&x) && (2..6).contains(&y);
+			assert_eq!(*pixel, if inside { Color::BLACK } else { Color::TRANSPARENT }, "pixel ({x}, {y})");
+		}
+	}
+
+	#[test]
+	fn scale_changes_resolution() {
+		let image_frame = rasterize(&black_square(DVec2::ZERO, 1.), [DVec2::ZERO, DVec2::splat(2.)], 3., AntiAliasing::default());
+		assert_eq!((image_frame.image.width, image_frame.image.height), (6, 6));
+		assert_eq!(image_frame.transform, DAffine2::from_scale(DVec2::splat(2.)));
+
+		let opaque = image_frame.image.data.iter().filter(|pixel| pixel.a() == 1.).count();
+		assert_eq!(opaque, 9);
+	}
+
+	#[test]
+	fn rasterize_node_bakes_the_transform() {
+		use crate::value::ClonedNode;
+		use crate::{Node, RasterizeNode};
+
+		let mut squares = black_square(DVec2::ZERO, 1.);
+		squares.subpaths.push(bezier_rs::Subpath::new_rect(DVec2::new(2., 0.), DVec2::new(3., 1.)));
+		squares.transform = DAffine2::from_translation(DVec2::new(10., 5.));
+
+		let node = RasterizeNode::new(ClonedNode::new(2.), ClonedNode::new(Some(Color::WHITE)), ClonedNode::new(AntiAliasing::default()));
+		let image_frame = node.eval(squares);
+		assert_eq!((image_frame.image.width, image_frame.image.height), (6, 2));
+		assert_eq!(image_frame.transform, DAffine2::from_translation(DVec2::new(10., 5.)) * DAffine2::from_scale(DVec2::new(3., 1.)));
+
+		let reds: Vec<_> = image_frame.image.data[..6].iter().map(|pixel| pixel.r().round()).collect();
+		assert_eq!(reds, [0., 0., 1., 1., 0., 0.], "The gap between the squares should show the background");
+		assert!(image_frame.image.data.iter().all(|pixel| pixel.a() == 1.));
+
+		assert!(node.eval(VectorData::empty()).image.data.is_empty());
+	}
+
+	#[test]
+	fn edges_are_antialiased() {
+		let image_frame = rasterize(&black_square(DVec2::new(0.5, 0.), 2.), [DVec2::ZERO, DVec2::new(3., 2.)], 1., AntiAliasing::default());
+		assert_eq!(alpha_rows(&image_frame), vec![vec![0.5, 1., 0.5]; 2]);
+	}
+
+	#[test]
+	fn partly_covered_pixels_blend_by_their_area() {
+		let square = black_square(DVec2::new(0.25, 0.), 2.5);
+		for anti_aliasing in [AntiAliasing::Supersample2x, AntiAliasing::Supersample4x] {
+			let image_frame = rasterize(&square, [DVec2::ZERO, DVec2::new(3., 1.)], 1., anti_aliasing);
+			assert_eq!(alpha_rows(&image_frame), [[0.75, 1., 0.75]], "{anti_aliasing}");
+		}
+		let image_frame = rasterize(&square, [DVec2::ZERO, DVec2::new(3., 1.)], 1., AntiAliasing::None);
+		assert_eq!(alpha_rows(&image_frame), [[1., 1., 1.]]);
+	}
+
+	#[test]
+	fn diagonal_edges_depend_on_anti_aliasing() {
+		let mut triangle = VectorData::from_subpath(bezier_rs::Subpath::from_anchors([DVec2::ZERO, DVec2::new(4., 0.), DVec2::new(0., 4.)], true));
+		triangle.style.set_fill(Fill::Solid(Color::BLACK));
+		let alphas = |anti_aliasing| alpha_rows(&rasterize(&triangle, [DVec2::ZERO, DVec2::splat(4.)], 1., anti_aliasing)).concat();
+
+		let hard = alphas(AntiAliasing::None);
+		assert!(hard.iter().all(|&alpha| alpha == 0. || alpha == 1.), "Without antialiasing the edge should be hard, not {hard:?}");
+		assert!(hard.contains(&0.) && hard.contains(&1.));
+
+		for anti_aliasing in [AntiAliasing::Supersample2x, AntiAliasing::Supersample4x] {
+			let smooth = alphas(anti_aliasing);
+			assert!(smooth.iter().any(|&alpha| alpha > 0. && alpha < 1.), "{anti_aliasing} should blend the edge, not {smooth:?}");
+		}
+	}
+
+	fn stroked_line(line_cap: LineCap, dash_lengths: Vec<f32>, dash_offset: f64) -> VectorData {
+		let mut line = VectorData::from_subpath(bezier_rs::Subpath::from_anchors([DVec2::new(2., 4.), DVec2::new(6., 4.)], false));
+		line.style.set_stroke(Stroke {
+			line_cap,
+			dash_lengths,
+			dash_offset,
+			..Stroke::new(Some(Color::BLACK), 2.)
+		});
+		line
+	}
+
+	fn covered_row(vector_data: &VectorData, row: usize) -> Vec<f32> {
+		let image_frame = rasterize(vector_data, [DVec2::ZERO, DVec2::splat(8.)], 1., AntiAliasing::None);
+		alpha_rows(&image_frame)[row].clone()
+	}
+
+	#[test]
+	fn strokes_are_centered_on_the_outline() {
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::splat(2.), DVec2::splat(6.)));
+		square.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.));
+		assert_eq!(covered_row(&square, 3), [0., 1., 1., 0., 0., 1., 1., 0.]);
+		assert_eq!(covered_row(&square, 1), [0., 1., 1., 1., 1., 1., 1., 0.]);
+	}
+
+	#[test]
+	fn line_caps_change_the_ends_of_open_subpaths() {
+		assert_eq!(covered_row(&stroked_line(LineCap::Butt, Vec::new(), 0.), 4), [0., 0., 1., 1., 1., 1., 0., 0.]);
+		assert_eq!(covered_row(&stroked_line(LineCap::Square, Vec::new(), 0.), 4), [0., 1., 1., 1., 1., 1., 1., 0.]);
+		assert_eq!(covered_row(&stroked_line(LineCap::Round, Vec::new(), 0.), 4), [0., 1., 1., 1., 1., 1., 1., 0.]);
+		assert_eq!(
+			covered_row(&stroked_line(LineCap::Butt, Vec::new(), 0.), 2),
+			[0.; 8],
+			"The stroke shouldn't reach beyond half its weight"
+		);
+	}
+
+	#[test]
+	fn dashes_follow_the_pattern_and_offset() {
+		assert_eq!(covered_row(&stroked_line(LineCap::Butt, vec![1.], 0.), 4), [0., 0., 1., 0., 1., 0., 0., 0.]);
+		assert_eq!(covered_row(&stroked_line(LineCap::Butt, vec![1.], 1.), 4), [0., 0., 0., 1., 0., 1., 0., 0.]);
+		assert_eq!(
+			covered_row(&stroked_line(LineCap::Butt, vec![2., 1.], 0.), 4),
+			[0., 0., 1., 1., 0., 1., 0., 0.],
+			"Odd patterns are repeated"
+		);
+		assert_eq!(
+			covered_row(&stroked_line(LineCap::Butt, vec![0., 0.], 0.), 4),
+			[0., 0., 1., 1., 1., 1., 0., 0.],
+			"Empty patterns are solid"
+		);
+	}
+
+	#[test]
+	fn gradients_blend_between_their_stops() {
+		use crate::vector::style::GradientType;
+
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::splat(4.)));
+		let gradient = Gradient::new(DVec2::ZERO, Color::BLACK, DVec2::X, Color::WHITE, DAffine2::IDENTITY, 0, GradientType::Linear);
+		square.style.set_fill(Fill::Gradient(gradient));
+		let image_frame = rasterize(&square, [DVec2::ZERO, DVec2::splat(4.)], 1., AntiAliasing::None);
+
+		let reds: Vec<_> = image_frame.image.data[..4].iter().map(|pixel| pixel.r()).collect();
+		let expected = [0.125, 0.375, 0.625, 0.875].map(Color::srgb_to_linear);
+		for (red, expected) in reds.iter().zip(expected) {
+			assert!((red - expected).abs() < 1e-6, "Expected {expected:?} but the row was {reds:?}");
+		}
+	}
+
+	#[test]
+	fn patterns_repeat_their_image() {
+		let (red, blue) = (Color::RED.to_linear_srgb(), Color::BLUE.to_linear_srgb());
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::splat(4.)));
+		let image = ImageFrame {
+			image: Image {
+				width: 2,
+				height: 1,
+				data: vec![red, blue],
+			},
+			transform: DAffine2::from_scale(DVec2::splat(2.)),
+		};
+		square.style.set_fill(Fill::Pattern(Pattern { image: image.clone(), repeat: true }));
+		let image_frame = rasterize(&square, [DVec2::ZERO, DVec2::splat(4.)], 1., AntiAliasing::None);
+		assert_eq!(image_frame.image.data[..4], [red, blue, red, blue]);
+
+		square.style.set_fill(Fill::Pattern(Pattern { image, repeat: false }));
+		let image_frame = rasterize(&square, [DVec2::ZERO, DVec2::splat(4.)], 1., AntiAliasing::None);
+		assert_eq!(image_frame.image.data[..4], [red, blue, Color::TRANSPARENT, Color::TRANSPARENT]);
+	}
+
+	#[test]
+	fn canvas_size_is_capped() {
+		assert_eq!(capped_scale(DVec2::splat(100.), 2., 4), 2.);
+
+		let scale = capped_scale(DVec2::splat(100_000.), 1., 2);
+		let pixels = (DVec2::splat(100_000.) * scale * 2.).ceil();
+		assert!(pixels.x * pixels.y <= MAX_PIXEL_COUNT + pixels.x + pixels.y + 1., "{pixels:?} is too large");
+		assert!(pixels.x >= 8191., "{pixels:?} shouldn't be shrunk more than needed");
+	}
+}
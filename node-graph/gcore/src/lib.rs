@@ -39,6 +39,9 @@ pub mod vector;
 #[cfg(feature = "alloc")]
 pub mod application_io;
 
+#[cfg(feature = "alloc")]
+pub mod animation;
+
 pub mod quantization;
 
 use core::any::TypeId;
@@ -164,7 +167,7 @@ impl<'i, I: 'i, O: 'i> Node<'i, I> for Pin<&'i (dyn NodeIO<'i, I, Output = O> +
 }
 
 #[cfg(feature = "alloc")]
-pub use crate::application_io::{ExtractImageFrame, SurfaceFrame, SurfaceId};
+pub use crate::application_io::{ExtractImageFrame, FrameNode, ImageFrameAvailableNode, SurfaceFrame, SurfaceId};
 #[cfg(feature = "wasm")]
 pub type WasmSurfaceHandle = application_io::SurfaceHandle<web_sys::HtmlCanvasElement>;
 #[cfg(feature = "wasm")]
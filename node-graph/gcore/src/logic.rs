@@ -7,3 +7,48 @@ fn log_to_console<T: core::fmt::Debug>(value: T) -> T {
 	debug!("{:#?}", value);
 	value
 }
+
+/// The number of inputs a [`MuxNode`] chooses between.
+pub const MUX_INPUT_COUNT: u32 = 4;
+
+pub struct MuxNode<Index, Input1, Input2, Input3> {
+	index: Index,
+	input_1: Input1,
+	input_2: Input2,
+	input_3: Input3,
+}
+
+/// Passes through the input at the index, counting the primary input as index 0. An index past the last input is clamped to it.
+#[node_macro::node_fn(MuxNode)]
+fn mux<T>(input_0: T, index: u32, input_1: T, input_2: T, input_3: T) -> T {
+	if index >= MUX_INPUT_COUNT {
+		warn!("The number of inputs is {} and the requested input is {}!", MUX_INPUT_COUNT, index);
+	}
+	match index {
+		0 => input_0,
+		1 => input_1,
+		2 => input_2,
+		_ => input_3,
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	fn mux(index: u32) -> &'static str {
+		MuxNode::new(ClonedNode::new(index), ClonedNode::new("one"), ClonedNode::new("two"), ClonedNode::new("three")).eval("zero")
+	}
+
+	#[test]
+	fn mux_selects_each_input() {
+		assert_eq!([0, 1, 2, 3].map(mux), ["zero", "one", "two", "three"]);
+	}
+
+	#[test]
+	fn mux_clamps_out_of_range_index() {
+		assert_eq!(mux(4), "three");
+		assert_eq!(mux(u32::MAX), "three");
+	}
+}
@@ -296,6 +296,70 @@ where
 	}
 }
 
+/// Runs the lambda on each element of the list, collecting what it returns into a list of the same length and order.
+#[derive(Debug, Clone, Copy)]
+pub struct MapNode<Lambda> {
+	lambda: Lambda,
+}
+
+#[cfg(feature = "alloc")]
+impl<'i, T: 'i, Lambda: 'i> Node<'i, alloc::vec::Vec<T>> for MapNode<Lambda>
+where
+	Lambda: for<'a> Node<'a, T>,
+	for<'a> <Lambda as Node<'a, T>>::Output: core::future::Future<Output = T> + 'a,
+{
+	type Output = core::pin::Pin<alloc::boxed::Box<dyn core::future::Future<Output = alloc::vec::Vec<T>> + 'i>>;
+	fn eval(&'i self, list: alloc::vec::Vec<T>) -> Self::Output {
+		alloc::boxed::Box::pin(async move {
+			let mut mapped = alloc::vec::Vec::with_capacity(list.len());
+			for element in list {
+				mapped.push(self.lambda.eval(element).await);
+			}
+			mapped
+		})
+	}
+}
+
+impl<Lambda> MapNode<Lambda> {
+	pub const fn new(lambda: Lambda) -> Self {
+		Self { lambda }
+	}
+}
+
+/// Combines the elements of the list into one value by running the lambda on the running result and each element in turn, starting from the initial value.
+/// An empty list gives the initial value.
+#[derive(Debug, Clone, Copy)]
+pub struct ReduceNode<Initial, Lambda> {
+	initial: Initial,
+	lambda: Lambda,
+}
+
+#[cfg(feature = "alloc")]
+impl<'i, T: 'i, Initial: 'i, Lambda: 'i> Node<'i, alloc::vec::Vec<T>> for ReduceNode<Initial, Lambda>
+where
+	Initial: for<'a> Node<'a, ()>,
+	for<'a> <Initial as Node<'a, ()>>::Output: core::future::Future<Output = T> + 'a,
+	Lambda: for<'a> Node<'a, (T, T)>,
+	for<'a> <Lambda as Node<'a, (T, T)>>::Output: core::future::Future<Output = T> + 'a,
+{
+	type Output = core::pin::Pin<alloc::boxed::Box<dyn core::future::Future<Output = T> + 'i>>;
+	fn eval(&'i self, list: alloc::vec::Vec<T>) -> Self::Output {
+		alloc::boxed::Box::pin(async move {
+			let mut reduced = self.initial.eval(()).await;
+			for element in list {
+				reduced = self.lambda.eval((reduced, element)).await;
+			}
+			reduced
+		})
+	}
+}
+
+impl<Initial, Lambda> ReduceNode<Initial, Lambda> {
+	pub const fn new(initial: Initial, lambda: Lambda) -> Self {
+		Self { initial, lambda }
+	}
+}
+
 pub struct IntoNode<I, O> {
 	_i: PhantomData<I>,
 	_o: PhantomData<O>,
@@ -170,6 +170,10 @@ pub trait Alpha {
 	fn multiplied_alpha(&self, alpha: Self::AlphaChannel) -> Self;
 }
 
+pub trait AlphaMut: Alpha {
+	fn set_alpha(&mut self, value: Self::AlphaChannel);
+}
+
 pub trait Depth {
 	type DepthChannel: Channel;
 	fn depth(&self) -> Self::DepthChannel;
@@ -607,7 +611,12 @@ pub(crate) mod image;
 
 #[cfg(test)]
 mod test {
-	use crate::{ops::CloneNode, structural::Then, value::ValueNode, Node};
+	use crate::{
+		ops::CloneNode,
+		structural::Then,
+		value::{ClonedNode, ValueNode},
+		Node,
+	};
 
 	use super::*;
 
@@ -623,6 +632,284 @@ mod test {
 		assert_eq!(array[0], Color::from_rgbaf32(0.33333334, 0.33333334, 0.33333334, 1.0).unwrap());*/
 	}
 
+	#[test]
+	fn opacity_alpha_modes() {
+		let half_opacity = |premultiplied| OpacityNode::new(ClonedNode::new(50_f32), ClonedNode::new(premultiplied)).eval(Color::RED);
+
+		// Straight alpha leaves the color channels untouched
+		let straight = half_opacity(false);
+		assert_eq!(straight, Color::from_rgbaf32_unchecked(1., 0., 0., 0.5));
+
+		// Premultiplied alpha scales the color channels too, so blending red over white gives pink
+		let premultiplied = half_opacity(true);
+		assert_eq!(premultiplied, Color::from_rgbaf32_unchecked(0.5, 0., 0., 0.5));
+		assert_eq!(blend_colors(premultiplied, Color::WHITE, BlendMode::Normal, 1.), Color::from_rgbaf32_unchecked(1., 0.5, 0.5, 1.));
+	}
+
+	#[test]
+	fn alpha_channel_adjust_leaves_color_untouched() {
+		// Levels that map 25% and below to transparent and 75% and above to opaque, stretching the range between
+		let levels = LevelsNode::new(
+			ClonedNode::new(25_f32),
+			ClonedNode::new(50_f32),
+			ClonedNode::new(75_f32),
+			ClonedNode::new(0_f32),
+			ClonedNode::new(100_f32),
+		);
+		let alpha_only = AlphaChannelAdjustNode::new(ValueNode::new(levels));
+
+		for (alpha, expected) in [(0.1, 0.), (0.5, 0.5), (0.9, 1.)] {
+			let color = Color::from_rgbaf32_unchecked(0.2, 0.4, 0.6, alpha);
+			let adjusted = alpha_only.eval(color);
+			assert_eq!((adjusted.r(), adjusted.g(), adjusted.b()), (0.2, 0.4, 0.6));
+			assert!((adjusted.a() - expected).abs() < 1e-4, "Alpha {alpha} should become {expected}, got {}", adjusted.a());
+		}
+
+		let invert_everything = InvertRGBNode::new(ClonedNode::new(true), ClonedNode::new(true), ClonedNode::new(true), ClonedNode::new(true));
+		let color_only = ColorChannelsAdjustNode::new(ValueNode::new(invert_everything));
+		let adjusted = color_only.eval(Color::from_rgbaf32_unchecked(0.25, 0.5, 1., 0.75));
+		assert_eq!(adjusted.a(), 0.75);
+		assert_ne!(adjusted.r(), 0.25);
+	}
+
+	#[test]
+	fn threshold_softness_widens_symmetrically() {
+		let threshold = |luminance: f32, softness: f32| {
+			let node = ThresholdNode::new(
+				ClonedNode::new(50_f32),
+				ClonedNode::new(100_f32),
+				ClonedNode::new(LuminanceCalculation::AverageChannels),
+				ClonedNode::new(softness),
+			);
+			node.eval(Color::from_rgbf32_unchecked(luminance, luminance, luminance)).r()
+		};
+		let cutoff = Color::srgb_to_linear(0.5);
+
+		// Without softness the cut is binary
+		assert_eq!(threshold(cutoff - 0.01, 0.), 0.);
+		assert_eq!(threshold(cutoff + 0.01, 0.), 1.);
+
+		// With softness, values equally far below and above the cutoff mirror each other
+		let below = threshold(cutoff - 0.05, 20.);
+		let above = threshold(cutoff + 0.05, 20.);
+		assert!(below > 0. && below < 0.5);
+		assert!((below + above - 1.).abs() < 1e-4);
+
+		// Outside the softened region the result is fully black or white again
+		assert_eq!(threshold(cutoff - 0.11, 20.), 0.);
+		assert_eq!(threshold(cutoff + 0.11, 20.), 1.);
+	}
+
+	#[test]
+	fn invert_channels() {
+		let invert = |color: Color, red, green, blue, alpha| InvertRGBNode::new(ClonedNode::new(red), ClonedNode::new(green), ClonedNode::new(blue), ClonedNode::new(alpha)).eval(color);
+
+		// The default of inverting the color channels keeps the original premultiplied formula
+		let color = Color::from_rgbaf32_unchecked(0.2, 0.4, 0.1, 0.5);
+		let gamma = color.to_gamma_srgb();
+		assert_eq!(invert(color, true, true, true, false), gamma.map_rgb(|c| gamma.a() - c).to_linear_srgb());
+
+		// Only the selected channels are inverted
+		let green = invert(Color::BLACK, false, true, false, false);
+		assert_eq!((green.r(), green.b(), green.a()), (0., 0., 1.));
+		assert!((green.g() - 1.).abs() < 1e-4);
+
+		// Inverting alpha of an opaque color makes it fully transparent, which premultiplies its color channels to zero
+		assert_eq!(invert(Color::RED, false, false, false, true), Color::from_rgbaf32_unchecked(0., 0., 0., 0.));
+	}
+
+	#[test]
+	fn hue_saturation_colorize_is_monochromatic() {
+		let colorize = HueSaturationNode::new(
+			ClonedNode::new(120_f32),
+			ClonedNode::new(50_f32),
+			ClonedNode::new(0_f32),
+			ClonedNode::new(true),
+			ClonedNode::new(HueRange::Master),
+		);
+		let colors = [
+			Color::RED,
+			Color::from_rgbf32_unchecked(0.1, 0.3, 0.9),
+			Color::from_rgbf32_unchecked(0.8, 0.5, 0.2),
+			Color::from_rgbf32_unchecked(0.4, 0.4, 0.4),
+		];
+
+		for color in colors {
+			let [hue, saturation, lightness, _] = colorize.eval(color).to_gamma_srgb().to_hsla();
+			assert!((hue - 1. / 3.).abs() < 1e-3, "{color:?} was colorized to hue {hue}");
+			assert!((saturation - 0.5).abs() < 1e-3, "{color:?} was colorized to saturation {saturation}");
+			// Colorize keeps the original lightness
+			assert!((lightness - color.to_gamma_srgb().to_hsla()[2]).abs() < 1e-3);
+		}
+	}
+
+	#[test]
+	fn hue_saturation_range_limits_the_shift() {
+		let shift_reds = HueSaturationNode::new(
+			ClonedNode::new(180_f32),
+			ClonedNode::new(0_f32),
+			ClonedNode::new(0_f32),
+			ClonedNode::new(false),
+			ClonedNode::new(HueRange::Reds),
+		);
+
+		// Reds are shifted to cyan
+		let [hue, ..] = shift_reds.eval(Color::RED).to_gamma_srgb().to_hsla();
+		assert!((hue - 0.5).abs() < 1e-3);
+
+		// Blues and grays are outside of the range
+		let blue = Color::from_rgbf32_unchecked(0.1, 0.2, 0.9);
+		let gray = Color::from_rgbf32_unchecked(0.5, 0.5, 0.5);
+		for color in [blue, gray] {
+			let result = shift_reds.eval(color);
+			assert!((result.r() - color.r()).abs() < 1e-4 && (result.g() - color.g()).abs() < 1e-4 && (result.b() - color.b()).abs() < 1e-4);
+		}
+	}
+
+	#[test]
+	fn white_balance_neutralizes_reference() {
+		let reference = Color::from_rgbf32_unchecked(1., 0.25, 0.25);
+		let balance = |strength: f32| WhiteBalanceNode::new(ClonedNode::new(reference), ClonedNode::new(strength)).eval(reference);
+
+		assert_eq!(balance(100.), Color::from_rgbf32_unchecked(0.5, 0.5, 0.5));
+		assert_eq!(balance(0.), reference);
+	}
+
+	#[test]
+	fn white_balance_zero_channel() {
+		assert_eq!(white_balance_gains(Color::from_rgbf32_unchecked(0.5, 0., 1.)), [1., 1., 0.5]);
+		assert_eq!(white_balance_gains(Color::BLACK), [1., 1., 1.]);
+	}
+
+	#[test]
+	fn tone_mapping_rolloff() {
+		let bright = Color::from_rgbaf32_unchecked(2., 4., 0.5, 1.);
+
+		assert_eq!(tone_map(bright, ToneMapping::None), bright);
+		for tone_mapping in [ToneMapping::Reinhard, ToneMapping::Filmic] {
+			let mapped = tone_map(bright, tone_mapping);
+			assert!(mapped.r() < 1. && mapped.g() < 1. && mapped.b() < mapped.r(), "{tone_mapping} mapped to {mapped:?}");
+			assert_eq!(mapped.a(), 1.);
+		}
+	}
+
+	#[test]
+	fn clamp_color_node() {
+		let clamp = |rolloff| ClampColorNode::new(ClonedNode::new(0_f32), ClonedNode::new(1_f32), ClonedNode::new(rolloff)).eval(Color::from_rgbaf32_unchecked(2., -1., 0.5, 1.));
+
+		assert_eq!(clamp(ToneMapping::None), Color::from_rgbaf32_unchecked(1., 0., 0.5, 1.));
+		assert!(clamp(ToneMapping::Reinhard).r() < 1.);
+	}
+
+	#[test]
+	fn flatten_alpha_over_white() {
+		let half_red = Color::RED.multiplied_alpha(0.5);
+		let flattened = FlattenAlphaNode::new(ClonedNode::new(Color::WHITE)).eval(half_red);
+
+		assert_eq!(flattened, Color::from_rgbaf32_unchecked(1., 0.5, 0.5, 1.));
+	}
+
+	#[test]
+	fn luminance_to_mask() {
+		let mask = |color, invert| LuminanceToMaskNode::new(ClonedNode::new(LuminanceCalculation::SRGB), ClonedNode::new(invert)).eval(color);
+
+		assert!((mask(Color::WHITE, false).a() - 1.).abs() < 1e-6, "white should become opaque");
+		assert_eq!(mask(Color::BLACK, false), Color::TRANSPARENT);
+		assert_eq!(mask(Color::BLACK, true), Color::WHITE);
+		assert!(mask(Color::WHITE, true).a() < 1e-6, "inverted white should become transparent");
+	}
+
+	#[test]
+	fn oklch_round_trip() {
+		let color = Color::from_rgbaf32_unchecked(0.8, 0.3, 0.1, 1.);
+		let [lightness, chroma, hue, alpha] = color.to_oklch();
+		let restored = Color::from_oklch(lightness, chroma, hue, alpha);
+		assert!(
+			(restored.r() - 0.8).abs() < 1e-4 && (restored.g() - 0.3).abs() < 1e-4 && (restored.b() - 0.1).abs() < 1e-4,
+			"{restored:?}"
+		);
+	}
+
+	#[test]
+	fn oklch_hue_shift_preserves_lightness_and_chroma() {
+		let color = Color::from_rgbaf32_unchecked(0.5, 0.4, 0.3, 1.);
+		let shifted = OkLchAdjustNode::new(ClonedNode::new(0_f32), ClonedNode::new(0_f32), ClonedNode::new(90_f32)).eval(color);
+
+		let [lightness, chroma, hue, _] = color.to_oklch();
+		let [shifted_lightness, shifted_chroma, shifted_hue, _] = shifted.to_oklch();
+		assert!((shifted_lightness - lightness).abs() < 1e-4, "lightness changed from {lightness} to {shifted_lightness}");
+		assert!((shifted_chroma - chroma).abs() < 1e-4, "chroma changed from {chroma} to {shifted_chroma}");
+		assert!(((shifted_hue - hue).rem_euclid(1.) - 0.25).abs() < 1e-3, "hue moved from {hue} to {shifted_hue}");
+	}
+
+	#[test]
+	fn temperature_tint() {
+		let color = Color::from_rgbaf32_unchecked(0.4, 0.5, 0.6, 0.8);
+		let adjust = |temperature: f32, tint: f32| TemperatureTintNode::new(ClonedNode::new(temperature), ClonedNode::new(tint)).eval(color);
+
+		assert_eq!(adjust(0., 0.), color);
+
+		let warmer = adjust(1500., 0.);
+		assert!(warmer.r() / warmer.b() > color.r() / color.b(), "positive temperature should warm the color");
+		let cooler = adjust(-1500., 0.);
+		assert!(cooler.r() / cooler.b() < color.r() / color.b(), "negative temperature should cool the color");
+
+		let magenta = adjust(0., 50.);
+		assert!(magenta.g() / magenta.r() < color.g() / color.r(), "positive tint should shift toward magenta");
+		assert_eq!(magenta.a(), color.a());
+	}
+
+	#[test]
+	fn duotone_follows_luminance_ramp() {
+		let shadows = Color::from_rgbf32_unchecked(0.2, 0., 0.4);
+		let highlights = Color::from_rgbf32_unchecked(1., 0.8, 0.2);
+		let midtones = Color::from_rgbf32_unchecked(0., 0.5, 0.5);
+		let duotone = |tritone, gray: f32| {
+			let node = DuotoneNode::new(
+				ClonedNode::new(shadows),
+				ClonedNode::new(highlights),
+				ClonedNode::new(tritone),
+				ClonedNode::new(midtones),
+				ClonedNode::new(LuminanceCalculation::AverageChannels),
+			);
+			node.eval(Color::from_rgbf32_unchecked(gray, gray, gray))
+		};
+
+		assert_eq!(duotone(false, 0.), shadows);
+		assert_eq!(duotone(false, 1.), highlights);
+		assert_eq!(duotone(true, 0.5), midtones);
+
+		// Each step along the ramp moves the same distance toward the highlight color
+		let ramp: Vec<_> = (0..=10).map(|step| duotone(false, step as f32 / 10.)).collect();
+		for pair in ramp.windows(2) {
+			assert!((pair[1].r() - pair[0].r() - 0.08).abs() < 1e-5, "{:?} to {:?} isn't a smooth step", pair[0], pair[1]);
+			assert!((pair[1].b() - pair[0].b() + 0.02).abs() < 1e-5, "{:?} to {:?} isn't a smooth step", pair[0], pair[1]);
+		}
+	}
+
+	#[test]
+	fn premultiply_round_trip() {
+		let colors = [
+			Color::from_rgbaf32_unchecked(0.2, 0.6, 1., 0.5),
+			Color::from_rgbaf32_unchecked(1., 0.3, 0.7, 0.01),
+			Color::from_rgbaf32_unchecked(0.9, 0.1, 0.4, 1.),
+		];
+		for color in colors {
+			let premultiplied = PremultiplyAlphaNode.eval(color);
+			assert_eq!(premultiplied.a(), color.a());
+			let restored = UnpremultiplyAlphaNode.eval(premultiplied);
+			let channels = |c: Color| [c.r(), c.g(), c.b(), c.a()];
+			assert!(
+				channels(restored).iter().zip(channels(color)).all(|(a, b)| (a - b).abs() < 1e-5),
+				"{color:?} round-tripped to {restored:?}"
+			);
+		}
+
+		// Fully transparent pixels lose their color, which unpremultiplying can't recover
+		let transparent_red = Color::from_rgbaf32_unchecked(1., 0., 0., 0.);
+		assert_eq!(UnpremultiplyAlphaNode.eval(PremultiplyAlphaNode.eval(transparent_red)), Color::TRANSPARENT);
+	}
+
 	#[test]
 	fn window_node() {
 		use alloc::vec;
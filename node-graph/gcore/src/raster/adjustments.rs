@@ -70,6 +70,38 @@ impl BlendMode {
 			&[BlendMode::Hue, BlendMode::Saturation, BlendMode::Color, BlendMode::Luminosity],
 		]
 	}
+
+	/// Convert the enum to the CSS string for the blend mode.
+	/// Modes without a CSS equivalent fall back to the closest one from their group, or to `normal` for the utility modes.
+	/// [Read more](https://developer.mozilla.org/en-US/docs/Web/CSS/blend-mode#values)
+	pub fn to_svg_style_name(&self) -> &'static str {
+		match self {
+			// Normal group
+			BlendMode::Normal => "normal",
+			// Darken group
+			BlendMode::Darken | BlendMode::DarkerColor => "darken",
+			BlendMode::Multiply => "multiply",
+			BlendMode::ColorBurn | BlendMode::LinearBurn => "color-burn",
+			// Lighten group
+			BlendMode::Lighten | BlendMode::LighterColor => "lighten",
+			BlendMode::Screen => "screen",
+			BlendMode::ColorDodge | BlendMode::LinearDodge => "color-dodge",
+			// Contrast group
+			BlendMode::Overlay => "overlay",
+			BlendMode::SoftLight => "soft-light",
+			BlendMode::HardLight | BlendMode::VividLight | BlendMode::LinearLight | BlendMode::PinLight | BlendMode::HardMix => "hard-light",
+			// Inversion group
+			BlendMode::Difference | BlendMode::Subtract | BlendMode::Divide => "difference",
+			BlendMode::Exclusion => "exclusion",
+			// Component group
+			BlendMode::Hue => "hue",
+			BlendMode::Saturation => "saturation",
+			BlendMode::Color => "color",
+			BlendMode::Luminosity => "luminosity",
+			// Other utility blend modes (hidden from the normal list)
+			BlendMode::Erase | BlendMode::Restore | BlendMode::MultiplyAlpha => "normal",
+		}
+	}
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -173,14 +205,57 @@ pub struct LuminanceNode<LuminanceCalculation> {
 
 #[node_macro::node_fn(LuminanceNode)]
 fn luminance_color_node(color: Color, luminance_calc: LuminanceCalculation) -> Color {
-	let luminance = match luminance_calc {
+	let luminance = calculate_luminance(color, luminance_calc);
+	color.map_rgb(|_| luminance)
+}
+
+/// The luminance of `color` as measured by the chosen [LuminanceCalculation].
+pub fn calculate_luminance(color: Color, luminance_calc: LuminanceCalculation) -> f32 {
+	match luminance_calc {
 		LuminanceCalculation::SRGB => color.luminance_srgb(),
 		LuminanceCalculation::Perceptual => color.luminance_perceptual(),
 		LuminanceCalculation::AverageChannels => color.average_rgb_channels(),
 		LuminanceCalculation::MinimumChannels => color.minimum_rgb_channels(),
 		LuminanceCalculation::MaximumChannels => color.maximum_rgb_channels(),
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LuminanceToMaskNode<LuminanceCalculation, Invert> {
+	luminance_calc: LuminanceCalculation,
+	invert: Invert,
+}
+
+/// Produces white with an alpha of the pixel's luminance, so the result works both as an alpha mask and as the stencil of the Mask node.
+#[node_macro::node_fn(LuminanceToMaskNode)]
+fn luminance_to_mask_node(color: Color, luminance_calc: LuminanceCalculation, invert: bool) -> Color {
+	let luminance = calculate_luminance(color, luminance_calc).clamp(0., 1.);
+	let alpha = if invert { 1. - luminance } else { luminance };
+	Color::WHITE.multiplied_alpha(alpha)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DuotoneNode<Shadows, Highlights, Tritone, Midtones, LuminanceCalculation> {
+	shadows: Shadows,
+	highlights: Highlights,
+	tritone: Tritone,
+	midtones: Midtones,
+	luminance_calc: LuminanceCalculation,
+}
+
+/// Maps the luminance of each pixel onto a gradient from the shadow color to the highlight color, passing through the midtone color halfway when `tritone` is enabled.
+#[node_macro::node_fn(DuotoneNode)]
+fn duotone_node(color: Color, shadows: Color, highlights: Color, tritone: bool, midtones: Color, luminance_calc: LuminanceCalculation) -> Color {
+	let luminance = calculate_luminance(color.to_unassociated_alpha(), luminance_calc).clamp(0., 1.);
+
+	let mapped = if !tritone {
+		shadows.lerp(highlights, luminance)
+	} else if luminance < 0.5 {
+		shadows.lerp(midtones, luminance * 2.)
+	} else {
+		midtones.lerp(highlights, luminance * 2. - 1.)
 	};
-	color.map_rgb(|_| luminance)
+	mapped.multiplied_alpha(color.a())
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -198,6 +273,24 @@ fn extract_channel_node(color: Color, channel: RedGreenBlue) -> Color {
 	color.map_rgb(|_| extracted_value)
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SplitChannelNode<TargetChannel> {
+	channel: TargetChannel,
+}
+
+/// Stores a channel as an opaque gray with the value exactly as the premultiplied color holds it.
+/// Unlike [ExtractChannelNode] the alpha isn't kept, so the channel can be recombined bit-for-bit by reading the red value back.
+/// The alpha channel can be split the same way with [ExtractAlphaNode].
+#[node_macro::node_fn(SplitChannelNode)]
+fn split_channel_node(color: Color, channel: RedGreenBlue) -> Color {
+	let value = match channel {
+		RedGreenBlue::Red => color.r(),
+		RedGreenBlue::Green => color.g(),
+		RedGreenBlue::Blue => color.b(),
+	};
+	Color::from_rgbaf32_unchecked(value, value, value, 1.)
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ExtractAlphaNode;
 
@@ -218,6 +311,22 @@ fn extract_opaque_node(color: Color) -> Color {
 	Color::from_rgbaf32(color.r() / color.a(), color.g() / color.a(), color.b() / color.a(), 1.0).unwrap()
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PremultiplyAlphaNode;
+
+#[node_macro::node_fn(PremultiplyAlphaNode)]
+fn premultiply_alpha_node(color: Color) -> Color {
+	color.premultiply_alpha()
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnpremultiplyAlphaNode;
+
+#[node_macro::node_fn(UnpremultiplyAlphaNode)]
+fn unpremultiply_alpha_node(color: Color) -> Color {
+	color.unpremultiply_alpha()
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct LevelsNode<InputStart, InputMid, InputEnd, OutputStart, OutputEnd> {
 	input_start: InputStart,
@@ -272,6 +381,92 @@ fn levels_node(color: Color, input_start: f32, input_mid: f32, input_end: f32, o
 	color.to_linear_srgb()
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct AlphaChannelAdjustNode<Adjustment> {
+	adjustment: Adjustment,
+}
+
+/// Runs the adjustment on the alpha channel alone, leaving the color channels untouched.
+/// The alpha is passed to the adjustment as an opaque gray whose gamma-encoded level equals the alpha, so an alpha of 50% is seen as a midtone,
+/// and the average gamma-encoded level of the adjusted channels becomes the new alpha.
+#[node_macro::node_fn(AlphaChannelAdjustNode)]
+fn alpha_channel_adjust<Adjustment>(color: Color, adjustment: &'input Adjustment) -> Color
+where
+	Adjustment: for<'any_input> Node<'any_input, Color, Output = Color> + 'input,
+{
+	alpha_from_gray(color, adjustment.eval(alpha_as_gray(color)))
+}
+
+/// Runs the adjustment on the alpha of the whole image, seen as an opaque grayscale image the same way as for a single color.
+/// This lets any node that adjusts images, like one connected as a lambda, be applied to the alpha alone. Pixels the adjusted image doesn't reach keep their alpha.
+#[cfg(feature = "alloc")]
+impl<'i, Adjustment: 'i> Node<'i, crate::raster::ImageFrame<Color>> for AlphaChannelAdjustNode<Adjustment>
+where
+	Adjustment: for<'a> Node<'a, crate::raster::ImageFrame<Color>>,
+	for<'a> <Adjustment as Node<'a, crate::raster::ImageFrame<Color>>>::Output: core::future::Future<Output = crate::raster::ImageFrame<Color>> + 'a,
+{
+	type Output = core::pin::Pin<alloc::boxed::Box<dyn core::future::Future<Output = crate::raster::ImageFrame<Color>> + 'i>>;
+	fn eval(&'i self, mut image_frame: crate::raster::ImageFrame<Color>) -> Self::Output {
+		alloc::boxed::Box::pin(async move {
+			let mut alpha = image_frame.clone();
+			alpha.image.data.iter_mut().for_each(|pixel| *pixel = alpha_as_gray(*pixel));
+			let adjusted = self.adjustment.eval(alpha).await;
+			for (pixel, adjusted) in image_frame.image.data.iter_mut().zip(adjusted.image.data) {
+				*pixel = alpha_from_gray(*pixel, adjusted);
+			}
+			image_frame
+		})
+	}
+}
+
+/// The alpha of the color as an opaque gray whose gamma-encoded level equals it
+fn alpha_as_gray(color: Color) -> Color {
+	let alpha = color.a();
+	Color::from_rgbaf32_unchecked(alpha, alpha, alpha, 1.).to_linear_srgb()
+}
+
+/// The color with its alpha replaced by the average gamma-encoded level of the adjusted gray
+fn alpha_from_gray(color: Color, adjusted: Color) -> Color {
+	let adjusted = adjusted.to_gamma_srgb();
+	let alpha = (adjusted.r() + adjusted.g() + adjusted.b()) / 3.;
+	Color::from_rgbaf32_unchecked(color.r(), color.g(), color.b(), alpha.clamp(0., 1.))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColorChannelsAdjustNode<Adjustment> {
+	adjustment: Adjustment,
+}
+
+/// Runs the adjustment on the color channels alone, restoring the original alpha afterwards.
+#[node_macro::node_fn(ColorChannelsAdjustNode)]
+fn color_channels_adjust<Adjustment>(color: Color, adjustment: &'input Adjustment) -> Color
+where
+	Adjustment: for<'any_input> Node<'any_input, Color, Output = Color> + 'input,
+{
+	let adjusted = adjustment.eval(color);
+	Color::from_rgbaf32_unchecked(adjusted.r(), adjusted.g(), adjusted.b(), color.a())
+}
+
+/// Runs the adjustment on the whole image, restoring the original alpha of each pixel afterwards like for a single color.
+#[cfg(feature = "alloc")]
+impl<'i, Adjustment: 'i> Node<'i, crate::raster::ImageFrame<Color>> for ColorChannelsAdjustNode<Adjustment>
+where
+	Adjustment: for<'a> Node<'a, crate::raster::ImageFrame<Color>>,
+	for<'a> <Adjustment as Node<'a, crate::raster::ImageFrame<Color>>>::Output: core::future::Future<Output = crate::raster::ImageFrame<Color>> + 'a,
+{
+	type Output = core::pin::Pin<alloc::boxed::Box<dyn core::future::Future<Output = crate::raster::ImageFrame<Color>> + 'i>>;
+	fn eval(&'i self, image_frame: crate::raster::ImageFrame<Color>) -> Self::Output {
+		alloc::boxed::Box::pin(async move {
+			let alphas: alloc::vec::Vec<_> = image_frame.image.data.iter().map(|pixel| pixel.a()).collect();
+			let mut adjusted = self.adjustment.eval(image_frame).await;
+			for (pixel, alpha) in adjusted.image.data.iter_mut().zip(alphas) {
+				*pixel = Color::from_rgbaf32_unchecked(pixel.r(), pixel.g(), pixel.b(), alpha);
+			}
+			adjusted
+		})
+	}
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct GrayscaleNode<Tint, Reds, Yellows, Greens, Cyans, Blues, Magentas> {
 	tint: Tint,
@@ -324,65 +519,182 @@ fn grayscale_color_node(color: Color, tint: Color, reds: f32, yellows: f32, gree
 	color.to_linear_srgb()
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum HueRange {
+	#[default]
+	Master,
+	Reds,
+	Yellows,
+	Greens,
+	Cyans,
+	Blues,
+	Magentas,
+}
+
+impl HueRange {
+	/// How strongly a color with the given hue and saturation (both from 0 to 1) belongs to this range.
+	/// Hues within 15° of the range's center are fully affected, fading out linearly until 45° away.
+	pub fn weight(&self, hue: f32, saturation: f32) -> f32 {
+		let center = match self {
+			HueRange::Master => return 1.,
+			HueRange::Reds => 0.,
+			HueRange::Yellows => 60.,
+			HueRange::Greens => 120.,
+			HueRange::Cyans => 180.,
+			HueRange::Blues => 240.,
+			HueRange::Magentas => 300.,
+		};
+		// Grays have no meaningful hue, so they never belong to a color range
+		if saturation <= 0. {
+			return 0.;
+		}
+
+		let distance = (hue * 360. - center).abs() % 360.;
+		let distance = distance.min(360. - distance);
+		((45. - distance) / 30.).clamp(0., 1.)
+	}
+}
+
+impl core::fmt::Display for HueRange {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			HueRange::Master => write!(f, "Master"),
+			HueRange::Reds => write!(f, "Reds"),
+			HueRange::Yellows => write!(f, "Yellows"),
+			HueRange::Greens => write!(f, "Greens"),
+			HueRange::Cyans => write!(f, "Cyans"),
+			HueRange::Blues => write!(f, "Blues"),
+			HueRange::Magentas => write!(f, "Magentas"),
+		}
+	}
+}
+
 #[derive(Debug)]
-pub struct HueSaturationNode<Hue, Saturation, Lightness> {
+pub struct HueSaturationNode<Hue, Saturation, Lightness, Colorize, Range> {
 	hue_shift: Hue,
 	saturation_shift: Saturation,
 	lightness_shift: Lightness,
+	colorize: Colorize,
+	range: Range,
 }
 
 #[node_macro::node_fn(HueSaturationNode)]
-fn hue_shift_color_node(color: Color, hue_shift: f32, saturation_shift: f32, lightness_shift: f32) -> Color {
+fn hue_shift_color_node(color: Color, hue_shift: f32, saturation_shift: f32, lightness_shift: f32, colorize: bool, range: HueRange) -> Color {
 	let color = color.to_gamma_srgb();
 
 	let [hue, saturation, lightness, alpha] = color.to_hsla();
 
-	let color = Color::from_hsla(
-		(hue + hue_shift / 360.) % 1.,
-		// TODO: Improve the way saturation works (it's slightly off)
-		(saturation + saturation_shift / 100.).clamp(0., 1.),
-		// TODO: Fix the way lightness works (it's very off)
-		(lightness + lightness_shift / 100.).clamp(0., 1.),
-		alpha,
-	);
+	let adjusted = if colorize {
+		// Colorize replaces the hue and saturation outright, turning the image into tints of a single hue
+		Color::from_hsla(hue_shift / 360., (saturation_shift / 100.).clamp(0., 1.), lightness, alpha)
+	} else {
+		// Saturation is scaled rather than offset, so grays stay gray and fully desaturating always reaches gray
+		let saturation_shift = saturation_shift / 100.;
+		let saturation = if saturation_shift < 0. {
+			saturation * (1. + saturation_shift)
+		} else if saturation_shift < 1. {
+			(saturation / (1. - saturation_shift)).min(1.)
+		} else if saturation > 0. {
+			1.
+		} else {
+			0.
+		};
+		Color::from_hsla(hue + hue_shift / 360., saturation, lightness, alpha)
+	};
 
-	color.to_linear_srgb()
+	// Lightness moves the channels toward white or black instead of offsetting the HSL lightness, which would wash out saturated colors
+	let lightness_shift = lightness_shift / 100.;
+	let adjusted = if lightness_shift < 0. {
+		adjusted.map_rgb(|c| c * (1. + lightness_shift))
+	} else {
+		adjusted.map_rgb(|c| c + (1. - c) * lightness_shift)
+	};
+
+	// Colorize affects the whole image, like it does in other editors
+	let weight = if colorize { 1. } else { range.weight(hue, saturation) };
+	color.lerp(adjusted, weight).to_linear_srgb()
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct InvertRGBNode;
+pub struct OkLchAdjustNode<Lightness, Chroma, Hue> {
+	lightness_shift: Lightness,
+	chroma_shift: Chroma,
+	hue_shift: Hue,
+}
 
-#[node_macro::node_fn(InvertRGBNode)]
-fn invert_image(color: Color) -> Color {
-	let color = color.to_gamma_srgb();
+// OkLCh keeps perceived lightness and colorfulness steady while the hue turns, unlike HSL where a hue shift can visibly brighten or darken a color.
+// Lightness is offset, chroma is scaled like the saturation of the Hue/Saturation node, and the hue is rotated in degrees.
+#[node_macro::node_fn(OkLchAdjustNode)]
+fn oklch_adjust_node(color: Color, lightness_shift: f32, chroma_shift: f32, hue_shift: f32) -> Color {
+	let [lightness, chroma, hue, alpha] = color.to_unassociated_alpha().to_oklch();
 
-	let color = color.map_rgb(|c| color.a() - c);
+	let lightness = (lightness + lightness_shift / 100.).clamp(0., 1.);
+	let chroma = (chroma * (1. + chroma_shift / 100.)).max(0.);
+	let hue = hue + hue_shift / 360.;
 
-	color.to_linear_srgb()
+	let adjusted = Color::from_oklch(lightness, chroma, hue, alpha).map_rgb(|c| c.max(0.));
+	Color::from_unassociated_alpha(adjusted.r(), adjusted.g(), adjusted.b(), alpha)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InvertRGBNode<Red, Green, Blue, Alpha> {
+	red: Red,
+	green: Green,
+	blue: Blue,
+	alpha: Alpha,
+}
+
+#[node_macro::node_fn(InvertRGBNode)]
+fn invert_image(color: Color, red: bool, green: bool, blue: bool, alpha: bool) -> Color {
+	invert_color(color, red, green, blue, alpha)
 }
 
 // TODO replace with trait based implementation
-impl<'i> Node<'i, &'i Color> for InvertRGBNode {
+impl<'i, Red, Green, Blue, Alpha> Node<'i, &'i Color> for InvertRGBNode<Red, Green, Blue, Alpha>
+where
+	Red: Node<'i, (), Output = bool>,
+	Green: Node<'i, (), Output = bool>,
+	Blue: Node<'i, (), Output = bool>,
+	Alpha: Node<'i, (), Output = bool>,
+{
 	type Output = Color;
 
 	fn eval(&'i self, color: &'i Color) -> Self::Output {
-		let color = color.to_gamma_srgb();
+		invert_color(*color, self.red.eval(()), self.green.eval(()), self.blue.eval(()), self.alpha.eval(()))
+	}
+}
 
-		let color = color.map_rgb(|c| color.a() - c);
+fn invert_color(color: Color, red: bool, green: bool, blue: bool, alpha: bool) -> Color {
+	let color = color.to_gamma_srgb();
 
-		color.to_linear_srgb()
-	}
+	let color = if alpha {
+		// Inverting alpha changes the amount the color channels are multiplied by,
+		// so the selected channels are inverted on the straight color, which is then multiplied by the new alpha
+		let straight = color.to_unassociated_alpha();
+		let alpha = 1. - straight.a();
+		let invert = |c: f32, enabled: bool| (if enabled { 1. - c } else { c }) * alpha;
+		Color::from_rgbaf32_unchecked(invert(straight.r(), red), invert(straight.g(), green), invert(straight.b(), blue), alpha)
+	} else {
+		// The color channels are premultiplied by alpha, so `alpha - c` is the inverse of the straight channel multiplied back by alpha
+		let invert = |c: f32, enabled: bool| if enabled { color.a() - c } else { c };
+		Color::from_rgbaf32_unchecked(invert(color.r(), red), invert(color.g(), green), invert(color.b(), blue), color.a())
+	};
+
+	color.to_linear_srgb()
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct ThresholdNode<MinLuminance, MaxLuminance, LuminanceCalc> {
+pub struct ThresholdNode<MinLuminance, MaxLuminance, LuminanceCalc, Softness> {
 	min_luminance: MinLuminance,
 	max_luminance: MaxLuminance,
 	luminance_calc: LuminanceCalc,
+	softness: Softness,
 }
 
 #[node_macro::node_fn(ThresholdNode)]
-fn threshold_node(color: Color, min_luminance: f32, max_luminance: f32, luminance_calc: LuminanceCalculation) -> Color {
+fn threshold_node(color: Color, min_luminance: f32, max_luminance: f32, luminance_calc: LuminanceCalculation, softness: f32) -> Color {
 	let min_luminance = Color::srgb_to_linear(min_luminance / 100.);
 	let max_luminance = Color::srgb_to_linear(max_luminance / 100.);
 
@@ -394,11 +706,32 @@ fn threshold_node(color: Color, min_luminance: f32, max_luminance: f32, luminanc
 		LuminanceCalculation::MaximumChannels => color.maximum_rgb_channels(),
 	};
 
-	if luminance >= min_luminance && luminance <= max_luminance {
-		Color::WHITE
-	} else {
-		Color::BLACK
+	if softness <= 0. {
+		return if luminance >= min_luminance && luminance <= max_luminance { Color::WHITE } else { Color::BLACK };
 	}
+
+	// Each edge of the range becomes a smoothstep ramp centered on the cutoff, spanning the softness in both directions.
+	// Edges at the very ends of the luminance range are left hard so pure black and pure white keep their full value.
+	let half_width = softness / 100. / 2.;
+	let lower = if min_luminance > 0. {
+		smoothstep(min_luminance - half_width, min_luminance + half_width, luminance)
+	} else {
+		1.
+	};
+	let upper = if max_luminance < 1. {
+		1. - smoothstep(max_luminance - half_width, max_luminance + half_width, luminance)
+	} else {
+		1.
+	};
+	let value = lower.min(upper);
+
+	Color::from_rgbf32_unchecked(value, value, value)
+}
+
+#[inline(always)]
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+	let t = ((x - edge0) / (edge1 - edge0)).clamp(0., 1.);
+	t * t * (3. - 2. * t)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -531,6 +864,38 @@ impl core::fmt::Display for RedGreenBlue {
 	}
 }
 
+/// A color channel including alpha, for nodes like Insert Channel that can also target the alpha channel.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum RedGreenBlueAlpha {
+	Red,
+	Green,
+	Blue,
+	Alpha,
+}
+
+impl core::fmt::Display for RedGreenBlueAlpha {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			RedGreenBlueAlpha::Red => write!(f, "Red"),
+			RedGreenBlueAlpha::Green => write!(f, "Green"),
+			RedGreenBlueAlpha::Blue => write!(f, "Blue"),
+			RedGreenBlueAlpha::Alpha => write!(f, "Alpha"),
+		}
+	}
+}
+
+impl From<RedGreenBlue> for RedGreenBlueAlpha {
+	fn from(channel: RedGreenBlue) -> Self {
+		match channel {
+			RedGreenBlue::Red => RedGreenBlueAlpha::Red,
+			RedGreenBlue::Green => RedGreenBlueAlpha::Green,
+			RedGreenBlue::Blue => RedGreenBlueAlpha::Blue,
+		}
+	}
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ChannelMixerNode<Monochrome, MonochromeR, MonochromeG, MonochromeB, MonochromeC, RedR, RedG, RedB, RedC, GreenR, GreenG, GreenB, GreenC, BlueR, BlueG, BlueB, BlueC> {
 	monochrome: Monochrome,
@@ -615,6 +980,26 @@ impl core::fmt::Display for RelativeAbsolute {
 	}
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum DownresFilter {
+	/// Averages each block of pixels, which is slower but avoids aliasing.
+	#[default]
+	Average,
+	/// Keeps the pixel closest to the center of each block.
+	Nearest,
+}
+
+impl core::fmt::Display for DownresFilter {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			DownresFilter::Average => write!(f, "Average"),
+			DownresFilter::Nearest => write!(f, "Nearest"),
+		}
+	}
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", derive(specta::Type))]
@@ -805,14 +1190,21 @@ fn selective_color_node(
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct OpacityNode<O> {
+pub struct OpacityNode<O, P> {
 	opacity_multiplier: O,
+	premultiplied: P,
 }
 
+// Straight alpha only scales the alpha channel, while premultiplied alpha also scales the color channels so the result
+// composites correctly with nodes such as Blend, which expect colors whose channels have already been multiplied by their alpha.
 #[node_macro::node_fn(OpacityNode)]
-fn image_opacity(color: Color, opacity_multiplier: f32) -> Color {
+fn image_opacity(color: Color, opacity_multiplier: f32, premultiplied: bool) -> Color {
 	let opacity_multiplier = opacity_multiplier / 100.;
-	Color::from_rgbaf32_unchecked(color.r(), color.g(), color.b(), color.a() * opacity_multiplier)
+	if premultiplied {
+		color.multiplied_alpha(opacity_multiplier)
+	} else {
+		Color::from_rgbaf32_unchecked(color.r(), color.g(), color.b(), color.a() * opacity_multiplier)
+	}
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -856,6 +1248,126 @@ fn exposure(color: Color, exposure: f32, offset: f32, gamma_correction: f32) ->
 	adjusted.map_rgb(|c: f32| c.clamp(0., 1.))
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum ToneMapping {
+	/// Leaves the values as they are, so anything outside of the range is clipped.
+	#[default]
+	None,
+	Reinhard,
+	Filmic,
+}
+
+impl core::fmt::Display for ToneMapping {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			ToneMapping::None => write!(f, "None"),
+			ToneMapping::Reinhard => write!(f, "Reinhard"),
+			ToneMapping::Filmic => write!(f, "Filmic"),
+		}
+	}
+}
+
+pub fn tone_map(color: Color, tone_mapping: ToneMapping) -> Color {
+	match tone_mapping {
+		ToneMapping::None => color,
+		ToneMapping::Reinhard => color.tone_map_reinhard(),
+		ToneMapping::Filmic => color.tone_map_filmic(),
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClampColorNode<Min, Max, Rolloff> {
+	min: Min,
+	max: Max,
+	rolloff: Rolloff,
+}
+
+/// Brings out-of-range channels, such as those pushed past 1 by Exposure, back into `[min, max]`.
+/// The rolloff compresses bright values smoothly first so they don't all clip to the same flat color.
+#[node_macro::node_fn(ClampColorNode)]
+fn clamp_color(color: Color, min: f32, max: f32, rolloff: ToneMapping) -> Color {
+	let (min, max) = if min <= max { (min, max) } else { (max, min) };
+
+	tone_map(color, rolloff).map_rgb(|c: f32| c.clamp(min, max))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FlattenAlphaNode<Background> {
+	background: Background,
+}
+
+/// Composites the color over a solid background so the result is fully opaque, for formats like JPEG that can't store alpha.
+/// Any transparency in the background color itself is ignored.
+#[node_macro::node_fn(FlattenAlphaNode)]
+fn flatten_alpha(color: Color, background: Color) -> Color {
+	let background = background.to_unassociated_alpha();
+	let background = Color::from_rgbaf32_unchecked(background.r(), background.g(), background.b(), 1.);
+
+	background.alpha_blend(color)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WhiteBalanceNode<Reference, Strength> {
+	reference: Reference,
+	strength: Strength,
+}
+
+/// Removes a color cast by scaling each channel so the `reference` color, which should have been a neutral gray, becomes gray.
+#[node_macro::node_fn(WhiteBalanceNode)]
+fn white_balance(color: Color, reference: Color, strength: f32) -> Color {
+	let [red_gain, green_gain, blue_gain] = white_balance_gains(reference);
+	let strength = strength / 100.;
+	let gain = |gain: f32| 1. + (gain - 1.) * strength;
+
+	let adjusted = Color::from_rgbaf32_unchecked(color.r() * gain(red_gain), color.g() * gain(green_gain), color.b() * gain(blue_gain), color.a());
+
+	adjusted.map_rgb(|c: f32| c.clamp(0., 1.))
+}
+
+/// The per-channel gains that turn the `reference` color into the gray with the same average channel value.
+/// A channel that is zero in the reference carries no information about the cast, so it is left unchanged instead of dividing by zero.
+pub fn white_balance_gains(reference: Color) -> [f32; 3] {
+	let channels = [reference.r(), reference.g(), reference.b()];
+	let gray = channels.iter().sum::<f32>() / 3.;
+
+	channels.map(|channel| if channel > f32::EPSILON { gray / channel } else { 1. })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TemperatureTintNode<Temperature, Tint> {
+	temperature: Temperature,
+	tint: Tint,
+}
+
+#[node_macro::node_fn(TemperatureTintNode)]
+fn temperature_tint(color: Color, temperature: f32, tint: f32) -> Color {
+	let [red_gain, green_gain, blue_gain] = temperature_tint_gains(temperature, tint);
+
+	Color::from_rgbaf32_unchecked(color.r() * red_gain, color.g() * green_gain, color.b() * blue_gain, color.a())
+}
+
+/// The daylight white point that a temperature offset of 0 K is measured from.
+const NEUTRAL_TEMPERATURE: f32 = 6500.;
+
+/// The per-channel gains for a `temperature` offset in Kelvin, where positive values warm the image, and a `tint` percentage, where positive values shift toward magenta.
+/// The gains re-light the image from the neutral daylight white to a black body of the offset temperature, then are normalized so the luminance of white is preserved.
+pub fn temperature_tint_gains(temperature: f32, tint: f32) -> [f32; 3] {
+	let neutral = Color::from_kelvin(NEUTRAL_TEMPERATURE);
+	let target = Color::from_kelvin(NEUTRAL_TEMPERATURE - temperature);
+	let tint = (tint / 100.).clamp(-1., 1.);
+
+	let gains = [target.r() / neutral.r(), (target.g() / neutral.g()) * (1. - 0.3 * tint), target.b() / neutral.b()];
+	// Comparing against the luminance of white rather than 1 keeps the gains exactly 1 at the neutral settings despite rounding
+	let luminance = Color::from_rgbf32_unchecked(gains[0], gains[1], gains[2]).luminance_srgb() / Color::WHITE.luminance_srgb();
+	if luminance > f32::EPSILON {
+		gains.map(|gain| gain / luminance)
+	} else {
+		[1.; 3]
+	}
+}
+
 #[cfg(feature = "alloc")]
 pub use index_node::IndexNode;
 
@@ -61,8 +61,8 @@ impl BrushCacheImpl {
 		if !strokes.is_empty() && self.prev_input.len() > num_blended_strokes {
 			let last_stroke = &self.prev_input[num_blended_strokes];
 			let same_style = strokes[0].style == last_stroke.style;
-			let prev_points = last_stroke.compute_blit_points();
-			let new_points = strokes[0].compute_blit_points();
+			let prev_points = last_stroke.compute_blit_stamps();
+			let new_points = strokes[0].compute_blit_stamps();
 			let is_point_prefix = new_points.get(..prev_points.len()) == Some(&prev_points);
 			if same_style && is_point_prefix {
 				first_stroke_texture = core::mem::take(&mut self.last_stroke_texture);
@@ -14,7 +14,7 @@ use bytemuck::{Pod, Zeroable};
 
 use super::{
 	discrete_srgb::{float_to_srgb_u8, srgb_u8_to_float},
-	Alpha, AssociatedAlpha, Luminance, Pixel, RGBMut, Rec709Primaries, RGB, SRGB,
+	Alpha, AlphaMut, AssociatedAlpha, Luminance, Pixel, RGBMut, Rec709Primaries, RGB, SRGB,
 };
 
 #[repr(C)]
@@ -216,6 +216,12 @@ impl Alpha for Color {
 	}
 }
 
+impl AlphaMut for Color {
+	fn set_alpha(&mut self, value: Self::AlphaChannel) {
+		self.alpha = value;
+	}
+}
+
 impl AssociatedAlpha for Color {
 	fn to_unassociated<Out: super::UnassociatedAlpha>(&self) -> Out {
 		todo!()
@@ -767,7 +773,10 @@ impl Color {
 		} else {
 			(max_channel - min_channel) / (2. - max_channel - min_channel)
 		};
-		let hue = if self.red >= self.green && self.red >= self.blue {
+		// Grays have no hue, so use 0 rather than dividing by zero
+		let hue = if min_channel == max_channel {
+			0.
+		} else if self.red >= self.green && self.red >= self.blue {
 			(self.green - self.blue) / (max_channel - min_channel)
 		} else if self.green >= self.red && self.green >= self.blue {
 			2. + (self.blue - self.red) / (max_channel - min_channel)
@@ -782,6 +791,108 @@ impl Color {
 		[hue, saturation, lightness, self.alpha]
 	}
 
+	// https://en.wikipedia.org/wiki/Planckian_locus#Approximation
+	/// Create the linear sRGB [Color] of a black body radiator at the given temperature in Kelvin, scaled to a luminance of 1.
+	/// Temperatures are clamped to the 1667 K to 25000 K range where the approximation of the Planckian locus holds.
+	///
+	/// # Examples
+	/// ```
+	/// use graphene_core::raster::color::Color;
+	/// let candle = Color::from_kelvin(1900.);
+	/// assert!(candle.r() > candle.b());
+	/// ```
+	#[allow(clippy::excessive_precision)]
+	pub fn from_kelvin(temperature: f32) -> Color {
+		let t = temperature.clamp(1667., 25000.);
+		let x = if t <= 4000. {
+			-0.2661239e9 / (t * t * t) - 0.2343589e6 / (t * t) + 0.8776956e3 / t + 0.179910
+		} else {
+			-3.0258469e9 / (t * t * t) + 2.1070379e6 / (t * t) + 0.2226347e3 / t + 0.240390
+		};
+		let y = if t <= 2222. {
+			-1.1063814 * x * x * x - 1.3481102 * x * x + 2.18555832 * x - 0.20219683
+		} else if t <= 4000. {
+			-0.9549476 * x * x * x - 1.37418593 * x * x + 2.09137015 * x - 0.16748867
+		} else {
+			3.081758 * x * x * x - 5.8733867 * x * x + 3.75112997 * x - 0.37001483
+		};
+
+		// CIE xyY with a luminance of 1 to XYZ, then XYZ to linear sRGB
+		let (big_x, big_z) = (x / y, (1. - x - y) / y);
+		let red = 3.2404542 * big_x - 1.5371385 - 0.4985314 * big_z;
+		let green = -0.969266 * big_x + 1.8760108 + 0.041556 * big_z;
+		let blue = 0.0556434 * big_x - 0.2040259 + 1.0572252 * big_z;
+
+		// Very warm temperatures lie just outside of the sRGB gamut
+		Color::from_rgbf32_unchecked(red.max(0.), green.max(0.), blue.max(0.))
+	}
+
+	// https://bottosson.github.io/posts/oklab/
+	/// Convert a linear sRGB [Color] with straight alpha to the perceptual OkLab space, as lightness (between 0 and 1), the green-red `a` axis, the blue-yellow `b` axis, and alpha.
+	///
+	/// # Examples
+	/// ```
+	/// use graphene_core::raster::color::Color;
+	/// let [lightness, a, b, alpha] = Color::WHITE.to_oklab();
+	/// assert!((lightness - 1.).abs() < 1e-4 && a.abs() < 1e-4 && b.abs() < 1e-4);
+	/// ```
+	#[allow(clippy::excessive_precision)]
+	pub fn to_oklab(&self) -> [f32; 4] {
+		let (red, green, blue) = (self.red, self.green, self.blue);
+		let l = (0.4122214708 * red + 0.5363325363 * green + 0.0514459929 * blue).cbrt();
+		let m = (0.2119034982 * red + 0.6806995451 * green + 0.1073969566 * blue).cbrt();
+		let s = (0.0883024619 * red + 0.2817188376 * green + 0.6299787005 * blue).cbrt();
+
+		[
+			0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+			1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+			0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+			self.alpha,
+		]
+	}
+
+	/// Create a linear sRGB [Color] with straight alpha from OkLab coordinates, the inverse of [Self::to_oklab].
+	/// Colors outside of the sRGB gamut produce channels outside of 0 to 1.
+	#[allow(clippy::excessive_precision)]
+	pub fn from_oklab(lightness: f32, a: f32, b: f32, alpha: f32) -> Color {
+		let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+		let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+		let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+		Color::from_rgbaf32_unchecked(
+			4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+			-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+			-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+			alpha,
+		)
+	}
+
+	/// Convert a linear sRGB [Color] with straight alpha to OkLCh, the polar form of OkLab, as lightness, chroma, hue (between 0 and 1), and alpha.
+	///
+	/// # Examples
+	/// ```
+	/// use graphene_core::raster::color::Color;
+	/// let [lightness, chroma, hue, alpha] = Color::RED.to_oklch();
+	/// ```
+	pub fn to_oklch(&self) -> [f32; 4] {
+		let [lightness, a, b, alpha] = self.to_oklab();
+		let chroma = (a * a + b * b).sqrt();
+		// Grays have no hue, so use 0 like `to_hsla` does
+		let hue = if chroma == 0. { 0. } else { b.atan2(a) / core::f32::consts::TAU };
+		#[cfg(not(target_arch = "spirv"))]
+		let hue = hue.rem_euclid(1.);
+		#[cfg(target_arch = "spirv")]
+		let hue = hue.rem_euclid(&1.);
+
+		[lightness, chroma, hue, alpha]
+	}
+
+	/// Create a linear sRGB [Color] with straight alpha from OkLCh coordinates, the inverse of [Self::to_oklch].
+	pub fn from_oklch(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Color {
+		let angle = hue * core::f32::consts::TAU;
+		Color::from_oklab(lightness, chroma * angle.cos(), chroma * angle.sin(), alpha)
+	}
+
 	// TODO: Readd formatting
 
 	/// Creates a color from a 8-character RGBA hex string (without a # prefix).
@@ -840,6 +951,21 @@ impl Color {
 		self.map_rgb(|c: f32| c.powf(inverse_gamma))
 	}
 
+	/// Compresses channels above 1 toward 1 with the Reinhard operator `c / (1 + c)`, which never quite reaches 1.
+	/// Negative channels are treated as 0.
+	#[inline(always)]
+	pub fn tone_map_reinhard(&self) -> Color {
+		self.map_rgb(|c: f32| c.max(0.) / (1. + c.max(0.)))
+	}
+
+	/// Compresses channels with a filmic curve, using Krzysztof Narkowicz's fit of the ACES curve.
+	/// Compared to Reinhard, it keeps more contrast in the midtones and rolls off highlights to 1 more gradually.
+	#[inline(always)]
+	pub fn tone_map_filmic(&self) -> Color {
+		// From https://knarkowicz.wordpress.com/2016/01/06/aces-filmic-tone-mapping-curve/
+		self.map_rgb(|c: f32| ((c * (2.51 * c + 0.03)) / (c * (2.43 * c + 0.59) + 0.14)).clamp(0., 1.))
+	}
+
 	#[inline(always)]
 	pub fn to_linear_srgb(&self) -> Self {
 		Self {
@@ -903,6 +1029,18 @@ impl Color {
 		}
 	}
 
+	/// Multiplies the color channels by alpha, treating `self` as a straight (unassociated) color.
+	#[inline(always)]
+	pub fn premultiply_alpha(&self) -> Self {
+		self.map_rgb(|channel| channel * self.alpha)
+	}
+
+	/// Divides the color channels by alpha, the inverse of [Self::premultiply_alpha]. Fully transparent colors are returned unchanged.
+	#[inline(always)]
+	pub fn unpremultiply_alpha(&self) -> Self {
+		self.to_unassociated_alpha()
+	}
+
 	#[inline(always)]
 	pub fn to_unassociated_alpha(&self) -> Self {
 		if self.alpha == 0. {
@@ -8,14 +8,54 @@ pub use to_path::*;
 
 use crate::Node;
 
-pub struct TextGenerator<Text, FontName, Size> {
+pub struct TextGenerator<Text, FontName, Size, LineHeight, LetterSpacing, MaxWidth, Align, FallbackFonts, Weight, Width, Slant> {
 	text: Text,
 	font_name: FontName,
 	font_size: Size,
+	line_height: LineHeight,
+	letter_spacing: LetterSpacing,
+	max_width: MaxWidth,
+	align: Align,
+	fallback_fonts: FallbackFonts,
+	weight: Weight,
+	width: Width,
+	slant: Slant,
 }
 
+/// A `max_width` of zero leaves lines unwrapped.
+/// Characters missing from the font are taken from the first of the `fallback_fonts` that has them.
+/// The `weight`, `width` and `slant` only affect variable fonts that vary along those axes.
 #[node_fn(TextGenerator)]
-fn generate_text<'a: 'input, T>(editor: EditorApi<'a, T>, text: String, font_name: Font, font_size: f64) -> crate::vector::VectorData {
-	let buzz_face = editor.font_cache.get(&font_name).map(|data| load_face(data));
-	crate::vector::VectorData::from_subpaths(to_path(&text, buzz_face, font_size as f64, None))
+fn generate_text<'a: 'input, T>(
+	editor: EditorApi<'a, T>,
+	text: String,
+	font_name: Font,
+	font_size: f64,
+	line_height: f64,
+	letter_spacing: f64,
+	max_width: f64,
+	align: TextAlign,
+	fallback_fonts: Vec<Font>,
+	weight: f64,
+	width: f64,
+	slant: f64,
+) -> crate::vector::VectorData {
+	let mut buzz_faces: Vec<_> = match editor.font_cache.get(&font_name) {
+		Some(primary) => core::iter::once(primary)
+			.chain(fallback_fonts.iter().filter_map(|font| editor.font_cache.get(font)))
+			.map(|data| load_face(data))
+			.collect(),
+		None => Vec::new(),
+	};
+	for buzz_face in &mut buzz_faces {
+		set_font_axes(buzz_face, &[(FontAxis::Weight, weight), (FontAxis::Width, width), (FontAxis::Slant, slant)]);
+	}
+	let typesetting = TypesettingConfig {
+		font_size,
+		line_height_ratio: line_height,
+		letter_spacing,
+		max_width: (max_width > 0.).then_some(max_width),
+		align,
+	};
+	crate::vector::VectorData::from_subpaths(to_path(&text, &buzz_faces, typesetting))
 }
@@ -1,23 +1,47 @@
 use crate::uuid::ManipulatorGroupId;
 
 use bezier_rs::{ManipulatorGroup, Subpath};
+use dyn_any::{DynAny, StaticType};
 use glam::DVec2;
-use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder};
-use rustybuzz::{GlyphBuffer, UnicodeBuffer};
+use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder, Tag};
+use rustybuzz::{UnicodeBuffer, Variation};
+use serde::{Deserialize, Serialize};
+
+use core::ops::Range;
 
 struct Builder {
 	current_subpath: Subpath<ManipulatorGroupId>,
 	other_subpaths: Vec<Subpath<ManipulatorGroupId>>,
 	pos: DVec2,
-	offset: DVec2,
-	ascender: f64,
+	/// The distance from the top of a line to the baseline of the primary font, in document units
+	baseline: f64,
+	/// The size of a font unit of the font whose glyph is being drawn, in document units
 	scale: f64,
 	id: ManipulatorGroupId,
 }
 
 impl Builder {
 	fn point(&self, x: f32, y: f32) -> DVec2 {
-		self.pos + self.offset + DVec2::new(x as f64, self.ascender - y as f64) * self.scale
+		self.pos + DVec2::new(x as f64 * self.scale, self.baseline - y as f64 * self.scale)
+	}
+
+	/// Draws a hollow box in place of a character that none of the fonts have, with its inner edge wound the opposite way to cut it out
+	fn missing_glyph_box(&mut self, width: f64, font_size: f64) {
+		let thickness = font_size * 0.05;
+		let [left, right] = [width * 0.1, width * 0.9];
+		let [top, bottom] = [self.baseline - font_size * 0.7, self.baseline];
+		let outer = [(left, top), (right, top), (right, bottom), (left, bottom)];
+		let inner = [
+			(left + thickness, top + thickness),
+			(left + thickness, bottom - thickness),
+			(right - thickness, bottom - thickness),
+			(right - thickness, top + thickness),
+		];
+
+		for corners in [outer, inner] {
+			let manipulator_groups = corners.map(|(x, y)| ManipulatorGroup::new_anchor_with_id(self.pos + DVec2::new(x, y), self.id.next_id()));
+			self.other_subpaths.push(Subpath::new(manipulator_groups.to_vec(), true));
+		}
 	}
 }
 
@@ -52,125 +76,501 @@ impl OutlineBuilder for Builder {
 	}
 }
 
-fn font_properties(buzz_face: &rustybuzz::Face, font_size: f64) -> (f64, f64, UnicodeBuffer) {
-	let scale = (buzz_face.units_per_em() as f64).recip() * font_size;
-	let line_height = font_size;
-	let buffer = UnicodeBuffer::new();
-	(scale, line_height, buffer)
+/// Distinguishes the horizontal placement of each line of text within the text block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, DynAny, specta::Type)]
+pub enum TextAlign {
+	#[default]
+	Left,
+	Center,
+	Right,
+}
+
+impl core::fmt::Display for TextAlign {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			TextAlign::Left => write!(f, "Left"),
+			TextAlign::Center => write!(f, "Center"),
+			TextAlign::Right => write!(f, "Right"),
+		}
+	}
 }
 
-fn push_str(buffer: &mut UnicodeBuffer, word: &str, trailing_space: bool) {
-	buffer.push_str(word);
+/// The options that control how a string is laid out into lines of glyphs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypesettingConfig {
+	pub font_size: f64,
+	/// The distance between the tops of consecutive lines, as a multiple of the font size
+	pub line_height_ratio: f64,
+	/// Extra space added after every character, in document units
+	pub letter_spacing: f64,
+	/// Lines are wrapped between words to fit within this width, or within a word that is too long to fit on its own line
+	pub max_width: Option<f64>,
+	pub align: TextAlign,
+}
 
-	if trailing_space {
-		buffer.push_str(" ");
+impl Default for TypesettingConfig {
+	fn default() -> Self {
+		Self {
+			font_size: 24.,
+			line_height_ratio: 1.,
+			letter_spacing: 0.,
+			max_width: None,
+			align: TextAlign::Left,
+		}
 	}
 }
 
-fn wrap_word(line_width: Option<f64>, glyph_buffer: &GlyphBuffer, scale: f64, x_pos: f64) -> bool {
-	if let Some(line_width) = line_width {
-		let word_length: i32 = glyph_buffer.glyph_positions().iter().map(|pos| pos.x_advance).sum();
-		let scaled_word_length = word_length as f64 * scale;
+/// A glyph from one of the fonts in the fallback chain, or a placeholder for a character that none of them have
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FontGlyph {
+	Glyph { face: usize, id: GlyphId },
+	Missing { width: f64 },
+}
 
-		if scaled_word_length + x_pos > line_width {
-			return true;
+/// Splits the text into runs that are each shaped with a single font, picking the first font in the chain with a glyph for each character.
+/// Whitespace stays in the current run when its font has a glyph for it, and characters that no font has are left to the primary font.
+fn font_runs(text: &str, font_count: usize, has_glyph: impl Fn(usize, char) -> bool) -> Vec<(usize, Range<usize>)> {
+	let mut runs: Vec<(usize, Range<usize>)> = Vec::new();
+	for (start, character) in text.char_indices() {
+		let face = match runs.last() {
+			Some(&(face, _)) if character.is_whitespace() && has_glyph(face, character) => face,
+			_ => (0..font_count).find(|&face| has_glyph(face, character)).unwrap_or(0),
+		};
+		let end = start + character.len_utf8();
+		match runs.last_mut() {
+			Some((run_face, range)) if *run_face == face => range.end = end,
+			_ => runs.push((face, start..end)),
 		}
 	}
-	false
+	runs
 }
 
-pub fn to_path(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, line_width: Option<f64>) -> Vec<Subpath<ManipulatorGroupId>> {
-	let buzz_face = match buzz_face {
-		Some(face) => face,
-		// Show blank layer if font has not loaded
-		None => return vec![],
-	};
+/// A shaped glyph with its advance and offset scaled to document units
+#[derive(Debug, Clone, Copy)]
+struct ShapedGlyph<G> {
+	glyph: G,
+	advance: DVec2,
+	offset: DVec2,
+	/// Whitespace doesn't count towards the width of a line, so trailing spaces never cause wrapping or shift aligned text
+	whitespace: bool,
+}
 
-	let (scale, line_height, mut buffer) = font_properties(&buzz_face, font_size);
+#[derive(Debug)]
+struct Line<G> {
+	glyphs: Vec<(G, DVec2)>,
+	pen: DVec2,
+	visible_width: f64,
+}
 
-	let mut builder = Builder {
-		current_subpath: Subpath::new(Vec::new(), false),
-		other_subpaths: Vec::new(),
-		pos: DVec2::ZERO,
-		offset: DVec2::ZERO,
-		ascender: (buzz_face.ascender() as f64 / buzz_face.height() as f64) * font_size / scale,
-		scale,
-		id: ManipulatorGroupId::ZERO,
-	};
+impl<G> Default for Line<G> {
+	fn default() -> Self {
+		Self {
+			glyphs: Vec::new(),
+			pen: DVec2::ZERO,
+			visible_width: 0.,
+		}
+	}
+}
 
-	for line in str.split('\n') {
-		let length = line.split(' ').count();
-		for (index, word) in line.split(' ').enumerate() {
-			push_str(&mut buffer, word, index != length - 1);
-			let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+/// The positions of the glyphs of a block of text, along with the size of the block
+#[derive(Debug)]
+struct Layout<G> {
+	glyphs: Vec<(G, DVec2)>,
+	size: DVec2,
+}
 
-			if wrap_word(line_width, &glyph_buffer, scale, builder.pos.x) {
-				builder.pos = DVec2::new(0., builder.pos.y + line_height);
+/// Breaks paragraphs of shaped words into lines, then positions each line according to the line height and alignment
+fn layout<G: Copy>(paragraphs: &[Vec<Vec<ShapedGlyph<G>>>], typesetting: TypesettingConfig) -> Layout<G> {
+	let line_height = typesetting.font_size * typesetting.line_height_ratio;
+	let fits = |line: &Line<G>, width: f64| line.pen.x <= 0. || typesetting.max_width.map_or(true, |max_width| line.pen.x + width <= max_width);
+
+	let mut lines = Vec::new();
+	for paragraph in paragraphs {
+		let mut line = Line::default();
+		for word in paragraph {
+			let visible = word.iter().take_while(|glyph| !glyph.whitespace);
+			let word_width = visible.clone().map(|glyph| glyph.advance.x).sum::<f64>() + typesetting.letter_spacing * (visible.count().max(1) - 1) as f64;
+			if !fits(&line, word_width) {
+				lines.push(core::mem::take(&mut line));
 			}
 
-			for (glyph_position, glyph_info) in glyph_buffer.glyph_positions().iter().zip(glyph_buffer.glyph_infos()) {
-				if let Some(line_width) = line_width {
-					if builder.pos.x + (glyph_position.x_advance as f64 * builder.scale) >= line_width {
-						builder.pos = DVec2::new(0., builder.pos.y + line_height);
-					}
+			for glyph in word {
+				if !glyph.whitespace && !fits(&line, glyph.advance.x) {
+					lines.push(core::mem::take(&mut line));
 				}
-				builder.offset = DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * builder.scale;
-				buzz_face.outline_glyph(GlyphId(glyph_info.glyph_id as u16), &mut builder);
-				if !builder.current_subpath.is_empty() {
-					builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
+				line.glyphs.push((glyph.glyph, line.pen + glyph.offset));
+				if !glyph.whitespace {
+					line.visible_width = line.pen.x + glyph.advance.x;
 				}
-
-				builder.pos += DVec2::new(glyph_position.x_advance as f64, glyph_position.y_advance as f64) * builder.scale;
+				line.pen += glyph.advance + DVec2::X * typesetting.letter_spacing;
 			}
-
-			buffer = glyph_buffer.clear();
 		}
-		builder.pos = DVec2::new(0., builder.pos.y + line_height);
+		lines.push(line);
 	}
-	builder.other_subpaths
-}
 
-pub fn bounding_box(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, line_width: Option<f64>) -> DVec2 {
-	let buzz_face = match buzz_face {
-		Some(face) => face,
-		// Show blank layer if font has not loaded
-		None => return DVec2::ZERO,
+	let block_width = typesetting.max_width.unwrap_or_else(|| lines.iter().map(|line| line.visible_width).fold(0., f64::max));
+	let mut result = Layout {
+		glyphs: Vec::new(),
+		size: DVec2::ZERO,
 	};
+	for (index, line) in lines.into_iter().enumerate() {
+		let x = match typesetting.align {
+			TextAlign::Left => 0.,
+			TextAlign::Center => (block_width - line.visible_width) / 2.,
+			TextAlign::Right => block_width - line.visible_width,
+		};
+		let origin = DVec2::new(x, index as f64 * line_height);
+		result.glyphs.extend(line.glyphs.into_iter().map(|(glyph, position)| (glyph, origin + position)));
+		result.size = result.size.max(origin + DVec2::new(line.pen.x, line_height));
+	}
+	result
+}
 
-	let (scale, line_height, mut buffer) = font_properties(&buzz_face, font_size);
-
-	let mut pos = DVec2::ZERO;
-	let mut bounds = DVec2::ZERO;
+/// Shapes each word of each line of the string, keeping the space after every word but the last one of a line.
+/// Each character is shaped with the first font of the chain that has a glyph for it.
+fn shape(str: &str, buzz_faces: &[rustybuzz::Face], font_size: f64) -> Vec<Vec<Vec<ShapedGlyph<FontGlyph>>>> {
+	let scales: Vec<_> = buzz_faces.iter().map(|face| (face.units_per_em() as f64).recip() * font_size).collect();
+	let mut buffer = UnicodeBuffer::new();
+	let mut text = String::new();
 
+	let mut paragraphs = Vec::new();
 	for line in str.split('\n') {
 		let length = line.split(' ').count();
+		let mut words = Vec::with_capacity(length);
 		for (index, word) in line.split(' ').enumerate() {
-			push_str(&mut buffer, word, index != length - 1);
+			text.clear();
+			text.push_str(word);
+			if index != length - 1 {
+				text.push(' ');
+			}
+
+			let mut glyphs = Vec::new();
+			for (face, range) in font_runs(&text, buzz_faces.len(), |face, character| buzz_faces[face].glyph_index(character).is_some()) {
+				buffer.push_str(&text[range.clone()]);
+				let glyph_buffer = rustybuzz::shape(&buzz_faces[face], &[], buffer);
 
-			let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+				glyphs.extend(glyph_buffer.glyph_positions().iter().zip(glyph_buffer.glyph_infos()).map(|(glyph_position, glyph_info)| {
+					let advance = DVec2::new(glyph_position.x_advance as f64, glyph_position.y_advance as f64) * scales[face];
+					let glyph = match glyph_info.glyph_id {
+						0 => FontGlyph::Missing { width: advance.x },
+						id => FontGlyph::Glyph { face, id: GlyphId(id as u16) },
+					};
+					ShapedGlyph {
+						glyph,
+						advance,
+						offset: DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * scales[face],
+						whitespace: range.start + glyph_info.cluster as usize >= word.len(),
+					}
+				}));
 
-			if wrap_word(line_width, &glyph_buffer, scale, pos.x) {
-				pos = DVec2::new(0., pos.y + line_height);
+				buffer = glyph_buffer.clear();
 			}
+			words.push(glyphs);
+		}
+		paragraphs.push(words);
+	}
+	paragraphs
+}
 
-			for glyph_position in glyph_buffer.glyph_positions() {
-				if let Some(line_width) = line_width {
-					if pos.x + (glyph_position.x_advance as f64 * scale) >= line_width {
-						pos = DVec2::new(0., pos.y + line_height);
-					}
+/// Converts the text to outlines using the first font, falling back to the following fonts for characters it doesn't have.
+/// Characters that none of the fonts have are drawn as a hollow box.
+pub fn to_path(str: &str, buzz_faces: &[rustybuzz::Face], typesetting: TypesettingConfig) -> Vec<Subpath<ManipulatorGroupId>> {
+	let Some(primary_face) = buzz_faces.first() else {
+		// Show blank layer if font has not loaded
+		return vec![];
+	};
+
+	let mut builder = Builder {
+		current_subpath: Subpath::new(Vec::new(), false),
+		other_subpaths: Vec::new(),
+		pos: DVec2::ZERO,
+		baseline: (primary_face.ascender() as f64 / primary_face.height() as f64) * typesetting.font_size,
+		scale: 1.,
+		id: ManipulatorGroupId::ZERO,
+	};
+
+	for (glyph, position) in layout(&shape(str, buzz_faces, typesetting.font_size), typesetting).glyphs {
+		builder.pos = position;
+		match glyph {
+			FontGlyph::Glyph { face, id } => {
+				builder.scale = (buzz_faces[face].units_per_em() as f64).recip() * typesetting.font_size;
+				buzz_faces[face].outline_glyph(id, &mut builder);
+				if !builder.current_subpath.is_empty() {
+					builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
 				}
-				pos += DVec2::new(glyph_position.x_advance as f64, glyph_position.y_advance as f64) * scale;
 			}
-			bounds = bounds.max(pos + DVec2::new(0., line_height));
-
-			buffer = glyph_buffer.clear();
+			FontGlyph::Missing { width } => builder.missing_glyph_box(width, typesetting.font_size),
 		}
-		pos = DVec2::new(0., pos.y + line_height);
+	}
+	builder.other_subpaths
+}
+
+pub fn bounding_box(str: &str, buzz_faces: &[rustybuzz::Face], typesetting: TypesettingConfig) -> DVec2 {
+	if buzz_faces.is_empty() {
+		// Show blank layer if font has not loaded
+		return DVec2::ZERO;
 	}
 
-	bounds
+	layout(&shape(str, buzz_faces, typesetting.font_size), typesetting).size
 }
 
 pub fn load_face(data: &[u8]) -> rustybuzz::Face {
 	rustybuzz::Face::from_slice(data, 0).expect("Loading font failed")
 }
+
+/// A registered variation axis of variable fonts that the Text node can set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontAxis {
+	Weight,
+	Width,
+	Slant,
+}
+
+impl FontAxis {
+	pub fn tag(self) -> Tag {
+		match self {
+			FontAxis::Weight => Tag::from_bytes(b"wght"),
+			FontAxis::Width => Tag::from_bytes(b"wdth"),
+			FontAxis::Slant => Tag::from_bytes(b"slnt"),
+		}
+	}
+
+	/// The value that the OpenType specification gives for a regular style: a weight of 400, a width of 100%, and no slant
+	pub fn regular_value(self) -> f64 {
+		match self {
+			FontAxis::Weight => 400.,
+			FontAxis::Width => 100.,
+			FontAxis::Slant => 0.,
+		}
+	}
+
+	/// The range of values the font supports along this axis, or `None` if it doesn't vary along it
+	pub fn range(self, buzz_face: &rustybuzz::Face) -> Option<[f64; 2]> {
+		let axis = buzz_face.variation_axes().into_iter().find(|axis| axis.tag == self.tag())?;
+		Some([axis.min_value as f64, axis.max_value as f64])
+	}
+}
+
+/// Moves the font to the given coordinates along the axes it varies along, which affects both its outlines and shaping.
+/// Axes the font doesn't have are ignored, so static fonts are left unchanged.
+pub fn set_font_axes(buzz_face: &mut rustybuzz::Face, values: &[(FontAxis, f64)]) {
+	let variations: Vec<_> = values.iter().map(|&(axis, value)| Variation { tag: axis.tag(), value: value as f32 }).collect();
+	buzz_face.set_variations(&variations);
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Stands in for shaping with a monospaced font where every character is 10 units wide
+	fn shape_monospaced(str: &str) -> Vec<Vec<Vec<ShapedGlyph<char>>>> {
+		let glyph = |glyph, whitespace| ShapedGlyph {
+			glyph,
+			advance: DVec2::new(10., 0.),
+			offset: DVec2::ZERO,
+			whitespace,
+		};
+		str.split('\n')
+			.map(|line| {
+				let length = line.split(' ').count();
+				let words = line.split(' ').enumerate();
+				words
+					.map(|(index, word)| word.chars().map(|c| glyph(c, false)).chain((index != length - 1).then(|| glyph(' ', true))).collect())
+					.collect()
+			})
+			.collect()
+	}
+
+	/// The visible characters of each line, found from the vertical position of each glyph
+	fn lines(layout: &Layout<char>, line_height: f64) -> Vec<String> {
+		let mut lines = Vec::new();
+		for &(glyph, position) in &layout.glyphs {
+			let index = (position.y / line_height).round() as usize;
+			lines.resize(lines.len().max(index + 1), String::new());
+			lines[index].push(glyph);
+		}
+		lines.into_iter().map(|line| line.trim_end().to_string()).collect()
+	}
+
+	fn typesetting(max_width: Option<f64>, align: TextAlign) -> TypesettingConfig {
+		TypesettingConfig {
+			font_size: 10.,
+			max_width,
+			align,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn defaults_lay_out_single_lines() {
+		let layout = layout(&shape_monospaced("ab c\nd"), typesetting(None, TextAlign::Left));
+		let positions: Vec<_> = layout.glyphs.iter().map(|&(glyph, position)| (glyph, position.x, position.y)).collect();
+		assert_eq!(positions, [('a', 0., 0.), ('b', 10., 0.), (' ', 20., 0.), ('c', 30., 0.), ('d', 0., 10.)]);
+		assert_eq!(layout.size, DVec2::new(40., 20.));
+	}
+
+	#[test]
+	fn wrapping_at_a_given_width() {
+		let words = shape_monospaced("hello world again");
+		assert_eq!(lines(&layout(&words, typesetting(Some(110.), TextAlign::Left)), 10.), ["hello world", "again"]);
+		assert_eq!(lines(&layout(&words, typesetting(Some(109.), TextAlign::Left)), 10.), ["hello", "world", "again"]);
+
+		let long_word = shape_monospaced("abcdefgh");
+		assert_eq!(lines(&layout(&long_word, typesetting(Some(35.), TextAlign::Left)), 10.), ["abc", "def", "gh"]);
+	}
+
+	#[test]
+	fn wrapped_lines_are_aligned_within_the_width() {
+		let words = shape_monospaced("ab cdef");
+		let first_x = |align| {
+			let layout = layout(&words, typesetting(Some(50.), align));
+			layout
+				.glyphs
+				.iter()
+				.filter(|&&(glyph, _)| glyph == 'a' || glyph == 'c')
+				.map(|&(_, position)| position.x)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(first_x(TextAlign::Left), [0., 0.]);
+		assert_eq!(first_x(TextAlign::Center), [15., 5.]);
+		assert_eq!(first_x(TextAlign::Right), [30., 10.]);
+	}
+
+	#[test]
+	fn line_height_and_letter_spacing() {
+		let typesetting = TypesettingConfig {
+			font_size: 10.,
+			line_height_ratio: 1.5,
+			letter_spacing: 2.,
+			..Default::default()
+		};
+		let layout = layout(&shape_monospaced("ab\nc"), typesetting);
+		let positions: Vec<_> = layout.glyphs.iter().map(|&(_, position)| position).collect();
+		assert_eq!(positions, [DVec2::ZERO, DVec2::new(12., 0.), DVec2::new(0., 15.)]);
+	}
+
+	#[test]
+	fn glyph_present_only_in_the_fallback() {
+		// The primary font only has Latin letters and the fallback only has Greek letters, while both have spaces
+		let has_glyph = |face: usize, character: char| character == ' ' || if face == 0 { character.is_ascii_alphabetic() } else { ('α'..='ω').contains(&character) };
+
+		assert_eq!(font_runs("ab", 2, has_glyph), [(0, 0..2)]);
+		assert_eq!(font_runs("aλb", 2, has_glyph), [(0, 0..1), (1, 1..3), (0, 3..4)]);
+		assert_eq!(font_runs("λ λ a", 2, has_glyph), [(1, 0..6), (0, 6..7)]);
+		assert_eq!(
+			font_runs("a☃", 2, has_glyph),
+			[(0, 0..4)],
+			"Characters missing from every font should be shaped as missing glyphs of the primary font"
+		);
+	}
+
+	fn builder(pos: DVec2, baseline: f64) -> Builder {
+		Builder {
+			current_subpath: Subpath::new(Vec::new(), false),
+			other_subpaths: Vec::new(),
+			pos,
+			baseline,
+			scale: 1.,
+			id: ManipulatorGroupId::ZERO,
+		}
+	}
+
+	#[test]
+	fn missing_glyph_box_is_hollow() {
+		let mut builder = builder(DVec2::new(20., 0.), 8.);
+		builder.missing_glyph_box(10., 10.);
+
+		let [outer, inner] = builder.other_subpaths.as_slice() else {
+			panic!("Expected an outer and an inner edge")
+		};
+		assert_eq!(outer.bounding_box(), Some([DVec2::new(21., 1.), DVec2::new(29., 8.)]));
+
+		let signed_area = |subpath: &Subpath<ManipulatorGroupId>| {
+			let anchors: Vec<_> = subpath.manipulator_groups().iter().map(|group| group.anchor).collect();
+			(0..anchors.len()).map(|index| anchors[index].perp_dot(anchors[(index + 1) % anchors.len()])).sum::<f64>() / 2.
+		};
+		assert!(signed_area(outer) * signed_area(inner) < 0., "The inner edge should wind the opposite way to cut out the box");
+	}
+
+	/// Builds a variable font with a weight axis from 100 to 900 and a single glyph: a square whose right edge moves 100 units further out at the heaviest weight
+	fn variable_font() -> Vec<u8> {
+		fn big_endian(values: &[i32], sizes: &[usize]) -> Vec<u8> {
+			values.iter().zip(sizes).flat_map(|(&value, &size)| value.to_be_bytes()[4 - size..].to_vec()).collect()
+		}
+
+		let head = [
+			big_endian(&[0x10000, 0x10000, 0, 0x5F0F3CF5, 0, 1000], &[4, 4, 4, 4, 2, 2]),
+			vec![0; 16],
+			big_endian(&[100, 0, 500, 400, 0, 8, 2, 0, 0], &[2; 9]),
+		]
+		.concat();
+		let hhea = big_endian(
+			&[0x10000, 800, -200, 0, 600, 0, 0, 500, 1, 0, 0, 0, 0, 0, 0, 0, 2],
+			&[4, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+		);
+		let maxp = big_endian(&[0x5000, 2], &[4, 2]);
+		let hmtx = big_endian(&[500, 0, 600, 100], &[2; 4]);
+		// Four on-curve points with their coordinates stored as 16-bit deltas from the previous point
+		let glyf = big_endian(
+			&[1, 100, 0, 500, 400, 3, 0, 0x01010101, 100, 400, 0, -400, 0, 0, 400, 0],
+			&[2, 2, 2, 2, 2, 2, 2, 4, 2, 2, 2, 2, 2, 2, 2, 2],
+		);
+		let loca = big_endian(&[0, 0, glyf.len() as i32 / 2], &[2; 3]);
+		let fvar = [
+			big_endian(&[1, 0, 16, 2, 1, 20, 0, 8], &[2; 8]),
+			b"wght".to_vec(),
+			big_endian(&[100 << 16, 400 << 16, 900 << 16, 0, 256], &[4, 4, 4, 2, 2]),
+		]
+		.concat();
+		// A single tuple peaking at the maximum weight, with deltas for every point including the four phantom points
+		let glyph_variations = [big_endian(&[1, 10, 11, 0xA000, 0x4000], &[2; 5]), vec![0, 7, 0, 100, 100, 0, 0, 0, 0, 0, 0x87, 0]].concat();
+		let gvar = [
+			big_endian(&[1, 0, 1, 0, 26, 2, 0, 26, 0, 0, glyph_variations.len() as i32 / 2], &[2, 2, 2, 2, 4, 2, 2, 4, 2, 2, 2]),
+			glyph_variations,
+		]
+		.concat();
+
+		let tables = [
+			(b"fvar", fvar),
+			(b"glyf", glyf),
+			(b"gvar", gvar),
+			(b"head", head),
+			(b"hhea", hhea),
+			(b"hmtx", hmtx),
+			(b"loca", loca),
+			(b"maxp", maxp),
+		];
+		let mut font = big_endian(&[0x10000, tables.len() as i32, 0, 0, 0], &[4, 2, 2, 2, 2]);
+		let mut offset = font.len() + tables.len() * 16;
+		for (tag, data) in &tables {
+			font.extend([tag.to_vec(), big_endian(&[0, offset as i32, data.len() as i32], &[4; 3])].concat());
+			offset += (data.len() + 3) / 4 * 4;
+		}
+		for (_, data) in &tables {
+			font.extend(data);
+			font.resize((font.len() + 3) / 4 * 4, 0);
+		}
+		font
+	}
+
+	#[test]
+	fn weight_axis_changes_outlines() {
+		let data = variable_font();
+		let mut buzz_face = load_face(&data);
+		assert_eq!(FontAxis::Weight.range(&buzz_face), Some([100., 900.]));
+		assert_eq!(FontAxis::Width.range(&buzz_face), None, "Axes the font doesn't vary along should be hidden");
+
+		let right_edge = |buzz_face: &rustybuzz::Face| {
+			let mut builder = builder(DVec2::ZERO, 0.);
+			buzz_face.outline_glyph(GlyphId(1), &mut builder);
+			builder.other_subpaths[0].bounding_box().unwrap()[1].x
+		};
+		assert_eq!(right_edge(&buzz_face), 500.);
+
+		set_font_axes(&mut buzz_face, &[(FontAxis::Weight, 900.), (FontAxis::Width, 50.)]);
+		assert_eq!(right_edge(&buzz_face), 600.);
+
+		set_font_axes(&mut buzz_face, &[(FontAxis::Weight, 650.)]);
+		assert_eq!(right_edge(&buzz_face), 550.);
+	}
+}
@@ -54,6 +54,36 @@ impl PartialEq for BrushStyle {
 	}
 }
 
+/// How the Brush node applies its strokes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BrushMode {
+	/// Each stroke is blended using the blend mode of its own style.
+	#[default]
+	Paint,
+	/// Every stroke erases, regardless of the blend mode of its style.
+	Erase,
+}
+
+impl BrushMode {
+	/// Applies the mode to a stroke's style, so the brush cache sees a different stroke whenever the mode changes it.
+	pub fn apply(&self, mut stroke: BrushStroke) -> BrushStroke {
+		if *self == BrushMode::Erase {
+			stroke.style.blend_mode = BlendMode::Erase;
+		}
+		stroke
+	}
+}
+
+impl core::fmt::Display for BrushMode {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			BrushMode::Paint => write!(f, "Paint"),
+			BrushMode::Erase => write!(f, "Erase"),
+		}
+	}
+}
+
 /// A single sample of brush parameters across the brush stroke.
 #[derive(Clone, Debug, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -61,13 +91,28 @@ pub struct BrushInputSample {
 	// The position of the sample in layer space, in pixels.
 	// The origin of layer space is not specified.
 	pub position: DVec2,
-	// Future work: pressure, stylus angle, etc.
+	// The stylus pressure from 0 to 1, which scales the flow of the stamps placed near this sample.
+	#[cfg_attr(feature = "serde", serde(default = "full_pressure"))]
+	pub pressure: f64,
+	// Future work: stylus angle, etc.
+}
+
+fn full_pressure() -> f64 {
+	1.
+}
+
+impl BrushInputSample {
+	/// A sample at full pressure, for input devices that don't report pressure.
+	pub fn new(position: DVec2) -> Self {
+		Self { position, pressure: full_pressure() }
+	}
 }
 
 impl Hash for BrushInputSample {
 	fn hash<H: Hasher>(&self, state: &mut H) {
 		self.position.x.to_bits().hash(state);
 		self.position.y.to_bits().hash(state);
+		self.pressure.to_bits().hash(state);
 	}
 }
 
@@ -93,36 +138,50 @@ impl BrushStroke {
 	}
 
 	pub fn compute_blit_points(&self) -> Vec<DVec2> {
+		self.compute_blit_stamps().into_iter().map(|(position, _)| position).collect()
+	}
+
+	/// The position of every stamp along the stroke, paired with the pressure at that point.
+	pub fn compute_blit_stamps(&self) -> Vec<(DVec2, f64)> {
 		// We always travel in a straight line towards the next user input,
 		// placing a blit point every time we travelled our spacing distance.
-		let spacing_dist = self.style.spacing / 100. * self.style.diameter;
+		// Spacing is limited to 1% of the diameter, since smaller spacing costs far more stamps without a visible difference.
+		let spacing_dist = self.style.spacing.max(1.) / 100. * self.style.diameter;
 
 		let Some(first_sample) = self.trace.first() else {
 			return Vec::new();
 		};
+		if spacing_dist <= 0. || !spacing_dist.is_finite() {
+			return vec![(first_sample.position, first_sample.pressure)];
+		}
 
 		let mut cur_pos = first_sample.position;
-		let mut result = vec![cur_pos];
+		let mut cur_pressure = first_sample.pressure;
+		let mut result = vec![(cur_pos, cur_pressure)];
 		let mut dist_until_next_blit = spacing_dist;
 		for sample in &self.trace[1..] {
 			// Travel to the next sample.
 			let delta = sample.position - cur_pos;
 			let mut dist_left = delta.length();
 			let unit_step = delta / dist_left;
+			// The pressure changes linearly between samples.
+			let pressure_step = (sample.pressure - cur_pressure) / dist_left;
 
 			while dist_left >= dist_until_next_blit {
 				// Take a step to the next blit point.
 				cur_pos += dist_until_next_blit * unit_step;
+				cur_pressure += dist_until_next_blit * pressure_step;
 				dist_left -= dist_until_next_blit;
 
 				// Blit.
-				result.push(cur_pos);
+				result.push((cur_pos, cur_pressure));
 				dist_until_next_blit = spacing_dist;
 			}
 
 			// Take the partial step to land at the sample.
 			dist_until_next_blit -= dist_left;
 			cur_pos = sample.position;
+			cur_pressure = sample.pressure;
 		}
 
 		result
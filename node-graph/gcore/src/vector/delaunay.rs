@@ -0,0 +1,129 @@
+use glam::DVec2;
+
+/// Triangulates distinct points with the Bowyer-Watson algorithm so that no point lies inside the circumcircle of any triangle.
+/// The triangles are indices into `points`, ordered counterclockwise (with y pointing up). Fewer than three points, or points all on one line, give no triangles.
+pub fn delaunay_triangles(points: &[DVec2]) -> Vec<[usize; 3]> {
+	let Some([min, max]) = bounds(points) else { return Vec::new() };
+	if points.len() < 3 {
+		return Vec::new();
+	}
+
+	// Start from a triangle large enough that its corners don't affect the circumcircle tests near the points
+	let center = (min + max) / 2.;
+	let margin = (max - min).max_element().max(f64::EPSILON) * 100.;
+	let mut vertices = points.to_vec();
+	vertices.extend([DVec2::new(-3., -3.), DVec2::new(3., -3.), DVec2::new(0., 3.)].map(|corner| center + corner * margin));
+	let mut triangles = vec![[points.len(), points.len() + 1, points.len() + 2]];
+
+	for (index, &point) in points.iter().enumerate() {
+		let (bad, good): (Vec<_>, Vec<_>) = triangles.into_iter().partition(|&triangle| in_circumcircle(triangle.map(|vertex| vertices[vertex]), point));
+		triangles = good;
+
+		// The edges of the cavity are those not shared by two of the removed triangles, which would list them in opposite directions
+		let edges = bad.iter().flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)]);
+		let boundary: Vec<_> = edges.clone().filter(|&(a, b)| !edges.clone().any(|edge| edge == (b, a))).collect();
+		triangles.extend(boundary.into_iter().map(|(a, b)| [a, b, index]));
+	}
+
+	triangles.retain(|triangle| triangle.iter().all(|&vertex| vertex < points.len()));
+	triangles
+}
+
+/// The part of the `bounds` rectangle closer to each point than to any other point, as a counterclockwise polygon per point.
+/// Cells entirely outside the bounds are empty.
+pub fn voronoi_cells(points: &[DVec2], bounds: [DVec2; 2]) -> Vec<Vec<DVec2>> {
+	let [min, max] = bounds;
+	let rectangle = vec![min, DVec2::new(max.x, min.y), max, DVec2::new(min.x, max.y)];
+
+	points
+		.iter()
+		.enumerate()
+		.map(|(index, &point)| {
+			points
+				.iter()
+				.enumerate()
+				.filter(|&(other_index, _)| other_index != index)
+				.fold(rectangle.clone(), |cell, (_, &other)| clip_closer(&cell, point, other))
+		})
+		.collect()
+}
+
+fn bounds(points: &[DVec2]) -> Option<[DVec2; 2]> {
+	points.iter().map(|&point| [point, point]).reduce(|[min_a, max_a], [min_b, max_b]| [min_a.min(min_b), max_a.max(max_b)])
+}
+
+/// Whether `point` lies strictly inside the circumcircle of the counterclockwise triangle
+fn in_circumcircle(triangle: [DVec2; 3], point: DVec2) -> bool {
+	let [a, b, c] = triangle.map(|vertex| vertex - point);
+	a.length_squared() * b.perp_dot(c) + b.length_squared() * c.perp_dot(a) + c.length_squared() * a.perp_dot(b) > 0.
+}
+
+/// Cuts away the part of the convex polygon closer to `other` than to `point`, using the Sutherland-Hodgman algorithm for the dividing line
+fn clip_closer(polygon: &[DVec2], point: DVec2, other: DVec2) -> Vec<DVec2> {
+	let direction = other - point;
+	let midpoint = (point + other) / 2.;
+	// Negative on the side of `point`
+	let distance = |vertex: DVec2| (vertex - midpoint).dot(direction);
+
+	let mut clipped = Vec::with_capacity(polygon.len() + 1);
+	for (index, &start) in polygon.iter().enumerate() {
+		let end = polygon[(index + 1) % polygon.len()];
+		let (start_distance, end_distance) = (distance(start), distance(end));
+
+		if start_distance <= 0. {
+			clipped.push(start);
+		}
+		if (start_distance < 0. && end_distance > 0.) || (start_distance > 0. && end_distance < 0.) {
+			clipped.push(start.lerp(end, start_distance / (start_distance - end_distance)));
+		}
+	}
+	clipped
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn points(coordinates: &[(f64, f64)]) -> Vec<DVec2> {
+		coordinates.iter().map(|&(x, y)| DVec2::new(x, y)).collect()
+	}
+
+	fn area(polygon: &[DVec2]) -> f64 {
+		(0..polygon.len()).map(|index| polygon[index].perp_dot(polygon[(index + 1) % polygon.len()])).sum::<f64>() / 2.
+	}
+
+	#[test]
+	fn triangulation_is_delaunay() {
+		// Four points on the convex hull and two inside give 2 * 6 - 2 - 4 triangles
+		let points = points(&[(0., 0.), (10., 0.), (10., 8.), (0., 8.), (3., 3.), (6., 5.)]);
+		let triangles = delaunay_triangles(&points);
+		assert_eq!(triangles.len(), 6);
+
+		for triangle in &triangles {
+			let corners = triangle.map(|vertex| points[vertex]);
+			assert!(area(&corners) > 0., "{triangle:?} should be counterclockwise");
+			assert!(points.iter().all(|&point| !in_circumcircle(corners, point)), "{triangle:?} should have an empty circumcircle");
+		}
+		let total_area: f64 = triangles.iter().map(|triangle| area(&triangle.map(|vertex| points[vertex]))).sum();
+		assert!((total_area - 80.).abs() < 1e-9, "The triangles should cover the convex hull");
+	}
+
+	#[test]
+	fn degenerate_points_have_no_triangles() {
+		assert!(delaunay_triangles(&[]).is_empty());
+		assert!(delaunay_triangles(&points(&[(0., 0.), (1., 1.)])).is_empty());
+		assert!(delaunay_triangles(&points(&[(0., 0.), (1., 1.), (2., 2.), (5., 5.)])).is_empty());
+	}
+
+	#[test]
+	fn voronoi_cells_tile_the_bounds() {
+		let points = points(&[(0., 0.), (2., 0.), (2., 2.), (0., 2.), (1., 1.)]);
+		let cells = voronoi_cells(&points, [DVec2::ZERO, DVec2::splat(2.)]);
+		assert_eq!(cells.len(), 5);
+
+		// The corners get the triangles cut off by the center's diamond
+		assert_eq!(cells.iter().map(Vec::len).collect::<Vec<_>>(), vec![3, 3, 3, 3, 4]);
+		assert!((area(&cells[4]) - 2.).abs() < 1e-9);
+		assert!((cells.iter().map(|cell| area(cell)).sum::<f64>() - 4.).abs() < 1e-9);
+	}
+}
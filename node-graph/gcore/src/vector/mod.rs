@@ -1,5 +1,6 @@
 pub mod brush_stroke;
 pub mod consts;
+pub mod delaunay;
 pub mod generator_nodes;
 pub mod manipulator_group;
 pub mod manipulator_point;
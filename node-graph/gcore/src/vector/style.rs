@@ -1,6 +1,8 @@
 //! Contains stylistic options for SVG elements.
 
 use crate::consts::{LAYER_OUTLINE_STROKE_COLOR, LAYER_OUTLINE_STROKE_WEIGHT};
+use crate::raster::ImageFrame;
+use crate::renderer::SvgIds;
 use crate::Color;
 
 use dyn_any::{DynAny, StaticType};
@@ -65,7 +67,7 @@ impl Gradient {
 	}
 
 	/// Adds the gradient def, returning the gradient id
-	fn render_defs(&self, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> u64 {
+	fn render_defs(&self, ids: SvgIds, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> u64 {
 		let bound_transform = DAffine2::from_scale_angle_translation(bounds[1] - bounds[0], 0., bounds[0]);
 		let transformed_bound_transform = DAffine2::from_scale_angle_translation(transformed_bounds[1] - transformed_bounds[0], 0., transformed_bounds[0]);
 		let updated_transform = multiplied_transform * bound_transform;
@@ -90,24 +92,15 @@ impl Gradient {
 			.map(|(i, entry)| entry.to_string() + if i == 5 { "" } else { "," })
 			.collect::<String>();
 
-		let gradient_id = crate::uuid::generate_uuid();
-		match self.gradient_type {
-			GradientType::Linear => {
-				let _ = write!(
-					svg_defs,
-					r#"<linearGradient id="{}" x1="{}" x2="{}" y1="{}" y2="{}" gradientTransform="matrix({})">{}</linearGradient>"#,
-					gradient_id, start.x, end.x, start.y, end.y, transform, positions
-				);
-			}
+		let (tag, attributes) = match self.gradient_type {
+			GradientType::Linear => ("linearGradient", format!(r#"x1="{}" x2="{}" y1="{}" y2="{}""#, start.x, end.x, start.y, end.y)),
 			GradientType::Radial => {
 				let radius = (f64::powi(start.x - end.x, 2) + f64::powi(start.y - end.y, 2)).sqrt();
-				let _ = write!(
-					svg_defs,
-					r#"<radialGradient id="{}" cx="{}" cy="{}" r="{}" gradientTransform="matrix({})">{}</radialGradient>"#,
-					gradient_id, start.x, start.y, radius, transform, positions
-				);
+				("radialGradient", format!(r#"cx="{}" cy="{}" r="{}""#, start.x, start.y, radius))
 			}
-		}
+		};
+		let gradient_id = ids.generate((tag, &attributes, &transform, &positions));
+		let _ = write!(svg_defs, r#"<{tag} id="{gradient_id}" {attributes} gradientTransform="matrix({transform})">{positions}</{tag}>"#);
 
 		gradient_id
 	}
@@ -152,9 +145,18 @@ impl Gradient {
 	}
 }
 
+/// An image pattern fill.
+///
+/// The transform of the image places a single tile in the space of the layer, which is then either repeated to cover the shape or drawn once.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, DynAny, Hash, specta::Type)]
+pub struct Pattern {
+	pub image: ImageFrame<Color>,
+	pub repeat: bool,
+}
+
 /// Describes the fill of a layer.
 ///
-/// Can be None, a solid [Color], a linear [Gradient], a radial [Gradient] or potentially some sort of image or pattern in the future
+/// Can be None, a solid [Color], a linear [Gradient], a radial [Gradient] or an image [Pattern]
 #[repr(C)]
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, DynAny, Hash, specta::Type)]
 pub enum Fill {
@@ -162,6 +164,7 @@ pub enum Fill {
 	None,
 	Solid(Color),
 	Gradient(Gradient),
+	Pattern(Pattern),
 }
 
 impl Fill {
@@ -177,18 +180,23 @@ impl Fill {
 			Self::Solid(color) => *color,
 			// TODO: Should correctly sample the gradient
 			Self::Gradient(Gradient { positions, .. }) => positions[0].1.unwrap_or(Color::BLACK),
+			// TODO: Should sample the image
+			Self::Pattern(_) => Color::BLACK,
 		}
 	}
 
 	/// Renders the fill, adding necessary defs.
-	pub fn render(&self, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
+	///
+	/// A [Pattern] is left out, since its image can only be referenced from within the SVG itself. The renderer adds the pattern and the `fill` attribute instead.
+	pub fn render(&self, ids: SvgIds, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
 		match self {
 			Self::None => r#" fill="none""#.to_string(),
 			Self::Solid(color) => format!(r##" fill="#{}"{}"##, color.rgb_hex(), format_opacity("fill", color.a())),
 			Self::Gradient(gradient) => {
-				let gradient_id = gradient.render_defs(svg_defs, multiplied_transform, bounds, transformed_bounds);
+				let gradient_id = gradient.render_defs(ids, svg_defs, multiplied_transform, bounds, transformed_bounds);
 				format!(r##" fill="url('#{}')""##, gradient_id)
 			}
+			Self::Pattern(_) => String::new(),
 		}
 	}
 
@@ -205,6 +213,15 @@ impl Fill {
 			None
 		}
 	}
+
+	/// Extract an image pattern from the fill
+	pub fn as_pattern(&self) -> Option<&Pattern> {
+		if let Self::Pattern(pattern) = self {
+			Some(pattern)
+		} else {
+			None
+		}
+	}
 }
 
 /// Enum describing the type of [Fill]
@@ -214,6 +231,7 @@ pub enum FillType {
 	None,
 	Solid,
 	Gradient,
+	Pattern,
 }
 
 /// The stroke (outline) style of an SVG element.
@@ -253,6 +271,31 @@ impl Display for LineJoin {
 	}
 }
 
+/// Where a stroke lies relative to the closed path it outlines. SVG only draws centered strokes, so the others are drawn by offsetting the path.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum StrokeAlign {
+	#[default]
+	Center,
+	Inside,
+	Outside,
+}
+
+impl StrokeAlign {
+	pub fn list() -> [StrokeAlign; 3] {
+		[StrokeAlign::Center, StrokeAlign::Inside, StrokeAlign::Outside]
+	}
+}
+
+impl Display for StrokeAlign {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			StrokeAlign::Center => write!(f, "Center"),
+			StrokeAlign::Inside => write!(f, "Inside"),
+			StrokeAlign::Outside => write!(f, "Outside"),
+		}
+	}
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, DynAny, specta::Type)]
 pub struct Stroke {
@@ -265,6 +308,9 @@ pub struct Stroke {
 	pub line_cap: LineCap,
 	pub line_join: LineJoin,
 	pub line_join_miter_limit: f64,
+	/// Paints the stroke with a gradient instead of its color. Documents from before gradient strokes existed have none.
+	#[serde(default)]
+	pub gradient: Option<Gradient>,
 }
 
 impl core::hash::Hash for Stroke {
@@ -277,6 +323,7 @@ impl core::hash::Hash for Stroke {
 		self.line_cap.hash(state);
 		self.line_join.hash(state);
 		self.line_join_miter_limit.to_bits().hash(state);
+		self.gradient.hash(state);
 	}
 }
 
@@ -290,6 +337,7 @@ impl Stroke {
 			line_cap: LineCap::Butt,
 			line_join: LineJoin::Miter,
 			line_join_miter_limit: 4.,
+			gradient: None,
 		}
 	}
 
@@ -327,23 +375,30 @@ impl Stroke {
 		self.line_join_miter_limit as f32
 	}
 
-	/// Provide the SVG attributes for the stroke.
-	pub fn render(&self) -> String {
-		if let Some(color) = self.color {
-			format!(
-				r##" stroke="#{}"{} stroke-width="{}" stroke-dasharray="{}" stroke-dashoffset="{}" stroke-linecap="{}" stroke-linejoin="{}" stroke-miterlimit="{}" "##,
-				color.rgb_hex(),
-				format_opacity("stroke", color.a()),
-				self.weight,
-				self.dash_lengths(),
-				self.dash_offset,
-				self.line_cap,
-				self.line_join,
-				self.line_join_miter_limit
-			)
-		} else {
-			String::new()
-		}
+	/// Provide the SVG attributes for the stroke, adding the gradient def if the stroke is painted with a gradient.
+	pub fn render(&self, ids: SvgIds, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
+		let paint = match (&self.gradient, self.color) {
+			(Some(gradient), _) => {
+				let gradient_id = gradient.render_defs(ids, svg_defs, multiplied_transform, bounds, transformed_bounds);
+				format!(r##" stroke="url('#{}')""##, gradient_id)
+			}
+			(None, Some(color)) => format!(r##" stroke="#{}"{}"##, color.rgb_hex(), format_opacity("stroke", color.a())),
+			(None, None) => return String::new(),
+		};
+		format!("{paint}{}", self.render_geometry())
+	}
+
+	/// The SVG attributes for the shape of the stroke, which are the same however it is painted
+	fn render_geometry(&self) -> String {
+		format!(
+			r#" stroke-width="{}" stroke-dasharray="{}" stroke-dashoffset="{}" stroke-linecap="{}" stroke-linejoin="{}" stroke-miterlimit="{}" "#,
+			self.weight,
+			self.dash_lengths(),
+			self.dash_offset,
+			self.line_cap,
+			self.line_join,
+			self.line_join_miter_limit
+		)
 	}
 
 	pub fn with_color(mut self, color: &Option<Color>) -> Option<Self> {
@@ -402,6 +457,24 @@ impl Default for Stroke {
 			line_cap: LineCap::Butt,
 			line_join: LineJoin::Miter,
 			line_join_miter_limit: 4.,
+			gradient: None,
+		}
+	}
+}
+
+/// An extra fill or stroke drawn beneath the main fill and stroke of a shape, each as a path of its own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, DynAny, Hash, specta::Type)]
+pub enum StyleLayer {
+	Fill(Fill),
+	Stroke(Stroke),
+}
+
+impl StyleLayer {
+	/// The attributes of the path drawn for this layer, which only paints the layer's own fill or stroke
+	pub fn render(&self, ids: SvgIds, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
+		match self {
+			Self::Fill(fill) => fill.render(ids, svg_defs, multiplied_transform, bounds, transformed_bounds),
+			Self::Stroke(stroke) => format!(r#" fill="none"{}"#, stroke.render(ids, svg_defs, multiplied_transform, bounds, transformed_bounds)),
 		}
 	}
 }
@@ -411,11 +484,23 @@ impl Default for Stroke {
 pub struct PathStyle {
 	stroke: Option<Stroke>,
 	fill: Fill,
+	/// Drawn back to front beneath the fill and stroke. Documents from before style layers existed have none.
+	#[serde(default)]
+	layers: Vec<StyleLayer>,
 }
 
 impl PathStyle {
 	pub const fn new(stroke: Option<Stroke>, fill: Fill) -> Self {
-		Self { stroke, fill }
+		Self { stroke, fill, layers: Vec::new() }
+	}
+
+	/// The extra fills and strokes drawn beneath the main ones, from back to front
+	pub fn layers(&self) -> &[StyleLayer] {
+		&self.layers
+	}
+
+	pub fn set_layers(&mut self, layers: Vec<StyleLayer>) {
+		self.layers = layers;
 	}
 
 	/// Get the current path's [Fill].
@@ -522,19 +607,39 @@ impl PathStyle {
 		self.stroke = None;
 	}
 
-	pub fn render(&self, view_mode: ViewMode, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
-		let fill_attribute = match (view_mode, &self.fill) {
-			(ViewMode::Outline, _) => Fill::None.render(svg_defs, multiplied_transform, bounds, transformed_bounds),
-			(_, fill) => fill.render(svg_defs, multiplied_transform, bounds, transformed_bounds),
-		};
-		let stroke_attribute = match (view_mode, &self.stroke) {
-			(ViewMode::Outline, _) => Stroke::new(Some(LAYER_OUTLINE_STROKE_COLOR), LAYER_OUTLINE_STROKE_WEIGHT).render(),
-			(_, Some(stroke)) => stroke.render(),
-			(_, None) => String::new(),
-		};
+	pub fn render(&self, view_mode: ViewMode, ids: SvgIds, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
+		if view_mode == ViewMode::Outline {
+			return Self::render_outline();
+		}
+		let fill_attribute = self.fill.render(ids, svg_defs, multiplied_transform, bounds, transformed_bounds);
+		let stroke_attribute = self
+			.stroke
+			.as_ref()
+			.map(|stroke| stroke.render(ids, svg_defs, multiplied_transform, bounds, transformed_bounds))
+			.unwrap_or_default();
 
 		format!("{}{}", fill_attribute, stroke_attribute)
 	}
+
+	/// The attributes of a path for each of the [`PathStyle::layers`], from back to front. The outline view only draws the shape once, so it has none.
+	pub fn render_layers(&self, view_mode: ViewMode, ids: SvgIds, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> Vec<String> {
+		if view_mode == ViewMode::Outline {
+			return Vec::new();
+		}
+		self.layers.iter().map(|layer| layer.render(ids, svg_defs, multiplied_transform, bounds, transformed_bounds)).collect()
+	}
+
+	/// The attributes used for every shape in [ViewMode::Outline], regardless of its style: no fill and a hairline stroke that stays one pixel wide at any zoom.
+	pub fn render_outline() -> String {
+		let stroke = Stroke::new(Some(LAYER_OUTLINE_STROKE_COLOR), LAYER_OUTLINE_STROKE_WEIGHT);
+		let stroke_attribute = format!(
+			r##" stroke="#{}"{}{}"##,
+			LAYER_OUTLINE_STROKE_COLOR.rgb_hex(),
+			format_opacity("stroke", LAYER_OUTLINE_STROKE_COLOR.a()),
+			stroke.render_geometry()
+		);
+		format!(r#" fill="none"{stroke_attribute}vector-effect="non-scaling-stroke""#)
+	}
 }
 
 /// Represents different ways of rendering an object
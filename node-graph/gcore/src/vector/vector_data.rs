@@ -105,6 +105,36 @@ impl Default for VectorData {
 	}
 }
 
+/// A list of anchor positions in document space, as extracted from [VectorData] by the Extract Vector Points node.
+#[derive(Clone, Debug, Default, PartialEq, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorPoints(pub Vec<DVec2>);
+
+impl VectorPoints {
+	pub fn from_vector_data(vector: &VectorData) -> Self {
+		Self(vector.manipulator_groups().map(|group| vector.transform.transform_point2(group.anchor)).collect())
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = DVec2> + '_ {
+		self.0.iter().copied()
+	}
+
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl core::hash::Hash for VectorPoints {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.0.len().hash(state);
+		self.0.iter().flat_map(|point| point.to_array()).for_each(|x| x.to_bits().hash(state));
+	}
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManipulatorPointId {
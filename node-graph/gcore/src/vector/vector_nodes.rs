@@ -1,11 +1,16 @@
-use super::style::{Fill, FillType, Gradient, GradientType, Stroke};
-use super::VectorData;
+use super::style::{Fill, FillType, Gradient, GradientType, Pattern, Stroke, StrokeAlign, StyleLayer};
+use super::{VectorData, VectorPoints};
+use crate::raster::ImageFrame;
+use crate::uuid::ManipulatorGroupId;
 use crate::{Color, Node};
-use bezier_rs::Subpath;
+use bezier_rs::{Bezier, ManipulatorGroup, Subpath, SubpathTValue};
+use core::future::Future;
+use core::pin::Pin;
+use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2};
 
 #[derive(Debug, Clone, Copy)]
-pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform, Positions> {
+pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform, Positions, PatternImage, Repeat> {
 	fill_type: FillType,
 	solid_color: SolidColor,
 	gradient_type: GradientType,
@@ -13,6 +18,8 @@ pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform
 	end: End,
 	transform: Transform,
 	positions: Positions,
+	pattern_image: PatternImage,
+	repeat: Repeat,
 }
 
 #[node_macro::node_fn(SetFillNode)]
@@ -25,6 +32,8 @@ fn set_vector_data_fill(
 	end: DVec2,
 	transform: DAffine2,
 	positions: Vec<(f64, Option<Color>)>,
+	pattern_image: ImageFrame<Color>,
+	repeat: bool,
 ) -> VectorData {
 	vector_data.style.set_fill(match fill_type {
 		FillType::None | FillType::Solid => solid_color.map_or(Fill::None, Fill::Solid),
@@ -35,12 +44,13 @@ fn set_vector_data_fill(
 			positions,
 			gradient_type,
 		}),
+		FillType::Pattern => Fill::Pattern(Pattern { image: pattern_image, repeat }),
 	});
 	vector_data
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct SetStrokeNode<Color, Weight, DashLengths, DashOffset, LineCap, LineJoin, MiterLimit> {
+pub struct SetStrokeNode<Color, Weight, DashLengths, DashOffset, LineCap, LineJoin, MiterLimit, Align, PaintType, GradientType, Start, End, Transform, Positions> {
 	color: Color,
 	weight: Weight,
 	dash_lengths: DashLengths,
@@ -48,8 +58,17 @@ pub struct SetStrokeNode<Color, Weight, DashLengths, DashOffset, LineCap, LineJo
 	line_cap: LineCap,
 	line_join: LineJoin,
 	miter_limit: MiterLimit,
+	align: Align,
+	paint_type: PaintType,
+	gradient_type: GradientType,
+	start: Start,
+	end: End,
+	transform: Transform,
+	positions: Positions,
 }
 
+/// Sets the stroke of the vector data. Inside and outside alignments move the closed subpaths by half the weight, so the fill shrinks or grows along with them.
+/// A gradient paint type paints the stroke with a gradient positioned the same way as a gradient fill, in place of the color.
 #[node_macro::node_fn(SetStrokeNode)]
 fn set_vector_data_stroke(
 	mut vector_data: VectorData,
@@ -60,7 +79,21 @@ fn set_vector_data_stroke(
 	line_cap: super::style::LineCap,
 	line_join: super::style::LineJoin,
 	miter_limit: f32,
+	align: StrokeAlign,
+	paint_type: FillType,
+	gradient_type: super::style::GradientType,
+	start: DVec2,
+	end: DVec2,
+	transform: DAffine2,
+	positions: Vec<(f64, Option<Color>)>,
 ) -> VectorData {
+	let join = match line_join {
+		super::style::LineJoin::Miter => bezier_rs::Join::Miter(Some(miter_limit as f64)),
+		super::style::LineJoin::Bevel => bezier_rs::Join::Bevel,
+		super::style::LineJoin::Round => bezier_rs::Join::Round,
+	};
+	align_stroke(&mut vector_data, weight as f64, align, join);
+
 	vector_data.style.set_stroke(Stroke {
 		color,
 		weight: weight as f64,
@@ -69,10 +102,63 @@ fn set_vector_data_stroke(
 		line_cap,
 		line_join,
 		line_join_miter_limit: miter_limit as f64,
+		gradient: (paint_type == FillType::Gradient).then_some(Gradient {
+			start,
+			end,
+			transform,
+			positions,
+			gradient_type,
+		}),
 	});
 	vector_data
 }
 
+/// Offsets each closed subpath by half the stroke weight, so the centered SVG stroke lies inside or outside of the original outline. Open subpaths have no inside, so they are left as they are.
+fn align_stroke(vector_data: &mut VectorData, weight: f64, align: StrokeAlign, join: bezier_rs::Join) {
+	let inwards = match align {
+		StrokeAlign::Center => return,
+		StrokeAlign::Inside => weight / 2.,
+		StrokeAlign::Outside => -weight / 2.,
+	};
+	// The weight is measured where the shape is drawn, so the offset has to happen after the transform
+	let transform = vector_data.transform;
+	if inwards == 0. || transform.matrix2.determinant() == 0. {
+		return;
+	}
+
+	for subpath in vector_data.subpaths.iter_mut().filter(|subpath| subpath.closed() && subpath.len() > 1) {
+		// Handles lying on their anchor turn straight segments into degenerate cubics, which bezier-rs can't clip against each other at concave corners
+		let manipulator_groups = subpath.manipulator_groups().iter().map(|group| ManipulatorGroup {
+			in_handle: group.in_handle.filter(|&handle| handle != group.anchor),
+			out_handle: group.out_handle.filter(|&handle| handle != group.anchor),
+			..group.clone()
+		});
+		let mut drawn = Subpath::new(manipulator_groups.collect(), true);
+		drawn.apply_transform(transform);
+
+		// bezier-rs offsets along the normals, which point inside a subpath with a positive signed area
+		let winding = drawn.iter().map(|bezier| doubled_signed_area(&bezier)).sum::<f64>().signum();
+		if winding == 0. {
+			continue;
+		}
+		let mut offset = drawn.offset(inwards * winding, join);
+		offset.apply_transform(transform.inverse());
+		*subpath = offset;
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SetStyleLayersNode<Layers> {
+	layers: Layers,
+}
+
+/// Replaces the extra fills and strokes drawn beneath the main fill and stroke
+#[node_macro::node_fn(SetStyleLayersNode)]
+fn set_vector_data_style_layers(mut vector_data: VectorData, layers: Vec<StyleLayer>) -> VectorData {
+	vector_data.style.set_layers(layers);
+	vector_data
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct RepeatNode<Direction, Count> {
 	direction: Direction,
@@ -134,6 +220,189 @@ fn circular_repeat_vector_data(mut vector_data: VectorData, rotation_offset: f32
 	vector_data
 }
 
+/// The line the Mirror node reflects a shape across, which passes through its axis position
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, DynAny, Hash)]
+pub enum MirrorAxis {
+	/// Flips the shape upside down
+	#[default]
+	Horizontal,
+	/// Flips the shape left to right
+	Vertical,
+	/// Uses the axis angle, counted from the horizontal
+	Custom,
+}
+
+impl MirrorAxis {
+	pub fn list() -> [MirrorAxis; 3] {
+		[MirrorAxis::Horizontal, MirrorAxis::Vertical, MirrorAxis::Custom]
+	}
+}
+
+impl core::fmt::Display for MirrorAxis {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			MirrorAxis::Horizontal => write!(f, "Horizontal"),
+			MirrorAxis::Vertical => write!(f, "Vertical"),
+			MirrorAxis::Custom => write!(f, "Custom"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MirrorNode<Axis, Position, Angle, KeepOriginal> {
+	axis: Axis,
+	position: Position,
+	angle: Angle,
+	keep_original: KeepOriginal,
+}
+
+/// Reflects the shape across a line through `position` in document space. With `keep_original`, the reflection is added alongside the original subpaths to make a symmetric shape.
+#[node_macro::node_fn(MirrorNode)]
+fn mirror_vector_data(mut vector_data: VectorData, axis: MirrorAxis, position: DVec2, angle: f64, keep_original: bool) -> VectorData {
+	let reflection = match axis {
+		MirrorAxis::Horizontal => DAffine2::from_scale(DVec2::new(1., -1.)),
+		MirrorAxis::Vertical => DAffine2::from_scale(DVec2::new(-1., 1.)),
+		MirrorAxis::Custom => DAffine2::from_angle(angle.to_radians()) * DAffine2::from_scale(DVec2::new(1., -1.)) * DAffine2::from_angle(-angle.to_radians()),
+	};
+	let reflection = DAffine2::from_translation(position) * reflection * DAffine2::from_translation(-position);
+	let transform = vector_data.transform.inverse() * reflection * vector_data.transform;
+
+	// Reversing the reflected subpaths keeps their winding direction, so the overlapping halves of a filled symmetric shape don't cancel out
+	let mirrored = vector_data.subpaths.iter().map(|subpath| {
+		let mut subpath = subpath.reverse();
+		subpath.apply_transform(transform);
+		subpath
+	});
+	vector_data.subpaths = if keep_original {
+		vector_data.subpaths.iter().cloned().chain(mirrored).collect()
+	} else {
+		mirrored.collect()
+	};
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TrimPathNode<Start, End, Offset, Wrap> {
+	start: Start,
+	end: End,
+	offset: Offset,
+	wrap: Wrap,
+}
+
+/// Keeps the part of each subpath between the `start` and `end` fractions of its length, with both shifted along the path by `offset`.
+/// When `start` comes after `end`, `wrap` keeps the part running from `start` past the end of the path around to `end`, rather than the part between them.
+#[node_macro::node_fn(TrimPathNode)]
+fn trim_path(mut vector_data: VectorData, start: f64, end: f64, offset: f64, wrap: bool) -> VectorData {
+	let (start, end) = (start.clamp(0., 1.), end.clamp(0., 1.));
+	let (start, kept) = match start <= end {
+		true => (start, end - start),
+		false if wrap => (start, 1. - start + end),
+		false => (end, start - end),
+	};
+	let start = (start + offset).rem_euclid(1.);
+	let end = start + kept;
+
+	vector_data.subpaths = vector_data
+		.subpaths
+		.iter()
+		.flat_map(|subpath| {
+			if kept >= 1. || subpath.len_segments() == 0 || subpath.length(None) == 0. {
+				return vec![subpath.clone()];
+			}
+			if kept <= 0. {
+				return Vec::new();
+			}
+
+			// The default tolerance of the arc length lookup is too coarse for trimming to land on the anchors at the right fractions
+			let at = |t: f64| SubpathTValue::GlobalEuclideanWithinError { t, error: 1e-6 };
+			let trim = |from: f64, to: f64| subpath.trim(at(from), at(to));
+			match end <= 1. {
+				true => vec![trim(start, end)],
+				// A closed subpath is trimmed across its closing point in one piece, but an open one has a gap there
+				false if subpath.closed() => vec![trim(start, end - 1.)],
+				false => vec![trim(start, 1.), trim(0., end - 1.)],
+			}
+		})
+		.collect();
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DashToGeometryNode<DashLengths, DashOffset> {
+	dash_lengths: DashLengths,
+	dash_offset: DashOffset,
+}
+
+/// Cuts each subpath into an open subpath per dash, following the same rules as a stroke's dash pattern: the lengths alternate between dashes and gaps,
+/// an odd number of lengths is repeated to make it even, and the pattern starts `dash_offset` into the pattern at the start of every subpath.
+/// The lengths are measured in document space, like those of a stroke, so the transform of the shape doesn't stretch the pattern.
+/// The stroke of the result is solid and its fill is removed, so it looks the same without a dash pattern. Patterns with no length leave the shape unchanged.
+#[node_macro::node_fn(DashToGeometryNode)]
+fn dash_to_geometry(mut vector_data: VectorData, dash_lengths: Vec<f32>, dash_offset: f32) -> VectorData {
+	let mut pattern: Vec<f64> = dash_lengths.iter().map(|&length| length.max(0.) as f64).collect();
+	if pattern.len() % 2 == 1 {
+		pattern.extend_from_within(..);
+	}
+	let pattern_length: f64 = pattern.iter().sum();
+	if !(pattern_length > 0.) || !pattern_length.is_finite() {
+		return vector_data;
+	}
+
+	// Dashing happens in document space and the dashes are brought back into the space of the shape afterwards
+	let transform = vector_data.transform;
+	let invertible = transform.matrix2.determinant() != 0.;
+
+	let mut dashes = Vec::new();
+	for subpath in &vector_data.subpaths {
+		let mut subpath = subpath.clone();
+		if invertible {
+			subpath.apply_transform(transform);
+		}
+		let length = subpath.length(None);
+		if subpath.len_segments() == 0 || length == 0. {
+			continue;
+		}
+		let at = |distance: f64| SubpathTValue::GlobalEuclideanWithinError { t: distance / length, error: 1e-6 };
+
+		// Find where along the pattern the subpath starts
+		let mut phase = (dash_offset as f64).rem_euclid(pattern_length);
+		let mut index = 0;
+		while phase >= pattern[index] {
+			phase -= pattern[index];
+			index = (index + 1) % pattern.len();
+		}
+
+		let mut distance = 0.;
+		let mut remaining = pattern[index] - phase;
+		while distance < length {
+			let end = (distance + remaining).min(length);
+			if index % 2 == 0 && end > distance {
+				let mut dash = subpath.trim(at(distance), at(end));
+				if invertible {
+					dash.apply_transform(transform.inverse());
+				}
+				dashes.push(dash);
+			}
+			distance = end;
+			index = (index + 1) % pattern.len();
+			remaining = pattern[index];
+		}
+	}
+
+	vector_data.subpaths = dashes;
+	vector_data.style.clear_fill();
+	if let Some(stroke) = vector_data.style.stroke() {
+		vector_data.style.set_stroke(Stroke {
+			dash_lengths: Vec::new(),
+			dash_offset: 0.,
+			..stroke
+		});
+	}
+	vector_data
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BoundingBoxNode;
 
@@ -145,3 +414,646 @@ fn generate_bounding_box(mut vector_data: VectorData) -> VectorData {
 		vector_data.transform.transform_point2(bounding_box[1]),
 	)])
 }
+
+#[derive(Debug, Clone, Copy)]
+pub struct PathToPointsNode;
+
+#[node_macro::node_fn(PathToPointsNode)]
+fn path_to_points(vector_data: VectorData) -> VectorPoints {
+	VectorPoints::from_vector_data(&vector_data)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PointsToPathNode<Closed, Smooth> {
+	closed: Closed,
+	smooth: Smooth,
+}
+
+/// Connects the points in order with straight lines, or with `smooth`, a Catmull-Rom spline passing through every point.
+#[node_macro::node_fn(PointsToPathNode)]
+fn points_to_path(points: VectorPoints, closed: bool, smooth: bool) -> VectorData {
+	if points.is_empty() {
+		return VectorData::empty();
+	}
+	if !smooth {
+		return VectorData::from_subpath(Subpath::from_anchors(points.iter(), closed));
+	}
+
+	let points = &points.0;
+	let neighbor = |index: usize, offset: isize| {
+		let index = index as isize + offset;
+		let index = if closed {
+			index.rem_euclid(points.len() as isize)
+		} else {
+			index.clamp(0, points.len() as isize - 1)
+		};
+		points[index as usize]
+	};
+	let manipulator_groups = (0..points.len())
+		.map(|index| {
+			// The Catmull-Rom tangent is half the difference of the neighbors, and cubic handles sit a third of the tangent away from the anchor
+			let handle_offset = (neighbor(index, 1) - neighbor(index, -1)) / 6.;
+			ManipulatorGroup::new(points[index], Some(points[index] - handle_offset), Some(points[index] + handle_offset))
+		})
+		.collect();
+	VectorData::from_subpath(Subpath::new(manipulator_groups, closed))
+}
+
+/// The corners of a shape's bounding box in document space. Empty shapes have a zero sized box at the origin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, DynAny)]
+pub struct VectorBounds {
+	pub min: DVec2,
+	pub max: DVec2,
+}
+
+impl VectorBounds {
+	pub fn center(&self) -> DVec2 {
+		(self.min + self.max) / 2.
+	}
+
+	pub fn size(&self) -> DVec2 {
+		self.max - self.min
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundsNode;
+
+#[node_macro::node_fn(BoundsNode)]
+fn vector_bounds(vector_data: VectorData) -> VectorBounds {
+	vector_data
+		.bounding_box_with_transform(vector_data.transform)
+		.map(|[min, max]| VectorBounds { min, max })
+		.unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundsMinNode;
+
+#[node_macro::node_fn(BoundsMinNode)]
+fn bounds_min(bounds: VectorBounds) -> DVec2 {
+	bounds.min
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundsMaxNode;
+
+#[node_macro::node_fn(BoundsMaxNode)]
+fn bounds_max(bounds: VectorBounds) -> DVec2 {
+	bounds.max
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundsCenterNode;
+
+#[node_macro::node_fn(BoundsCenterNode)]
+fn bounds_center(bounds: VectorBounds) -> DVec2 {
+	bounds.center()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoundsSizeNode;
+
+#[node_macro::node_fn(BoundsSizeNode)]
+fn bounds_size(bounds: VectorBounds) -> DVec2 {
+	bounds.size()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ConvexHullNode;
+
+/// Wraps the anchors and handles of every subpath in a closed subpath, with the transform baked in. Since curves lie within the hull of their control points, so does the whole shape.
+/// Points that all lie on one line give an open subpath between the outermost two, and fewer than two distinct points give back the input.
+#[node_macro::node_fn(ConvexHullNode)]
+fn generate_convex_hull(vector_data: VectorData) -> VectorData {
+	let points = vector_data
+		.subpaths
+		.iter()
+		.flat_map(|subpath| subpath.manipulator_groups())
+		.flat_map(|group| [Some(group.anchor), group.in_handle, group.out_handle])
+		.flatten()
+		.map(|point| vector_data.transform.transform_point2(point))
+		.collect();
+
+	let hull = convex_hull(points);
+	match hull.len() {
+		0 | 1 => vector_data,
+		2 => VectorData::from_subpath(Subpath::from_anchors(hull, false)),
+		_ => VectorData::from_subpath(Subpath::from_anchors(hull, true)),
+	}
+}
+
+/// Andrew's monotone chain algorithm. The corners are ordered counterclockwise (with y pointing up) from the leftmost point, leaving out points in the middle of an edge.
+fn convex_hull(mut points: Vec<DVec2>) -> Vec<DVec2> {
+	points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+	points.dedup();
+	if points.len() < 3 {
+		return points;
+	}
+
+	let turns_left = |hull: &[DVec2], point: DVec2| {
+		let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+		(b - a).perp_dot(point - a) > 0.
+	};
+
+	// Build the lower hull from left to right, then the upper hull from right to left
+	let mut hull: Vec<DVec2> = Vec::with_capacity(points.len() + 1);
+	for &point in &points {
+		while hull.len() >= 2 && !turns_left(&hull, point) {
+			hull.pop();
+		}
+		hull.push(point);
+	}
+	let upper_start = hull.len() + 1;
+	for &point in points.iter().rev().skip(1) {
+		while hull.len() >= upper_start && !turns_left(&hull, point) {
+			hull.pop();
+		}
+		hull.push(point);
+	}
+
+	// The upper hull ends back at the leftmost point
+	hull.pop();
+	hull
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DelaunayNode<Voronoi> {
+	voronoi: Voronoi,
+}
+
+/// Connects the anchors of every subpath, with the transform baked in, into a closed triangle subpath per Delaunay triangle.
+/// With `voronoi`, each anchor instead gets a closed cell subpath around it, clipped to the bounding box of the input.
+#[node_macro::node_fn(DelaunayNode)]
+fn delaunay_triangulation(vector_data: VectorData, voronoi: bool) -> VectorData {
+	let mut points: Vec<_> = vector_data
+		.subpaths
+		.iter()
+		.flat_map(|subpath| subpath.manipulator_groups())
+		.map(|group| vector_data.transform.transform_point2(group.anchor))
+		.collect();
+	points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+	points.dedup();
+
+	let polygons = if voronoi {
+		let Some(bounds) = vector_data.bounding_box_with_transform(vector_data.transform) else {
+			return VectorData::empty();
+		};
+		super::delaunay::voronoi_cells(&points, bounds)
+	} else {
+		super::delaunay::delaunay_triangles(&points)
+			.into_iter()
+			.map(|triangle| triangle.map(|vertex| points[vertex]).to_vec())
+			.collect()
+	};
+
+	VectorData::from_subpaths(polygons.into_iter().filter(|polygon| polygon.len() >= 3).map(|polygon| Subpath::from_anchors(polygon, true)).collect())
+}
+
+/// The length and enclosed area of each subpath of a shape, measured in document space.
+#[derive(Debug, Clone, Default, PartialEq, DynAny)]
+pub struct VectorMeasurements {
+	pub lengths: Vec<f64>,
+	pub areas: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MeasureNode<CloseOpenSubpaths> {
+	close_open_subpaths: CloseOpenSubpaths,
+}
+
+/// Measures each subpath with the shape's transform applied. Open subpaths enclose no area unless `close_open_subpaths` closes them with a straight line, the way fills do.
+#[node_macro::node_fn(MeasureNode)]
+fn measure_vector_data(vector_data: VectorData, close_open_subpaths: bool) -> VectorMeasurements {
+	let mut measurements = VectorMeasurements::default();
+	for subpath in &vector_data.subpaths {
+		let mut subpath = subpath.clone();
+		subpath.apply_transform(vector_data.transform);
+
+		measurements.lengths.push(subpath.length(None));
+		measurements.areas.push(if subpath.closed() || close_open_subpaths { enclosed_area(&subpath) } else { 0. });
+	}
+	measurements
+}
+
+/// The area enclosed by the subpath, which is closed with a straight line if it's open. Self-overlapping parts of opposite winding cancel out.
+fn enclosed_area(subpath: &Subpath<ManipulatorGroupId>) -> f64 {
+	let mut doubled_area: f64 = subpath.iter().map(|bezier| doubled_signed_area(&bezier)).sum();
+	if !subpath.closed() {
+		if let (Some(first), Some(last)) = (subpath.manipulator_groups().first(), subpath.manipulator_groups().last()) {
+			doubled_area += last.anchor.perp_dot(first.anchor);
+		}
+	}
+	(doubled_area / 2.).abs()
+}
+
+/// Twice the signed area swept between the segment and the origin, found by integrating `x dy - y dx` along the curve
+fn doubled_signed_area(bezier: &Bezier) -> f64 {
+	let (start, end) = (bezier.start(), bezier.end());
+	let [handle_start, handle_end] = match (bezier.handle_start(), bezier.handle_end()) {
+		(Some(handle_start), Some(handle_end)) => [handle_start, handle_end],
+		// Elevate quadratic segments to cubic ones
+		(Some(handle), None) => [start + (handle - start) * (2. / 3.), end + (handle - end) * (2. / 3.)],
+		_ => return start.perp_dot(end),
+	};
+
+	let points = [start, handle_start, handle_end, end];
+	let cross = |a: usize, b: usize| points[a].perp_dot(points[b]);
+	(6. * cross(0, 1) + 3. * cross(0, 2) + cross(0, 3) + 3. * cross(1, 2) + 3. * cross(1, 3) + 6. * cross(2, 3)) / 10.
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MeasuredLengthNode;
+
+#[node_macro::node_fn(MeasuredLengthNode)]
+fn measured_length(measurements: VectorMeasurements) -> f64 {
+	measurements.lengths.iter().sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MeasuredAreaNode;
+
+#[node_macro::node_fn(MeasuredAreaNode)]
+fn measured_area(measurements: VectorMeasurements) -> f64 {
+	measurements.areas.iter().sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ForEachSubpathNode<Lambda> {
+	lambda: Lambda,
+}
+
+/// Runs the lambda on each subpath as a shape of its own, sharing the style and transform of the whole shape, then collects the returned subpaths back into one shape.
+impl<'i, Lambda: 'i> Node<'i, VectorData> for ForEachSubpathNode<Lambda>
+where
+	Lambda: for<'a> Node<'a, VectorData>,
+	for<'a> <Lambda as Node<'a, VectorData>>::Output: Future<Output = VectorData> + 'a,
+{
+	type Output = Pin<Box<dyn Future<Output = VectorData> + 'i>>;
+	fn eval(&'i self, vector_data: VectorData) -> Self::Output {
+		Box::pin(async move {
+			let mut single_subpath = VectorData::empty();
+			single_subpath.transform = vector_data.transform;
+			single_subpath.style = vector_data.style.clone();
+			let mut subpaths = Vec::with_capacity(vector_data.subpaths.len());
+
+			for subpath in &vector_data.subpaths {
+				single_subpath.subpaths = vec![subpath.clone()];
+				let mapped = self.lambda.eval(single_subpath.clone()).await;
+
+				// The lambda may have changed the transform, so its subpaths are brought back into the space of the original shape
+				let to_original = vector_data.transform.inverse() * mapped.transform;
+				subpaths.extend(mapped.subpaths.into_iter().map(|mut subpath| {
+					if mapped.transform != vector_data.transform {
+						subpath.apply_transform(to_original);
+					}
+					subpath
+				}));
+			}
+
+			VectorData { subpaths, ..vector_data }
+		})
+	}
+}
+
+impl<Lambda> ForEachSubpathNode<Lambda> {
+	pub const fn new(lambda: Lambda) -> Self {
+		Self { lambda }
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MeasuredSubpathLengthsNode;
+
+#[node_macro::node_fn(MeasuredSubpathLengthsNode)]
+fn measured_subpath_lengths(measurements: VectorMeasurements) -> Vec<f64> {
+	measurements.lengths
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MeasuredSubpathAreasNode;
+
+#[node_macro::node_fn(MeasuredSubpathAreasNode)]
+fn measured_subpath_areas(measurements: VectorMeasurements) -> Vec<f64> {
+	measurements.areas
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+	use core::f64::consts::{PI, TAU};
+
+	fn measure(vector_data: VectorData, close_open_subpaths: bool) -> VectorMeasurements {
+		MeasureNode::new(ClonedNode::new(close_open_subpaths)).eval(vector_data)
+	}
+
+	#[test]
+	fn unit_circle() {
+		let circle = VectorData::from_subpath(Subpath::new_ellipse(DVec2::splat(-1.), DVec2::splat(1.)));
+		let measurements = measure(circle, false);
+
+		assert!((measurements.lengths[0] - TAU).abs() < 1e-2, "length {}", measurements.lengths[0]);
+		assert!((measurements.areas[0] - PI).abs() < 1e-2, "area {}", measurements.areas[0]);
+	}
+
+	#[test]
+	fn transform_is_applied() {
+		let mut circle = VectorData::from_subpath(Subpath::new_ellipse(DVec2::splat(-1.), DVec2::splat(1.)));
+		circle.transform = DAffine2::from_scale_angle_translation(DVec2::splat(2.), 1., DVec2::new(5., -3.));
+		let measurements = measure(circle, false);
+
+		assert!((measurements.lengths[0] - 2. * TAU).abs() < 2e-2);
+		assert!((measurements.areas[0] - 4. * PI).abs() < 4e-2);
+	}
+
+	#[test]
+	fn open_subpaths_are_optionally_closed() {
+		let corner = Subpath::from_anchors([DVec2::ZERO, DVec2::new(2., 0.), DVec2::new(2., 2.)], false);
+		let square = Subpath::new_rect(DVec2::new(10., 10.), DVec2::new(13., 13.));
+		let shape = VectorData::from_subpaths(vec![corner, square]);
+
+		let open = measure(shape.clone(), false);
+		assert_eq!(open.lengths, vec![4., 12.]);
+		assert_eq!(open.areas, vec![0., 9.]);
+
+		let closed = measure(shape, true);
+		assert_eq!(closed.lengths, vec![4., 12.], "Closing only affects the area");
+		assert_eq!(closed.areas, vec![2., 9.]);
+
+		assert_eq!(MeasuredLengthNode::new().eval(closed.clone()), 16.);
+		assert_eq!(MeasuredAreaNode::new().eval(closed.clone()), 11.);
+		assert_eq!(MeasuredSubpathLengthsNode::new().eval(closed.clone()), vec![4., 12.]);
+		assert_eq!(MeasuredSubpathAreasNode::new().eval(closed), vec![2., 9.]);
+	}
+
+	#[test]
+	fn mirror_across_x_axis() {
+		let rectangle = VectorData::from_subpath(Subpath::new_rect(DVec2::new(1., 1.), DVec2::new(2., 3.)));
+		let mirror = |axis: MirrorAxis, angle: f64, keep_original: bool, vector_data: VectorData| {
+			MirrorNode::new(ClonedNode::new(axis), ClonedNode::new(DVec2::ZERO), ClonedNode::new(angle), ClonedNode::new(keep_original)).eval(vector_data)
+		};
+
+		let symmetric = mirror(MirrorAxis::Horizontal, 0., true, rectangle.clone());
+		assert_eq!(symmetric.subpaths.len(), 2);
+		assert_eq!(symmetric.subpaths[0], rectangle.subpaths[0]);
+		assert_eq!(symmetric.bounding_box(), Some([DVec2::new(1., -3.), DVec2::new(2., 3.)]));
+		let areas = measure(symmetric, false).areas;
+		assert_eq!(areas, vec![2., 2.]);
+
+		let flipped = mirror(MirrorAxis::Horizontal, 0., false, rectangle.clone());
+		assert_eq!(flipped.subpaths.len(), 1);
+		assert_eq!(flipped.bounding_box(), Some([DVec2::new(1., -3.), DVec2::new(2., -1.)]));
+
+		let vertical = mirror(MirrorAxis::Vertical, 0., false, rectangle.clone());
+		let custom = mirror(MirrorAxis::Custom, 90., false, rectangle);
+		assert_eq!(vertical.bounding_box(), Some([DVec2::new(-2., 1.), DVec2::new(-1., 3.)]));
+		let [custom_min, custom_max] = custom.bounding_box().unwrap();
+		assert!(custom_min.abs_diff_eq(DVec2::new(-2., 1.), 1e-9) && custom_max.abs_diff_eq(DVec2::new(-1., 3.), 1e-9));
+	}
+
+	#[test]
+	fn mirror_keeps_the_winding_and_transform() {
+		let mut rectangle = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		rectangle.transform = DAffine2::from_translation(DVec2::new(0., 5.));
+		let node = MirrorNode::new(ClonedNode::new(MirrorAxis::Horizontal), ClonedNode::new(DVec2::new(0., 3.)), ClonedNode::new(0.), ClonedNode::new(true));
+		let symmetric = node.eval(rectangle.clone());
+
+		assert_eq!(symmetric.transform, rectangle.transform);
+		assert_eq!(symmetric.bounding_box_with_transform(symmetric.transform), Some([DVec2::ZERO, DVec2::new(1., 6.)]));
+		let winding = |subpath: &Subpath<ManipulatorGroupId>| subpath.iter().map(|bezier| doubled_signed_area(&bezier)).sum::<f64>().signum();
+		assert_eq!(winding(&symmetric.subpaths[0]), winding(&symmetric.subpaths[1]));
+	}
+
+	#[test]
+	fn trim_path_keeps_part_of_the_length() {
+		let trim = |start: f64, end: f64, offset: f64, wrap: bool, vector_data: VectorData| {
+			TrimPathNode::new(ClonedNode::new(start), ClonedNode::new(end), ClonedNode::new(offset), ClonedNode::new(wrap)).eval(vector_data)
+		};
+		let circle = VectorData::from_subpath(Subpath::new_ellipse(DVec2::splat(-1.), DVec2::splat(1.)));
+		let line = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::new(4., 0.), DVec2::new(4., 4.)], false));
+		let length = |vector_data: VectorData| measure(vector_data, false).lengths;
+
+		assert_eq!(trim(0., 1., 0., true, circle.clone()), circle);
+		assert_eq!(trim(0., 1., 0.3, false, line.clone()), line);
+
+		let half_circle = trim(0., 0.5, 0., true, circle.clone());
+		assert!(!half_circle.subpaths[0].closed());
+		assert!((length(half_circle)[0] - core::f64::consts::PI).abs() < 1e-2);
+		let half_line = trim(0., 0.5, 0., true, line.clone());
+		assert!(half_line.subpaths[0].manipulator_groups().last().unwrap().anchor.abs_diff_eq(DVec2::new(4., 0.), 1e-4));
+		assert!((length(half_line)[0] - 4.).abs() < 1e-4);
+
+		// The last quarter and the first quarter
+		let wrapped = trim(0.75, 0.25, 0., true, line.clone());
+		assert_eq!(wrapped.subpaths.len(), 2);
+		assert!(length(wrapped).iter().all(|length| (length - 2.).abs() < 1e-4));
+		let swapped = trim(0.75, 0.25, 0., false, line.clone());
+		assert_eq!(swapped.subpaths.len(), 1);
+		assert!((length(swapped)[0] - 4.).abs() < 1e-4);
+		let offset_circle = trim(0., 0.25, 0.875, true, circle);
+		assert_eq!(offset_circle.subpaths.len(), 1, "A closed subpath is trimmed across its closing point in one piece");
+		assert!((length(offset_circle)[0] - core::f64::consts::FRAC_PI_2).abs() < 1e-2);
+
+		assert!(trim(0.5, 0.5, 0., true, line).subpaths.is_empty());
+	}
+
+	#[test]
+	fn dashes_become_subpaths() {
+		let dash = |dash_lengths: Vec<f32>, dash_offset: f32, vector_data: VectorData| DashToGeometryNode::new(ClonedNode::new(dash_lengths), ClonedNode::new(dash_offset)).eval(vector_data);
+		let mut line = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::new(100., 0.)], false));
+		line.style.set_stroke(Stroke {
+			dash_lengths: vec![10., 10.],
+			..Stroke::new(Some(Color::BLACK), 2.)
+		});
+
+		let dashes = dash(vec![10., 10.], 0., line.clone());
+		assert_eq!(dashes.subpaths.len(), 5);
+		assert!(dashes.subpaths.iter().all(|dash| !dash.closed()));
+		assert!(measure(dashes.clone(), false).lengths.iter().all(|length| (length - 10.).abs() < 1e-3));
+		assert!(dashes.subpaths[1].manipulator_groups()[0].anchor.abs_diff_eq(DVec2::new(20., 0.), 1e-3));
+		assert_eq!(dashes.style.stroke(), Some(Stroke::new(Some(Color::BLACK), 2.)), "The dashes are now geometry");
+
+		// Starting halfway into the first dash leaves a short dash at each end
+		let offset = dash(vec![10., 10.], 5., line.clone());
+		assert_eq!(offset.subpaths.len(), 6);
+		let lengths = measure(offset, false).lengths;
+		assert!((lengths[0] - 5.).abs() < 1e-3 && (lengths[5] - 5.).abs() < 1e-3);
+
+		// An odd pattern is repeated, so it alternates between 10 long dashes and 10 long gaps
+		assert_eq!(dash(vec![10.], 0., line.clone()).subpaths.len(), 5);
+		assert_eq!(dash(Vec::new(), 0., line.clone()), line);
+
+		// Each subpath restarts the pattern, including the closing segment of closed subpaths
+		let square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(20.)));
+		assert_eq!(dash(vec![5., 5.], 0., square).subpaths.len(), 8);
+
+		// The pattern is measured after the transform, so scaling the line up doubles the number of dashes but keeps them in the same space
+		let mut scaled = line.clone();
+		scaled.transform = DAffine2::from_scale(DVec2::splat(2.));
+		let scaled_dashes = dash(vec![10., 10.], 0., scaled);
+		assert_eq!(scaled_dashes.subpaths.len(), 10);
+		assert!(scaled_dashes.subpaths[1].manipulator_groups()[0].anchor.abs_diff_eq(DVec2::new(10., 0.), 1e-3));
+		assert!(measure(scaled_dashes, false).lengths.iter().all(|length| (length - 10.).abs() < 1e-3));
+	}
+
+	#[test]
+	fn delaunay_triangles_and_voronoi_cells() {
+		let points = [(0., 0.), (2., 0.), (2., 2.), (0., 2.), (1., 1.)].map(|(x, y)| DVec2::new(x, y));
+		let mut shape = VectorData::from_subpath(Subpath::from_anchors(points, true));
+		shape.transform = DAffine2::from_scale(DVec2::splat(3.));
+
+		let triangles = DelaunayNode::new(ClonedNode::new(false)).eval(shape.clone());
+		assert_eq!(triangles.subpaths.len(), 4);
+		assert!(triangles.subpaths.iter().all(|triangle| triangle.closed() && triangle.len() == 3));
+
+		let cells = DelaunayNode::new(ClonedNode::new(true)).eval(shape);
+		assert_eq!(cells.subpaths.len(), 5);
+		let area: f64 = measure(cells, false).areas.iter().sum();
+		assert!((area - 36.).abs() < 1e-9, "The cells should tile the transformed bounding box");
+	}
+
+	#[test]
+	fn square_round_trips_through_points() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		square.transform = DAffine2::from_translation(DVec2::new(5., 0.));
+
+		let points = PathToPointsNode::new().eval(square);
+		assert_eq!(points.0, [(5., 0.), (6., 0.), (6., 1.), (5., 1.)].map(|(x, y)| DVec2::new(x, y)));
+
+		let path = PointsToPathNode::new(ClonedNode::new(true), ClonedNode::new(false)).eval(points.clone());
+		assert_eq!(anchors(&path), points.0);
+		assert!(path.subpaths[0].closed());
+		let measurements = measure(path, false);
+		assert_eq!((measurements.lengths[0], measurements.areas[0]), (4., 1.));
+
+		let open = PointsToPathNode::new(ClonedNode::new(false), ClonedNode::new(false)).eval(points);
+		assert!(!open.subpaths[0].closed());
+		assert_eq!(measure(open, false).lengths[0], 3.);
+	}
+
+	#[test]
+	fn smooth_path_passes_through_the_points() {
+		let points = VectorPoints([(0., 0.), (1., 1.), (2., 0.), (3., 1.)].map(|(x, y)| DVec2::new(x, y)).to_vec());
+		let path = PointsToPathNode::new(ClonedNode::new(false), ClonedNode::new(true)).eval(points.clone());
+		assert_eq!(anchors(&path), points.0);
+
+		let groups = path.subpaths[0].manipulator_groups();
+		// The handles of an inner point are mirrored along the direction between its neighbors
+		assert_eq!(groups[1].in_handle, Some(DVec2::new(1. - 1. / 3., 1.)));
+		assert_eq!(groups[1].out_handle, Some(DVec2::new(1. + 1. / 3., 1.)));
+		assert!(measure(path, false).lengths[0] > 3. * 2_f64.sqrt(), "Curving through the points is longer than the straight polyline");
+	}
+
+	#[test]
+	fn bounds_of_a_transformed_ellipse() {
+		let mut ellipse = VectorData::from_subpath(Subpath::new_ellipse(DVec2::ZERO, DVec2::new(4., 2.)));
+		ellipse.transform = DAffine2::from_scale_angle_translation(DVec2::splat(2.), 0., DVec2::new(10., 0.));
+		let bounds = BoundsNode::new().eval(ellipse);
+
+		let values = [
+			BoundsMinNode::new().eval(bounds),
+			BoundsMaxNode::new().eval(bounds),
+			BoundsCenterNode::new().eval(bounds),
+			BoundsSizeNode::new().eval(bounds),
+		];
+		let expected = [(10., 0.), (18., 4.), (14., 2.), (8., 4.)].map(|(x, y)| DVec2::new(x, y));
+		for (value, expected) in values.into_iter().zip(expected) {
+			assert!(value.abs_diff_eq(expected, 1e-9), "{value} should be {expected}");
+		}
+
+		assert_eq!(BoundsNode::new().eval(VectorData::empty()), VectorBounds::default());
+	}
+
+	fn anchors(vector_data: &VectorData) -> Vec<DVec2> {
+		vector_data.subpaths.iter().flat_map(|subpath| subpath.manipulator_groups().iter().map(|group| group.anchor)).collect()
+	}
+
+	#[test]
+	fn convex_hull_of_points() {
+		let points = [(0., 0.), (1., 1.), (4., 0.), (2., 0.), (3., 2.), (4., 4.), (1., 3.), (0., 4.), (2., 2.)].map(|(x, y)| DVec2::new(x, y));
+		let hull = ConvexHullNode::new().eval(VectorData::from_subpath(Subpath::from_anchors(points, false)));
+
+		assert_eq!(hull.subpaths.len(), 1);
+		assert!(hull.subpaths[0].closed());
+		let corners = [(0., 0.), (4., 0.), (4., 4.), (0., 4.)].map(|(x, y)| DVec2::new(x, y));
+		assert_eq!(anchors(&hull), corners, "Inner and edge points are left out");
+	}
+
+	#[test]
+	fn convex_hull_includes_handles_and_transform() {
+		let mut circle = VectorData::from_subpath(Subpath::new_ellipse(DVec2::splat(-1.), DVec2::splat(1.)));
+		circle.transform = DAffine2::from_translation(DVec2::new(10., 0.));
+		let hull = ConvexHullNode::new().eval(circle);
+
+		// The anchors sit between pairs of handles, so only the eight handles are corners
+		let corners = anchors(&hull);
+		assert_eq!(corners.len(), 8);
+		for offset in corners.iter().map(|corner| (*corner - DVec2::new(10., 0.)).abs()) {
+			assert!((offset.max_element() - 1.).abs() < 1e-9, "{offset}");
+			assert!((offset.min_element() - 0.55).abs() < 1e-2, "{offset}");
+		}
+	}
+
+	#[test]
+	fn convex_hull_of_degenerate_points() {
+		let line = Subpath::from_anchors([DVec2::ZERO, DVec2::new(1., 1.), DVec2::new(3., 3.), DVec2::new(1., 1.)], false);
+		let hull = ConvexHullNode::new().eval(VectorData::from_subpath(line));
+		assert!(!hull.subpaths[0].closed());
+		assert_eq!(anchors(&hull), vec![DVec2::ZERO, DVec2::new(3., 3.)]);
+
+		let point = VectorData::from_subpath(Subpath::from_anchors([DVec2::ONE, DVec2::ONE], false));
+		assert_eq!(ConvexHullNode::new().eval(point.clone()), point);
+		assert_eq!(ConvexHullNode::new().eval(VectorData::empty()), VectorData::empty());
+	}
+
+	#[test]
+	fn stroke_alignment_offsets_closed_shapes() {
+		use crate::vector::style::{LineCap, LineJoin};
+
+		let stroke = |align: StrokeAlign, vector_data: VectorData| {
+			let node = SetStrokeNode::new(
+				ClonedNode::new(Some(Color::BLACK)),
+				ClonedNode::new(2_f32),
+				ClonedNode::new(Vec::<f32>::new()),
+				ClonedNode::new(0_f32),
+				ClonedNode::new(LineCap::Butt),
+				ClonedNode::new(LineJoin::Miter),
+				ClonedNode::new(4_f32),
+				ClonedNode::new(align),
+				ClonedNode::new(FillType::Solid),
+				ClonedNode::new(GradientType::Linear),
+				ClonedNode::new(DVec2::ZERO),
+				ClonedNode::new(DVec2::X),
+				ClonedNode::new(DAffine2::IDENTITY),
+				ClonedNode::new(Vec::<(f64, Option<Color>)>::new()),
+			);
+			node.eval(vector_data)
+		};
+		let assert_bounds = |vector_data: VectorData, min: f64, max: f64| {
+			let [found_min, found_max] = vector_data.bounding_box_with_transform(vector_data.transform).unwrap();
+			assert!(
+				found_min.abs_diff_eq(DVec2::splat(min), 1e-9) && found_max.abs_diff_eq(DVec2::splat(max), 1e-9),
+				"{found_min} {found_max}"
+			);
+		};
+
+		// A square 10 units wide where it's drawn, so the offset has to take the transform into account
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(5.)));
+		square.transform = DAffine2::from_scale(DVec2::splat(2.));
+
+		let center = stroke(StrokeAlign::Center, square.clone());
+		assert!(center.subpaths == square.subpaths, "A centered stroke leaves the geometry as it is");
+		assert_eq!(center.style.stroke().map(|stroke| stroke.weight), Some(2.));
+
+		assert_bounds(stroke(StrokeAlign::Inside, square.clone()), 1., 9.);
+		assert_bounds(stroke(StrokeAlign::Outside, square.clone()), -1., 11.);
+
+		let mut reversed = square.clone();
+		reversed.subpaths[0] = reversed.subpaths[0].reverse();
+		assert_bounds(stroke(StrokeAlign::Inside, reversed), 1., 9.);
+
+		let line = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::X], false));
+		assert!(stroke(StrokeAlign::Inside, line.clone()).subpaths == line.subpaths, "Open subpaths have no inside to move towards");
+	}
+}
@@ -44,6 +44,19 @@ pub struct DocumentNode {
 }
 
 impl DocumentNode {
+	/// The ids of the nodes connected to this node's inputs.
+	pub fn upstream_node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+		self.inputs.iter().filter_map(|input| if let NodeInput::Node { node_id, .. } = input { Some(*node_id) } else { None })
+	}
+
+	/// The number of outputs the node provides, which is one unless it is implemented by a network.
+	pub fn output_count(&self) -> usize {
+		match &self.implementation {
+			DocumentNodeImplementation::Network(network) => network.outputs.len(),
+			_ => 1,
+		}
+	}
+
 	pub fn populate_first_network_input(&mut self, node_id: NodeId, output_index: usize, offset: usize, lambda: bool) {
 		let (index, _) = self
 			.inputs
@@ -56,6 +69,19 @@ impl DocumentNode {
 		self.inputs[index] = NodeInput::Node { node_id, output_index, lambda };
 	}
 
+	/// Makes the first network input, skipping `offset` of them, take the argument this node is called with, like a lambda does.
+	pub fn short_circuit_first_network_input(&mut self, ty: Type, offset: usize) {
+		let (index, _) = self
+			.inputs
+			.iter()
+			.enumerate()
+			.filter(|(_, input)| matches!(input, NodeInput::Network(_) | NodeInput::ShortCircut(_)))
+			.nth(offset)
+			.unwrap_or_else(|| panic!("no network input found for {self:#?} and offset: {offset}"));
+
+		self.inputs[index] = NodeInput::ShortCircut(ty);
+	}
+
 	fn resolve_proto_node(mut self) -> ProtoNode {
 		assert_ne!(self.inputs.len(), 0, "Resolving document node {:#?} with no inputs", self);
 		let first = self.inputs.remove(0);
@@ -220,7 +246,8 @@ impl NodeInput {
 			NodeInput::Node { .. } => true,
 			NodeInput::Value { exposed, .. } => *exposed,
 			NodeInput::Network(_) => false,
-			NodeInput::ShortCircut(_) => false,
+			// Short circuiting inputs of nodes in the graph are the lambda inputs that call the node connected to them
+			NodeInput::ShortCircut(_) => true,
 			NodeInput::Inline(_) => false,
 		}
 	}
@@ -281,14 +308,26 @@ impl NodeOutput {
 	}
 }
 
+/// The proto nodes whose output is their first input, which lets [`NodeNetwork::validate`] follow types through them
+const PASS_THROUGH_NODES: [&str; 4] = [
+	"graphene_core::ops::IdNode",
+	"graphene_core::ops::CloneNode",
+	"graphene_core::value::ClonedNode",
+	"graphene_core::value::ValueNode",
+];
+
 #[derive(Clone, Debug, Default, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NodeNetwork {
 	pub inputs: Vec<NodeId>,
 	pub outputs: Vec<NodeOutput>,
+	#[cfg_attr(feature = "serde", serde(serialize_with = "serialize_nodes_sorted"))]
 	pub nodes: HashMap<NodeId, DocumentNode>,
 	/// These nodes are replaced with identity nodes when flattening
 	pub disabled: Vec<NodeId>,
+	/// These nodes are bypassed from their properties, which also replaces them with identity nodes when flattening so their primary input passes straight through
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub bypassed: Vec<NodeId>,
 	/// In the case where a new node is chosen as output - what was the original
 	pub previous_outputs: Option<Vec<NodeOutput>>,
 }
@@ -304,10 +343,34 @@ impl std::hash::Hash for NodeNetwork {
 			node.hash(state);
 		}
 		self.disabled.hash(state);
+		self.bypassed.hash(state);
 		self.previous_outputs.hash(state);
 	}
 }
 
+/// Serializes the nodes ordered by their id so the same network always produces the same output
+#[cfg(feature = "serde")]
+fn serialize_nodes_sorted<S: serde::Serializer>(nodes: &HashMap<NodeId, DocumentNode>, serializer: S) -> Result<S::Ok, S::Error> {
+	use serde::Serialize;
+
+	let nodes: std::collections::BTreeMap<_, _> = nodes.iter().collect();
+	nodes.serialize(serializer)
+}
+
+/// Interchange functions for external tooling
+#[cfg(feature = "serde")]
+impl NodeNetwork {
+	/// Serializes the network to JSON. The output is deterministic, so it can be diffed or generated by tools outside the editor.
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string_pretty(self)
+	}
+
+	/// Deserializes a network previously produced by [`NodeNetwork::to_json`] or written by an external tool.
+	pub fn from_json(json: &str) -> serde_json::Result<Self> {
+		serde_json::from_str(json)
+	}
+}
+
 /// Graph modification functions
 impl NodeNetwork {
 	/// Get the original output nodes of this network, ignoring any preview node
@@ -325,6 +388,7 @@ impl NodeNetwork {
 			outputs: vec![NodeOutput::new(0, 0)],
 			nodes: [(0, node)].into_iter().collect(),
 			disabled: vec![],
+			bypassed: vec![],
 			previous_outputs: None,
 		}
 	}
@@ -534,6 +598,397 @@ impl NodeNetwork {
 	}
 }
 
+/// An issue with a [NodeNetwork] found by [NodeNetwork::validate].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkDiagnostic {
+	/// The ids of the nodes containing the nested network the issue was found in, empty for the network being validated.
+	pub network_path: Vec<NodeId>,
+	/// The node with the issue, or the node referenced by the network's inputs or outputs.
+	pub node_id: NodeId,
+	/// The input of the node with the issue, if the issue is specific to one input.
+	pub input_index: Option<usize>,
+	pub message: String,
+}
+
+/// Functions for validating the network
+impl NodeNetwork {
+	/// Walks the network and any nested networks, reporting inputs that reference nonexistent nodes or outputs,
+	/// values whose types don't match the nested network input they feed, and nodes that are part of a cycle.
+	pub fn validate(&self) -> Vec<NetworkDiagnostic> {
+		let mut diagnostics = Vec::new();
+		self.validate_nested(&mut Vec::new(), &mut diagnostics);
+		diagnostics
+	}
+
+	fn validate_nested(&self, network_path: &mut Vec<NodeId>, diagnostics: &mut Vec<NetworkDiagnostic>) {
+		let mut diagnostic = |node_id, input_index, message: String| {
+			diagnostics.push(NetworkDiagnostic {
+				network_path: network_path.clone(),
+				node_id,
+				input_index,
+				message,
+			})
+		};
+
+		for &input in &self.inputs {
+			if !self.nodes.contains_key(&input) {
+				diagnostic(input, None, format!("The network input references node {input}, which does not exist"));
+			}
+		}
+		for output in &self.outputs {
+			match self.nodes.get(&output.node_id) {
+				None => diagnostic(output.node_id, None, format!("The network output references node {}, which does not exist", output.node_id)),
+				Some(node) if output.node_output_index >= node.output_count() => diagnostic(
+					output.node_id,
+					None,
+					format!(
+						"The network output references output {} of '{}', which only has {}",
+						output.node_output_index,
+						node.name,
+						node.output_count()
+					),
+				),
+				Some(_) => {}
+			}
+		}
+
+		let mut node_ids: Vec<_> = self.nodes.keys().copied().collect();
+		node_ids.sort_unstable();
+
+		for &node_id in &node_ids {
+			let node = &self.nodes[&node_id];
+			let expected_types = node.implementation.get_network().map(NodeNetwork::network_input_types);
+
+			for (input_index, input) in node.inputs.iter().enumerate() {
+				match input {
+					NodeInput::Node {
+						node_id: source_id,
+						output_index,
+						lambda,
+					} => match self.nodes.get(source_id) {
+						None => diagnostic(node_id, Some(input_index), format!("Input references node {source_id}, which does not exist")),
+						Some(source) if *output_index >= source.output_count() => diagnostic(
+							node_id,
+							Some(input_index),
+							format!("Input references output {output_index} of '{}', which only has {}", source.name, source.output_count()),
+						),
+						// Lambdas pass the node itself rather than its output, so there's no output type to compare
+						Some(_) if *lambda => {}
+						Some(source) => {
+							let expected = expected_types.as_ref().and_then(|types| types.get(input_index).cloned().flatten());
+							let found = self.output_type(*source_id, *output_index, &mut Vec::new());
+							if let Some((expected, found)) = expected.zip(found).filter(|(expected, found)| !expected.is_generic() && !found.is_generic() && expected != found) {
+								diagnostic(node_id, Some(input_index), format!("Input receives {found} from '{}' but the network expects {expected}", source.name));
+							}
+						}
+					},
+					NodeInput::Value { tagged_value, .. } => {
+						let Some(expected) = expected_types.as_ref().and_then(|types| types.get(input_index).cloned().flatten()) else {
+							continue;
+						};
+						let found = tagged_value.ty();
+						if !expected.is_generic() && expected != found {
+							diagnostic(node_id, Some(input_index), format!("Input has a value of type {found} but the network expects {expected}"));
+						}
+					}
+					_ => {}
+				}
+			}
+
+			if let Some(expected_types) = &expected_types {
+				if expected_types.len() != node.inputs.len() {
+					diagnostic(node_id, None, format!("'{}' has {} inputs but its network has {}", node.name, node.inputs.len(), expected_types.len()));
+				}
+			}
+		}
+
+		for node_id in self.nodes_in_cycles() {
+			diagnostic(node_id, None, format!("'{}' is part of a cycle", self.nodes[&node_id].name));
+		}
+
+		for node_id in node_ids {
+			if let DocumentNodeImplementation::Network(nested_network) = &self.nodes[&node_id].implementation {
+				network_path.push(node_id);
+				nested_network.validate_nested(network_path, diagnostics);
+				network_path.pop();
+			}
+		}
+	}
+
+	/// The type each of the network's inputs expects, matching them to the `Network` inputs of their nodes the same way as [NodeNetwork::flatten].
+	fn network_input_types(&self) -> Vec<Option<Type>> {
+		let mut offsets: HashMap<NodeId, usize> = HashMap::new();
+		self.inputs
+			.iter()
+			.map(|input_node| {
+				let offset = offsets.entry(*input_node).or_insert(0);
+				let ty = self.nodes.get(input_node).and_then(|node| {
+					node.inputs
+						.iter()
+						.filter_map(|input| if let NodeInput::Network(ty) = input { Some(ty.clone()) } else { None })
+						.nth(*offset)
+				});
+				*offset += 1;
+				ty
+			})
+			.collect()
+	}
+
+	/// The type of an output of a node, when it can be worked out from the document alone without the node registry.
+	/// That's the case for values and network inputs, which identity and value nodes pass through unchanged, and for the outputs of nested networks built from those.
+	fn output_type(&self, node_id: NodeId, output_index: usize, visited: &mut Vec<NodeId>) -> Option<Type> {
+		// Cycles have no type to find, and are reported on their own
+		if visited.contains(&node_id) {
+			return None;
+		}
+		visited.push(node_id);
+
+		let node = self.nodes.get(&node_id)?;
+		match &node.implementation {
+			DocumentNodeImplementation::Network(network) => {
+				let output = network.outputs.get(output_index)?;
+				network.output_type(output.node_id, output.node_output_index, &mut Vec::new())
+			}
+			DocumentNodeImplementation::Unresolved(identifier) if output_index == 0 && PASS_THROUGH_NODES.contains(&identifier.name.as_ref()) => match node.inputs.first()? {
+				NodeInput::Value { tagged_value, .. } => Some(tagged_value.ty()),
+				NodeInput::Network(ty) => Some(ty.clone()),
+				NodeInput::Node { node_id, output_index, lambda: false } => self.output_type(*node_id, *output_index, visited),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// The ids of all nodes that can reach themselves by following their inputs, in ascending order.
+	pub fn nodes_in_cycles(&self) -> Vec<NodeId> {
+		let mut in_cycles: Vec<_> = self
+			.nodes
+			.iter()
+			.filter(|(&node_id, node)| node.upstream_node_ids().any(|source| self.is_upstream_of(node_id, source)))
+			.map(|(&node_id, _)| node_id)
+			.collect();
+		in_cycles.sort_unstable();
+		in_cycles
+	}
+
+	/// Whether `target` can be reached from `start` by following node inputs, including when they are the same node.
+	fn is_upstream_of(&self, target: NodeId, start: NodeId) -> bool {
+		let mut visited = HashSet::new();
+		let mut stack = vec![start];
+		while let Some(node_id) = stack.pop() {
+			if node_id == target {
+				return true;
+			}
+			if visited.insert(node_id) {
+				stack.extend(self.nodes.get(&node_id).into_iter().flat_map(DocumentNode::upstream_node_ids));
+			}
+		}
+		false
+	}
+
+	/// Disconnects inputs until neither the network nor any nested network contains a cycle, since a cyclic graph can't be compiled.
+	/// Each disconnected input is replaced with an empty value and reported so the caller can warn about it.
+	pub fn break_cycles(&mut self) -> Vec<NetworkDiagnostic> {
+		let mut diagnostics = Vec::new();
+		self.break_nested_cycles(&mut Vec::new(), &mut diagnostics);
+		diagnostics
+	}
+
+	fn break_nested_cycles(&mut self, network_path: &mut Vec<NodeId>, diagnostics: &mut Vec<NetworkDiagnostic>) {
+		while let Some(&node_id) = self.nodes_in_cycles().first() {
+			let inputs = &self.nodes[&node_id].inputs;
+			let cyclic_input = inputs
+				.iter()
+				.position(|input| matches!(input, NodeInput::Node { node_id: source, .. } if self.is_upstream_of(node_id, *source)));
+			let Some(input_index) = cyclic_input else { break };
+
+			let node = self.nodes.get_mut(&node_id).unwrap();
+			node.inputs[input_index] = NodeInput::value(TaggedValue::None, false);
+			diagnostics.push(NetworkDiagnostic {
+				network_path: network_path.clone(),
+				node_id,
+				input_index: Some(input_index),
+				message: format!("Disconnected the input of '{}' because it formed a cycle", node.name),
+			});
+		}
+
+		let mut node_ids: Vec<_> = self.nodes.keys().copied().collect();
+		node_ids.sort_unstable();
+		for node_id in node_ids {
+			if let Some(nested_network) = self.nodes.get_mut(&node_id).and_then(|node| node.implementation.get_network_mut()) {
+				network_path.push(node_id);
+				nested_network.break_nested_cycles(network_path, diagnostics);
+				network_path.pop();
+			}
+		}
+	}
+}
+
+/// Functions for grouping nodes into nested networks
+impl NodeNetwork {
+	/// Moves the given nodes into a nested network, which becomes the implementation of a new node with the id `group_id` that takes their place.
+	/// Links from other nodes into the selection become inputs of the new node and links out of the selection become its outputs, so the network flattens to the same graph as before.
+	/// Fails without changing the network if a node is a network input or if the new node would be part of a cycle.
+	pub fn collapse_nodes(&mut self, node_ids: &[NodeId], group_id: NodeId) -> Result<(), String> {
+		if node_ids.is_empty() {
+			return Err("There are no nodes to collapse".to_string());
+		}
+		if self.nodes.contains_key(&group_id) {
+			return Err(format!("The id {group_id} of the new node is already in use"));
+		}
+		let selected = node_ids.iter().copied().collect::<HashSet<_>>();
+		for &node_id in &selected {
+			let node = self.nodes.get(&node_id).ok_or_else(|| format!("Node with id {node_id} does not exist"))?;
+			if self.inputs.contains(&node_id) || node.inputs.iter().any(|input| matches!(input, NodeInput::Network(_) | NodeInput::ShortCircut(_))) {
+				return Err(format!("The network input '{}' can't be collapsed", node.name));
+			}
+		}
+
+		let mut network = self.clone();
+		let mut inner_network = NodeNetwork::default();
+		let mut group_inputs = Vec::new();
+
+		// Links into the selection are replaced by network inputs of the nested network, in the order the inputs appear on each node
+		let mut sorted_ids = selected.iter().copied().collect::<Vec<_>>();
+		sorted_ids.sort_unstable();
+		for &node_id in &sorted_ids {
+			let mut node = network.nodes.remove(&node_id).unwrap();
+			for input in &mut node.inputs {
+				if let &mut NodeInput::Node { node_id: source, .. } = input {
+					if !selected.contains(&source) {
+						group_inputs.push(std::mem::replace(input, NodeInput::Network(generic!(T))));
+						inner_network.inputs.push(node_id);
+					}
+				}
+			}
+			inner_network.nodes.insert(node_id, node);
+		}
+
+		// Links out of the selection are rerouted through the outputs of the new node, starting with the network outputs
+		fn group_output(inner_outputs: &mut Vec<NodeOutput>, output: NodeOutput) -> usize {
+			inner_outputs.iter().position(|&existing| existing == output).unwrap_or_else(|| {
+				inner_outputs.push(output);
+				inner_outputs.len() - 1
+			})
+		}
+		for output in network.outputs.iter_mut().chain(network.previous_outputs.iter_mut().flatten()) {
+			if selected.contains(&output.node_id) {
+				*output = NodeOutput::new(group_id, group_output(&mut inner_network.outputs, *output));
+			}
+		}
+		let mut other_ids = network.nodes.keys().copied().collect::<Vec<_>>();
+		other_ids.sort_unstable();
+		for node_id in other_ids {
+			for input in &mut network.nodes.get_mut(&node_id).unwrap().inputs {
+				if let NodeInput::Node { node_id: source, output_index, .. } = input {
+					if selected.contains(source) {
+						*output_index = group_output(&mut inner_network.outputs, NodeOutput::new(*source, *output_index));
+						*source = group_id;
+					}
+				}
+			}
+		}
+
+		inner_network.disabled = network.disabled.iter().copied().filter(|id| selected.contains(id)).collect();
+		network.disabled.retain(|id| !selected.contains(id));
+		inner_network.bypassed = network.bypassed.iter().copied().filter(|id| selected.contains(id)).collect();
+		network.bypassed.retain(|id| !selected.contains(id));
+
+		let position_sum = inner_network.nodes.values().fold(IVec2::ZERO, |sum, node| sum + node.metadata.position);
+		let group_node = DocumentNode {
+			name: "Group".into(),
+			inputs: group_inputs,
+			implementation: DocumentNodeImplementation::Network(inner_network),
+			metadata: DocumentNodeMetadata::position(position_sum / selected.len() as i32),
+			..Default::default()
+		};
+		network.nodes.insert(group_id, group_node);
+
+		if network.nodes_in_cycles().contains(&group_id) {
+			return Err("Collapsing the nodes would create a cycle through a node outside of them".to_string());
+		}
+		*self = network;
+		Ok(())
+	}
+
+	/// Replaces a node implemented by a nested network with the nodes of that network, which is the inverse of [NodeNetwork::collapse_nodes].
+	/// Nested nodes keep their ids unless they clash with a node in this network, in which case they're given a new id from `gen_id`.
+	/// Returns the ids of the nodes that were moved into this network.
+	pub fn expand_node(&mut self, node_id: NodeId, gen_id: impl Fn() -> NodeId) -> Result<Vec<NodeId>, String> {
+		let node = self.nodes.get(&node_id).ok_or_else(|| format!("Node with id {node_id} does not exist"))?;
+		if node.implementation.get_network().is_none() {
+			return Err(format!("'{}' isn't implemented by a nested network", node.name));
+		}
+		let node = self.nodes.remove(&node_id).unwrap();
+		let DocumentNodeImplementation::Network(mut inner_network) = node.implementation else {
+			unreachable!("The node was checked to be a network");
+		};
+
+		let new_ids = inner_network
+			.nodes
+			.keys()
+			.map(|&id| (id, if self.nodes.contains_key(&id) { gen_id() } else { id }))
+			.collect::<HashMap<_, _>>();
+		inner_network.map_ids(|id| new_ids.get(&id).copied().unwrap_or(id));
+
+		// Connect the inputs of the node to the network inputs of the nested nodes, the same way flattening does
+		let mut network_offsets = HashMap::new();
+		for (document_input, &network_input) in node.inputs.into_iter().zip(inner_network.inputs.iter()) {
+			let offset = network_offsets.entry(network_input).or_insert(0);
+			let Some(inner_node) = inner_network.nodes.get_mut(&network_input) else { continue };
+			match document_input {
+				NodeInput::Network(_) => {
+					*offset += 1;
+					if let Some(index) = self.inputs.iter().position(|&id| id == node_id) {
+						self.inputs[index] = network_input;
+					}
+				}
+				NodeInput::Node { .. } | NodeInput::Value { .. } => {
+					let index = inner_node
+						.inputs
+						.iter()
+						.enumerate()
+						.filter(|(_, input)| matches!(input, NodeInput::Network(_) | NodeInput::ShortCircut(_)))
+						.nth(*offset);
+					if let Some((index, _)) = index {
+						inner_node.inputs[index] = document_input;
+					}
+				}
+				NodeInput::ShortCircut(_) | NodeInput::Inline(_) => (),
+			}
+		}
+
+		// Connect the nodes that used the outputs of the node to the nested nodes providing them
+		for (index, output) in inner_network.outputs.iter().enumerate() {
+			for lambda in [false, true] {
+				self.replace_node_inputs(
+					NodeInput::Node { node_id, output_index: index, lambda },
+					NodeInput::Node {
+						node_id: output.node_id,
+						output_index: output.node_output_index,
+						lambda,
+					},
+				);
+			}
+			self.replace_network_outputs(NodeOutput::new(node_id, index), *output);
+			for previous_output in self.previous_outputs.iter_mut().flatten() {
+				if *previous_output == NodeOutput::new(node_id, index) {
+					*previous_output = *output;
+				}
+			}
+		}
+
+		self.disabled.retain(|&id| id != node_id);
+		self.disabled.extend(inner_network.disabled);
+		self.bypassed.retain(|&id| id != node_id);
+		self.bypassed.extend(inner_network.bypassed);
+		let mut expanded_ids = inner_network.nodes.keys().copied().collect::<Vec<_>>();
+		expanded_ids.sort_unstable();
+		self.nodes.extend(inner_network.nodes);
+		Ok(expanded_ids)
+	}
+}
+
 struct FlowIter<'a> {
 	stack: Vec<NodeId>,
 	network: &'a NodeNetwork,
@@ -563,6 +1018,7 @@ impl NodeNetwork {
 		self.inputs.iter_mut().for_each(|id| *id = f(*id));
 		self.outputs.iter_mut().for_each(|output| output.node_id = f(output.node_id));
 		self.disabled.iter_mut().for_each(|id| *id = f(*id));
+		self.bypassed.iter_mut().for_each(|id| *id = f(*id));
 		self.previous_outputs
 			.iter_mut()
 			.for_each(|nodes| nodes.iter_mut().for_each(|output| output.node_id = f(output.node_id)));
@@ -668,7 +1124,7 @@ impl NodeNetwork {
 			return;
 		};
 
-		if self.disabled.contains(&id) {
+		if self.disabled.contains(&id) || self.bypassed.contains(&id) {
 			node.implementation = DocumentNodeImplementation::Unresolved("graphene_core::ops::IdNode".into());
 			node.inputs.drain(1..);
 			self.nodes.insert(id, node);
@@ -723,6 +1179,7 @@ impl NodeNetwork {
 			// Copy nodes from the inner network into the parent network
 			self.nodes.extend(inner_network.nodes);
 			self.disabled.extend(inner_network.disabled);
+			self.bypassed.extend(inner_network.bypassed);
 
 			let mut network_offsets = HashMap::new();
 			assert_eq!(
@@ -749,7 +1206,11 @@ impl NodeNetwork {
 							self.inputs[index] = *network_input;
 						}
 					}
-					NodeInput::ShortCircut(_) => (),
+					NodeInput::ShortCircut(ty) => {
+						let network_input = self.nodes.get_mut(network_input).unwrap();
+						network_input.short_circuit_first_network_input(ty, *offset);
+						*offset += 1;
+					}
 					NodeInput::Value { .. } => unreachable!("Value inputs should have been replaced with value nodes"),
 					NodeInput::Inline(_) => (),
 				}
@@ -1048,6 +1509,35 @@ mod test {
 		assert_eq!(flat_network, network);
 	}
 
+	#[test]
+	fn flatten_short_circuits_inner_network_inputs() {
+		let mut network = NodeNetwork {
+			inputs: vec![],
+			outputs: vec![NodeOutput::new(1, 0)],
+			nodes: [(
+				1,
+				DocumentNode {
+					name: "Inc".into(),
+					inputs: vec![NodeInput::ShortCircut(concrete!(u32)), NodeInput::value(TaggedValue::U32(2), false)],
+					implementation: DocumentNodeImplementation::Network(add_network()),
+					..Default::default()
+				},
+			)]
+			.into_iter()
+			.collect(),
+			..Default::default()
+		};
+		network.flatten_with_fns(1, |self_id, inner_id| self_id * 10 + inner_id, gen_node_id);
+
+		let cons_inputs = &network.nodes[&10].inputs;
+		assert_eq!(
+			cons_inputs[0],
+			NodeInput::ShortCircut(concrete!(u32)),
+			"The inner node should be called with the argument of the network"
+		);
+		assert!(matches!(cons_inputs[1], NodeInput::Node { lambda: false, .. }));
+	}
+
 	#[test]
 	fn resolve_proto_node_add() {
 		let document_node = DocumentNode {
@@ -1225,6 +1715,187 @@ mod test {
 		assert_eq!(ids, vec![11, 10010], "Should only contain identity and values");
 	}
 
+	#[test]
+	fn validate_well_formed_network() {
+		assert_eq!(add_network().validate(), Vec::new());
+		assert_eq!(two_node_identity().validate(), Vec::new());
+	}
+
+	#[test]
+	fn validate_malformed_network() {
+		let nested = DocumentNode {
+			name: "Nested network".into(),
+			inputs: vec![NodeInput::value(TaggedValue::F32(1.), false), NodeInput::node(3, 0)],
+			implementation: DocumentNodeImplementation::Network(two_node_identity()),
+			..Default::default()
+		};
+		let missing_output = DocumentNode {
+			name: "Missing output".into(),
+			inputs: vec![NodeInput::node(1, 2)],
+			..Default::default()
+		};
+		let network = NodeNetwork {
+			inputs: vec![],
+			outputs: vec![NodeOutput::new(2, 0), NodeOutput::new(42, 0)],
+			nodes: [(1, nested), (2, missing_output)].into_iter().collect(),
+			..Default::default()
+		};
+
+		let diagnostics: Vec<_> = network.validate().into_iter().map(|diagnostic| (diagnostic.node_id, diagnostic.input_index)).collect();
+		assert_eq!(
+			diagnostics,
+			vec![
+				(42, None),   // The network output references a node that doesn't exist
+				(1, Some(0)), // An f32 value feeds a network input expecting u32
+				(1, Some(1)), // The input references a node that doesn't exist
+				(2, Some(0)), // The input references the third output of a node with two outputs
+			]
+		);
+	}
+
+	#[test]
+	fn validate_reports_mismatched_output_types() {
+		let value = |tagged_value| DocumentNode {
+			name: "Value".into(),
+			inputs: vec![NodeInput::value(tagged_value, false)],
+			..Default::default()
+		};
+		let nested = |input| DocumentNode {
+			name: "Nested network".into(),
+			inputs: vec![NodeInput::node(input, 0), NodeInput::node(input, 0)],
+			implementation: DocumentNodeImplementation::Network(two_node_identity()),
+			..Default::default()
+		};
+		let network = NodeNetwork {
+			outputs: vec![NodeOutput::new(3, 0), NodeOutput::new(4, 1)],
+			nodes: [(1, value(TaggedValue::U32(1))), (2, value(TaggedValue::F32(1.))), (3, nested(1)), (4, nested(2)), (5, nested(4))]
+				.into_iter()
+				.collect(),
+			..Default::default()
+		};
+
+		let diagnostics: Vec<_> = network.validate().into_iter().map(|diagnostic| (diagnostic.node_id, diagnostic.input_index)).collect();
+		assert_eq!(
+			diagnostics,
+			vec![
+				(4, Some(0)), // An f32 from a value node feeds a network input expecting u32
+				(4, Some(1)),
+			],
+			"The u32 outputs of the nested networks match the inputs they feed"
+		);
+	}
+
+	#[test]
+	fn validate_reports_cycles() {
+		let node = |input| DocumentNode {
+			name: "Identity".into(),
+			inputs: vec![NodeInput::node(input, 0)],
+			..Default::default()
+		};
+		let network = NodeNetwork {
+			outputs: vec![NodeOutput::new(1, 0)],
+			nodes: [(1, node(2)), (2, node(1)), (3, node(1))].into_iter().collect(),
+			..Default::default()
+		};
+
+		assert_eq!(network.nodes_in_cycles(), vec![1, 2]);
+		let diagnostics = network.validate();
+		assert_eq!(diagnostics.iter().map(|diagnostic| diagnostic.node_id).collect::<Vec<_>>(), vec![1, 2]);
+		assert!(diagnostics.iter().all(|diagnostic| diagnostic.message.contains("cycle")));
+	}
+
+	/// A network where nodes 1 and 2 can be collapsed, with links into them from the input node and out of them to the output node, including a lambda link.
+	fn group_network() -> NodeNetwork {
+		let node = |name: &str, inputs| DocumentNode {
+			name: name.into(),
+			inputs,
+			..Default::default()
+		};
+		NodeNetwork {
+			inputs: vec![0],
+			outputs: vec![NodeOutput::new(3, 0)],
+			nodes: [
+				(0, node("Input", vec![NodeInput::Network(concrete!(u32))])),
+				(1, node("Increment", vec![NodeInput::node(0, 0), NodeInput::value(TaggedValue::U32(1), false)])),
+				(2, node("Double", vec![NodeInput::node(1, 0), NodeInput::node(1, 0)])),
+				(3, node("Sum", vec![NodeInput::node(2, 0), NodeInput::node(0, 0), NodeInput::lambda(1, 0)])),
+			]
+			.into_iter()
+			.collect(),
+			disabled: vec![2],
+			..Default::default()
+		}
+	}
+
+	/// Describes each node of a flattened network by its name and the names of the nodes it's connected to, which doesn't depend on the generated ids.
+	fn describe_flattened(mut network: NodeNetwork) -> Vec<String> {
+		let node_ids = network.nodes.keys().copied().collect::<Vec<_>>();
+		for id in node_ids {
+			network.flatten(id);
+		}
+		let name = |id: &NodeId| network.nodes[id].name.clone();
+		let mut descriptions = network
+			.nodes
+			.values()
+			.map(|node| {
+				let inputs = node.inputs.iter().map(|input| match input {
+					NodeInput::Node { node_id, output_index, lambda } => format!("{}:{output_index}:{lambda}", name(node_id)),
+					input => format!("{input:?}"),
+				});
+				format!("{} {:?} ({})", node.name, node.implementation, inputs.collect::<Vec<_>>().join(", "))
+			})
+			.collect::<Vec<_>>();
+		descriptions.extend(network.outputs.iter().map(|output| format!("output {}:{}", name(&output.node_id), output.node_output_index)));
+		descriptions.extend(network.inputs.iter().map(|input| format!("input {}", name(input))));
+		descriptions.sort();
+		descriptions
+	}
+
+	#[test]
+	fn collapse_nodes_into_group() {
+		let mut network = group_network();
+		network.bypassed = vec![1];
+		network.collapse_nodes(&[1, 2], 10).unwrap();
+
+		let group = &network.nodes[&10];
+		assert_eq!(group.inputs, vec![NodeInput::node(0, 0)], "The link from the input node should become the only input of the group");
+		assert_eq!(network.nodes[&3].inputs, vec![NodeInput::node(10, 0), NodeInput::node(0, 0), NodeInput::lambda(10, 1)]);
+		assert!(network.disabled.is_empty());
+		assert!(network.bypassed.is_empty());
+
+		let inner_network = group.implementation.get_network().unwrap();
+		assert_eq!(inner_network.inputs, vec![1]);
+		assert_eq!(inner_network.outputs, vec![NodeOutput::new(2, 0), NodeOutput::new(1, 0)]);
+		assert_eq!(inner_network.disabled, vec![2]);
+		assert_eq!(inner_network.bypassed, vec![1]);
+		assert_eq!(inner_network.nodes[&1].inputs[0], NodeInput::Network(generic!(T)));
+	}
+
+	#[test]
+	fn collapse_then_expand_round_trip() {
+		let mut network = group_network();
+		network.collapse_nodes(&[1, 2], 10).unwrap();
+		assert_eq!(
+			describe_flattened(network.clone()),
+			describe_flattened(group_network()),
+			"The group should flatten to the original graph"
+		);
+
+		let expanded_ids = network.expand_node(10, gen_node_id).unwrap();
+		assert_eq!(expanded_ids, vec![1, 2]);
+		assert_eq!(network, group_network());
+	}
+
+	#[test]
+	fn collapse_rejects_invalid_selections() {
+		let mut network = group_network();
+		assert!(network.collapse_nodes(&[1, 3], 10).is_err(), "The group would form a cycle with node 2");
+		assert!(network.collapse_nodes(&[0, 1], 10).is_err(), "The network input can't be collapsed");
+		assert!(network.collapse_nodes(&[1], 2).is_err(), "The id of the group is already used");
+		assert_eq!(network, group_network(), "A failed collapse should leave the network unchanged");
+		assert!(network.expand_node(1, gen_node_id).is_err(), "Only nodes implemented by networks can be expanded");
+	}
+
 	// TODO: Write more tests
 	/*
 	#[test]
@@ -1,6 +1,6 @@
 use super::DocumentNode;
 use crate::graphene_compiler::Any;
-pub use crate::imaginate_input::{ImaginateCache, ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
+pub use crate::imaginate_input::{ImaginateCache, ImaginateControlType, ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
 use crate::proto::{Any as DAny, FutureAny};
 
 use graphene_core::raster::brush_cache::BrushCache;
@@ -36,17 +36,26 @@ pub enum TaggedValue {
 	LuminanceCalculation(LuminanceCalculation),
 	ImaginateSamplingMethod(ImaginateSamplingMethod),
 	ImaginateMaskStartingFill(ImaginateMaskStartingFill),
+	ImaginateControlType(ImaginateControlType),
 	ImaginateController(ImaginateController),
 	LayerPath(Option<Vec<u64>>),
 	VectorData(graphene_core::vector::VectorData),
 	Fill(graphene_core::vector::style::Fill),
 	Stroke(graphene_core::vector::style::Stroke),
 	VecF32(Vec<f32>),
+	VecF64(Vec<f64>),
 	RedGreenBlue(graphene_core::raster::RedGreenBlue),
+	RedGreenBlueAlpha(graphene_core::raster::RedGreenBlueAlpha),
 	RelativeAbsolute(graphene_core::raster::RelativeAbsolute),
+	DownresFilter(graphene_core::raster::DownresFilter),
+	HueRange(graphene_core::raster::HueRange),
+	ToneMapping(graphene_core::raster::ToneMapping),
 	SelectiveColorChoice(graphene_core::raster::SelectiveColorChoice),
 	LineCap(graphene_core::vector::style::LineCap),
 	LineJoin(graphene_core::vector::style::LineJoin),
+	StrokeAlign(graphene_core::vector::style::StrokeAlign),
+	StyleLayers(Vec<graphene_core::vector::style::StyleLayer>),
+	MirrorAxis(graphene_core::vector::MirrorAxis),
 	FillType(graphene_core::vector::style::FillType),
 	GradientType(graphene_core::vector::style::GradientType),
 	GradientPositions(Vec<(f64, Option<graphene_core::Color>)>),
@@ -54,14 +63,21 @@ pub enum TaggedValue {
 	OptionalColor(Option<graphene_core::raster::color::Color>),
 	ManipulatorGroupIds(Vec<graphene_core::uuid::ManipulatorGroupId>),
 	Font(graphene_core::text::Font),
+	TextAlign(graphene_core::text::TextAlign),
+	Fonts(Vec<graphene_core::text::Font>),
 	BrushStrokes(Vec<graphene_core::vector::brush_stroke::BrushStroke>),
 	BrushCache(BrushCache),
+	BrushMode(graphene_core::vector::brush_stroke::BrushMode),
+	VectorPoints(graphene_core::vector::VectorPoints),
 	Segments(Vec<graphene_core::raster::ImageFrame<Color>>),
 	DocumentNode(DocumentNode),
 	GraphicGroup(graphene_core::GraphicGroup),
 	Artboard(graphene_core::Artboard),
 	IVec2(glam::IVec2),
+	UVec2(glam::UVec2),
 	SurfaceFrame(graphene_core::SurfaceFrame),
+	AnimationCurve(graphene_core::animation::AnimationCurve),
+	AntiAliasing(graphene_core::renderer::AntiAliasing),
 }
 
 #[allow(clippy::derived_hash_with_manual_eq)]
@@ -91,6 +107,7 @@ impl Hash for TaggedValue {
 			Self::LuminanceCalculation(l) => l.hash(state),
 			Self::ImaginateSamplingMethod(m) => m.hash(state),
 			Self::ImaginateMaskStartingFill(f) => f.hash(state),
+			Self::ImaginateControlType(t) => t.hash(state),
 			Self::ImaginateController(s) => s.hash(state),
 			Self::LayerPath(p) => p.hash(state),
 			Self::ImageFrame(i) => i.hash(state),
@@ -98,11 +115,19 @@ impl Hash for TaggedValue {
 			Self::Fill(fill) => fill.hash(state),
 			Self::Stroke(stroke) => stroke.hash(state),
 			Self::VecF32(vec_f32) => vec_f32.iter().for_each(|val| val.to_bits().hash(state)),
+			Self::VecF64(vec_f64) => vec_f64.iter().for_each(|val| val.to_bits().hash(state)),
 			Self::RedGreenBlue(red_green_blue) => red_green_blue.hash(state),
+			Self::RedGreenBlueAlpha(red_green_blue_alpha) => red_green_blue_alpha.hash(state),
 			Self::RelativeAbsolute(relative_absolute) => relative_absolute.hash(state),
+			Self::DownresFilter(downres_filter) => downres_filter.hash(state),
+			Self::HueRange(hue_range) => hue_range.hash(state),
+			Self::ToneMapping(tone_mapping) => tone_mapping.hash(state),
 			Self::SelectiveColorChoice(selective_color_choice) => selective_color_choice.hash(state),
 			Self::LineCap(line_cap) => line_cap.hash(state),
 			Self::LineJoin(line_join) => line_join.hash(state),
+			Self::StrokeAlign(stroke_align) => stroke_align.hash(state),
+			Self::StyleLayers(style_layers) => style_layers.hash(state),
+			Self::MirrorAxis(mirror_axis) => mirror_axis.hash(state),
 			Self::FillType(fill_type) => fill_type.hash(state),
 			Self::GradientType(gradient_type) => gradient_type.hash(state),
 			Self::GradientPositions(gradient_positions) => {
@@ -116,8 +141,12 @@ impl Hash for TaggedValue {
 			Self::OptionalColor(color) => color.hash(state),
 			Self::ManipulatorGroupIds(mirror) => mirror.hash(state),
 			Self::Font(font) => font.hash(state),
+			Self::TextAlign(text_align) => text_align.hash(state),
+			Self::Fonts(fonts) => fonts.hash(state),
 			Self::BrushStrokes(brush_strokes) => brush_strokes.hash(state),
 			Self::BrushCache(brush_cache) => brush_cache.hash(state),
+			Self::BrushMode(brush_mode) => brush_mode.hash(state),
+			Self::VectorPoints(vector_points) => vector_points.hash(state),
 			Self::Segments(segments) => {
 				for segment in segments {
 					segment.hash(state)
@@ -127,7 +156,10 @@ impl Hash for TaggedValue {
 			Self::GraphicGroup(graphic_group) => graphic_group.hash(state),
 			Self::Artboard(artboard) => artboard.hash(state),
 			Self::IVec2(v) => v.hash(state),
+			Self::UVec2(v) => v.hash(state),
 			Self::SurfaceFrame(surface_id) => surface_id.hash(state),
+			Self::AnimationCurve(curve) => curve.hash(state),
+			Self::AntiAliasing(anti_aliasing) => anti_aliasing.hash(state),
 		}
 	}
 }
@@ -155,17 +187,26 @@ impl<'a> TaggedValue {
 			TaggedValue::LuminanceCalculation(x) => Box::new(x),
 			TaggedValue::ImaginateSamplingMethod(x) => Box::new(x),
 			TaggedValue::ImaginateMaskStartingFill(x) => Box::new(x),
+			TaggedValue::ImaginateControlType(x) => Box::new(x),
 			TaggedValue::ImaginateController(x) => Box::new(x),
 			TaggedValue::LayerPath(x) => Box::new(x),
 			TaggedValue::VectorData(x) => Box::new(x),
 			TaggedValue::Fill(x) => Box::new(x),
 			TaggedValue::Stroke(x) => Box::new(x),
 			TaggedValue::VecF32(x) => Box::new(x),
+			TaggedValue::VecF64(x) => Box::new(x),
 			TaggedValue::RedGreenBlue(x) => Box::new(x),
+			TaggedValue::RedGreenBlueAlpha(x) => Box::new(x),
 			TaggedValue::RelativeAbsolute(x) => Box::new(x),
+			TaggedValue::DownresFilter(x) => Box::new(x),
+			TaggedValue::HueRange(x) => Box::new(x),
+			TaggedValue::ToneMapping(x) => Box::new(x),
 			TaggedValue::SelectiveColorChoice(x) => Box::new(x),
 			TaggedValue::LineCap(x) => Box::new(x),
 			TaggedValue::LineJoin(x) => Box::new(x),
+			TaggedValue::StrokeAlign(x) => Box::new(x),
+			TaggedValue::StyleLayers(x) => Box::new(x),
+			TaggedValue::MirrorAxis(x) => Box::new(x),
 			TaggedValue::FillType(x) => Box::new(x),
 			TaggedValue::GradientType(x) => Box::new(x),
 			TaggedValue::GradientPositions(x) => Box::new(x),
@@ -173,14 +214,21 @@ impl<'a> TaggedValue {
 			TaggedValue::OptionalColor(x) => Box::new(x),
 			TaggedValue::ManipulatorGroupIds(x) => Box::new(x),
 			TaggedValue::Font(x) => Box::new(x),
+			TaggedValue::TextAlign(x) => Box::new(x),
+			TaggedValue::Fonts(x) => Box::new(x),
 			TaggedValue::BrushStrokes(x) => Box::new(x),
 			TaggedValue::BrushCache(x) => Box::new(x),
+			TaggedValue::BrushMode(x) => Box::new(x),
+			TaggedValue::VectorPoints(x) => Box::new(x),
 			TaggedValue::Segments(x) => Box::new(x),
 			TaggedValue::DocumentNode(x) => Box::new(x),
 			TaggedValue::GraphicGroup(x) => Box::new(x),
 			TaggedValue::Artboard(x) => Box::new(x),
 			TaggedValue::IVec2(x) => Box::new(x),
+			TaggedValue::UVec2(x) => Box::new(x),
 			TaggedValue::SurfaceFrame(x) => Box::new(x),
+			TaggedValue::AnimationCurve(x) => Box::new(x),
+			TaggedValue::AntiAliasing(x) => Box::new(x),
 		}
 	}
 
@@ -219,6 +267,7 @@ impl<'a> TaggedValue {
 			TaggedValue::BlendMode(_) => concrete!(BlendMode),
 			TaggedValue::ImaginateSamplingMethod(_) => concrete!(ImaginateSamplingMethod),
 			TaggedValue::ImaginateMaskStartingFill(_) => concrete!(ImaginateMaskStartingFill),
+			TaggedValue::ImaginateControlType(_) => concrete!(ImaginateControlType),
 			TaggedValue::ImaginateController(_) => concrete!(ImaginateController),
 			TaggedValue::LayerPath(_) => concrete!(Option<Vec<u64>>),
 			TaggedValue::DAffine2(_) => concrete!(DAffine2),
@@ -227,11 +276,19 @@ impl<'a> TaggedValue {
 			TaggedValue::Fill(_) => concrete!(graphene_core::vector::style::Fill),
 			TaggedValue::Stroke(_) => concrete!(graphene_core::vector::style::Stroke),
 			TaggedValue::VecF32(_) => concrete!(Vec<f32>),
+			TaggedValue::VecF64(_) => concrete!(Vec<f64>),
 			TaggedValue::RedGreenBlue(_) => concrete!(graphene_core::raster::RedGreenBlue),
+			TaggedValue::RedGreenBlueAlpha(_) => concrete!(graphene_core::raster::RedGreenBlueAlpha),
 			TaggedValue::RelativeAbsolute(_) => concrete!(graphene_core::raster::RelativeAbsolute),
+			TaggedValue::DownresFilter(_) => concrete!(graphene_core::raster::DownresFilter),
+			TaggedValue::HueRange(_) => concrete!(graphene_core::raster::HueRange),
+			TaggedValue::ToneMapping(_) => concrete!(graphene_core::raster::ToneMapping),
 			TaggedValue::SelectiveColorChoice(_) => concrete!(graphene_core::raster::SelectiveColorChoice),
 			TaggedValue::LineCap(_) => concrete!(graphene_core::vector::style::LineCap),
 			TaggedValue::LineJoin(_) => concrete!(graphene_core::vector::style::LineJoin),
+			TaggedValue::StrokeAlign(_) => concrete!(graphene_core::vector::style::StrokeAlign),
+			TaggedValue::StyleLayers(_) => concrete!(Vec<graphene_core::vector::style::StyleLayer>),
+			TaggedValue::MirrorAxis(_) => concrete!(graphene_core::vector::MirrorAxis),
 			TaggedValue::FillType(_) => concrete!(graphene_core::vector::style::FillType),
 			TaggedValue::GradientType(_) => concrete!(graphene_core::vector::style::GradientType),
 			TaggedValue::GradientPositions(_) => concrete!(Vec<(f64, Option<graphene_core::Color>)>),
@@ -239,14 +296,21 @@ impl<'a> TaggedValue {
 			TaggedValue::OptionalColor(_) => concrete!(Option<graphene_core::Color>),
 			TaggedValue::ManipulatorGroupIds(_) => concrete!(Vec<graphene_core::uuid::ManipulatorGroupId>),
 			TaggedValue::Font(_) => concrete!(graphene_core::text::Font),
+			TaggedValue::TextAlign(_) => concrete!(graphene_core::text::TextAlign),
+			TaggedValue::Fonts(_) => concrete!(Vec<graphene_core::text::Font>),
 			TaggedValue::BrushStrokes(_) => concrete!(Vec<graphene_core::vector::brush_stroke::BrushStroke>),
 			TaggedValue::BrushCache(_) => concrete!(BrushCache),
-			TaggedValue::Segments(_) => concrete!(graphene_core::raster::IndexNode<Vec<graphene_core::raster::ImageFrame<Color>>>),
+			TaggedValue::BrushMode(_) => concrete!(graphene_core::vector::brush_stroke::BrushMode),
+			TaggedValue::VectorPoints(_) => concrete!(graphene_core::vector::VectorPoints),
+			TaggedValue::Segments(_) => concrete!(Vec<graphene_core::raster::ImageFrame<Color>>),
 			TaggedValue::DocumentNode(_) => concrete!(crate::document::DocumentNode),
 			TaggedValue::GraphicGroup(_) => concrete!(graphene_core::GraphicGroup),
 			TaggedValue::Artboard(_) => concrete!(graphene_core::Artboard),
 			TaggedValue::IVec2(_) => concrete!(glam::IVec2),
+			TaggedValue::UVec2(_) => concrete!(glam::UVec2),
 			TaggedValue::SurfaceFrame(_) => concrete!(graphene_core::SurfaceFrame),
+			TaggedValue::AnimationCurve(_) => concrete!(graphene_core::animation::AnimationCurve),
+			TaggedValue::AntiAliasing(_) => concrete!(graphene_core::renderer::AntiAliasing),
 		}
 	}
 
@@ -272,6 +336,7 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<BlendMode>() => Ok(TaggedValue::BlendMode(*downcast(input).unwrap())),
 			x if x == TypeId::of::<ImaginateSamplingMethod>() => Ok(TaggedValue::ImaginateSamplingMethod(*downcast(input).unwrap())),
 			x if x == TypeId::of::<ImaginateMaskStartingFill>() => Ok(TaggedValue::ImaginateMaskStartingFill(*downcast(input).unwrap())),
+			x if x == TypeId::of::<ImaginateControlType>() => Ok(TaggedValue::ImaginateControlType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<ImaginateController>() => Ok(TaggedValue::ImaginateController(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Option<Vec<u64>>>() => Ok(TaggedValue::LayerPath(*downcast(input).unwrap())),
 			x if x == TypeId::of::<DAffine2>() => Ok(TaggedValue::DAffine2(*downcast(input).unwrap())),
@@ -280,11 +345,19 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<graphene_core::vector::style::Fill>() => Ok(TaggedValue::Fill(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::Stroke>() => Ok(TaggedValue::Stroke(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<f32>>() => Ok(TaggedValue::VecF32(*downcast(input).unwrap())),
+			x if x == TypeId::of::<Vec<f64>>() => Ok(TaggedValue::VecF64(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::RedGreenBlue>() => Ok(TaggedValue::RedGreenBlue(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::RedGreenBlueAlpha>() => Ok(TaggedValue::RedGreenBlueAlpha(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::RelativeAbsolute>() => Ok(TaggedValue::RelativeAbsolute(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::DownresFilter>() => Ok(TaggedValue::DownresFilter(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::HueRange>() => Ok(TaggedValue::HueRange(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::ToneMapping>() => Ok(TaggedValue::ToneMapping(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::SelectiveColorChoice>() => Ok(TaggedValue::SelectiveColorChoice(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineCap>() => Ok(TaggedValue::LineCap(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineJoin>() => Ok(TaggedValue::LineJoin(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::StrokeAlign>() => Ok(TaggedValue::StrokeAlign(*downcast(input).unwrap())),
+			x if x == TypeId::of::<Vec<graphene_core::vector::style::StyleLayer>>() => Ok(TaggedValue::StyleLayers(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::MirrorAxis>() => Ok(TaggedValue::MirrorAxis(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::FillType>() => Ok(TaggedValue::FillType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::GradientType>() => Ok(TaggedValue::GradientType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<(f64, Option<graphene_core::Color>)>>() => Ok(TaggedValue::GradientPositions(*downcast(input).unwrap())),
@@ -292,14 +365,21 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<Option<graphene_core::Color>>() => Ok(TaggedValue::OptionalColor(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<graphene_core::uuid::ManipulatorGroupId>>() => Ok(TaggedValue::ManipulatorGroupIds(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::text::Font>() => Ok(TaggedValue::Font(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::TextAlign>() => Ok(TaggedValue::TextAlign(*downcast(input).unwrap())),
+			x if x == TypeId::of::<Vec<graphene_core::text::Font>>() => Ok(TaggedValue::Fonts(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<graphene_core::vector::brush_stroke::BrushStroke>>() => Ok(TaggedValue::BrushStrokes(*downcast(input).unwrap())),
 			x if x == TypeId::of::<BrushCache>() => Ok(TaggedValue::BrushCache(*downcast(input).unwrap())),
-			x if x == TypeId::of::<graphene_core::raster::IndexNode<Vec<graphene_core::raster::ImageFrame<Color>>>>() => Ok(TaggedValue::Segments(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::brush_stroke::BrushMode>() => Ok(TaggedValue::BrushMode(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::VectorPoints>() => Ok(TaggedValue::VectorPoints(*downcast(input).unwrap())),
+			x if x == TypeId::of::<Vec<graphene_core::raster::ImageFrame<Color>>>() => Ok(TaggedValue::Segments(*downcast(input).unwrap())),
 			x if x == TypeId::of::<crate::document::DocumentNode>() => Ok(TaggedValue::DocumentNode(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::GraphicGroup>() => Ok(TaggedValue::GraphicGroup(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::Artboard>() => Ok(TaggedValue::Artboard(*downcast(input).unwrap())),
 			x if x == TypeId::of::<glam::IVec2>() => Ok(TaggedValue::IVec2(*downcast(input).unwrap())),
+			x if x == TypeId::of::<glam::UVec2>() => Ok(TaggedValue::UVec2(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::SurfaceFrame>() => Ok(TaggedValue::SurfaceFrame(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::animation::AnimationCurve>() => Ok(TaggedValue::AnimationCurve(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::renderer::AntiAliasing>() => Ok(TaggedValue::AntiAliasing(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::WasmSurfaceHandleFrame>() => {
 				let frame = *downcast::<graphene_core::WasmSurfaceHandleFrame>(input).unwrap();
 				Ok(TaggedValue::SurfaceFrame(frame.into()))
@@ -307,6 +387,29 @@ impl<'a> TaggedValue {
 			_ => Err(format!("Cannot convert {:?} to TaggedValue", DynAny::type_name(input.as_ref()))),
 		}
 	}
+
+	/// Clones a value introspected from a node, such as the value cached by a Monitor node, into a [TaggedValue].
+	/// Only the types which are commonly passed between nodes are supported.
+	pub fn try_from_std_any_ref(input: &dyn std::any::Any) -> Result<Self, String> {
+		use std::any::TypeId;
+
+		match input.type_id() {
+			x if x == TypeId::of::<()>() => Ok(TaggedValue::None),
+			x if x == TypeId::of::<String>() => Ok(TaggedValue::String(input.downcast_ref::<String>().unwrap().clone())),
+			x if x == TypeId::of::<u32>() => Ok(TaggedValue::U32(*input.downcast_ref().unwrap())),
+			x if x == TypeId::of::<f32>() => Ok(TaggedValue::F32(*input.downcast_ref().unwrap())),
+			x if x == TypeId::of::<f64>() => Ok(TaggedValue::F64(*input.downcast_ref().unwrap())),
+			x if x == TypeId::of::<bool>() => Ok(TaggedValue::Bool(*input.downcast_ref().unwrap())),
+			x if x == TypeId::of::<DVec2>() => Ok(TaggedValue::DVec2(*input.downcast_ref().unwrap())),
+			x if x == TypeId::of::<DAffine2>() => Ok(TaggedValue::DAffine2(*input.downcast_ref().unwrap())),
+			x if x == TypeId::of::<Color>() => Ok(TaggedValue::Color(*input.downcast_ref().unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::ImageFrame<Color>>() => Ok(TaggedValue::ImageFrame(input.downcast_ref::<graphene_core::raster::ImageFrame<Color>>().unwrap().clone())),
+			x if x == TypeId::of::<graphene_core::vector::VectorData>() => Ok(TaggedValue::VectorData(input.downcast_ref::<graphene_core::vector::VectorData>().unwrap().clone())),
+			x if x == TypeId::of::<graphene_core::GraphicGroup>() => Ok(TaggedValue::GraphicGroup(input.downcast_ref::<graphene_core::GraphicGroup>().unwrap().clone())),
+			x if x == TypeId::of::<graphene_core::Artboard>() => Ok(TaggedValue::Artboard(input.downcast_ref::<graphene_core::Artboard>().unwrap().clone())),
+			_ => Err("Cannot convert the introspected value to a TaggedValue".to_string()),
+		}
+	}
 }
 
 pub struct UpcastNode {
@@ -4,7 +4,7 @@ use std::borrow::Cow;
 use std::fmt::Debug;
 use std::sync::{
 	atomic::{AtomicBool, Ordering},
-	Arc, Mutex,
+	mpsc, Arc, Mutex,
 };
 
 #[derive(Default, Debug, Clone, DynAny, specta::Type)]
@@ -39,38 +39,76 @@ struct InternalImaginateControl {
 	status: Mutex<ImaginateStatus>,
 	trigger_regenerate: AtomicBool,
 	#[serde(skip)]
+	termination_requested: AtomicBool,
+	#[serde(skip)]
 	termination_sender: Mutex<Option<Box<dyn ImaginateTerminationHandle>>>,
+	#[serde(skip)]
+	status_subscribers: Mutex<Vec<mpsc::Sender<ImaginateStatus>>>,
 }
 
+/// Controls a single Imaginate node's generation. It is shared between the node and whatever drives it, whether that's the properties panel or a script,
+/// so its methods can be called without access to the editor.
 #[derive(Debug, Default, Clone, DynAny, specta::Type)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImaginateController(Arc<InternalImaginateControl>);
 
 impl ImaginateController {
+	/// The status of the most recent generation.
 	pub fn get_status(&self) -> ImaginateStatus {
 		self.0.status.lock().as_deref().cloned().unwrap_or_default()
 	}
 
+	/// Records the status of the generation and sends it to every subscriber.
 	pub fn set_status(&self, status: ImaginateStatus) {
 		if let Ok(mut lock) = self.0.status.lock() {
-			*lock = status
+			*lock = status.clone()
+		}
+		if let Ok(mut subscribers) = self.0.status_subscribers.lock() {
+			subscribers.retain(|subscriber| subscriber.send(status.clone()).is_ok());
+		}
+	}
+
+	/// Receives every status set from now on, in order. Dropping the receiver ends the subscription.
+	pub fn subscribe(&self) -> mpsc::Receiver<ImaginateStatus> {
+		let (sender, receiver) = mpsc::channel();
+		if let Ok(mut subscribers) = self.0.status_subscribers.lock() {
+			subscribers.push(sender);
 		}
+		receiver
+	}
+
+	/// Whether a generation is currently underway.
+	pub fn is_busy(&self) -> bool {
+		self.get_status().is_busy()
+	}
+
+	/// The progress of the current generation in percent, if one is underway or has finished.
+	pub fn progress(&self) -> Option<f64> {
+		self.get_status().progress()
 	}
 
 	pub fn take_regenerate_trigger(&self) -> bool {
 		self.0.trigger_regenerate.swap(false, Ordering::SeqCst)
 	}
 
+	/// Asks the node to start a new generation the next time it's evaluated.
 	pub fn trigger_regenerate(&self) {
 		self.0.trigger_regenerate.store(true, Ordering::SeqCst)
 	}
 
+	/// Stops the generation in progress, if any, along with the rest of its batch.
 	pub fn request_termination(&self) {
+		self.0.termination_requested.store(true, Ordering::SeqCst);
 		if let Some(handle) = self.0.termination_sender.lock().ok().and_then(|mut lock| lock.take()) {
 			handle.terminate()
 		}
 	}
 
+	/// Whether termination was requested since this was last called, which a batch checks before starting each of its generations.
+	pub fn take_termination_request(&self) -> bool {
+		self.0.termination_requested.swap(false, Ordering::SeqCst)
+	}
+
 	pub fn set_termination_handle<H: ImaginateTerminationHandle>(&self, handle: Box<H>) {
 		if let Ok(mut lock) = self.0.termination_sender.lock() {
 			*lock = Some(handle)
@@ -117,6 +155,21 @@ impl ImaginateStatus {
 			Self::Failed(err) => Cow::Owned(format!("Failed: {err}")),
 		}
 	}
+
+	/// Whether the generation has begun but not yet finished, failed, or been terminated.
+	pub fn is_busy(&self) -> bool {
+		matches!(self, Self::Beginning | Self::Uploading | Self::Generating(_) | Self::Terminating)
+	}
+
+	/// The progress of the generation in percent. It is complete once the server is done, while the image is still downloading.
+	pub fn progress(&self) -> Option<f64> {
+		match self {
+			Self::Beginning => Some(0.),
+			Self::Generating(percent) => Some(*percent),
+			Self::Uploading | Self::ReadyDone => Some(100.),
+			Self::Ready | Self::Terminating | Self::Terminated | Self::Failed(_) => None,
+		}
+	}
 }
 
 #[allow(clippy::derived_hash_with_manual_eq)]
@@ -192,6 +245,50 @@ impl std::fmt::Display for ImaginateMaskStartingFill {
 	}
 }
 
+/// The kind of guidance a control image gives the generation, each handled by its own ControlNet preprocessor and model on the server.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, DynAny, specta::Type, Hash)]
+pub enum ImaginateControlType {
+	#[default]
+	Depth,
+	Edges,
+	Pose,
+}
+
+impl ImaginateControlType {
+	/// The name of the ControlNet preprocessor that turns the control image into guidance.
+	pub fn module(&self) -> &'static str {
+		match self {
+			ImaginateControlType::Depth => "depth",
+			ImaginateControlType::Edges => "canny",
+			ImaginateControlType::Pose => "openpose",
+		}
+	}
+
+	/// The name of the ControlNet model trained on the preprocessor's output.
+	pub fn model(&self) -> &'static str {
+		match self {
+			ImaginateControlType::Depth => "control_v11f1p_sd15_depth",
+			ImaginateControlType::Edges => "control_v11p_sd15_canny",
+			ImaginateControlType::Pose => "control_v11p_sd15_openpose",
+		}
+	}
+
+	pub fn list() -> [ImaginateControlType; 3] {
+		[ImaginateControlType::Depth, ImaginateControlType::Edges, ImaginateControlType::Pose]
+	}
+}
+
+impl std::fmt::Display for ImaginateControlType {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ImaginateControlType::Depth => write!(f, "Depth"),
+			ImaginateControlType::Edges => write!(f, "Edges"),
+			ImaginateControlType::Pose => write!(f, "Pose"),
+		}
+	}
+}
+
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, DynAny, specta::Type, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImaginateSamplingMethod {
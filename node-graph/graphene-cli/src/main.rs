@@ -55,6 +55,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 		application_io: &application_io,
 		node_graph_message_sender: &UpdateLogger {},
 		imaginate_preferences: &ImaginatePreferences::default(),
+		animation_frame: 0.,
 	};
 
 	loop {
@@ -207,6 +208,7 @@ mod test {
 			application_io: &block_on(WasmApplicationIo::new()),
 			node_graph_message_sender: &UpdateLogger {},
 			imaginate_preferences: &ImaginatePreferences::default(),
+			animation_frame: 0.,
 		};
 		let result = (&executor).execute(editor_api.clone()).await.unwrap();
 		println!("result: {:?}", result);
@@ -223,6 +225,7 @@ mod test {
 			application_io: &block_on(WasmApplicationIo::new()),
 			node_graph_message_sender: &UpdateLogger {},
 			imaginate_preferences: &ImaginatePreferences::default(),
+			animation_frame: 0.,
 		};
 		let result = (&executor).execute(editor_api.clone()).await.unwrap();
 		println!("result: {:?}", result);
@@ -7,8 +7,8 @@ use graphene_core::raster::{Alpha, Color, Image, ImageFrame, Pixel, Sample};
 use graphene_core::raster::{BlendMode, BlendNode};
 use graphene_core::transform::{Transform, TransformMut};
 use graphene_core::value::{ClonedNode, CopiedNode, OnceCellNode, ValueNode};
-use graphene_core::vector::brush_stroke::{BrushStroke, BrushStyle};
-use graphene_core::vector::VectorData;
+use graphene_core::vector::brush_stroke::{BrushMode, BrushStroke, BrushStyle};
+use graphene_core::vector::{VectorData, VectorPoints};
 use graphene_core::Node;
 use node_macro::node_fn;
 
@@ -59,8 +59,8 @@ fn into_iter<'i: 'input, _T: Send + Sync>(vec: &'i Vec<_T>) -> Box<dyn Iterator<
 pub struct VectorPointsNode;
 
 #[node_fn(VectorPointsNode)]
-fn vector_points(vector: VectorData) -> Vec<DVec2> {
-	vector.subpaths.iter().flat_map(|subpath| subpath.manipulator_groups().iter().map(|group| group.anchor)).collect()
+fn vector_points(vector: VectorData) -> VectorPoints {
+	VectorPoints::from_vector_data(&vector)
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -159,19 +159,22 @@ fn translate_node<Data: TransformMut>(offset: DVec2, mut translatable: Data) ->
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct BlitNode<P, Texture, Positions, BlendFn> {
+pub struct BlitNode<P, Texture, Stamps, BlendFn> {
 	texture: Texture,
-	positions: Positions,
+	stamps: Stamps,
 	blend_mode: BlendFn,
 	_p: PhantomData<P>,
 }
 
+/// Blits the texture centered at each stamp position, with the texture's opacity scaled by the stamp's pressure.
 #[node_fn(BlitNode<_P>)]
-fn blit_node<_P: Alpha + Pixel + std::fmt::Debug, BlendFn>(mut target: ImageFrame<_P>, texture: Image<_P>, positions: Vec<DVec2>, blend_mode: BlendFn) -> ImageFrame<_P>
+fn blit_node<_P: Alpha + Pixel + std::fmt::Debug, BlendFn>(mut target: ImageFrame<_P>, texture: Image<_P>, stamps: Vec<(DVec2, f64)>, blend_mode: BlendFn) -> ImageFrame<_P>
 where
 	BlendFn: for<'any_input> Node<'any_input, (_P, _P), Output = _P>,
 {
-	if positions.is_empty() {
+	use graphene_core::raster::Channel;
+
+	if stamps.is_empty() {
 		return target;
 	}
 
@@ -179,7 +182,8 @@ where
 	let texture_size = DVec2::new(texture.width as f64, texture.height as f64);
 	let document_to_target = DAffine2::from_translation(-texture_size / 2.) * DAffine2::from_scale(target_size) * target.transform.inverse();
 
-	for position in positions {
+	for (position, pressure) in stamps {
+		let pressure = pressure.clamp(0., 1.) as f32;
 		let start = document_to_target.transform_point2(position).round();
 		let stop = start + texture_size;
 
@@ -201,7 +205,10 @@ where
 
 		for y in blit_area_offset.y..blit_area_offset.y + blit_area_dimensions.y {
 			for x in blit_area_offset.x..blit_area_offset.x + blit_area_dimensions.x {
-				let src_pixel = texture.data[texture_index(x, y)];
+				let mut src_pixel = texture.data[texture_index(x, y)];
+				if pressure < 1. {
+					src_pixel = src_pixel.multiplied_alpha(_P::AlphaChannel::from_linear(pressure));
+				}
 				let dst_pixel = &mut target.image.data[target_index(x + clamp_start.x, y + clamp_start.y)];
 				*dst_pixel = blend_mode.eval((src_pixel, *dst_pixel));
 			}
@@ -221,6 +228,48 @@ pub fn create_brush_texture(brush_style: &BrushStyle) -> Image<Color> {
 	blend_executor.eval((blank_texture, stamp)).image
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScatterNode<Points, Diameter, Hardness, ColorNode> {
+	points: Points,
+	diameter: Diameter,
+	hardness: Hardness,
+	color: ColorNode,
+}
+
+/// Stamps a round brush tip onto the image at each point, growing the image to fit the stamps if needed.
+#[node_fn(ScatterNode)]
+fn scatter(image: ImageFrame<Color>, points: VectorPoints, diameter: f64, hardness: f64, color: Color) -> ImageFrame<Color> {
+	if points.is_empty() {
+		return image;
+	}
+
+	let style = BrushStyle {
+		color,
+		diameter: diameter.max(1.),
+		hardness: hardness.clamp(0., 100.),
+		..Default::default()
+	};
+	let brush_texture = create_brush_texture(&style);
+
+	let radius = DVec2::splat(style.diameter / 2.);
+	let points_bbox = points
+		.iter()
+		.map(|point| AxisAlignedBbox {
+			start: point - radius,
+			end: point + radius,
+		})
+		.reduce(|a, b| a.union(&b))
+		.unwrap_or(AxisAlignedBbox::ZERO);
+	let image_bbox = Bbox::from_transform(image.transform).to_axis_aligned_bbox();
+	let bbox = if image_bbox.size().length() < 0.1 { points_bbox } else { points_bbox.union(&image_bbox) };
+
+	let target = ExtendImageToBoundsNode::new(CopiedNode::new(bbox.to_transform())).eval(image);
+	let stamps: Vec<_> = points.iter().map(|point| (point, 1.)).collect();
+	let normal_blend = BlendNode::new(CopiedNode::new(BlendMode::Normal), CopiedNode::new(100.));
+	let blit_node = BlitNode::new(ClonedNode::new(brush_texture), ClonedNode::new(stamps), ClonedNode::new(normal_blend));
+	blit_node.eval(target)
+}
+
 macro_rules! inline_blend_funcs {
 	($bg:ident, $fg:ident, $blend_mode:ident, $opacity:ident, [$($mode:path,)*]) => {
 		match std::hint::black_box($blend_mode) {
@@ -281,14 +330,40 @@ pub fn blend_with_mode(background: ImageFrame<Color>, foreground: ImageFrame<Col
 	)
 }
 
-pub struct BrushNode<Bounds, Strokes, Cache> {
+pub struct BrushNode<Bounds, Strokes, Cache, Mode, OverrideStyle, Spacing, Hardness> {
 	bounds: Bounds,
 	strokes: Strokes,
 	cache: Cache,
+	mode: Mode,
+	override_style: OverrideStyle,
+	spacing: Spacing,
+	hardness: Hardness,
 }
 
+/// When `override_style` is set, every stroke is drawn with the given `spacing` and `hardness` (both percentages) instead of those of its own style.
 #[node_macro::node_fn(BrushNode)]
-async fn brush(image: ImageFrame<Color>, bounds: ImageFrame<Color>, strokes: Vec<BrushStroke>, cache: BrushCache) -> ImageFrame<Color> {
+async fn brush(
+	image: ImageFrame<Color>,
+	bounds: ImageFrame<Color>,
+	strokes: Vec<BrushStroke>,
+	cache: BrushCache,
+	mode: BrushMode,
+	override_style: bool,
+	spacing: f64,
+	hardness: f64,
+) -> ImageFrame<Color> {
+	let strokes: Vec<_> = strokes
+		.into_iter()
+		.map(|stroke| mode.apply(stroke))
+		.map(|mut stroke| {
+			if override_style {
+				stroke.style.spacing = spacing.max(1.);
+				stroke.style.hardness = hardness.clamp(0., 100.);
+			}
+			stroke
+		})
+		.collect();
+
 	let stroke_bbox = strokes.iter().map(|s| s.bounding_box()).reduce(|a, b| a.union(&b)).unwrap_or(AxisAlignedBbox::ZERO);
 	let image_bbox = Bbox::from_transform(image.transform).to_axis_aligned_bbox();
 	let bbox = if image_bbox.size().length() < 0.1 { stroke_bbox } else { stroke_bbox.union(&image_bbox) };
@@ -317,7 +392,7 @@ async fn brush(image: ImageFrame<Color>, bounds: ImageFrame<Color>, strokes: Vec
 
 		// Compute transformation from stroke texture space into layer space, and create the stroke texture.
 		let skip = if idx == 0 { brush_plan.first_stroke_point_skip } else { 0 };
-		let positions: Vec<_> = stroke.compute_blit_points().into_iter().skip(skip).collect();
+		let positions: Vec<_> = stroke.compute_blit_stamps().into_iter().skip(skip).collect();
 		let stroke_texture = if idx == 0 && positions.is_empty() {
 			core::mem::take(&mut brush_plan.first_stroke_texture)
 		} else {
@@ -327,7 +402,7 @@ async fn brush(image: ImageFrame<Color>, bounds: ImageFrame<Color>, strokes: Vec
 			let stroke_size = bbox.size() + DVec2::splat(stroke.style.diameter);
 			// For numerical stability we want to place the first blit point at a stable, integer offset
 			// in layer space.
-			let snap_offset = positions[0].floor() - positions[0];
+			let snap_offset = positions[0].0.floor() - positions[0].0;
 			let stroke_origin_in_layer = bbox.start - snap_offset - DVec2::splat(stroke.style.diameter / 2.0);
 			let stroke_to_layer = DAffine2::from_translation(stroke_origin_in_layer) * DAffine2::from_scale(stroke_size);
 
@@ -365,7 +440,7 @@ async fn brush(image: ImageFrame<Color>, bounds: ImageFrame<Color>, strokes: Vec
 				cache.store_brush(stroke.style.clone(), tex.clone());
 				tex
 			});
-			let positions: Vec<_> = stroke.compute_blit_points().into_iter().collect();
+			let positions = stroke.compute_blit_stamps();
 
 			match stroke.style.blend_mode {
 				BlendMode::Erase => {
@@ -403,6 +478,7 @@ mod test {
 	use graphene_core::structural::Then;
 	use graphene_core::transform::{Transform, TransformMut};
 	use graphene_core::value::{ClonedNode, ValueNode};
+	use graphene_core::vector::brush_stroke::BrushInputSample;
 
 	use glam::DAffine2;
 
@@ -433,6 +509,91 @@ mod test {
 		assert_eq!(image.sample(DVec2::splat(0.), DVec2::ONE), Some(Color::BLACK));
 	}
 
+	fn erase_with_pressure(pressure: f64) -> ImageFrame<Color> {
+		let image = ImageFrame {
+			image: Image::new(20, 20, Color::WHITE),
+			transform: DAffine2::from_scale(DVec2::splat(20.)),
+		};
+		let stroke = BrushStroke {
+			style: BrushStyle {
+				diameter: 6.,
+				hardness: 100.,
+				..Default::default()
+			},
+			trace: [DVec2::new(5., 10.), DVec2::new(15., 10.)]
+				.into_iter()
+				.map(|position| BrushInputSample { position, pressure })
+				.collect(),
+		};
+		let brush = BrushNode::new(
+			ClonedNode::new(ImageFrame::<Color>::empty()),
+			ClonedNode::new(vec![stroke]),
+			ClonedNode::new(BrushCache::new_proto()),
+			ClonedNode::new(BrushMode::Erase),
+			ClonedNode::new(false),
+			ClonedNode::new(50.),
+			ClonedNode::new(50.),
+		);
+		futures::executor::block_on(brush.eval(image))
+	}
+
+	#[test]
+	fn test_brush_erase_mode_reduces_alpha() {
+		let alpha_at = |image: &ImageFrame<Color>, x: usize, y: usize| image.image.data[y * image.image.width as usize + x].a();
+
+		let erased = erase_with_pressure(1.);
+		assert_eq!((erased.image.width, erased.image.height), (20, 20));
+		assert!(alpha_at(&erased, 10, 10) < 0.01, "the stroke should erase the center");
+		assert_eq!(alpha_at(&erased, 0, 0), 1., "pixels away from the stroke should be untouched");
+
+		// Lower pressure erases less
+		let lightly_erased = erase_with_pressure(0.25);
+		assert!(alpha_at(&lightly_erased, 10, 10) > alpha_at(&erased, 10, 10));
+		assert!(alpha_at(&lightly_erased, 10, 10) < 1.);
+	}
+
+	#[test]
+	fn test_brush_spacing_controls_stamp_count() {
+		let stroke_with_spacing = |spacing| BrushStroke {
+			style: BrushStyle {
+				diameter: 10.,
+				spacing,
+				..Default::default()
+			},
+			trace: vec![BrushInputSample::new(DVec2::ZERO), BrushInputSample::new(DVec2::new(100., 0.))],
+		};
+
+		let dense = stroke_with_spacing(10.).compute_blit_points();
+		let sparse = stroke_with_spacing(100.).compute_blit_points();
+		assert_eq!(dense.len(), 101);
+		assert_eq!(sparse.len(), 11);
+
+		// Spacing can't drop low enough to place an unbounded number of stamps
+		assert_eq!(stroke_with_spacing(0.).compute_blit_points(), stroke_with_spacing(1.).compute_blit_points());
+	}
+
+	#[test]
+	fn test_scatter_vector_points() {
+		use graphene_core::uuid::ManipulatorGroupId;
+		use graphene_core::vector::bezier_rs::Subpath;
+
+		let mut vector = VectorData::from_subpath(Subpath::<ManipulatorGroupId>::from_anchors([DVec2::new(5., 5.), DVec2::new(35., 15.)], false));
+		vector.transform = DAffine2::from_translation(DVec2::new(10., 0.));
+		let points = VectorPointsNode.eval(vector);
+		assert_eq!(points, VectorPoints(vec![DVec2::new(15., 5.), DVec2::new(45., 15.)]));
+
+		let scatter = ScatterNode::new(ClonedNode::new(points), ClonedNode::new(10.), ClonedNode::new(100.), ClonedNode::new(Color::BLACK));
+		let image = scatter.eval(ImageFrame::<Color>::empty());
+		assert_eq!((image.image.width, image.image.height), (40, 20));
+		assert_eq!(image.transform, DAffine2::from_translation(DVec2::new(10., 0.)) * DAffine2::from_scale(DVec2::new(40., 20.)));
+
+		// The image spans document space from (10, 0) to (50, 20) at one pixel per unit
+		let alpha_at = |x: usize, y: usize| image.image.data[y * 40 + (x - 10)].a();
+		assert!(alpha_at(15, 5) > 0.99, "the first point should be stamped");
+		assert!(alpha_at(45, 15) > 0.99, "the second point should be stamped");
+		assert_eq!(alpha_at(30, 10), 0., "the space between points should stay empty");
+	}
+
 	#[test]
 	fn test_brush() {
 		let brush_texture_node = BrushStampGeneratorNode::new(ClonedNode::new(Color::BLACK), ClonedNode::new(1.), ClonedNode::new(1.));
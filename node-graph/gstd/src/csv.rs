@@ -0,0 +1,210 @@
+use dyn_any::{DynAny, StaticType};
+use graphene_core::Node;
+
+use std::sync::Arc;
+
+/// The cells of one CSV column, typed as numbers when every cell that isn't empty is a number, and as text otherwise.
+/// Empty cells and cells missing from short rows are `None`.
+#[derive(Debug, Clone, PartialEq, DynAny)]
+pub enum CsvColumn {
+	Numbers(Vec<Option<f64>>),
+	Text(Vec<Option<String>>),
+}
+
+impl Default for CsvColumn {
+	fn default() -> Self {
+		Self::Numbers(Vec::new())
+	}
+}
+
+impl CsvColumn {
+	pub fn len(&self) -> usize {
+		match self {
+			Self::Numbers(numbers) => numbers.len(),
+			Self::Text(text) => text.len(),
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// The columns of a CSV file, named by the header row.
+#[derive(Debug, Clone, Default, PartialEq, DynAny)]
+pub struct CsvTable {
+	pub headers: Vec<String>,
+	pub columns: Vec<CsvColumn>,
+}
+
+impl CsvTable {
+	/// Parses CSV text whose first row holds the column names. Fields are separated by commas and may be enclosed in double quotes,
+	/// in which case they can contain commas, line breaks and doubled `""` quotes.
+	pub fn parse(text: &str) -> Result<Self, String> {
+		let mut rows = parse_rows(text)?.into_iter();
+		let headers = rows.next().ok_or("The CSV file is empty")?;
+		let rows: Vec<Vec<String>> = rows.collect();
+
+		let columns = (0..headers.len())
+			.map(|column| {
+				let cells: Vec<Option<&str>> = rows.iter().map(|row| row.get(column).map(|cell| cell.trim()).filter(|cell| !cell.is_empty())).collect();
+				let numbers: Option<Vec<Option<f64>>> = cells.iter().map(|cell| cell.map(|cell| cell.parse::<f64>().ok()).transpose()).collect();
+				match numbers {
+					Some(numbers) if cells.iter().any(Option::is_some) => CsvColumn::Numbers(numbers),
+					_ => CsvColumn::Text(cells.into_iter().map(|cell| cell.map(str::to_string)).collect()),
+				}
+			})
+			.collect();
+		Ok(Self { headers, columns })
+	}
+
+	pub fn column(&self, name: &str) -> Option<&CsvColumn> {
+		let index = self.headers.iter().position(|header| header.trim() == name.trim())?;
+		self.columns.get(index)
+	}
+}
+
+/// Splits CSV text into rows of fields, skipping blank lines.
+fn parse_rows(text: &str) -> Result<Vec<Vec<String>>, String> {
+	let mut rows = Vec::new();
+	let mut row = Vec::new();
+	let mut field = String::new();
+	let mut in_quotes = false;
+	let mut chars = text.chars().peekable();
+
+	while let Some(character) = chars.next() {
+		match (character, in_quotes) {
+			('"', true) if chars.peek() == Some(&'"') => {
+				chars.next();
+				field.push('"');
+			}
+			('"', true) => in_quotes = false,
+			('"', false) if field.trim().is_empty() => {
+				field.clear();
+				in_quotes = true;
+			}
+			(',', false) => row.push(std::mem::take(&mut field)),
+			('\r', false) if chars.peek() == Some(&'\n') => {}
+			('\n' | '\r', false) => {
+				row.push(std::mem::take(&mut field));
+				if row.len() > 1 || !row[0].is_empty() {
+					rows.push(std::mem::take(&mut row));
+				} else {
+					row.clear();
+				}
+			}
+			_ => field.push(character),
+		}
+	}
+	if in_quotes {
+		return Err("A quoted field isn't closed".to_string());
+	}
+	if !row.is_empty() || !field.is_empty() {
+		row.push(field);
+		rows.push(row);
+	}
+	Ok(rows)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DecodeCsvNode;
+
+#[node_macro::node_fn(DecodeCsvNode)]
+fn decode_csv(data: Arc<[u8]>) -> CsvTable {
+	CsvTable::parse(&String::from_utf8_lossy(data.as_ref())).unwrap_or_else(|error| {
+		warn!("Failed to parse the CSV file: {error}");
+		CsvTable::default()
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CsvColumnNode<Column> {
+	column: Column,
+}
+
+/// Selects the column with the given header name, or an empty column if there is none.
+#[node_macro::node_fn(CsvColumnNode)]
+fn csv_column(table: CsvTable, column: String) -> CsvColumn {
+	table.column(&column).cloned().unwrap_or_else(|| {
+		warn!("The CSV file has no column named `{column}`, the columns are {:?}", table.headers);
+		CsvColumn::default()
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CsvNumbersNode;
+
+/// The numbers of each row, with 0 for missing values. A text column gives 0 for every cell that isn't a number.
+#[node_macro::node_fn(CsvNumbersNode)]
+fn csv_numbers(column: CsvColumn) -> Vec<f32> {
+	match column {
+		CsvColumn::Numbers(numbers) => numbers.into_iter().map(|number| number.unwrap_or_default() as f32).collect(),
+		CsvColumn::Text(text) => text.into_iter().map(|cell| cell.and_then(|cell| cell.parse::<f32>().ok()).unwrap_or_default()).collect(),
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CsvTextNode;
+
+/// The text of each row, with an empty string for missing values.
+#[node_macro::node_fn(CsvTextNode)]
+fn csv_text(column: CsvColumn) -> Vec<String> {
+	match column {
+		CsvColumn::Numbers(numbers) => numbers.into_iter().map(|number| number.map(|number| number.to_string()).unwrap_or_default()).collect(),
+		CsvColumn::Text(text) => text.into_iter().map(Option::unwrap_or_default).collect(),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::value::ClonedNode;
+
+	const CSV: &str = "name,width, height,note\r
+Card,85.6,53.98,\"Wallet, sized\"\r
+\"Letter \"\"US\"\"\",215.9,279.4,\r
+A4,210,,\"Two\r
+lines\"\r
+\r
+Postcard,148\r
+";
+
+	#[test]
+	fn parses_typed_columns() {
+		let table = CsvTable::parse(CSV).unwrap();
+		assert_eq!(table.headers, ["name", "width", " height", "note"]);
+
+		assert_eq!(
+			table.column("name"),
+			Some(&CsvColumn::Text(vec![Some("Card".into()), Some("Letter \"US\"".into()), Some("A4".into()), Some("Postcard".into())]))
+		);
+		assert_eq!(table.column("width"), Some(&CsvColumn::Numbers(vec![Some(85.6), Some(215.9), Some(210.), Some(148.)])));
+		assert_eq!(
+			table.column("height"),
+			Some(&CsvColumn::Numbers(vec![Some(53.98), Some(279.4), None, None])),
+			"Missing cells should be empty"
+		);
+		assert_eq!(
+			table.column("note"),
+			Some(&CsvColumn::Text(vec![Some("Wallet, sized".into()), None, Some("Two\r\nlines".into()), None]))
+		);
+		assert_eq!(table.column("depth"), None);
+	}
+
+	#[test]
+	fn column_nodes_fill_in_missing_values() {
+		let table = CsvTable::parse(CSV).unwrap();
+		let column = |name: &str| CsvColumnNode::new(ClonedNode::new(name.to_string())).eval(table.clone());
+
+		assert_eq!(CsvNumbersNode::new().eval(column("height")), vec![53.98, 279.4, 0., 0.]);
+		assert_eq!(CsvTextNode::new().eval(column("height")), ["53.98", "279.4", "", ""]);
+		assert_eq!(CsvTextNode::new().eval(column("name")), ["Card", "Letter \"US\"", "A4", "Postcard"]);
+		assert!(column("depth").is_empty());
+	}
+
+	#[test]
+	fn invalid_csv_files() {
+		assert!(CsvTable::parse("").is_err());
+		assert!(CsvTable::parse("name\n\"Unclosed\n").is_err());
+	}
+}
@@ -361,6 +361,7 @@ fn map_gpu_single_image(input: Image<Color>, node: String) -> Image<Color> {
 	let network = NodeNetwork {
 		inputs: vec![0],
 		disabled: vec![],
+		bypassed: vec![],
 		previous_outputs: None,
 		outputs: vec![NodeOutput::new(0, 0)],
 		nodes: [(
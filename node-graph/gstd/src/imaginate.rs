@@ -3,9 +3,11 @@ use core::any::TypeId;
 use core::future::Future;
 use futures::{future::Either, TryFutureExt};
 use glam::{DVec2, U64Vec2};
-use graph_craft::imaginate_input::{ImaginateController, ImaginateMaskStartingFill, ImaginatePreferences, ImaginateSamplingMethod, ImaginateServerStatus, ImaginateStatus, ImaginateTerminationHandle};
+use graph_craft::imaginate_input::{
+	ImaginateControlType, ImaginateController, ImaginateMaskStartingFill, ImaginatePreferences, ImaginateSamplingMethod, ImaginateServerStatus, ImaginateStatus, ImaginateTerminationHandle,
+};
 use graphene_core::application_io::NodeGraphUpdateMessage;
-use graphene_core::raster::{Color, Image, Luma, Pixel};
+use graphene_core::raster::{Color, Image, ImageFrame, Luma, Pixel};
 use image::{DynamicImage, ImageBuffer, ImageOutputFormat};
 use reqwest::Url;
 
@@ -257,8 +259,71 @@ struct ImaginateCommonImageRequest<'a> {
 	tiling: bool,
 	negative_prompt: String,
 	sampler_index: &'a str,
+	#[serde(flatten)]
+	variation: Option<ImaginateVariation>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	alwayson_scripts: Option<ImaginateAlwaysOnScripts>,
+}
+
+/// Blends the noise of the seed with the noise of a secondary seed, giving controlled variations of the seed's result.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct ImaginateVariation {
+	subseed: f64,
+	subseed_strength: f64,
+}
+
+/// The variation towards `variation_seed` at a percentage `strength`, or none at 0% so the seed is reproduced exactly.
+fn variation(variation_seed: f64, strength: f32) -> Option<ImaginateVariation> {
+	(strength > 0.).then(|| ImaginateVariation {
+		subseed: variation_seed,
+		subseed_strength: (strength as f64 * 0.01).min(1.),
+	})
+}
+
+/// Extensions that run alongside every generation, which is how the server accepts ControlNet guidance.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct ImaginateAlwaysOnScripts {
+	controlnet: ImaginateControlNet,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct ImaginateControlNet {
+	args: Vec<ImaginateControlNetUnit>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct ImaginateControlNetUnit {
+	input_image: String,
+	module: &'static str,
+	model: &'static str,
 }
 
+/// The scripts guiding the generation with the control image, or none when no control image is connected so the request is unchanged.
+fn control_scripts(control_image: ImageFrame<Color>, control_type: ImaginateControlType) -> Result<Option<ImaginateAlwaysOnScripts>, Error> {
+	if control_image.image.width == 0 || control_image.image.height == 0 {
+		return Ok(None);
+	}
+	let unit = ImaginateControlNetUnit {
+		input_image: image_to_base64(control_image.image)?,
+		module: control_type.module(),
+		model: control_type.model(),
+	};
+	Ok(Some(ImaginateAlwaysOnScripts {
+		controlnet: ImaginateControlNet { args: vec![unit] },
+	}))
+}
+
+/// The seeds of a batch of `count` variations, counting up from the base seed so each variation can be reproduced on its own.
+pub fn batch_seeds(seed: f64, count: u32) -> Vec<f64> {
+	(0..count.max(1)).map(|index| seed + index as f64).collect()
+}
+
+/// Generates a batch of variations, one after another, each with its own seed from [`batch_seeds`].
+/// Terminating or failing a generation skips the rest of the batch, so only the variations finished before it are returned.
 #[cfg(feature = "imaginate")]
 #[allow(clippy::too_many_arguments)]
 pub async fn imaginate<'a, P: Pixel>(
@@ -280,7 +345,14 @@ pub async fn imaginate<'a, P: Pixel>(
 	mask_starting_fill: impl Future<Output = ImaginateMaskStartingFill>,
 	improve_faces: impl Future<Output = bool>,
 	tiling: impl Future<Output = bool>,
-) -> Image<P> {
+	batch_count: impl Future<Output = u32>,
+	control_image: impl Future<Output = ImageFrame<Color>>,
+	control_type: impl Future<Output = ImaginateControlType>,
+	variation_seed: impl Future<Output = f64>,
+	variation_strength: impl Future<Output = f32>,
+) -> Vec<Image<P>> {
+	use futures::future::ready;
+
 	let WasmEditorApi {
 		node_graph_message_sender,
 		imaginate_preferences,
@@ -291,40 +363,63 @@ pub async fn imaginate<'a, P: Pixel>(
 		node_graph_message_sender.send(NodeGraphUpdateMessage::ImaginateStatusUpdate);
 	};
 	let host_name = imaginate_preferences.get_host_name();
-	imaginate_maybe_fail(
-		image,
-		host_name,
-		set_progress,
-		&controller,
-		seed,
-		res,
-		samples,
-		sampling_method,
-		prompt_guidance,
-		prompt,
-		negative_prompt,
-		adapt_input_image,
-		image_creativity,
-		masking_layer,
-		inpaint,
-		mask_blur,
-		mask_starting_fill,
-		improve_faces,
-		tiling,
-	)
-	.await
-	.unwrap_or_else(|err| {
-		match err {
-			Error::Terminated => {
+
+	let (res, samples, sampling_method, prompt_guidance) = (res.await, samples.await, sampling_method.await, prompt_guidance.await);
+	let (prompt, negative_prompt, adapt_input_image, image_creativity) = (prompt.await, negative_prompt.await, adapt_input_image.await, image_creativity.await);
+	let (masking_layer, inpaint, mask_blur, mask_starting_fill) = (masking_layer.await, inpaint.await, mask_blur.await, mask_starting_fill.await);
+	let (improve_faces, tiling, control_image, control_type) = (improve_faces.await, tiling.await, control_image.await, control_type.await);
+	let (variation_seed, variation_strength) = (variation_seed.await, variation_strength.await);
+	let seeds = batch_seeds(seed.await, batch_count.await);
+
+	// A termination requested while no batch was running was meant for an earlier one
+	controller.take_termination_request();
+
+	let mut images = Vec::with_capacity(seeds.len());
+	for seed in seeds {
+		if controller.take_termination_request() {
+			set_progress(ImaginateStatus::Terminated);
+			break;
+		}
+		let result = imaginate_maybe_fail(
+			image.clone(),
+			host_name,
+			&set_progress,
+			&controller,
+			ready(seed),
+			ready(res),
+			ready(samples),
+			ready(sampling_method),
+			ready(prompt_guidance),
+			ready(prompt.clone()),
+			ready(negative_prompt.clone()),
+			ready(adapt_input_image),
+			ready(image_creativity),
+			ready(masking_layer.clone()),
+			ready(inpaint),
+			ready(mask_blur),
+			ready(mask_starting_fill),
+			ready(improve_faces),
+			ready(tiling),
+			ready(control_image.clone()),
+			ready(control_type),
+			ready(variation_seed),
+			ready(variation_strength),
+		)
+		.await;
+		match result {
+			Ok(image) => images.push(image),
+			Err(Error::Terminated) => {
 				set_progress(ImaginateStatus::Terminated);
+				break;
 			}
-			err => {
+			Err(err) => {
 				error!("{err}");
 				set_progress(ImaginateStatus::Failed(err.to_string()));
+				break;
 			}
-		};
-		Image::empty()
-	})
+		}
+	}
+	images
 }
 
 #[cfg(feature = "imaginate")]
@@ -349,6 +444,10 @@ async fn imaginate_maybe_fail<'a, P: Pixel, F: Fn(ImaginateStatus)>(
 	_mask_starting_fill: impl Future<Output = ImaginateMaskStartingFill>,
 	improve_faces: impl Future<Output = bool>,
 	tiling: impl Future<Output = bool>,
+	control_image: impl Future<Output = ImageFrame<Color>>,
+	control_type: impl Future<Output = ImaginateControlType>,
+	variation_seed: impl Future<Output = f64>,
+	variation_strength: impl Future<Output = f32>,
 ) -> Result<Image<P>, Error> {
 	set_progress(ImaginateStatus::Beginning);
 
@@ -374,6 +473,8 @@ async fn imaginate_maybe_fail<'a, P: Pixel, F: Fn(ImaginateStatus)>(
 		tiling: tiling.await,
 		negative_prompt: negative_prompt.await,
 		sampler_index,
+		variation: variation(variation_seed.await, variation_strength.await),
+		alwayson_scripts: control_scripts(control_image.await, control_type.await)?,
 	};
 	let request_builder = if adapt_input_image.await {
 		let base64_data = image_to_base64(image)?;
@@ -491,6 +592,16 @@ fn base64_to_image<D: AsRef<[u8]>, P: Pixel>(base64_data: D) -> Result<Image<P>,
 	Ok(Image { data: result_data, width, height })
 }
 
+/// Commonly used resolutions, all multiples of 64 within the 1 megapixel limit of [`pick_safe_imaginate_resolution`].
+pub const IMAGINATE_RESOLUTION_PRESETS: [(&str, (u64, u64)); 6] = [
+	("512×512 Square", (512, 512)),
+	("768×768 Square", (768, 768)),
+	("512×768 Portrait", (512, 768)),
+	("768×512 Landscape", (768, 512)),
+	("576×1024 Tall Portrait", (576, 1024)),
+	("1024×576 Wide Landscape", (1024, 576)),
+];
+
 pub fn pick_safe_imaginate_resolution((width, height): (f64, f64)) -> (u64, u64) {
 	const MAX_RESOLUTION: u64 = 1000 * 1000;
 
@@ -520,3 +631,173 @@ pub fn pick_safe_imaginate_resolution((width, height): (f64, f64)) -> (u64, u64)
 		size.into()
 	}
 }
+
+#[cfg(all(test, feature = "imaginate"))]
+mod test {
+	use super::*;
+	use std::io::{BufRead, BufReader, Read, Write};
+	use std::net::{SocketAddr, TcpListener, TcpStream};
+	use std::sync::mpsc::{channel, Receiver, Sender};
+	use std::sync::{Arc, Mutex};
+
+	/// Serves the Stable Diffusion API endpoints Imaginate calls. Progress is always reported as halfway, and generating returns a 1x1 image.
+	/// Generating only finishes once a second progress request has arrived, which Imaginate sends after it has handled the response to the first.
+	fn spawn_mock_server() -> SocketAddr {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let address = listener.local_addr().unwrap();
+		let (progress_requested, progress_requests) = channel();
+		let progress_requests = Arc::new(Mutex::new(progress_requests));
+		std::thread::spawn(move || {
+			for stream in listener.incoming().flatten() {
+				let (progress_requested, progress_requests) = (progress_requested.clone(), progress_requests.clone());
+				std::thread::spawn(move || respond(stream, progress_requested, progress_requests));
+			}
+		});
+		address
+	}
+
+	fn respond(mut stream: TcpStream, progress_requested: Sender<()>, progress_requests: Arc<Mutex<Receiver<()>>>) {
+		let mut reader = BufReader::new(stream.try_clone().unwrap());
+		let mut request_line = String::new();
+		reader.read_line(&mut request_line).unwrap();
+		let mut content_length = 0;
+		loop {
+			let mut header = String::new();
+			reader.read_line(&mut header).unwrap();
+			if header.trim().is_empty() {
+				break;
+			}
+			if let Some((name, value)) = header.split_once(':') {
+				if name.eq_ignore_ascii_case("content-length") {
+					content_length = value.trim().parse().unwrap();
+				}
+			}
+		}
+		let mut request_body = vec![0; content_length];
+		reader.read_exact(&mut request_body).unwrap();
+
+		let body = if request_line.contains(SDAPI_TEXT_TO_IMAGE) {
+			let progress_requests = progress_requests.lock().unwrap();
+			for _ in 0..2 {
+				progress_requests.recv().unwrap();
+			}
+			let image = image_to_base64(Image::new(1, 1, Color::RED)).unwrap();
+			format!(r#"{{"images": ["{image}"]}}"#)
+		} else {
+			progress_requested.send(()).unwrap();
+			r#"{"progress": 0.5}"#.to_string()
+		};
+		let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+		// Imaginate stops reading the last progress response once the generation has finished
+		let _ = stream.write_all(response.as_bytes());
+	}
+
+	#[test]
+	fn batch_seeds_are_distinct_and_deterministic() {
+		let seeds = batch_seeds(42., 3);
+		assert_eq!(seeds, vec![42., 43., 44.]);
+		assert_eq!(seeds, batch_seeds(42., 3));
+		assert_eq!(batch_seeds(42., 0), vec![42.], "A batch always generates at least one image");
+	}
+
+	/// The JSON sent for a text to image request with a seed of 42 and typical settings, along with the given variation and scripts
+	fn text_to_image_request(variation: Option<ImaginateVariation>, alwayson_scripts: Option<ImaginateAlwaysOnScripts>) -> serde_json::Value {
+		let common = ImaginateCommonImageRequest {
+			prompt: "A test".to_string(),
+			seed: 42.,
+			steps: 30,
+			cfg_scale: 7.5,
+			width: 512.,
+			height: 512.,
+			restore_faces: false,
+			tiling: false,
+			negative_prompt: String::new(),
+			sampler_index: "Euler a",
+			variation,
+			alwayson_scripts,
+		};
+		let request = ImaginateTextToImageRequest {
+			common,
+			override_settings: Default::default(),
+		};
+		serde_json::to_value(request).unwrap()
+	}
+
+	#[test]
+	fn control_fields_are_only_sent_with_a_control_image() {
+		let request = |control_image: ImageFrame<Color>| text_to_image_request(None, control_scripts(control_image, ImaginateControlType::Edges).unwrap());
+
+		let without_control = request(ImageFrame::empty());
+		assert!(without_control.get("alwayson_scripts").is_none(), "{without_control}");
+
+		let with_control = request(ImageFrame {
+			image: Image::new(2, 2, Color::WHITE),
+			transform: Default::default(),
+		});
+		let unit = &with_control["alwayson_scripts"]["controlnet"]["args"][0];
+		assert_eq!(unit["module"], "canny");
+		assert_eq!(unit["model"], ImaginateControlType::Edges.model());
+		assert!(unit["input_image"].as_str().is_some_and(|image| !image.is_empty()));
+	}
+
+	#[test]
+	fn variation_fields_are_only_sent_with_a_variation_strength() {
+		let request = |variation_strength: f32| text_to_image_request(variation(7., variation_strength), None);
+
+		let without_variation = request(0.);
+		assert_eq!(without_variation["seed"], 42.);
+		assert!(without_variation.get("subseed").is_none(), "{without_variation}");
+		assert!(without_variation.get("subseed_strength").is_none(), "{without_variation}");
+
+		let with_variation = request(25.);
+		assert_eq!(with_variation["seed"], 42.);
+		assert_eq!(with_variation["subseed"], 7.);
+		assert_eq!(with_variation["subseed_strength"], 0.25);
+	}
+
+	#[tokio::test]
+	async fn status_transitions_through_a_generation() {
+		let address = spawn_mock_server();
+		let controller = ImaginateController::default();
+		let statuses = controller.subscribe();
+
+		let image: Image<Color> = imaginate_maybe_fail(
+			Image::empty(),
+			&format!("http://{address}/"),
+			|status| controller.set_status(status),
+			&controller,
+			async { 0. },
+			async { Some(DVec2::splat(64.)) },
+			async { 30 },
+			async { ImaginateSamplingMethod::default() },
+			async { 7.5 },
+			async { "A test".to_string() },
+			async { String::new() },
+			async { false },
+			async { 66. },
+			async { None },
+			async { false },
+			async { 4. },
+			async { ImaginateMaskStartingFill::default() },
+			async { false },
+			async { false },
+			async { ImageFrame::empty() },
+			async { ImaginateControlType::default() },
+			async { 0. },
+			async { 0. },
+		)
+		.await
+		.unwrap();
+		assert_eq!((image.width, image.height), (1, 1));
+
+		let mut transitions: Vec<_> = statuses.try_iter().collect();
+		transitions.dedup();
+		assert_eq!(
+			transitions,
+			vec![ImaginateStatus::Beginning, ImaginateStatus::Generating(50.), ImaginateStatus::Uploading, ImaginateStatus::ReadyDone]
+		);
+		assert_eq!(controller.get_status(), ImaginateStatus::ReadyDone);
+		assert!(!controller.is_busy());
+		assert_eq!(controller.progress(), Some(100.));
+	}
+}
@@ -23,6 +23,12 @@ pub mod image_segmentation;
 
 pub mod brush;
 
+pub mod lut;
+
+pub mod palette;
+
+pub mod csv;
+
 #[cfg(feature = "wasm")]
 pub mod wasm_application_io;
 
@@ -0,0 +1,227 @@
+use dyn_any::{DynAny, StaticType};
+use graphene_core::raster::{Color, ImageFrame};
+use graphene_core::Node;
+
+use std::sync::Arc;
+
+/// A 3D color lookup table, as stored in the `.cube` format.
+/// An empty table leaves colors unchanged.
+#[derive(Debug, Clone, Default, PartialEq, DynAny)]
+pub struct Lut3D {
+	size: usize,
+	domain_min: [f32; 3],
+	domain_max: [f32; 3],
+	/// `size`³ entries, with the red coordinate changing fastest and blue slowest.
+	table: Vec<[f32; 3]>,
+}
+
+impl Lut3D {
+	/// The largest supported `LUT_3D_SIZE`, which is also the largest the `.cube` specification allows
+	pub const MAX_SIZE: usize = 256;
+
+	/// Parses the text of a `.cube` file, which contains a `LUT_3D_SIZE` line, optional `TITLE`, `DOMAIN_MIN`, `DOMAIN_MAX` and `LUT_3D_INPUT_RANGE` lines, and one line of RGB values per entry.
+	/// Other keywords, such as those added by particular applications, are ignored.
+	pub fn parse_cube(text: &str) -> Result<Self, String> {
+		let mut size = None;
+		let mut domain_min = [0.; 3];
+		let mut domain_max = [1.; 3];
+		let mut table = Vec::new();
+
+		let parse_triplet = |values: &[&str], line: &str| -> Result<[f32; 3], String> {
+			match values {
+				[r, g, b] => {
+					let parse = |value: &str| value.parse::<f32>().map_err(|_| format!("Invalid number `{value}` in the line `{line}`"));
+					Ok([parse(r)?, parse(g)?, parse(b)?])
+				}
+				_ => Err(format!("Expected three values in the line `{line}`")),
+			}
+		};
+
+		for line in text.lines().map(str::trim) {
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+			let mut words = line.split_whitespace();
+			let Some(keyword) = words.next() else { continue };
+			let values: Vec<&str> = words.collect();
+			match keyword {
+				"TITLE" => {}
+				"LUT_1D_SIZE" => return Err("1D LUTs aren't supported".to_string()),
+				"LUT_3D_SIZE" => {
+					let parsed = values.first().and_then(|value| value.parse::<usize>().ok()).filter(|size| (2..=Self::MAX_SIZE).contains(size));
+					size = Some(parsed.ok_or_else(|| format!("Invalid LUT size in the line `{line}`, which must be between 2 and {}", Self::MAX_SIZE))?);
+				}
+				"DOMAIN_MIN" => domain_min = parse_triplet(&values, line)?,
+				"DOMAIN_MAX" => domain_max = parse_triplet(&values, line)?,
+				// The same domain for all three channels
+				"LUT_3D_INPUT_RANGE" => {
+					let [min, max] = values[..] else {
+						return Err(format!("Expected two values in the line `{line}`"));
+					};
+					domain_min = parse_triplet(&[min; 3], line)?;
+					domain_max = parse_triplet(&[max; 3], line)?;
+				}
+				_ if keyword.starts_with(|c: char| c.is_ascii_alphabetic()) => {}
+				_ => {
+					let mut entry = vec![keyword];
+					entry.extend(values);
+					table.push(parse_triplet(&entry, line)?);
+				}
+			}
+		}
+
+		let size = size.ok_or("Missing LUT_3D_SIZE")?;
+		if table.len() != size.pow(3) {
+			return Err(format!("Expected {} entries for a LUT of size {size}, but found {}", size.pow(3), table.len()));
+		}
+		Ok(Self { size, domain_min, domain_max, table })
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.table.is_empty()
+	}
+
+	/// Looks up a color with trilinear interpolation between the nearest entries, clamping inputs to the domain.
+	pub fn sample(&self, rgb: [f32; 3]) -> [f32; 3] {
+		if self.is_empty() {
+			return rgb;
+		}
+
+		let max_index = (self.size - 1) as f32;
+		let mut lower = [0; 3];
+		let mut upper = [0; 3];
+		let mut factor = [0.; 3];
+		for channel in 0..3 {
+			let range = self.domain_max[channel] - self.domain_min[channel];
+			let position = if range > 0. { (rgb[channel] - self.domain_min[channel]) / range } else { 0. };
+			let position = (position * max_index).clamp(0., max_index);
+			lower[channel] = position.floor() as usize;
+			upper[channel] = (lower[channel] + 1).min(self.size - 1);
+			factor[channel] = position - lower[channel] as f32;
+		}
+
+		let entry = |r: usize, g: usize, b: usize| self.table[(b * self.size + g) * self.size + r];
+		let lerp = |a: [f32; 3], b: [f32; 3], t: f32| [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t);
+
+		let [r0, g0, b0] = lower;
+		let [r1, g1, b1] = upper;
+		let [tr, tg, tb] = factor;
+		let front = lerp(lerp(entry(r0, g0, b0), entry(r1, g0, b0), tr), lerp(entry(r0, g1, b0), entry(r1, g1, b0), tr), tg);
+		let back = lerp(lerp(entry(r0, g0, b1), entry(r1, g0, b1), tr), lerp(entry(r0, g1, b1), entry(r1, g1, b1), tr), tg);
+		lerp(front, back, tb)
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DecodeCubeLutNode;
+
+#[node_macro::node_fn(DecodeCubeLutNode)]
+fn decode_cube_lut(data: Arc<[u8]>) -> Lut3D {
+	Lut3D::parse_cube(&String::from_utf8_lossy(data.as_ref())).unwrap_or_else(|error| {
+		warn!("Failed to parse the .cube LUT: {error}");
+		Lut3D::default()
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ApplyLutNode<Lut, Intensity> {
+	lut: Lut,
+	intensity: Intensity,
+}
+
+/// Maps each pixel through the LUT, then mixes the result with the original color by `intensity`.
+/// Like most LUTs expect, the lookup happens on gamma encoded sRGB values with straight alpha.
+#[node_macro::node_fn(ApplyLutNode)]
+fn apply_lut(image_frame: ImageFrame<Color>, lut: Lut3D, intensity: f32) -> ImageFrame<Color> {
+	let intensity = (intensity / 100.).clamp(0., 1.);
+	if lut.is_empty() || intensity == 0. {
+		return image_frame;
+	}
+
+	let mut image_frame = image_frame;
+	for pixel in image_frame.image.data.iter_mut() {
+		let straight = pixel.to_unassociated_alpha().to_gamma_srgb();
+		let [r, g, b] = lut.sample([straight.r(), straight.g(), straight.b()]);
+		let mapped = Color::from_rgbaf32_unchecked(r, g, b, straight.a()).to_linear_srgb();
+		let mapped = Color::from_unassociated_alpha(mapped.r(), mapped.g(), mapped.b(), pixel.a());
+		*pixel = pixel.lerp(mapped, intensity);
+	}
+	image_frame
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::raster::{Alpha, Image};
+	use graphene_core::value::ClonedNode;
+
+	use glam::DAffine2;
+
+	/// A LUT of size 2 whose entries are given by `map` applied to the corners of the color cube.
+	fn cube_from_corners(map: impl Fn([f32; 3]) -> [f32; 3]) -> String {
+		let mut text = "TITLE \"Test\"\n# Corners of the color cube\nLUT_3D_SIZE 2\n".to_string();
+		for b in [0., 1.] {
+			for g in [0., 1.] {
+				for r in [0., 1.] {
+					let [r, g, b] = map([r, g, b]);
+					text += &format!("{r} {g} {b}\n");
+				}
+			}
+		}
+		text
+	}
+
+	fn apply(cube: &str, color: Color, intensity: f32) -> Color {
+		let lut = Lut3D::parse_cube(cube).unwrap();
+		let image_frame = ImageFrame {
+			image: Image::new(1, 1, color),
+			transform: DAffine2::IDENTITY,
+		};
+		ApplyLutNode::new(ClonedNode::new(lut), ClonedNode::new(intensity)).eval(image_frame).image.data[0]
+	}
+
+	fn assert_close(actual: Color, expected: Color) {
+		let channels = |c: Color| [c.r(), c.g(), c.b(), c.a()];
+		assert!(
+			channels(actual).iter().zip(channels(expected)).all(|(a, b)| (a - b).abs() < 1e-4),
+			"expected {expected:?} but got {actual:?}"
+		);
+	}
+
+	#[test]
+	fn identity_lut_leaves_colors_unchanged() {
+		let identity = cube_from_corners(|rgb| rgb);
+		for color in [Color::from_rgbaf32_unchecked(0.2, 0.5, 0.8, 1.), Color::from_rgbaf32_unchecked(0.9, 0.1, 0.3, 1.).multiplied_alpha(0.5)] {
+			assert_close(apply(&identity, color, 100.), color);
+		}
+	}
+
+	#[test]
+	fn lut_swapping_red_and_blue() {
+		let swap = cube_from_corners(|[r, g, b]| [b, g, r]);
+
+		assert_close(apply(&swap, Color::RED, 100.), Color::BLUE);
+		assert_eq!(apply(&swap, Color::RED, 0.), Color::RED);
+		assert_close(apply(&swap, Color::RED, 50.), Color::from_rgbaf32_unchecked(0.5, 0., 0.5, 1.));
+	}
+
+	#[test]
+	fn invalid_cube_files() {
+		assert!(Lut3D::parse_cube("0 0 0\n").is_err());
+		assert!(Lut3D::parse_cube("LUT_3D_SIZE 2\n0 0 0\n").is_err());
+		assert!(Lut3D::parse_cube("LUT_1D_SIZE 2\n0 0 0\n1 1 1\n").is_err());
+		assert!(Lut3D::parse_cube("LUT_3D_SIZE 257\n").is_err(), "Sizes over the maximum are rejected before reading the table");
+		assert!(Lut3D::parse_cube("LUT_3D_SIZE 18446744073709551615\n").is_err());
+	}
+
+	#[test]
+	fn unknown_keywords_are_skipped() {
+		let mut text = "LUT_3D_SIZE 2\nLUT_3D_INPUT_RANGE 0 2\nLUT_IN_VIDEO_RANGE\n".to_string();
+		for index in 0..8 {
+			text += &format!("{} {} {}\n", index & 1, (index >> 1) & 1, (index >> 2) & 1);
+		}
+		let lut = Lut3D::parse_cube(&text).unwrap();
+		assert_eq!((lut.domain_min, lut.domain_max), ([0.; 3], [2.; 3]));
+		assert_eq!(lut.sample([1., 1., 1.]), [0.5; 3]);
+	}
+}
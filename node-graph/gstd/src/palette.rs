@@ -0,0 +1,294 @@
+use dyn_any::{DynAny, StaticType};
+use graphene_core::raster::Color;
+use graphene_core::Node;
+
+use std::sync::Arc;
+
+/// A color of a palette, with the name it was given in the palette file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteEntry {
+	pub name: String,
+	pub color: Color,
+}
+
+/// A list of named colors, as imported from an Adobe Swatch Exchange (`.ase`) or GIMP palette (`.gpl`) file.
+#[derive(Debug, Clone, Default, PartialEq, DynAny)]
+pub struct Palette {
+	pub entries: Vec<PaletteEntry>,
+}
+
+impl Palette {
+	/// Parses either palette format, telling them apart by the signature at the start of the file.
+	pub fn parse(data: &[u8]) -> Result<Self, String> {
+		if data.starts_with(ASE_SIGNATURE) {
+			Self::parse_ase(data)
+		} else {
+			Self::parse_gpl(&String::from_utf8_lossy(data))
+		}
+	}
+
+	/// Parses the text of a `.gpl` file, which starts with a `GIMP Palette` line and optional `Name:` and `Columns:` lines, then has one line per color with its red, green and blue values from 0 to 255 followed by an optional name.
+	/// Lines that aren't valid colors are skipped with a warning.
+	pub fn parse_gpl(text: &str) -> Result<Self, String> {
+		let mut lines = text.lines().map(str::trim);
+		if lines.next() != Some("GIMP Palette") {
+			return Err("Missing the `GIMP Palette` header".to_string());
+		}
+
+		let mut entries = Vec::new();
+		for line in lines {
+			if line.is_empty() || line.starts_with('#') || line.starts_with("Name:") || line.starts_with("Columns:") {
+				continue;
+			}
+			let mut words = line.split_whitespace();
+			let channels = [words.next(), words.next(), words.next()].map(|channel| channel.and_then(|channel| channel.parse::<u8>().ok()));
+			let [Some(red), Some(green), Some(blue)] = channels else {
+				warn!("Skipping the palette line `{line}` because it isn't a color");
+				continue;
+			};
+			let name = words.collect::<Vec<_>>().join(" ");
+			entries.push(PaletteEntry {
+				name,
+				color: Color::from_rgb8_srgb(red, green, blue),
+			});
+		}
+		Ok(Self { entries })
+	}
+
+	/// Parses the binary data of an `.ase` file, made of big endian blocks of which only the color entries are used. Group blocks are flattened away.
+	/// RGB, CMYK, Lab and gray colors are converted to linear sRGB, while entries in other color models are skipped with a warning.
+	pub fn parse_ase(data: &[u8]) -> Result<Self, String> {
+		let mut reader = AseReader::new(data);
+		if reader.bytes(4)? != ASE_SIGNATURE {
+			return Err("Missing the `ASEF` signature".to_string());
+		}
+		let _version = (reader.u16()?, reader.u16()?);
+		let block_count = reader.u32()?;
+
+		let mut entries = Vec::new();
+		for _ in 0..block_count {
+			let block_type = reader.u16()?;
+			let length = reader.u32()? as usize;
+			let mut block = AseReader::new(reader.bytes(length)?);
+			if block_type != ASE_COLOR_ENTRY {
+				continue;
+			}
+
+			let name = block.name()?;
+			let model = block.bytes(4)?;
+			let color = match model {
+				b"RGB " => {
+					let [red, green, blue] = [block.f32()?, block.f32()?, block.f32()?];
+					Color::from_rgbf32_unchecked(red, green, blue).to_linear_srgb()
+				}
+				b"CMYK" => {
+					let [cyan, magenta, yellow, black] = [block.f32()?, block.f32()?, block.f32()?, block.f32()?];
+					let rgb = [cyan, magenta, yellow].map(|ink| (1. - ink) * (1. - black));
+					Color::from_rgbf32_unchecked(rgb[0], rgb[1], rgb[2]).to_linear_srgb()
+				}
+				b"LAB " => lab_to_color(block.f32()? * 100., block.f32()?, block.f32()?),
+				b"Gray" => {
+					let gray = block.f32()?;
+					Color::from_rgbf32_unchecked(gray, gray, gray).to_linear_srgb()
+				}
+				_ => {
+					warn!("Skipping the swatch `{name}` because its color model `{}` isn't supported", String::from_utf8_lossy(model));
+					continue;
+				}
+			};
+			let [red, green, blue] = [color.r(), color.g(), color.b()].map(|channel| channel.clamp(0., 1.));
+			entries.push(PaletteEntry {
+				name,
+				color: Color::from_rgbf32_unchecked(red, green, blue),
+			});
+		}
+		Ok(Self { entries })
+	}
+}
+
+const ASE_SIGNATURE: &[u8] = b"ASEF";
+const ASE_COLOR_ENTRY: u16 = 0x0001;
+
+/// Reads the big endian values of an `.ase` file, failing instead of reading past its end.
+struct AseReader<'a> {
+	data: &'a [u8],
+	position: usize,
+}
+
+impl<'a> AseReader<'a> {
+	fn new(data: &'a [u8]) -> Self {
+		Self { data, position: 0 }
+	}
+
+	fn bytes(&mut self, count: usize) -> Result<&'a [u8], String> {
+		let bytes = self.data.get(self.position..self.position + count).ok_or("Unexpected end of the swatch file")?;
+		self.position += count;
+		Ok(bytes)
+	}
+
+	fn u16(&mut self) -> Result<u16, String> {
+		Ok(u16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+	}
+
+	fn u32(&mut self) -> Result<u32, String> {
+		Ok(u32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+	}
+
+	fn f32(&mut self) -> Result<f32, String> {
+		Ok(f32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+	}
+
+	/// A name is stored as its length in UTF-16 code units, including a null terminator, followed by those code units.
+	fn name(&mut self) -> Result<String, String> {
+		let length = self.u16()? as usize;
+		let units = (0..length).map(|_| self.u16()).collect::<Result<Vec<_>, _>>()?;
+		Ok(String::from_utf16_lossy(&units).trim_end_matches('\0').to_string())
+	}
+}
+
+/// Converts a CIE L*a*b* color relative to the D50 white point, as `.ase` files store it, to linear sRGB.
+fn lab_to_color(lightness: f32, a: f32, b: f32) -> Color {
+	const EPSILON: f32 = 216. / 24389.;
+	const KAPPA: f32 = 24389. / 27.;
+	const WHITE_D50: [f32; 3] = [0.96422, 1., 0.82521];
+
+	let fy = (lightness + 16.) / 116.;
+	let fx = fy + a / 500.;
+	let fz = fy - b / 200.;
+	let inverse = |t: f32| if t.powi(3) > EPSILON { t.powi(3) } else { (116. * t - 16.) / KAPPA };
+	let [x, y, z] = [inverse(fx), inverse(fy), inverse(fz)];
+	let [x, y, z] = [x * WHITE_D50[0], y * WHITE_D50[1], z * WHITE_D50[2]];
+
+	// The XYZ to linear sRGB matrix, Bradford adapted from the D65 white point of sRGB to D50
+	let red = 3.1338561 * x - 1.6168667 * y - 0.4906146 * z;
+	let green = -0.9787684 * x + 1.9161415 * y + 0.033454 * z;
+	let blue = 0.0719453 * x - 0.2289914 * y + 1.4052427 * z;
+	Color::from_rgbf32_unchecked(red, green, blue)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DecodePaletteNode;
+
+#[node_macro::node_fn(DecodePaletteNode)]
+fn decode_palette(data: Arc<[u8]>) -> Palette {
+	Palette::parse(data.as_ref()).unwrap_or_else(|error| {
+		warn!("Failed to parse the palette: {error}");
+		Palette::default()
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PaletteColorNode<Index> {
+	index: Index,
+}
+
+/// Picks the color at the index of the palette. An index past the last color is clamped to it, and an empty palette gives black.
+#[node_macro::node_fn(PaletteColorNode)]
+fn palette_color(palette: Palette, index: u32) -> Color {
+	let Some(last) = palette.entries.len().checked_sub(1) else {
+		return Color::BLACK;
+	};
+	if index as usize > last {
+		warn!("The number of palette colors is {} and the requested color is {}!", palette.entries.len(), index);
+	}
+	palette.entries[(index as usize).min(last)].color
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::value::ClonedNode;
+
+	const GPL: &str = "GIMP Palette
+Name: Test
+Columns: 3
+#
+255   0   0	Red
+  0 128 255	Sky Blue
+not a color
+ 17  17  17
+";
+
+	/// Writes an `.ase` file with a group containing a swatch for each of the given color models and values.
+	fn ase(swatches: &[(&str, &[u8; 4], &[f32])]) -> Vec<u8> {
+		fn block(data: &mut Vec<u8>, block_type: u16, body: &[u8]) {
+			data.extend(block_type.to_be_bytes());
+			data.extend((body.len() as u32).to_be_bytes());
+			data.extend(body);
+		}
+		fn name(body: &mut Vec<u8>, name: &str) {
+			let units: Vec<u16> = name.encode_utf16().chain([0]).collect();
+			body.extend((units.len() as u16).to_be_bytes());
+			units.iter().for_each(|unit| body.extend(unit.to_be_bytes()));
+		}
+
+		let mut data = b"ASEF".to_vec();
+		data.extend(1_u16.to_be_bytes());
+		data.extend(0_u16.to_be_bytes());
+		data.extend((swatches.len() as u32 + 2).to_be_bytes());
+
+		let mut group = Vec::new();
+		name(&mut group, "Group");
+		block(&mut data, 0xC001, &group);
+		for &(swatch_name, model, values) in swatches {
+			let mut body = Vec::new();
+			name(&mut body, swatch_name);
+			body.extend(model);
+			values.iter().for_each(|value| body.extend(value.to_be_bytes()));
+			body.extend(2_u16.to_be_bytes());
+			block(&mut data, ASE_COLOR_ENTRY, &body);
+		}
+		block(&mut data, 0xC002, &[]);
+		data
+	}
+
+	fn assert_close(actual: Color, expected: Color) {
+		let channels = |c: Color| [c.r(), c.g(), c.b(), c.a()];
+		assert!(
+			channels(actual).iter().zip(channels(expected)).all(|(a, b)| (a - b).abs() < 1e-3),
+			"expected {expected:?} but got {actual:?}"
+		);
+	}
+
+	#[test]
+	fn gpl_palette() {
+		let palette = Palette::parse(GPL.as_bytes()).unwrap();
+		let names: Vec<_> = palette.entries.iter().map(|entry| entry.name.as_str()).collect();
+		assert_eq!(names, ["Red", "Sky Blue", ""], "The invalid line should be skipped");
+		assert_eq!(palette.entries[0].color, Color::from_rgb8_srgb(255, 0, 0));
+		assert_eq!(palette.entries[1].color, Color::from_rgb8_srgb(0, 128, 255));
+
+		assert!(Palette::parse_gpl("255 0 0 Red\n").is_err());
+	}
+
+	#[test]
+	fn ase_palette() {
+		let data = ase(&[
+			("Red", b"RGB ", &[1., 0., 0.]),
+			("Cyan", b"CMYK", &[1., 0., 0., 0.]),
+			("White", b"LAB ", &[1., 0., 0.]),
+			("Gray", b"Gray", &[0.5]),
+			("Unknown", b"HSV ", &[0., 0., 0.]),
+		]);
+		let palette = Palette::parse(&data).unwrap();
+
+		let names: Vec<_> = palette.entries.iter().map(|entry| entry.name.as_str()).collect();
+		assert_eq!(names, ["Red", "Cyan", "White", "Gray"], "The unsupported color model should be skipped");
+		assert_close(palette.entries[0].color, Color::RED);
+		assert_close(palette.entries[1].color, Color::from_rgbf32_unchecked(0., 1., 1.));
+		assert_close(palette.entries[2].color, Color::WHITE);
+		assert_close(palette.entries[3].color, Color::from_rgbf32_unchecked(0.5, 0.5, 0.5).to_linear_srgb());
+
+		assert!(Palette::parse_ase(&data[..data.len() - 3]).is_err(), "A truncated file should fail to parse");
+	}
+
+	#[test]
+	fn palette_color_clamps_the_index() {
+		let palette = Palette::parse(GPL.as_bytes()).unwrap();
+		let color = |index: u32| PaletteColorNode::new(ClonedNode::new(index)).eval(palette.clone());
+
+		assert_eq!(color(1), palette.entries[1].color);
+		assert_eq!(color(7), palette.entries[2].color);
+		assert_eq!(PaletteColorNode::new(ClonedNode::new(0)).eval(Palette::default()), Color::BLACK);
+	}
+}
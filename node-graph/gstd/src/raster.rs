@@ -1,14 +1,19 @@
 use dyn_any::{DynAny, StaticType};
-use glam::{DAffine2, DVec2};
-use graph_craft::imaginate_input::{ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
+use glam::{DAffine2, DVec2, IVec2, UVec2};
+use graph_craft::imaginate_input::{ImaginateControlType, ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
 use graph_craft::proto::DynFuture;
-use graphene_core::raster::{Alpha, BlendMode, BlendNode, Image, ImageFrame, Linear, LinearChannel, Luminance, Pixel, RGBMut, Raster, RasterMut, RedGreenBlue, Sample};
+use graphene_core::raster::{calculate_luminance, GaussianNode, LuminanceCalculation};
+use graphene_core::raster::{Alpha, AlphaMut, BlendMode, BlendNode, DownresFilter, Image, ImageFrame, Linear, LinearChannel, Luminance, Pixel, RGBMut, Raster, RasterMut, RedGreenBlueAlpha, Sample};
 use graphene_core::transform::Transform;
 
 use crate::wasm_application_io::WasmEditorApi;
+use graphene_core::raster::adjustments::blend_colors;
 use graphene_core::raster::bbox::{AxisAlignedBbox, Bbox};
+use graphene_core::renderer::{rasterize, AntiAliasing};
 use graphene_core::value::CopiedNode;
-use graphene_core::{Color, Node};
+use graphene_core::vector::style::Fill;
+use graphene_core::vector::VectorData;
+use graphene_core::{Artboard, Color, GraphicElementData, GraphicGroup, Node};
 
 use std::collections::HashMap;
 use std::fmt::Debug;
@@ -41,6 +46,24 @@ impl FileSystem for StdFs {
 }
 type Reader = Box<dyn std::io::Read>;
 
+/// Writes the images of a render made with [`ImageOutput::LinkedFile`](graphene_core::renderer::ImageOutput) into the directory as the PNG files its `href`s point to.
+/// Images sharing an id are written once. Returns the paths of the written files, in the order the images appear in the SVG.
+pub fn write_linked_images(render: &graphene_core::renderer::SvgRender, directory: &Path) -> Result<Vec<std::path::PathBuf>, Error> {
+	let mut written = Vec::new();
+	for (id, image) in &render.image_data {
+		let path = directory.join(graphene_core::renderer::linked_image_file_name(*id));
+		if written.contains(&path) {
+			continue;
+		}
+
+		let (data, width, height) = image.clone().into_flat_u8();
+		let buffer = image::RgbaImage::from_raw(width, height, data).ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid image size"))?;
+		buffer.save_with_format(&path, image::ImageFormat::Png).map_err(Error::Image)?;
+		written.push(path);
+	}
+	Ok(written)
+}
+
 pub struct FileNode<FileSystem> {
 	fs: FileSystem,
 }
@@ -55,14 +78,24 @@ fn buffer_node<R: std::io::Read>(reader: R) -> Result<Vec<u8>, Error> {
 	Ok(std::io::Read::bytes(reader).collect::<Result<Vec<_>, _>>()?)
 }
 
-pub struct DownresNode<P> {
-	_p: PhantomData<P>,
+pub struct DownresNode<Factor, Filter> {
+	factor: Factor,
+	filter: Filter,
 }
 
-#[node_macro::node_fn(DownresNode<_P>)]
-fn downres<_P: Pixel>(image_frame: ImageFrame<_P>) -> ImageFrame<_P> {
-	let target_width = (image_frame.transform.transform_vector2((1., 0.).into()).length() as usize).min(image_frame.image.width as usize);
-	let target_height = (image_frame.transform.transform_vector2((0., 1.).into()).length() as usize).min(image_frame.image.height as usize);
+/// Reduces the resolution of the image to at most the size it is displayed at, further divided by `factor`.
+/// The transform is kept so the image still covers the same area.
+#[node_macro::node_fn(DownresNode)]
+fn downres(image_frame: ImageFrame<Color>, factor: f64, filter: DownresFilter) -> ImageFrame<Color> {
+	let (source_width, source_height) = (image_frame.image.width as usize, image_frame.image.height as usize);
+	let factor = factor.max(1.);
+	let target_size = |axis: DVec2, source: usize| ((image_frame.transform.transform_vector2(axis).length().min(source as f64) / factor).ceil() as usize).clamp(source.min(1), source);
+	let target_width = target_size(DVec2::X, source_width);
+	let target_height = target_size(DVec2::Y, source_height);
+
+	if target_width == source_width && target_height == source_height {
+		return image_frame;
+	}
 
 	let mut image = Image {
 		width: target_width as u32,
@@ -70,10 +103,33 @@ fn downres<_P: Pixel>(image_frame: ImageFrame<_P>) -> ImageFrame<_P> {
 		data: Vec::with_capacity(target_width * target_height),
 	};
 
-	let scale_factor = DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64) / DVec2::new(target_width as f64, target_height as f64);
+	let block_size = DVec2::new(source_width as f64 / target_width as f64, source_height as f64 / target_height as f64);
+	// The range of source pixels covered by a target pixel along one axis, always containing at least one pixel
+	let block = |target: usize, size: f64, source: usize| {
+		let start = ((target as f64 * size) as usize).min(source - 1);
+		let end = (((target + 1) as f64 * size).ceil() as usize).clamp(start + 1, source);
+		start..end
+	};
+	let source_pixel = |x: usize, y: usize| image_frame.image.data[y * source_width + x];
+
 	for y in 0..target_height {
+		let rows = block(y, block_size.y, source_height);
 		for x in 0..target_width {
-			let pixel = image_frame.sample(DVec2::new(x as f64, y as f64) * scale_factor);
+			let columns = block(x, block_size.x, source_width);
+			let pixel = match filter {
+				DownresFilter::Nearest => source_pixel((columns.start + columns.end) / 2, (rows.start + rows.end) / 2),
+				DownresFilter::Average => {
+					let count = (rows.len() * columns.len()) as f32;
+					let (mut red, mut green, mut blue, mut alpha) = (0., 0., 0., 0.);
+					for pixel in rows.clone().flat_map(|y| columns.clone().map(move |x| (x, y))).map(|(x, y)| source_pixel(x, y)) {
+						red += pixel.r();
+						green += pixel.g();
+						blue += pixel.b();
+						alpha += pixel.a();
+					}
+					Color::from_rgbaf32_unchecked(red / count, green / count, blue / count, alpha / count)
+				}
+			};
 			image.data.push(pixel);
 		}
 	}
@@ -84,6 +140,352 @@ fn downres<_P: Pixel>(image_frame: ImageFrame<_P>) -> ImageFrame<_P> {
 	}
 }
 
+/// Aggregate statistics of an image's straight (unpremultiplied) colors, with the alpha channel measured alongside the color channels.
+#[derive(Debug, Clone, PartialEq, DynAny)]
+pub struct ImageStats {
+	pub min: Color,
+	pub max: Color,
+	pub mean: Color,
+	/// Pixel counts for 256 evenly spaced bins of channel values from 0 to 1, with the red, green, blue, and alpha counts of each bin.
+	pub histogram: Vec<[u32; 4]>,
+}
+
+impl ImageStats {
+	pub const HISTOGRAM_BINS: usize = 256;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImageStatsNode;
+
+/// Measures the image in a single pass over its pixels. An empty image has transparent black statistics and an empty histogram.
+#[node_macro::node_fn(ImageStatsNode)]
+fn image_stats(image_frame: ImageFrame<Color>) -> ImageStats {
+	let mut histogram = vec![[0; 4]; ImageStats::HISTOGRAM_BINS];
+	let mut min = [f32::INFINITY; 4];
+	let mut max = [f32::NEG_INFINITY; 4];
+	let mut sum = [0_f64; 4];
+
+	for pixel in &image_frame.image.data {
+		let pixel = pixel.to_unassociated_alpha();
+		let channels = [pixel.r(), pixel.g(), pixel.b(), pixel.a()];
+		for (channel, value) in channels.into_iter().enumerate() {
+			min[channel] = min[channel].min(value);
+			max[channel] = max[channel].max(value);
+			sum[channel] += value as f64;
+			let bin = (value.clamp(0., 1.) * (ImageStats::HISTOGRAM_BINS - 1) as f32).round() as usize;
+			histogram[bin][channel] += 1;
+		}
+	}
+
+	let count = image_frame.image.data.len();
+	if count == 0 {
+		return ImageStats {
+			min: Color::TRANSPARENT,
+			max: Color::TRANSPARENT,
+			mean: Color::TRANSPARENT,
+			histogram,
+		};
+	}
+
+	let color = |[red, green, blue, alpha]: [f32; 4]| Color::from_rgbaf32_unchecked(red, green, blue, alpha);
+	ImageStats {
+		min: color(min),
+		max: color(max),
+		mean: color(sum.map(|sum| (sum / count as f64) as f32)),
+		histogram,
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImageStatsMinNode;
+
+#[node_macro::node_fn(ImageStatsMinNode)]
+fn image_stats_min(stats: ImageStats) -> Color {
+	stats.min
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImageStatsMaxNode;
+
+#[node_macro::node_fn(ImageStatsMaxNode)]
+fn image_stats_max(stats: ImageStats) -> Color {
+	stats.max
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImageStatsMeanNode;
+
+#[node_macro::node_fn(ImageStatsMeanNode)]
+fn image_stats_mean(stats: ImageStats) -> Color {
+	stats.mean
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SamplePixelNode<UV> {
+	uv: UV,
+}
+
+/// Bilinearly interpolates the color at `uv`, where (0, 0) is the top left and (1, 1) the bottom right corner of the image.
+/// Positions past the edges are clamped to the nearest edge pixels.
+#[node_macro::node_fn(SamplePixelNode)]
+fn sample_pixel(image_frame: ImageFrame<Color>, uv: DVec2) -> Color {
+	sample_bilinear(&image_frame.image, uv)
+}
+
+fn sample_bilinear(image: &Image<Color>, uv: DVec2) -> Color {
+	let (width, height) = (image.width as usize, image.height as usize);
+	if width == 0 || height == 0 || !uv.is_finite() {
+		return Color::TRANSPARENT;
+	}
+
+	// Pixel centers are offset by half a pixel from their corners
+	let position = uv * DVec2::new(width as f64, height as f64) - 0.5;
+	let position = position.clamp(DVec2::ZERO, DVec2::new(width as f64 - 1., height as f64 - 1.));
+	let start = position.floor();
+	let factor = position - start;
+
+	let (left, top) = (start.x as usize, start.y as usize);
+	let (right, bottom) = ((left + 1).min(width - 1), (top + 1).min(height - 1));
+	let pixel = |x: usize, y: usize| image.data[y * width + x];
+
+	let top_row = pixel(left, top).lerp(pixel(right, top), factor.x as f32);
+	let bottom_row = pixel(left, bottom).lerp(pixel(right, bottom), factor.x as f32);
+	top_row.lerp(bottom_row, factor.y as f32)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SetPixelNode<UV, PixelColor> {
+	uv: UV,
+	color: PixelColor,
+}
+
+/// Replaces the pixel containing `uv`, using the same coordinates as [SamplePixelNode] and clamping to the image edges.
+#[node_macro::node_fn(SetPixelNode)]
+fn set_pixel(image_frame: ImageFrame<Color>, uv: DVec2, color: Color) -> ImageFrame<Color> {
+	let mut image_frame = image_frame;
+	let (width, height) = (image_frame.image.width as usize, image_frame.image.height as usize);
+	if width == 0 || height == 0 || !uv.is_finite() {
+		return image_frame;
+	}
+
+	let position = (uv * DVec2::new(width as f64, height as f64)).floor();
+	let x = position.x.clamp(0., width as f64 - 1.) as usize;
+	let y = position.y.clamp(0., height as f64 - 1.) as usize;
+	*image_frame.get_mut(x, y) = color;
+
+	image_frame
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DisplaceNode<Map, Strength> {
+	map: Map,
+	strength: Strength,
+}
+
+/// Moves each pixel by an offset read from the displacement map where it overlaps the image, with red driving the horizontal and green the vertical offset.
+/// A channel value of 0.5 leaves the pixel in place, while 0 and 1 shift it by `strength` pixels in either direction.
+#[node_macro::node_fn(DisplaceNode)]
+fn displace(image_frame: ImageFrame<Color>, map: ImageFrame<Color>, strength: f64) -> ImageFrame<Color> {
+	let (width, height) = (image_frame.image.width, image_frame.image.height);
+	if strength == 0. || width == 0 || height == 0 || map.image.width == 0 || map.image.height == 0 || map.transform.matrix2.determinant() == 0. {
+		return image_frame;
+	}
+
+	let size = DVec2::new(width as f64, height as f64);
+	let pixel_to_map = map.transform.inverse() * image_frame.transform * DAffine2::from_scale(1. / size);
+	let mut result = image_frame.clone();
+	for y in 0..height {
+		for x in 0..width {
+			let center = DVec2::new(x as f64 + 0.5, y as f64 + 0.5);
+			let displacement = sample_bilinear(&map.image, pixel_to_map.transform_point2(center)).to_unassociated_alpha();
+			let offset = DVec2::new(displacement.r() as f64 - 0.5, displacement.g() as f64 - 0.5) * 2. * strength;
+			*result.get_mut(x as usize, y as usize) = sample_bilinear(&image_frame.image, (center + offset) / size);
+		}
+	}
+
+	result
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LensDistortionNode<K1, K2, Center, ClampEdges> {
+	k1: K1,
+	k2: K2,
+	center: Center,
+	clamp_edges: ClampEdges,
+}
+
+/// Applies radial distortion around `center` (in UV coordinates) with the polynomial model `1 + k1 r² + k2 r⁴`, where `r` is 1 at the corners of the image.
+/// Positive coefficients give pincushion distortion and negative ones barrel distortion, so the opposite coefficients correct a lens with that distortion.
+/// Pixels sampled from outside the image are either transparent or copied from the nearest edge when `clamp_edges` is set.
+#[node_macro::node_fn(LensDistortionNode)]
+fn lens_distortion(image_frame: ImageFrame<Color>, k1: f64, k2: f64, center: DVec2, clamp_edges: bool) -> ImageFrame<Color> {
+	let (width, height) = (image_frame.image.width, image_frame.image.height);
+	if (k1 == 0. && k2 == 0.) || width == 0 || height == 0 {
+		return image_frame;
+	}
+
+	let size = DVec2::new(width as f64, height as f64);
+	let center = center * size;
+	let normalization = (size / 2.).length();
+	let mut result = image_frame.clone();
+	for y in 0..height {
+		for x in 0..width {
+			let offset = (DVec2::new(x as f64 + 0.5, y as f64 + 0.5) - center) / normalization;
+			let r2 = offset.length_squared();
+			let source = (center + offset * (1. + k1 * r2 + k2 * r2 * r2) * normalization) / size;
+
+			let outside = source.cmplt(DVec2::ZERO).any() || source.cmpgt(DVec2::ONE).any();
+			*result.get_mut(x as usize, y as usize) = if outside && !clamp_edges {
+				Color::TRANSPARENT
+			} else {
+				sample_bilinear(&image_frame.image, source)
+			};
+		}
+	}
+
+	result
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BloomNode<Threshold, Radius, Intensity, LuminanceCalc> {
+	threshold: Threshold,
+	radius: Radius,
+	intensity: Intensity,
+	luminance_calc: LuminanceCalc,
+}
+
+/// Adds a glow around the bright parts of the image by blurring the pixels whose luminance is at least `threshold` and adding them back on top.
+#[node_macro::node_fn(BloomNode)]
+fn bloom(image_frame: ImageFrame<Color>, threshold: f32, radius: f64, intensity: f32, luminance_calc: LuminanceCalculation) -> ImageFrame<Color> {
+	let intensity = intensity / 100.;
+	if intensity <= 0. || image_frame.image.width == 0 || image_frame.image.height == 0 {
+		return image_frame;
+	}
+
+	let threshold = threshold / 100.;
+	let mut bright = image_frame.image.clone();
+	bright.data.iter_mut().for_each(|pixel| {
+		if calculate_luminance(pixel.to_unassociated_alpha(), luminance_calc) < threshold {
+			*pixel = Color::TRANSPARENT;
+		}
+	});
+	let glow = gaussian_blur(&bright, radius);
+
+	let mut image_frame = image_frame;
+	for (pixel, glow) in image_frame.image.data.iter_mut().zip(glow.data) {
+		*pixel = Color::from_rgbaf32_unchecked(
+			pixel.r() + glow.r() * intensity,
+			pixel.g() + glow.g() * intensity,
+			pixel.b() + glow.b() * intensity,
+			(pixel.a() + glow.a() * intensity).min(1.),
+		);
+	}
+	image_frame
+}
+
+/// Blurs the image with a separable Gaussian kernel reaching `radius` pixels out, treating pixels past the edges like the nearest edge pixel.
+fn gaussian_blur(image: &Image<Color>, radius: f64) -> Image<Color> {
+	let radius = radius.max(0.).ceil() as i64;
+	if radius == 0 {
+		return image.clone();
+	}
+
+	// Most of the weight of a Gaussian lies within three standard deviations of its center
+	let gaussian = GaussianNode::new(CopiedNode::new(radius as f64 / 3.));
+	let weights: Vec<f32> = (-radius..=radius).map(|offset| gaussian.eval(offset as f32)).collect();
+	let total: f32 = weights.iter().sum();
+	let weights: Vec<f32> = weights.iter().map(|weight| weight / total).collect();
+
+	let (width, height) = (image.width as i64, image.height as i64);
+	let blur_pass = |source: &Image<Color>, step: (i64, i64)| {
+		let mut result = source.clone();
+		for y in 0..height {
+			for x in 0..width {
+				let mut sum = [0.; 4];
+				for (weight, offset) in weights.iter().zip(-radius..=radius) {
+					let sample_x = (x + offset * step.0).clamp(0, width - 1);
+					let sample_y = (y + offset * step.1).clamp(0, height - 1);
+					let color = source.data[(sample_y * width + sample_x) as usize];
+					sum[0] += color.r() * weight;
+					sum[1] += color.g() * weight;
+					sum[2] += color.b() * weight;
+					sum[3] += color.a() * weight;
+				}
+				result.data[(y * width + x) as usize] = Color::from_rgbaf32_unchecked(sum[0], sum[1], sum[2], sum[3]);
+			}
+		}
+		result
+	};
+
+	let horizontal = blur_pass(image, (1, 0));
+	blur_pass(&horizontal, (0, 1))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FilmGrainNode<Intensity, Size, Seed, Monochrome, GrainBlendMode> {
+	intensity: Intensity,
+	size: Size,
+	seed: Seed,
+	monochrome: Monochrome,
+	blend_mode: GrainBlendMode,
+}
+
+/// Blends noise over the image. The noise is placed in document space with grains `size` units across, so it keeps its scale regardless of the image resolution.
+#[node_macro::node_fn(FilmGrainNode)]
+fn film_grain(image_frame: ImageFrame<Color>, intensity: f32, size: f64, seed: u32, monochrome: bool, blend_mode: BlendMode) -> ImageFrame<Color> {
+	let mut image_frame = image_frame;
+	let opacity = (intensity / 100.).clamp(0., 1.);
+	let (width, height) = (image_frame.image.width, image_frame.image.height);
+	if opacity == 0. || width == 0 || height == 0 {
+		return image_frame;
+	}
+
+	let pixel_to_noise = DAffine2::from_scale(DVec2::splat(1. / size.max(0.01))) * image_frame.transform * DAffine2::from_scale(DVec2::new(1. / width as f64, 1. / height as f64));
+	for y in 0..height {
+		for x in 0..width {
+			let position = pixel_to_noise.transform_point2(DVec2::new(x as f64 + 0.5, y as f64 + 0.5));
+			let grain = if monochrome {
+				let value = value_noise(position, seed, 0);
+				Color::from_rgbf32_unchecked(value, value, value)
+			} else {
+				Color::from_rgbf32_unchecked(value_noise(position, seed, 0), value_noise(position, seed, 1), value_noise(position, seed, 2))
+			};
+
+			// Grain only changes the color of the pixel, so restore its original coverage after blending
+			let pixel = image_frame.get_mut(x as usize, y as usize);
+			let blended = blend_colors(grain, *pixel, blend_mode, opacity).to_unassociated_alpha();
+			*pixel = Color::from_unassociated_alpha(blended.r(), blended.g(), blended.b(), pixel.a());
+		}
+	}
+
+	image_frame
+}
+
+/// Smoothly interpolated pseudorandom values between 0 and 1 on an integer lattice, with independent values for each `channel`.
+fn value_noise(position: DVec2, seed: u32, channel: u32) -> f32 {
+	let cell = position.floor();
+	let t = position - cell;
+	let t = t * t * (DVec2::splat(3.) - 2. * t);
+	let (x, y) = (cell.x as i64 as u32, cell.y as i64 as u32);
+
+	let lattice = |dx: u32, dy: u32| lattice_hash(seed, channel, x.wrapping_add(dx), y.wrapping_add(dy));
+	let top = lattice(0, 0) + (lattice(1, 0) - lattice(0, 0)) * t.x as f32;
+	let bottom = lattice(0, 1) + (lattice(1, 1) - lattice(0, 1)) * t.x as f32;
+	top + (bottom - top) * t.y as f32
+}
+
+/// Hashes a lattice point to a value between 0 and 1.
+fn lattice_hash(seed: u32, channel: u32, x: u32, y: u32) -> f32 {
+	let mut hash = seed ^ channel.wrapping_mul(0x27d4_eb2d);
+	for value in [x, y] {
+		hash ^= value.wrapping_mul(0x9e37_79b9);
+		hash = (hash ^ (hash >> 16)).wrapping_mul(0x85eb_ca6b);
+		hash = (hash ^ (hash >> 13)).wrapping_mul(0xc2b2_ae35);
+		hash ^= hash >> 16;
+	}
+	hash as f32 / u32::MAX as f32
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct MapImageNode<P, MapFn> {
 	map_fn: MapFn,
@@ -112,7 +514,7 @@ pub struct InsertChannelNode<P, S, Insertion, TargetChannel> {
 #[node_macro::node_fn(InsertChannelNode<_P, _S>)]
 fn insert_channel_node<
 	// _P is the color of the input image.
-	_P: RGBMut,
+	_P: RGBMut + AlphaMut,
 	_S: Pixel + Luminance,
 	// Input image
 	Input: RasterMut<Pixel = _P>,
@@ -120,7 +522,7 @@ fn insert_channel_node<
 >(
 	mut image: Input,
 	insertion: Insertion,
-	target_channel: RedGreenBlue,
+	target_channel: RedGreenBlueAlpha,
 ) -> Input
 where
 	_P::ColorChannel: Linear,
@@ -139,9 +541,22 @@ where
 			let image_pixel = image.get_pixel_mut(x, y).unwrap();
 			let insertion_pixel = insertion.get_pixel(x, y).unwrap();
 			match target_channel {
-				RedGreenBlue::Red => image_pixel.set_red(insertion_pixel.l().cast_linear_channel()),
-				RedGreenBlue::Green => image_pixel.set_green(insertion_pixel.l().cast_linear_channel()),
-				RedGreenBlue::Blue => image_pixel.set_blue(insertion_pixel.l().cast_linear_channel()),
+				RedGreenBlueAlpha::Red => image_pixel.set_red(insertion_pixel.l().cast_linear_channel()),
+				RedGreenBlueAlpha::Green => image_pixel.set_green(insertion_pixel.l().cast_linear_channel()),
+				RedGreenBlueAlpha::Blue => image_pixel.set_blue(insertion_pixel.l().cast_linear_channel()),
+				RedGreenBlueAlpha::Alpha => {
+					// The color channels are premultiplied, so they're scaled along with the alpha to keep the straight color
+					let old_alpha: f64 = image_pixel.a().cast_linear_channel();
+					let new_alpha: f64 = insertion_pixel.l().cast_linear_channel();
+					let scale = if old_alpha > 0. { new_alpha / old_alpha } else { 0. };
+					let scaled = |channel: _P::ColorChannel| -> _P::ColorChannel { Linear::from_f64(channel.to_f64() * scale) };
+
+					let (red, green, blue) = (scaled(image_pixel.r()), scaled(image_pixel.g()), scaled(image_pixel.b()));
+					image_pixel.set_red(red);
+					image_pixel.set_green(green);
+					image_pixel.set_blue(blue);
+					image_pixel.set_alpha(insertion_pixel.l().cast_linear_channel());
+				}
 			}
 		}
 	}
@@ -201,6 +616,35 @@ fn mask_imge<
 	image
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct MergeChannelsNode<Green, Blue, Alpha> {
+	green: Green,
+	blue: Blue,
+	alpha: Alpha,
+}
+
+/// Rebuilds an image from channels split by [graphene_core::raster::SplitChannelNode], reading the red value of each gray channel image as the stored value of that channel.
+/// The result has the size and transform of the red image. Pixels outside a smaller channel image count as zero, or as opaque for the alpha.
+#[node_macro::node_fn(MergeChannelsNode)]
+fn merge_channels(red: ImageFrame<Color>, green: ImageFrame<Color>, blue: ImageFrame<Color>, alpha: ImageFrame<Color>) -> ImageFrame<Color> {
+	let channel = |image: &Image<Color>, x: u32, y: u32, missing: f32| {
+		if x < image.width && y < image.height {
+			image.data[(y * image.width + x) as usize].r()
+		} else {
+			missing
+		}
+	};
+
+	let mut merged = red;
+	let width = merged.image.width;
+	for (index, pixel) in merged.image.data.iter_mut().enumerate() {
+		let (x, y) = (index as u32 % width, index as u32 / width);
+		let (r, g, b, a) = (pixel.r(), channel(&green.image, x, y, 0.), channel(&blue.image, x, y, 0.), channel(&alpha.image, x, y, 1.));
+		*pixel = Color::from_rgbaf32_unchecked(r, g, b, a);
+	}
+	merged
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BlendImageTupleNode<P, Fg, MapFn> {
 	map_fn: MapFn,
@@ -422,13 +866,204 @@ fn empty_image<_P: Pixel>(transform: DAffine2, color: _P) -> ImageFrame<_P> {
 	ImageFrame { image, transform }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct SolidColorNode<Resolution, FillColor> {
+	resolution: Resolution,
+	color: FillColor,
+}
+
+/// An image of the given resolution filled with one color, placed at the origin with one pixel per document unit
+#[node_macro::node_fn(SolidColorNode)]
+fn solid_color(_no_primary_input: (), resolution: UVec2, color: Color) -> ImageFrame<Color> {
+	let image = Image::new(resolution.x, resolution.y, color);
+	ImageFrame {
+		image,
+		transform: DAffine2::from_scale(resolution.as_dvec2()),
+	}
+}
+
+/// One image to composite along with how it blends onto the images beneath it, with its opacity in percent.
+pub type CompositeLayer = (ImageFrame<Color>, BlendMode, f32);
+
+/// The most pixels a composite is allowed to have, the same as the limit on exported images
+const MAX_COMPOSITE_PIXEL_COUNT: f64 = 8192. * 8192.;
+
+/// The pixels per document unit of the sharpest layer, lowered if needed so a canvas of `size` document units stays within [`MAX_COMPOSITE_PIXEL_COUNT`].
+/// Layers squashed to no area are ignored, and a resolution of one pixel per unit is used when there are no others.
+fn composite_resolution(layers: &[CompositeLayer], size: DVec2) -> f64 {
+	let resolution = layers
+		.iter()
+		.map(|(image_frame, _, _)| ((image_frame.image.width as f64 * image_frame.image.height as f64) / image_frame.transform.matrix2.determinant().abs()).sqrt())
+		.filter(|resolution| resolution.is_finite())
+		.fold(0., f64::max);
+	let resolution = if resolution > 0. { resolution } else { 1. };
+
+	let pixels = (size * resolution).ceil().max(DVec2::ONE);
+	let pixel_count = pixels.x * pixels.y;
+	if pixel_count > MAX_COMPOSITE_PIXEL_COUNT {
+		resolution * (MAX_COMPOSITE_PIXEL_COUNT / pixel_count).sqrt()
+	} else {
+		resolution
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompositeLayersNode;
+
+/// Composites the layers bottom-to-top into a single image covering all of them, at the resolution of the sharpest layer.
+/// Each layer is resampled through its own transform, and empty layers are skipped. The canvas is capped at [`MAX_COMPOSITE_PIXEL_COUNT`] pixels.
+#[node_macro::node_fn(CompositeLayersNode)]
+fn composite_layers(layers: Vec<CompositeLayer>) -> ImageFrame<Color> {
+	let layers: Vec<_> = layers.into_iter().filter(|(image_frame, _, _)| image_frame.image.width > 0 && image_frame.image.height > 0).collect();
+
+	let Some(aabb) = layers
+		.iter()
+		.map(|(image_frame, _, _)| Bbox::unit().affine_transform(image_frame.transform).to_axis_aligned_bbox())
+		.reduce(|a, b| a.union(&b))
+	else {
+		return ImageFrame::empty();
+	};
+
+	// The canvas is aligned to the pixel grid of the chosen resolution
+	let resolution = composite_resolution(&layers, aabb.end - aabb.start);
+	let start = (aabb.start * resolution).floor();
+	let pixels = ((aabb.end * resolution).ceil() - start).max(DVec2::ONE);
+	let canvas = ImageFrame {
+		image: Image::new(pixels.x as u32, pixels.y as u32, Color::TRANSPARENT),
+		transform: DAffine2::from_scale_angle_translation(pixels / resolution, 0., start / resolution),
+	};
+
+	layers.into_iter().fold(canvas, |canvas, (image_frame, blend_mode, opacity)| {
+		let opacity = (opacity / 100.).clamp(0., 1.);
+		blend_image_closure(image_frame, canvas, |foreground, background| blend_colors(foreground, background, blend_mode, opacity))
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompositeLayerListNode<BottomBlendMode, BottomOpacity, SecondImage, SecondBlendMode, SecondOpacity, ThirdImage, ThirdBlendMode, ThirdOpacity, TopImage, TopBlendMode, TopOpacity> {
+	bottom_blend_mode: BottomBlendMode,
+	bottom_opacity: BottomOpacity,
+	second_image: SecondImage,
+	second_blend_mode: SecondBlendMode,
+	second_opacity: SecondOpacity,
+	third_image: ThirdImage,
+	third_blend_mode: ThirdBlendMode,
+	third_opacity: ThirdOpacity,
+	top_image: TopImage,
+	top_blend_mode: TopBlendMode,
+	top_opacity: TopOpacity,
+}
+
+/// Gathers up to four images, starting from the bottom one, into the list of layers read by [`CompositeLayersNode`].
+#[node_macro::node_fn(CompositeLayerListNode)]
+fn composite_layer_list(
+	bottom_image: ImageFrame<Color>,
+	bottom_blend_mode: BlendMode,
+	bottom_opacity: f32,
+	second_image: ImageFrame<Color>,
+	second_blend_mode: BlendMode,
+	second_opacity: f32,
+	third_image: ImageFrame<Color>,
+	third_blend_mode: BlendMode,
+	third_opacity: f32,
+	top_image: ImageFrame<Color>,
+	top_blend_mode: BlendMode,
+	top_opacity: f32,
+) -> Vec<CompositeLayer> {
+	vec![
+		(bottom_image, bottom_blend_mode, bottom_opacity),
+		(second_image, second_blend_mode, second_opacity),
+		(third_image, third_blend_mode, third_opacity),
+		(top_image, top_blend_mode, top_opacity),
+	]
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExportArtboardsNode;
+
+/// Renders every visible [`Artboard`] found in the graphic group to its own image, sized to the artboard's dimensions and paired with the name of its layer.
+#[node_macro::node_fn(ExportArtboardsNode)]
+fn export_artboards(graphic_group: GraphicGroup) -> Vec<(String, ImageFrame<Color>)> {
+	let mut artboards = Vec::new();
+	collect_artboards(&graphic_group, &mut artboards);
+
+	artboards
+		.into_iter()
+		.enumerate()
+		.map(|(index, (name, artboard))| {
+			let name = if name.is_empty() { format!("Artboard {}", index + 1) } else { name.to_string() };
+			(name, render_artboard(artboard))
+		})
+		.collect()
+}
+
+fn collect_artboards<'a>(graphic_group: &'a GraphicGroup, artboards: &mut Vec<(&'a str, &'a Artboard)>) {
+	for element in graphic_group.iter().filter(|element| element.visible) {
+		match &element.graphic_element_data {
+			GraphicElementData::Artboard(artboard) => artboards.push((&element.name, artboard)),
+			GraphicElementData::GraphicGroup(graphic_group) => collect_artboards(graphic_group, artboards),
+			_ => {}
+		}
+	}
+}
+
+/// Draws the artboard's background and contents into an image covering exactly the artboard's bounds.
+/// Content outside the artboard is always cropped, so clipped and unclipped artboards export identically, and overlapping artboards never include each other's content.
+fn render_artboard(artboard: &Artboard) -> ImageFrame<Color> {
+	let size = artboard.dimensions.max(IVec2::ZERO).as_uvec2();
+	let transform = DAffine2::from_scale_angle_translation(size.as_dvec2(), 0., artboard.location.as_dvec2());
+	let background = artboard.background_fill();
+	let mut image_frame = ImageFrame {
+		image: Image::new(size.x, size.y, if let Fill::Solid(color) = background { color } else { Color::TRANSPARENT }),
+		transform,
+	};
+	// Gradients vary across the artboard, so they're rasterized like a shape covering it
+	if background.as_gradient().is_some() {
+		let mut area = VectorData::from_subpath(bezier_rs::Subpath::new_rect(artboard.location.as_dvec2(), artboard.location.as_dvec2() + size.as_dvec2()));
+		area.style.set_fill(background);
+		image_frame = composite_vector_data(&area, image_frame, BlendMode::Normal, 1.);
+	}
+
+	composite_graphic_group(&artboard.graphic_group, image_frame, 1.)
+}
+
+fn composite_graphic_group(graphic_group: &GraphicGroup, mut target: ImageFrame<Color>, opacity: f32) -> ImageFrame<Color> {
+	for element in graphic_group.iter().filter(|element| element.visible) {
+		let opacity = opacity * element.opacity;
+		target = match &element.graphic_element_data {
+			GraphicElementData::ImageFrame(image_frame) => blend_image_closure(image_frame, target, |foreground, background| {
+				graphene_core::raster::blend_colors(foreground, background, element.blend_mode, opacity)
+			}),
+			GraphicElementData::GraphicGroup(graphic_group) => composite_graphic_group(graphic_group, target, opacity),
+			// Nested artboards are exported on their own
+			GraphicElementData::Artboard(_) => target,
+			GraphicElementData::VectorShape(vector_data) => composite_vector_data(vector_data, target, element.blend_mode, opacity),
+			GraphicElementData::Text(_) => {
+				warn!("Text in layer \"{}\" can't be rasterized when exporting artboards and was skipped", element.name);
+				target
+			}
+		};
+	}
+	target
+}
+
+fn composite_vector_data(vector_data: &VectorData, target: ImageFrame<Color>, blend_mode: BlendMode, opacity: f32) -> ImageFrame<Color> {
+	// The shape is rasterized over exactly the pixels of the target, so the two line up when blended
+	let bounds = [target.transform.transform_point2(DVec2::ZERO), target.transform.transform_point2(DVec2::ONE)];
+	let scale = target.image.width as f64 / (bounds[1].x - bounds[0].x);
+	let rasterized = rasterize(vector_data, bounds, scale, AntiAliasing::default());
+	blend_image_closure(&rasterized, target, |foreground, background| {
+		graphene_core::raster::blend_colors(foreground, background, blend_mode, opacity)
+	})
+}
+
 macro_rules! generate_imaginate_node {
 	($($val:ident: $t:ident: $o:ty,)*) => {
 		pub struct ImaginateNode<P: Pixel, E, C, $($t,)*> {
 			editor_api: E,
 			controller: C,
 			$($val: $t,)*
-			cache: std::sync::Mutex<HashMap<u64, Image<P>>>,
+			cache: std::sync::Mutex<HashMap<u64, Vec<Image<P>>>>,
 		}
 
 		impl<'e, P: Pixel, E, C, $($t,)*> ImaginateNode<P, E, C, $($t,)*>
@@ -447,7 +1082,7 @@ macro_rules! generate_imaginate_node {
 			E: for<'any_input> Node<'any_input, (), Output = DynFuture<'any_input, WasmEditorApi<'e>>>,
 			C: for<'any_input> Node<'any_input, (), Output = DynFuture<'any_input, ImaginateController>>,
 		{
-			type Output = DynFuture<'i, ImageFrame<P>>;
+			type Output = DynFuture<'i, Vec<ImageFrame<P>>>;
 
 			fn eval(&'i self, frame: ImageFrame<P>) -> Self::Output {
 				let controller = self.controller.eval(());
@@ -461,21 +1096,18 @@ macro_rules! generate_imaginate_node {
 				Box::pin(async move {
 					let controller: std::pin::Pin<Box<dyn std::future::Future<Output = ImaginateController>>> = controller;
 					let controller: ImaginateController = controller.await;
-					if controller.take_regenerate_trigger() {
+					let images = if controller.take_regenerate_trigger() {
 						let editor_api = self.editor_api.eval(());
-						let image = super::imaginate::imaginate(frame.image, editor_api, controller, $($val,)*).await;
-
-						self.cache.lock().unwrap().insert(hash, image.clone());
-						return ImageFrame {
-							image,
-							..frame
-						}
-					}
-					let image = self.cache.lock().unwrap().get(&hash).cloned().unwrap_or_default();
-					ImageFrame {
-						image,
-						..frame
-					}
+						let images = super::imaginate::imaginate(frame.image, editor_api, controller, $($val,)*).await;
+
+						self.cache.lock().unwrap().insert(hash, images.clone());
+						images
+					} else {
+						self.cache.lock().unwrap().get(&hash).cloned().unwrap_or_default()
+					};
+					// An empty image stands in for the first variation until one has been generated
+					let images = if images.is_empty() { vec![Image::default()] } else { images };
+					images.into_iter().map(|image| ImageFrame { image, transform: frame.transform }).collect()
 				})
 			}
 		}
@@ -498,6 +1130,11 @@ generate_imaginate_node! {
 	mask_starting_fill: MaskStartingFill: ImaginateMaskStartingFill,
 	improve_faces: ImproveFaces: bool,
 	tiling: Tiling: bool,
+	batch_count: BatchCount: u32,
+	control_image: ControlImage: ImageFrame<Color>,
+	control_type: ControlType: ImaginateControlType,
+	variation_seed: VariationSeed: f64,
+	variation_strength: VariationStrength: f32,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -511,6 +1148,436 @@ fn image_frame<_P: Pixel>(image: Image<_P>, transform: DAffine2) -> graphene_cor
 }
 #[cfg(test)]
 mod test {
+	use super::*;
+	use graphene_core::value::ClonedNode;
+	use graphene_core::GraphicElement;
+
+	fn layer(name: &str, graphic_element_data: impl Into<GraphicElementData>) -> GraphicElement {
+		GraphicElement {
+			name: name.to_string(),
+			blend_mode: BlendMode::Normal,
+			opacity: 1.,
+			visible: true,
+			locked: false,
+			collapsed: false,
+			graphic_element_data: graphic_element_data.into(),
+			clip: None,
+		}
+	}
+
+	fn checkerboard() -> ImageFrame<Color> {
+		let mut image = Image::new(2, 2, Color::BLACK);
+		image.data[1] = Color::WHITE;
+		image.data[2] = Color::WHITE;
+		ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::splat(2.)),
+		}
+	}
+
+	#[test]
+	fn sample_pixel_center_of_checkerboard() {
+		let sample = |uv: DVec2| SamplePixelNode::new(ClonedNode::new(uv)).eval(checkerboard());
+
+		// The center is equally far from all four pixels
+		assert_eq!(sample(DVec2::splat(0.5)), Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 1.));
+		// Pixel centers and positions past the edges give the exact pixel color
+		assert_eq!(sample(DVec2::splat(0.25)), Color::BLACK);
+		assert_eq!(sample(DVec2::new(2., -1.)), Color::WHITE);
+		// Halfway between a black and a white pixel
+		assert_eq!(sample(DVec2::new(0.5, 0.25)), Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 1.));
+	}
+
+	#[test]
+	fn set_pixel_then_sample() {
+		let image_frame = SetPixelNode::new(ClonedNode::new(DVec2::new(0.75, 0.75)), ClonedNode::new(Color::RED)).eval(checkerboard());
+
+		assert_eq!(image_frame.image.data, vec![Color::BLACK, Color::WHITE, Color::WHITE, Color::RED]);
+		assert_eq!(SamplePixelNode::new(ClonedNode::new(DVec2::splat(0.75))).eval(image_frame), Color::RED);
+	}
+
+	#[test]
+	fn solid_color_fills_its_resolution() {
+		let image_frame = SolidColorNode::new(ClonedNode::new(UVec2::new(3, 2)), ClonedNode::new(Color::RED)).eval(());
+
+		assert_eq!((image_frame.image.width, image_frame.image.height), (3, 2));
+		assert!(image_frame.image.data.iter().all(|&color| color == Color::RED));
+		assert_eq!(image_frame.transform, DAffine2::from_scale(DVec2::new(3., 2.)));
+	}
+
+	#[test]
+	fn split_and_merge_channels_round_trip() {
+		use graphene_core::raster::{ExtractAlphaNode, RedGreenBlue, SplitChannelNode};
+
+		let mut image = Image::new(3, 2, Color::BLACK);
+		for (index, pixel) in image.data.iter_mut().enumerate() {
+			*pixel = Color::from_rgbaf32_unchecked(0.1 * index as f32, 0.7, 1. / 3., 1.).multiplied_alpha(1. / (index + 2) as f32);
+		}
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::new(3., 2.)),
+		};
+
+		let map_pixels = |map: &dyn Fn(Color) -> Color| {
+			let mut mapped = image_frame.clone();
+			mapped.image.data.iter_mut().for_each(|pixel| *pixel = map(*pixel));
+			mapped
+		};
+		let split = |channel| {
+			let node = SplitChannelNode::new(ClonedNode::new(channel));
+			map_pixels(&|color| node.eval(color))
+		};
+		let alpha = map_pixels(&|color| ExtractAlphaNode.eval(color));
+
+		let merged = MergeChannelsNode::new(ClonedNode::new(split(RedGreenBlue::Green)), ClonedNode::new(split(RedGreenBlue::Blue)), ClonedNode::new(alpha)).eval(split(RedGreenBlue::Red));
+
+		let bits = |image_frame: &ImageFrame<Color>| {
+			image_frame
+				.image
+				.data
+				.iter()
+				.flat_map(|color| [color.r(), color.g(), color.b(), color.a()].map(f32::to_bits))
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(bits(&merged), bits(&image_frame), "Merging the split channels should restore the image bit-for-bit");
+		assert_eq!(merged.transform, image_frame.transform);
+	}
+
+	#[test]
+	fn insert_alpha_channel_keeps_straight_color() {
+		let color = Color::from_rgbaf32_unchecked(0.8, 0.4, 0.2, 1.).multiplied_alpha(0.5);
+		let image_frame = ImageFrame {
+			image: Image::new(1, 1, color),
+			transform: DAffine2::IDENTITY,
+		};
+		let insert = |value: f32, channel| {
+			let insertion = ImageFrame {
+				image: Image::new(1, 1, Color::from_rgbf32_unchecked(value, value, value)),
+				transform: DAffine2::IDENTITY,
+			};
+			InsertChannelNode::new(ClonedNode::new(insertion), ClonedNode::new(channel)).eval(image_frame.clone()).image.data[0]
+		};
+
+		let red = insert(0.25, RedGreenBlueAlpha::Red);
+		assert!((red.r() - 0.25).abs() < 1e-4 && red.g() == color.g() && red.a() == color.a());
+
+		let opaque = insert(1., RedGreenBlueAlpha::Alpha);
+		assert!((opaque.a() - 1.).abs() < 1e-4);
+		let straight = opaque.to_unassociated_alpha();
+		for (actual, expected) in [(straight.r(), 0.8), (straight.g(), 0.4), (straight.b(), 0.2)] {
+			assert!((actual - expected).abs() < 1e-4, "expected {expected} but got {actual}");
+		}
+
+		let transparent = insert(0., RedGreenBlueAlpha::Alpha);
+		assert_eq!([transparent.r(), transparent.g(), transparent.b(), transparent.a()], [0.; 4]);
+	}
+
+	#[test]
+	fn displace_with_neutral_map_is_identity() {
+		let mut image = Image::new(4, 4, Color::BLACK);
+		for (index, pixel) in image.data.iter_mut().enumerate() {
+			*pixel = Color::from_rgbaf32_unchecked(index as f32 / 16., 0.25, 1. - index as f32 / 16., 1.);
+		}
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::splat(4.)),
+		};
+		let neutral_map = ImageFrame {
+			image: Image::new(2, 2, Color::from_rgbf32_unchecked(0.5, 0.5, 0.5)),
+			transform: DAffine2::from_scale(DVec2::splat(4.)),
+		};
+
+		let displaced = DisplaceNode::new(ClonedNode::new(neutral_map), ClonedNode::new(10.)).eval(image_frame.clone());
+		assert_eq!(displaced, image_frame);
+
+		// Full red shifts every pixel by the strength, sampling from one pixel to the right and clamping at the edge
+		let red_map = ImageFrame {
+			image: Image::new(1, 1, Color::from_rgbf32_unchecked(1., 0.5, 0.5)),
+			transform: DAffine2::from_scale(DVec2::splat(4.)),
+		};
+		let shifted = DisplaceNode::new(ClonedNode::new(red_map), ClonedNode::new(1.)).eval(image_frame.clone());
+		assert_eq!(shifted.image.data[0], image_frame.image.data[1]);
+		assert_eq!(shifted.image.data[3], image_frame.image.data[3]);
+	}
+
+	#[test]
+	fn lens_distortion() {
+		let mut image = Image::new(5, 5, Color::BLACK);
+		image.data[2 * 5 + 2] = Color::WHITE;
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::splat(5.)),
+		};
+		let distort = |k1: f64, clamp_edges: bool| {
+			let node = LensDistortionNode::new(ClonedNode::new(k1), ClonedNode::new(0.), ClonedNode::new(DVec2::splat(0.5)), ClonedNode::new(clamp_edges));
+			node.eval(image_frame.clone())
+		};
+
+		assert_eq!(distort(0., false), image_frame);
+
+		// Pincushion distortion pulls the corners from outside of the image, while the center stays put
+		let pincushion = distort(1., false);
+		assert_eq!(pincushion.image.data[2 * 5 + 2], Color::WHITE);
+		assert_eq!(pincushion.image.data[0], Color::TRANSPARENT);
+		assert_eq!(distort(1., true).image.data[0], Color::BLACK);
+	}
+
+	#[test]
+	fn bloom_spreads_bright_dot() {
+		let mut image = Image::new(9, 9, Color::BLACK);
+		image.data[4 * 9 + 4] = Color::WHITE;
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::splat(9.)),
+		};
+		let bloom = |intensity: f32| {
+			let node = BloomNode::new(ClonedNode::new(50_f32), ClonedNode::new(3.), ClonedNode::new(intensity), ClonedNode::new(LuminanceCalculation::SRGB));
+			node.eval(image_frame.clone())
+		};
+
+		assert_eq!(bloom(0.), image_frame);
+
+		let glowing = bloom(100.);
+		let red_at = |x: usize, y: usize| glowing.image.data[y * 9 + x].r();
+		assert!(red_at(5, 4) > 0. && red_at(4, 6) > 0., "the halo should spread around the dot");
+		assert!(red_at(5, 4) > red_at(6, 4), "the halo should fade with distance");
+		assert_eq!(red_at(0, 0), 0., "the halo shouldn't reach past the radius");
+		assert!(red_at(4, 4) >= 1., "the dot itself should stay bright");
+	}
+
+	#[test]
+	fn film_grain_intensity() {
+		let mut image = Image::new(8, 8, Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 1.));
+		image.data[0] = Color::TRANSPARENT;
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::splat(8.)),
+		};
+		let grain = |intensity: f32, seed: u32| {
+			let node = FilmGrainNode::new(
+				ClonedNode::new(intensity),
+				ClonedNode::new(2.),
+				ClonedNode::new(seed),
+				ClonedNode::new(true),
+				ClonedNode::new(BlendMode::Overlay),
+			);
+			node.eval(image_frame.clone())
+		};
+
+		assert_eq!(grain(0., 1), image_frame);
+
+		let grainy = grain(100., 1);
+		assert_ne!(grainy, image_frame);
+		assert_eq!(grainy, grain(100., 1), "the same seed should give the same grain");
+		assert_ne!(grainy, grain(100., 2));
+		assert_eq!(grainy.image.data[0], Color::TRANSPARENT, "grain shouldn't add coverage to transparent pixels");
+		assert!(
+			grainy.image.data.iter().all(|pixel| pixel.r() == pixel.g() && pixel.g() == pixel.b()),
+			"monochrome grain should stay gray"
+		);
+	}
+
+	#[test]
+	fn image_stats_of_known_image() {
+		let mut image = Image::new(4, 1, Color::BLACK);
+		image.data[1] = Color::WHITE;
+		image.data[2] = Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 1.);
+		image.data[3] = Color::RED.multiplied_alpha(0.5);
+		let stats = ImageStatsNode::new().eval(ImageFrame { image, transform: DAffine2::IDENTITY });
+
+		assert_eq!(stats.min, Color::from_rgbaf32_unchecked(0., 0., 0., 0.5));
+		assert_eq!(stats.max, Color::WHITE);
+		// The half transparent red is measured by its straight color
+		assert_eq!(stats.mean, Color::from_rgbaf32_unchecked(0.625, 0.375, 0.375, 0.875));
+
+		let red_counts: Vec<_> = stats.histogram.iter().map(|bin| bin[0]).collect();
+		assert_eq!((red_counts[0], red_counts[128], red_counts[255]), (1, 1, 2));
+		assert_eq!(stats.histogram.iter().map(|bin| bin[3]).sum::<u32>(), 4);
+	}
+
+	#[test]
+	fn image_stats_of_empty_image() {
+		let stats = ImageStatsNode::new().eval(ImageFrame::empty());
+
+		assert_eq!((stats.min, stats.max, stats.mean), (Color::TRANSPARENT, Color::TRANSPARENT, Color::TRANSPARENT));
+		assert!(stats.histogram.iter().all(|bin| *bin == [0; 4]));
+	}
+
+	#[test]
+	fn downres_solid_image_by_two() {
+		let color = Color::from_rgbaf32_unchecked(0.2, 0.4, 0.6, 1.);
+		let image_frame = ImageFrame {
+			image: Image::new(4, 4, color),
+			transform: DAffine2::from_scale(DVec2::splat(4.)),
+		};
+
+		for filter in [DownresFilter::Average, DownresFilter::Nearest] {
+			let result = DownresNode::new(ClonedNode::new(2.), ClonedNode::new(filter)).eval(image_frame.clone());
+			assert_eq!((result.image.width, result.image.height), (2, 2));
+			assert!(result.image.data.iter().all(|&pixel| pixel == color), "{filter} should keep the color of a solid image");
+			assert_eq!(result.transform, image_frame.transform, "The visual size should be unchanged");
+		}
+	}
+
+	#[test]
+	fn downres_averages_blocks() {
+		let mut image = Image::new(2, 1, Color::BLACK);
+		image.data[1] = Color::WHITE;
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::new(2., 1.)),
+		};
+
+		let result = DownresNode::new(ClonedNode::new(2.), ClonedNode::new(DownresFilter::Average)).eval(image_frame);
+		assert_eq!(result.image.data, vec![Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 1.)]);
+	}
+
+	#[test]
+	fn export_overlapping_artboards() {
+		// A blue image covering the left half of a red 4x2 artboard
+		let mut first = Artboard::new(IVec2::ZERO, IVec2::new(4, 2));
+		first.background = Color::RED;
+		first.graphic_group.push(layer(
+			"Image",
+			ImageFrame {
+				image: Image::new(1, 1, Color::BLUE),
+				transform: DAffine2::from_scale(DVec2::splat(2.)),
+			},
+		));
+		// An empty green artboard overlapping the right half of the first one
+		let mut second = Artboard::new(IVec2::new(2, 0), IVec2::new(2, 2));
+		second.background = Color::GREEN;
+
+		let mut graphic_group = GraphicGroup::EMPTY;
+		graphic_group.push(layer("First", first));
+		graphic_group.push(layer("", second));
+
+		let images = ExportArtboardsNode::new().eval(graphic_group);
+		assert_eq!(images.len(), 2);
+
+		let (name, first) = &images[0];
+		assert_eq!(name, "First");
+		assert_eq!((first.image.width, first.image.height), (4, 2));
+		assert_eq!(first.image.data[0], Color::BLUE);
+		assert_eq!(first.image.data[3], Color::RED);
+
+		let (name, second) = &images[1];
+		assert_eq!(name, "Artboard 2");
+		assert_eq!((second.image.width, second.image.height), (2, 2));
+		assert!(second.image.data.iter().all(|&pixel| pixel == Color::GREEN));
+	}
+
+	#[test]
+	fn export_artboard_with_vector_shape() {
+		// A black square covering the bottom right quarter of a white 4x4 artboard placed away from the origin
+		let mut artboard = Artboard::new(IVec2::new(10, 20), IVec2::new(4, 4));
+		artboard.background = Color::WHITE;
+		let mut square = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::new(12., 22.), DVec2::new(14., 24.)));
+		square.style.set_fill(Fill::Solid(Color::BLACK));
+		artboard.graphic_group.push(layer("Square", square));
+
+		let mut graphic_group = GraphicGroup::EMPTY;
+		graphic_group.push(layer("Artboard", artboard));
+
+		let images = ExportArtboardsNode::new().eval(graphic_group);
+		let image = &images[0].1.image;
+		for (index, &pixel) in image.data.iter().enumerate() {
+			let (x, y) = (index % 4, index / 4);
+			let expected = if x >= 2 && y >= 2 { Color::BLACK } else { Color::WHITE };
+			assert_eq!(pixel, expected, "pixel ({x}, {y})");
+		}
+	}
+
+	#[test]
+	fn export_artboard_with_gradient_background() {
+		use graphene_core::vector::style::{FillType, Gradient, GradientType};
+
+		let mut artboard = Artboard::new(IVec2::new(10, 20), IVec2::new(4, 1));
+		artboard.background_type = FillType::Gradient;
+		artboard.background_gradient = Gradient::new(DVec2::ZERO, Color::BLACK, DVec2::X, Color::WHITE, DAffine2::IDENTITY, 0, GradientType::Linear);
+		let mut graphic_group = GraphicGroup::EMPTY;
+		graphic_group.push(layer("Artboard", artboard));
+
+		let images = ExportArtboardsNode::new().eval(graphic_group);
+		let reds: Vec<_> = images[0].1.image.data.iter().map(|pixel| pixel.r()).collect();
+		assert!(reds.windows(2).all(|pair| pair[0] < pair[1]), "The background should brighten from left to right, not {reds:?}");
+		assert!(images[0].1.image.data.iter().all(|pixel| pixel.a() == 1.));
+	}
+
+	#[test]
+	fn two_opaque_normal_layers_show_only_the_top() {
+		let solid = |color| ImageFrame {
+			image: Image::new(2, 2, color),
+			transform: DAffine2::from_scale(DVec2::splat(2.)),
+		};
+		let layers = vec![(solid(Color::RED), BlendMode::Normal, 100.), (solid(Color::BLUE), BlendMode::Normal, 100.)];
+
+		let composite = CompositeLayersNode::new().eval(layers);
+		assert_eq!((composite.image.width, composite.image.height), (2, 2));
+		assert!(composite.image.data.iter().all(|&pixel| pixel == Color::BLUE));
+	}
+
+	#[test]
+	fn composite_layers_resamples_into_a_common_canvas() {
+		// A red square on the left and a half-opaque blue square on the right, each a single pixel scaled up to 2x2 units
+		let bottom = ImageFrame {
+			image: Image::new(1, 1, Color::RED),
+			transform: DAffine2::from_scale(DVec2::splat(2.)),
+		};
+		let top = ImageFrame {
+			image: Image::new(1, 1, Color::BLUE),
+			transform: DAffine2::from_scale_angle_translation(DVec2::splat(2.), 0., DVec2::new(2., 0.)),
+		};
+		let empty = || ClonedNode::new(ImageFrame::<Color>::empty());
+		let normal = || ClonedNode::new(BlendMode::Normal);
+		let layer_list = CompositeLayerListNode::new(
+			normal(),
+			ClonedNode::new(100.),
+			empty(),
+			normal(),
+			ClonedNode::new(100.),
+			empty(),
+			normal(),
+			ClonedNode::new(100.),
+			ClonedNode::new(top),
+			normal(),
+			ClonedNode::new(50.),
+		);
+
+		// Both layers have half a pixel per unit, so the canvas does too
+		let composite = CompositeLayersNode::new().eval(layer_list.eval(bottom));
+		assert_eq!((composite.image.width, composite.image.height), (2, 1));
+		assert_eq!(composite.transform, DAffine2::from_scale(DVec2::new(4., 2.)));
+		assert_eq!(composite.image.data[0], Color::RED);
+		let right = composite.image.data[1];
+		assert!((right.a() - 0.5).abs() < 1e-6, "The half-opaque layer over nothing should be half-transparent, got {right:?}");
+		assert_eq!(right.r(), 0.);
+	}
+
+	#[test]
+	fn composite_resolution_follows_the_sharpest_layer_within_the_cap() {
+		let layers = |sizes: &[(u32, f64)]| -> Vec<CompositeLayer> {
+			let layer = |&(size, scale): &(u32, f64)| {
+				let image_frame = ImageFrame {
+					image: Image::new(size, size, Color::RED),
+					transform: DAffine2::from_scale(DVec2::splat(scale)),
+				};
+				(image_frame, BlendMode::Normal, 100.)
+			};
+			sizes.iter().map(layer).collect()
+		};
+
+		// A 4x4 image over 2x2 units has 2 pixels per unit, which beats the 1x1 image over 10x10 units
+		assert_eq!(composite_resolution(&layers(&[(1, 10.), (4, 2.)]), DVec2::splat(10.)), 2.);
+
+		// A million pixels per unit over 10x10 units would be far too large, so it's capped
+		let capped = composite_resolution(&layers(&[(1000, 0.001)]), DVec2::splat(10.));
+		assert!((capped * 10.).powi(2) <= MAX_COMPOSITE_PIXEL_COUNT + 1e-3, "{capped} pixels per unit is over the cap");
+		assert!(capped > 800.);
+
+		// Layers squashed to nothing don't count
+		assert_eq!(composite_resolution(&layers(&[(4, 0.)]), DVec2::ONE), 1.);
+	}
 
 	#[test]
 	fn load_image() {
@@ -525,4 +1592,32 @@ mod test {
 		export.eval((picture, "test-image-1-result.png")).unwrap();
 		*/
 	}
+
+	#[test]
+	fn linked_images_match_their_files() {
+		use graphene_core::renderer::{GraphicElementRendered, RenderParams, SvgRender};
+		use graphene_core::vector::style::ViewMode;
+
+		let other = ImageFrame {
+			image: Image::new(1, 1, Color::BLUE),
+			transform: DAffine2::IDENTITY,
+		};
+		let mut group = GraphicGroup::EMPTY;
+		group.extend([layer("first", checkerboard()), layer("other", other), layer("copy", checkerboard())]);
+		let mut render = SvgRender::new();
+		group.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, None, false).with_deterministic_ids().with_linked_images());
+
+		let svg = render.svg.to_string();
+		let hrefs: Vec<_> = svg.split(r#"href=""#).skip(1).map(|rest| rest.split('"').next().unwrap()).collect();
+		assert_eq!(hrefs.len(), 3);
+		assert_eq!(hrefs[0], hrefs[2], "Identical images should link to the same file");
+
+		let directory = tempfile::tempdir().unwrap();
+		let written = write_linked_images(&render, directory.path()).unwrap();
+		let file_names: Vec<_> = written.iter().map(|path| path.file_name().unwrap().to_str().unwrap()).collect();
+		assert_eq!(file_names, &hrefs[..2]);
+
+		let decoded = image::open(&written[0]).unwrap();
+		assert_eq!((decoded.width(), decoded.height()), (2, 2));
+	}
 }
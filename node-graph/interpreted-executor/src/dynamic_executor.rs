@@ -62,6 +62,12 @@ impl DynamicExecutor {
 		self.tree.introspect(node_path)
 	}
 
+	/// The output type inferred for the primitive node which was compiled from the document node at `node_path`.
+	pub fn output_type_of_path(&self, node_path: &[NodeId]) -> Option<Type> {
+		let id = self.tree.node_id_of_path(node_path)?;
+		self.typing_context.type_of(id).map(|node_io| node_io.output.clone())
+	}
+
 	pub fn input_type(&self) -> Option<Type> {
 		self.typing_context.type_of(self.output).map(|node_io| node_io.input.clone())
 	}
@@ -122,6 +128,10 @@ impl BorrowTree {
 		Some(node.serialize())
 	}
 
+	pub fn node_id_of_path(&self, node_path: &[NodeId]) -> Option<NodeId> {
+		self.source_map.get(node_path).copied()
+	}
+
 	pub fn get(&self, id: NodeId) -> Option<SharedNodeContainer> {
 		self.nodes.get(&id).cloned()
 	}
@@ -124,4 +124,89 @@ mod tests {
 
 		let _exec = block_on(DynamicExecutor::new(protograph)).map(|_e| panic!("The network should not type check ")).unwrap_err();
 	}
+
+	#[test]
+	fn bypassed_node_passes_its_input_through() {
+		use graph_craft::document::*;
+		use graph_craft::graphene_compiler::{Compiler, Executor};
+
+		use crate::dynamic_executor::DynamicExecutor;
+		use graphene_core::raster::Color;
+
+		let invert_network = |bypassed: Vec<NodeId>| NodeNetwork {
+			inputs: vec![0],
+			outputs: vec![NodeOutput::new(0, 0)],
+			nodes: [(
+				0,
+				DocumentNode {
+					name: "Invert RGB".into(),
+					inputs: vec![
+						NodeInput::Network(concrete!(Color)),
+						NodeInput::value(TaggedValue::Bool(true), false),
+						NodeInput::value(TaggedValue::Bool(true), false),
+						NodeInput::value(TaggedValue::Bool(true), false),
+						NodeInput::value(TaggedValue::Bool(false), false),
+					],
+					implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::raster::InvertRGBNode<_, _, _, _>")),
+					..Default::default()
+				},
+			)]
+			.into_iter()
+			.collect(),
+			bypassed,
+			..Default::default()
+		};
+		let execute = |network: NodeNetwork| {
+			let protograph = Compiler {}.compile_single(network).expect("Graph should be generated");
+			let exec = block_on(DynamicExecutor::new(protograph)).unwrap_or_else(|e| panic!("Failed to create executor: {}", e));
+			block_on((&exec).execute(Color::RED)).unwrap()
+		};
+
+		assert_ne!(execute(invert_network(Vec::new())), TaggedValue::Color(Color::RED), "The enabled node should invert the color");
+		assert_eq!(execute(invert_network(vec![0])), TaggedValue::Color(Color::RED));
+	}
+
+	#[test]
+	fn map_runs_identity_lambda_on_each_element() {
+		use graph_craft::document::*;
+		use graph_craft::graphene_compiler::{Compiler, Executor};
+
+		use crate::dynamic_executor::DynamicExecutor;
+
+		let network = NodeNetwork {
+			inputs: vec![0],
+			outputs: vec![NodeOutput::new(0, 0)],
+			nodes: [
+				(
+					0,
+					DocumentNode {
+						name: "Map".into(),
+						inputs: vec![NodeInput::Network(concrete!(Vec<f32>)), NodeInput::lambda(1, 0)],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::MapNode<_>")),
+						..Default::default()
+					},
+				),
+				// The identity lambda, typed as a function from one element to another so the map node type checks
+				(
+					1,
+					DocumentNode {
+						name: "Identity".into(),
+						inputs: vec![NodeInput::ShortCircut(concrete!(f32))],
+						implementation: DocumentNodeImplementation::Unresolved(NodeIdentifier::new("graphene_core::ops::IdNode")),
+						..Default::default()
+					},
+				),
+			]
+			.into_iter()
+			.collect(),
+			..Default::default()
+		};
+
+		let protograph = Compiler {}.compile_single(network).expect("Graph should be generated");
+		let exec = block_on(DynamicExecutor::new(protograph)).unwrap_or_else(|e| panic!("Failed to create executor: {}", e));
+
+		let list = vec![1., 2.5, -3.];
+		let result = block_on((&exec).execute(list.clone())).unwrap();
+		assert_eq!(result, TaggedValue::VecF32(list));
+	}
 }
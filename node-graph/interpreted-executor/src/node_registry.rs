@@ -1,4 +1,4 @@
-use graph_craft::imaginate_input::{ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
+use graph_craft::imaginate_input::{ImaginateControlType, ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
 use graph_craft::proto::{NodeConstructor, TypeErasedBox};
 use graphene_core::ops::IdNode;
 use graphene_core::quantization::{PackedPixel, QuantizationChannels};
@@ -7,8 +7,8 @@ use graphene_core::raster::brush_cache::BrushCache;
 use graphene_core::raster::color::Color;
 use graphene_core::structural::Then;
 use graphene_core::value::{ClonedNode, CopiedNode, ValueNode};
-use graphene_core::vector::brush_stroke::BrushStroke;
-use graphene_core::vector::VectorData;
+use graphene_core::vector::brush_stroke::{BrushMode, BrushStroke};
+use graphene_core::vector::{VectorBounds, VectorData, VectorMeasurements, VectorPoints};
 use graphene_core::{application_io::SurfaceHandle, SurfaceFrame, WasmSurfaceHandleFrame};
 use graphene_core::{concrete, generic};
 use graphene_core::{fn_type, raster::*};
@@ -73,6 +73,12 @@ macro_rules! register_node {
 		]
 	};
 }
+/// Registers the Monitor node for each of the types, since a monitor can be placed after any node and must pass its value through unchanged
+macro_rules! monitor_nodes {
+	($($type:ty),* $(,)?) => {
+		[$(register_node!(graphene_core::memo::MonitorNode<_>, input: $type, params: [])),*].into_iter().flatten().collect::<Vec<_>>()
+	};
+}
 macro_rules! async_node {
 	// TODO: we currently need to annotate the type here because the compiler would otherwise (correctly)
 	// assign a Pin<Box<dyn Fututure<Output=T>>> type to the node, which is not what we want for now.
@@ -186,6 +192,7 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		register_node!(graphene_core::structural::ConsNode<_, _>, input: &u32, params: [&u32]),
 		register_node!(graphene_core::ops::AddNode, input: (u32, u32), params: []),
 		register_node!(graphene_core::ops::AddNode, input: (u32, &u32), params: []),
+		register_node!(graphene_core::ops::SndNode, input: (ImageFrame<Color>, ImageFrame<Color>), params: []),
 		register_node!(graphene_core::ops::CloneNode<_>, input: &ImageFrame<Color>, params: []),
 		register_node!(graphene_core::ops::CloneNode<_>, input: &WasmEditorApi, params: []),
 		register_node!(graphene_core::ops::AddParameterNode<_>, input: u32, params: [u32]),
@@ -251,15 +258,40 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		register_node!(graphene_core::logic::LogToConsoleNode, input: DVec2, params: []),
 		register_node!(graphene_core::logic::LogToConsoleNode, input: VectorData, params: []),
 		register_node!(graphene_core::logic::LogToConsoleNode, input: DAffine2, params: []),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: f64, params: [u32, f64, f64, f64]),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: u32, params: [u32, u32, u32, u32]),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: bool, params: [u32, bool, bool, bool]),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: String, params: [u32, String, String, String]),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: Color, params: [u32, Color, Color, Color]),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: VectorData, params: [u32, VectorData, VectorData, VectorData]),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: ImageFrame<Color>, params: [u32, ImageFrame<Color>, ImageFrame<Color>, ImageFrame<Color>]),
+		register_node!(graphene_core::logic::MuxNode<_, _, _, _>, input: graphene_core::GraphicGroup, params: [u32, graphene_core::GraphicGroup, graphene_core::GraphicGroup, graphene_core::GraphicGroup]),
 		async_node!(graphene_core::ops::IntoNode<_, ImageFrame<SRGBA8>>, input: ImageFrame<Color>, output: ImageFrame<SRGBA8>, params: []),
 		async_node!(graphene_core::ops::IntoNode<_, ImageFrame<Color>>, input: ImageFrame<SRGBA8>, output: ImageFrame<Color>, params: []),
 		#[cfg(feature = "gpu")]
 		async_node!(graphene_core::ops::IntoNode<_, &WgpuExecutor>, input: WasmEditorApi, output: &WgpuExecutor, params: []),
-		register_node!(graphene_std::raster::DownresNode<_>, input: ImageFrame<Color>, params: []),
+		register_node!(graphene_std::raster::DownresNode<_, _>, input: ImageFrame<Color>, params: [f64, DownresFilter]),
+		register_node!(graphene_std::raster::ImageStatsNode, input: ImageFrame<Color>, params: []),
+		register_node!(graphene_std::raster::ImageStatsMinNode, input: ImageStats, params: []),
+		register_node!(graphene_std::raster::ImageStatsMaxNode, input: ImageStats, params: []),
+		register_node!(graphene_std::raster::ImageStatsMeanNode, input: ImageStats, params: []),
+		register_node!(graphene_std::raster::SamplePixelNode<_>, input: ImageFrame<Color>, params: [DVec2]),
+		register_node!(graphene_std::raster::SetPixelNode<_, _>, input: ImageFrame<Color>, params: [DVec2, Color]),
+		register_node!(graphene_std::raster::DisplaceNode<_, _>, input: ImageFrame<Color>, params: [ImageFrame<Color>, f64]),
+		register_node!(graphene_std::raster::BloomNode<_, _, _, _>, input: ImageFrame<Color>, params: [f32, f64, f32, LuminanceCalculation]),
+		register_node!(graphene_std::raster::LensDistortionNode<_, _, _, _>, input: ImageFrame<Color>, params: [f64, f64, DVec2, bool]),
+		register_node!(graphene_std::raster::FilmGrainNode<_, _, _, _, _>, input: ImageFrame<Color>, params: [f32, f64, u32, bool, BlendMode]),
+		register_node!(
+			graphene_std::raster::CompositeLayerListNode<_, _, _, _, _, _, _, _, _, _, _>,
+			input: ImageFrame<Color>,
+			params: [BlendMode, f32, ImageFrame<Color>, BlendMode, f32, ImageFrame<Color>, BlendMode, f32, ImageFrame<Color>, BlendMode, f32]
+		),
+		register_node!(graphene_std::raster::CompositeLayersNode, input: Vec<graphene_std::raster::CompositeLayer>, params: []),
 		register_node!(graphene_std::raster::MaskImageNode<_, _, _>, input: ImageFrame<Color>, params: [ImageFrame<Color>]),
 		register_node!(graphene_std::raster::MaskImageNode<_, _, _>, input: ImageFrame<Color>, params: [ImageFrame<Luma>]),
-		register_node!(graphene_std::raster::InsertChannelNode<_, _, _, _>, input: ImageFrame<Color>, params: [ImageFrame<Color>, RedGreenBlue]),
-		register_node!(graphene_std::raster::InsertChannelNode<_, _, _, _>, input: ImageFrame<Color>, params: [ImageFrame<Luma>, RedGreenBlue]),
+		register_node!(graphene_std::raster::InsertChannelNode<_, _, _, _>, input: ImageFrame<Color>, params: [ImageFrame<Color>, RedGreenBlueAlpha]),
+		register_node!(graphene_std::raster::InsertChannelNode<_, _, _, _>, input: ImageFrame<Color>, params: [ImageFrame<Luma>, RedGreenBlueAlpha]),
+		register_node!(graphene_std::raster::MergeChannelsNode<_, _, _>, input: ImageFrame<Color>, params: [ImageFrame<Color>, ImageFrame<Color>, ImageFrame<Color>]),
 		vec![(
 			NodeIdentifier::new("graphene_std::raster::CombineChannelsNode"),
 			|args| {
@@ -272,9 +304,9 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 					let channel_b: ImageFrame<Color> = DowncastBothNode::new(args[2].clone()).eval(()).await;
 					let channel_a: ImageFrame<Color> = DowncastBothNode::new(args[3].clone()).eval(()).await;
 
-					let insert_r = InsertChannelNode::new(ClonedNode::new(channel_r.clone()), CopiedNode::new(RedGreenBlue::Red));
-					let insert_g = InsertChannelNode::new(ClonedNode::new(channel_g.clone()), CopiedNode::new(RedGreenBlue::Green));
-					let insert_b = InsertChannelNode::new(ClonedNode::new(channel_b.clone()), CopiedNode::new(RedGreenBlue::Blue));
+					let insert_r = InsertChannelNode::new(ClonedNode::new(channel_r.clone()), CopiedNode::new(RedGreenBlueAlpha::Red));
+					let insert_g = InsertChannelNode::new(ClonedNode::new(channel_g.clone()), CopiedNode::new(RedGreenBlueAlpha::Green));
+					let insert_b = InsertChannelNode::new(ClonedNode::new(channel_b.clone()), CopiedNode::new(RedGreenBlueAlpha::Blue));
 					let complete_node = insert_r.then(insert_g).then(insert_b);
 					let complete_node = complete_node.then(MaskImageNode::new(ClonedNode::new(channel_a.clone())));
 
@@ -304,10 +336,61 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 			),
 		)],
 		register_node!(graphene_std::raster::EmptyImageNode<_, _>, input: DAffine2, params: [Color]),
-		register_node!(graphene_core::memo::MonitorNode<_>, input: ImageFrame<Color>, params: []),
-		register_node!(graphene_core::memo::MonitorNode<_>, input: graphene_core::GraphicGroup, params: []),
+		register_node!(graphene_std::raster::SolidColorNode<_, _>, input: (), params: [glam::UVec2, Color]),
+		monitor_nodes![
+			(),
+			bool,
+			u32,
+			f32,
+			f64,
+			String,
+			DVec2,
+			Option<DVec2>,
+			DAffine2,
+			glam::IVec2,
+			glam::UVec2,
+			Color,
+			Option<Color>,
+			Vec<f32>,
+			Vec<String>,
+			Image<Color>,
+			ImageFrame<Color>,
+			Vec<ImageFrame<Color>>,
+			graphene_core::GraphicGroup,
+			graphene_core::Artboard,
+			VectorData,
+			VectorPoints,
+			VectorBounds,
+			VectorMeasurements,
+			Vec<graphene_core::vector::bezier_rs::Subpath<graphene_core::uuid::ManipulatorGroupId>>,
+			Vec<graphene_core::uuid::ManipulatorGroupId>,
+			graphene_core::vector::style::Fill,
+			graphene_core::vector::style::Stroke,
+			Vec<graphene_core::vector::style::StyleLayer>,
+			Vec<(f64, Option<Color>)>,
+			graphene_core::text::Font,
+			Vec<graphene_core::text::Font>,
+			Vec<BrushStroke>,
+			BlendMode,
+			LuminanceCalculation,
+			RedGreenBlue,
+			RedGreenBlueAlpha,
+			graphene_core::quantization::QuantizationChannels,
+			graphene_core::animation::AnimationCurve,
+			graphene_std::palette::Palette,
+			graphene_std::csv::CsvTable,
+			graphene_std::csv::CsvColumn,
+		],
 		async_node!(graphene_std::wasm_application_io::LoadResourceNode<_>, input: WasmEditorApi, output: Arc<[u8]>, params: [String]),
 		register_node!(graphene_std::wasm_application_io::DecodeImageNode, input: Arc<[u8]>, params: []),
+		register_node!(graphene_std::lut::DecodeCubeLutNode, input: Arc<[u8]>, params: []),
+		register_node!(graphene_std::lut::ApplyLutNode<_, _>, input: ImageFrame<Color>, params: [graphene_std::lut::Lut3D, f32]),
+		register_node!(graphene_std::palette::DecodePaletteNode, input: Arc<[u8]>, params: []),
+		register_node!(graphene_std::palette::PaletteColorNode<_>, input: graphene_std::palette::Palette, params: [u32]),
+		register_node!(graphene_std::csv::DecodeCsvNode, input: Arc<[u8]>, params: []),
+		register_node!(graphene_std::csv::CsvColumnNode<_>, input: graphene_std::csv::CsvTable, params: [String]),
+		register_node!(graphene_std::csv::CsvNumbersNode, input: graphene_std::csv::CsvColumn, params: []),
+		register_node!(graphene_std::csv::CsvTextNode, input: graphene_std::csv::CsvColumn, params: []),
 		async_node!(graphene_std::wasm_application_io::CreateSurfaceNode, input: WasmEditorApi, output: Arc<SurfaceHandle<<graphene_std::wasm_application_io::WasmApplicationIo as graphene_core::application_io::ApplicationIo>::Surface>>, params: []),
 		async_node!(
 			graphene_std::wasm_application_io::DrawImageFrameNode<_>,
@@ -406,13 +489,47 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 			),
 		)],
 		register_node!(graphene_std::brush::IntoIterNode<_>, input: &Vec<BrushStroke>, params: []),
-		async_node!(graphene_std::brush::BrushNode<_, _, _>, input: ImageFrame<Color>, output: ImageFrame<Color>, params: [ImageFrame<Color>, Vec<BrushStroke>, BrushCache]),
+		async_node!(
+			graphene_std::brush::BrushNode<_, _, _, _, _, _, _>,
+			input: ImageFrame<Color>,
+			output: ImageFrame<Color>,
+			params: [ImageFrame<Color>, Vec<BrushStroke>, BrushCache, BrushMode, bool, f64, f64]
+		),
 		// Filters
 		raster_node!(graphene_core::raster::LuminanceNode<_>, params: [LuminanceCalculation]),
+		raster_node!(graphene_core::raster::LuminanceToMaskNode<_, _>, params: [LuminanceCalculation, bool]),
+		raster_node!(graphene_core::raster::DuotoneNode<_, _, _, _, _>, params: [Color, Color, bool, Color, LuminanceCalculation]),
 		raster_node!(graphene_core::raster::ExtractChannelNode<_>, params: [RedGreenBlue]),
+		raster_node!(graphene_core::raster::SplitChannelNode<_>, params: [RedGreenBlue]),
 		raster_node!(graphene_core::raster::ExtractAlphaNode<>, params: []),
 		raster_node!(graphene_core::raster::ExtractOpaqueNode<>, params: []),
+		raster_node!(graphene_core::raster::PremultiplyAlphaNode<>, params: []),
+		raster_node!(graphene_core::raster::UnpremultiplyAlphaNode<>, params: []),
 		raster_node!(graphene_core::raster::LevelsNode<_, _, _, _, _>, params: [f32, f32, f32, f32, f32]),
+		vec![(
+			NodeIdentifier::new("graphene_core::raster::AlphaChannelAdjustNode<graphene_core::raster::LevelsNode<_, _, _, _, _>>"),
+			|args| {
+				Box::pin(async move {
+					let mut levels = Vec::new();
+					for arg in &args {
+						let value: DowncastBothNode<(), f32> = DowncastBothNode::new(arg.clone());
+						levels.push(ClonedNode::new(value.eval(()).await));
+					}
+					let (input_start, input_mid, input_end, output_start, output_end) = (levels[0], levels[1], levels[2], levels[3], levels[4]);
+					let levels_node = graphene_core::raster::LevelsNode::new(input_start, input_mid, input_end, output_start, output_end);
+					let alpha_node = graphene_core::raster::AlphaChannelAdjustNode::new(ValueNode::new(levels_node));
+					let map_image_frame_node = graphene_std::raster::MapImageNode::new(ValueNode::new(alpha_node));
+					let map_image_frame_node = FutureWrapperNode::new(map_image_frame_node);
+					let any: DynAnyNode<ImageFrame<Color>, _, _> = graphene_std::any::DynAnyNode::new(map_image_frame_node);
+					any.into_type_erased()
+				})
+			},
+			NodeIOTypes::new(
+				concrete!(ImageFrame<Color>),
+				concrete!(ImageFrame<Color>),
+				vec![fn_type!(f32), fn_type!(f32), fn_type!(f32), fn_type!(f32), fn_type!(f32)],
+			),
+		)],
 		register_node!(graphene_std::image_segmentation::ImageSegmentationNode<_>, input: ImageFrame<Color>, params: [ImageFrame<Color>]),
 		register_node!(graphene_core::raster::IndexNode<_>, input: Vec<ImageFrame<Color>>, params: [u32]),
 		vec![(
@@ -435,9 +552,10 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 			),
 		)],
 		raster_node!(graphene_core::raster::GrayscaleNode<_, _, _, _, _, _, _>, params: [Color, f32, f32, f32, f32, f32, f32]),
-		raster_node!(graphene_core::raster::HueSaturationNode<_, _, _>, params: [f32, f32, f32]),
-		raster_node!(graphene_core::raster::InvertRGBNode, params: []),
-		raster_node!(graphene_core::raster::ThresholdNode<_, _, _>, params: [f32, f32, LuminanceCalculation]),
+		raster_node!(graphene_core::raster::HueSaturationNode<_, _, _, _, _>, params: [f32, f32, f32, bool, HueRange]),
+		raster_node!(graphene_core::raster::OkLchAdjustNode<_, _, _>, params: [f32, f32, f32]),
+		raster_node!(graphene_core::raster::InvertRGBNode<_, _, _, _>, params: [bool, bool, bool, bool]),
+		raster_node!(graphene_core::raster::ThresholdNode<_, _, _, _>, params: [f32, f32, LuminanceCalculation, f32]),
 		raster_node!(graphene_core::raster::VibranceNode<_>, params: [f32]),
 		raster_node!(
 			graphene_core::raster::ChannelMixerNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>,
@@ -476,9 +594,13 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 			},
 			NodeIOTypes::new(concrete!(ImageFrame<Color>), concrete!(ImageFrame<Color>), vec![fn_type!(f32), fn_type!(f32), fn_type!(bool)]),
 		)],
-		raster_node!(graphene_core::raster::OpacityNode<_>, params: [f32]),
+		raster_node!(graphene_core::raster::OpacityNode<_, _>, params: [f32, bool]),
 		raster_node!(graphene_core::raster::PosterizeNode<_>, params: [f32]),
 		raster_node!(graphene_core::raster::ExposureNode<_, _, _>, params: [f32, f32, f32]),
+		raster_node!(graphene_core::raster::WhiteBalanceNode<_, _>, params: [Color, f32]),
+		raster_node!(graphene_core::raster::TemperatureTintNode<_, _>, params: [f32, f32]),
+		raster_node!(graphene_core::raster::ClampColorNode<_, _, _>, params: [f32, f32, ToneMapping]),
+		raster_node!(graphene_core::raster::FlattenAlphaNode<_>, params: [Color]),
 		register_node!(graphene_core::memo::LetNode<_>, input: Option<ImageFrame<Color>>, params: []),
 		register_node!(graphene_core::memo::LetNode<_>, input: Option<WasmEditorApi>, params: []),
 		async_node!(graphene_core::memo::EndLetNode<_>, input: WasmEditorApi, output: ImageFrame<Color>, params: [ImageFrame<Color>]),
@@ -517,21 +639,117 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 				NodeIOTypes::new(concrete!(()), concrete!(WasmEditorApi), vec![fn_type!(Option<WasmEditorApi>, WasmEditorApi)]),
 			),
 			(
-				NodeIdentifier::new("graphene_std::raster::ImaginateNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
+				NodeIdentifier::new("graphene_core::vector::ForEachSubpathNode<_>"),
+				|args| {
+					Box::pin(async move {
+						let lambda: DowncastBothNode<VectorData, VectorData> = graphene_std::any::DowncastBothNode::new(args[0].clone());
+						let node = graphene_core::vector::ForEachSubpathNode::new(lambda);
+						let any: DynAnyNode<VectorData, _, _> = graphene_std::any::DynAnyNode::new(node);
+
+						any.into_type_erased()
+					})
+				},
+				NodeIOTypes::new(concrete!(VectorData), concrete!(VectorData), vec![fn_type!(VectorData, VectorData)]),
+			),
+			(
+				NodeIdentifier::new("graphene_core::ops::MapNode<_>"),
+				|args| {
+					Box::pin(async move {
+						let lambda: DowncastBothNode<ImageFrame<Color>, ImageFrame<Color>> = DowncastBothNode::new(args[0].clone());
+						let node = graphene_core::ops::MapNode::new(lambda);
+						let any: DynAnyNode<Vec<ImageFrame<Color>>, _, _> = graphene_std::any::DynAnyNode::new(node);
+						any.into_type_erased()
+					})
+				},
+				NodeIOTypes::new(
+					concrete!(Vec<ImageFrame<Color>>),
+					concrete!(Vec<ImageFrame<Color>>),
+					vec![fn_type!(ImageFrame<Color>, ImageFrame<Color>)],
+				),
+			),
+			(
+				NodeIdentifier::new("graphene_core::raster::AlphaChannelAdjustNode<_>"),
+				|args| {
+					Box::pin(async move {
+						let lambda: DowncastBothNode<ImageFrame<Color>, ImageFrame<Color>> = DowncastBothNode::new(args[0].clone());
+						let node = graphene_core::raster::AlphaChannelAdjustNode::new(lambda);
+						let any: DynAnyNode<ImageFrame<Color>, _, _> = graphene_std::any::DynAnyNode::new(node);
+						any.into_type_erased()
+					})
+				},
+				NodeIOTypes::new(concrete!(ImageFrame<Color>), concrete!(ImageFrame<Color>), vec![fn_type!(ImageFrame<Color>, ImageFrame<Color>)]),
+			),
+			(
+				NodeIdentifier::new("graphene_core::raster::ColorChannelsAdjustNode<_>"),
+				|args| {
+					Box::pin(async move {
+						let lambda: DowncastBothNode<ImageFrame<Color>, ImageFrame<Color>> = DowncastBothNode::new(args[0].clone());
+						let node = graphene_core::raster::ColorChannelsAdjustNode::new(lambda);
+						let any: DynAnyNode<ImageFrame<Color>, _, _> = graphene_std::any::DynAnyNode::new(node);
+						any.into_type_erased()
+					})
+				},
+				NodeIOTypes::new(concrete!(ImageFrame<Color>), concrete!(ImageFrame<Color>), vec![fn_type!(ImageFrame<Color>, ImageFrame<Color>)]),
+			),
+			(
+				NodeIdentifier::new("graphene_core::ops::MapNode<_>"),
+				|args| {
+					Box::pin(async move {
+						let lambda: DowncastBothNode<f32, f32> = DowncastBothNode::new(args[0].clone());
+						let node = graphene_core::ops::MapNode::new(lambda);
+						let any: DynAnyNode<Vec<f32>, _, _> = graphene_std::any::DynAnyNode::new(node);
+						any.into_type_erased()
+					})
+				},
+				NodeIOTypes::new(concrete!(Vec<f32>), concrete!(Vec<f32>), vec![fn_type!(f32, f32)]),
+			),
+			(
+				NodeIdentifier::new("graphene_core::ops::ReduceNode<_, _>"),
+				|args| {
+					Box::pin(async move {
+						let initial: DowncastBothNode<(), ImageFrame<Color>> = DowncastBothNode::new(args[0].clone());
+						let lambda: DowncastBothNode<(ImageFrame<Color>, ImageFrame<Color>), ImageFrame<Color>> = DowncastBothNode::new(args[1].clone());
+						let node = graphene_core::ops::ReduceNode::new(initial, lambda);
+						let any: DynAnyNode<Vec<ImageFrame<Color>>, _, _> = graphene_std::any::DynAnyNode::new(node);
+						any.into_type_erased()
+					})
+				},
+				NodeIOTypes::new(
+					concrete!(Vec<ImageFrame<Color>>),
+					concrete!(ImageFrame<Color>),
+					vec![fn_type!(ImageFrame<Color>), fn_type!((ImageFrame<Color>, ImageFrame<Color>), ImageFrame<Color>)],
+				),
+			),
+			(
+				NodeIdentifier::new("graphene_core::ops::ReduceNode<_, _>"),
+				|args| {
+					Box::pin(async move {
+						let initial: DowncastBothNode<(), f32> = DowncastBothNode::new(args[0].clone());
+						let lambda: DowncastBothNode<(f32, f32), f32> = DowncastBothNode::new(args[1].clone());
+						let node = graphene_core::ops::ReduceNode::new(initial, lambda);
+						let any: DynAnyNode<Vec<f32>, _, _> = graphene_std::any::DynAnyNode::new(node);
+						any.into_type_erased()
+					})
+				},
+				NodeIOTypes::new(concrete!(Vec<f32>), concrete!(f32), vec![fn_type!(f32), fn_type!((f32, f32), f32)]),
+			),
+			(
+				NodeIdentifier::new("graphene_std::raster::ImaginateNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
 				|args: Vec<graph_craft::proto::SharedNodeContainer>| {
 					Box::pin(async move {
 						use graphene_std::raster::ImaginateNode;
 						macro_rules! instanciate_imaginate_node {
 							($($i:expr,)*) => { ImaginateNode::new($(graphene_std::any::input_node(args[$i].clone()),)* ) };
 						}
-						let node: ImaginateNode<Color, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _> = instanciate_imaginate_node!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,);
+						let node: ImaginateNode<Color, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _> =
+							instanciate_imaginate_node!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,);
 						let any = graphene_std::any::DynAnyNode::new(node);
 						any.into_type_erased()
 					})
 				},
 				NodeIOTypes::new(
 					concrete!(ImageFrame<Color>),
-					concrete!(ImageFrame<Color>),
+					concrete!(Vec<ImageFrame<Color>>),
 					vec![
 						fn_type!(WasmEditorApi),
 						fn_type!(ImaginateController),
@@ -550,6 +768,11 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 						fn_type!(ImaginateMaskStartingFill),
 						fn_type!(bool),
 						fn_type!(bool),
+						fn_type!(u32),
+						fn_type!(ImageFrame<Color>),
+						fn_type!(ImaginateControlType),
+						fn_type!(f64),
+						fn_type!(f32),
 					],
 				),
 			),
@@ -557,7 +780,11 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: Image<Color>, params: [Image<Color>]),
 		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: ImageFrame<Color>, params: [ImageFrame<Color>]),
 		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: QuantizationChannels, params: [QuantizationChannels]),
+		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: ImageStats, params: [ImageStats]),
 		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: Vec<DVec2>, params: [Vec<DVec2>]),
+		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: VectorPoints, params: [VectorPoints]),
+		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: VectorMeasurements, params: [VectorMeasurements]),
+		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: VectorBounds, params: [VectorBounds]),
 		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: Arc<WasmSurfaceHandle>, params: [Arc<WasmSurfaceHandle>]),
 		#[cfg(feature = "gpu")]
 		async_node!(graphene_core::memo::MemoNode<_, _>, input: (), output: ShaderInputFrame<WgpuExecutor>, params: [ShaderInputFrame<WgpuExecutor>]),
@@ -578,25 +805,62 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: ImageFrame<Color>, params: [ImageFrame<Color>]),
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: VectorData, params: [DAffine2]),
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: ImageFrame<Color>, params: [DAffine2]),
-		register_node!(graphene_core::vector::SetFillNode<_, _, _, _, _, _, _>, input: VectorData, params: [graphene_core::vector::style::FillType, Option<graphene_core::Color>, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, Option<graphene_core::Color>)>]),
-		register_node!(graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, f32, Vec<f32>, f32, graphene_core::vector::style::LineCap, graphene_core::vector::style::LineJoin, f32]),
+		register_node!(graphene_core::vector::SetFillNode<_, _, _, _, _, _, _, _, _>, input: VectorData, params: [graphene_core::vector::style::FillType, Option<graphene_core::Color>, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, Option<graphene_core::Color>)>, ImageFrame<Color>, bool]),
+		register_node!(graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, f32, Vec<f32>, f32, graphene_core::vector::style::LineCap, graphene_core::vector::style::LineJoin, f32, graphene_core::vector::style::StrokeAlign, graphene_core::vector::style::FillType, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, Option<graphene_core::Color>)>]),
+		register_node!(graphene_core::vector::SetStyleLayersNode<_>, input: VectorData, params: [Vec<graphene_core::vector::style::StyleLayer>]),
 		register_node!(graphene_core::vector::RepeatNode<_, _>, input: VectorData, params: [DVec2, u32]),
+		register_node!(graphene_core::vector::MirrorNode<_, _, _, _>, input: VectorData, params: [graphene_core::vector::MirrorAxis, DVec2, f64, bool]),
+		register_node!(graphene_core::vector::TrimPathNode<_, _, _, _>, input: VectorData, params: [f64, f64, f64, bool]),
+		register_node!(graphene_core::vector::DashToGeometryNode<_, _>, input: VectorData, params: [Vec<f32>, f32]),
 		register_node!(graphene_core::vector::BoundingBoxNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::BoundsNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::BoundsMinNode, input: VectorBounds, params: []),
+		register_node!(graphene_core::vector::BoundsMaxNode, input: VectorBounds, params: []),
+		register_node!(graphene_core::vector::BoundsCenterNode, input: VectorBounds, params: []),
+		register_node!(graphene_core::vector::BoundsSizeNode, input: VectorBounds, params: []),
+		register_node!(graphene_core::vector::PathToPointsNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::PointsToPathNode<_, _>, input: VectorPoints, params: [bool, bool]),
+		register_node!(graphene_core::vector::ConvexHullNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::DelaunayNode<_>, input: VectorData, params: [bool]),
 		register_node!(graphene_core::vector::CircularRepeatNode<_, _, _>, input: VectorData, params: [f32, f32, u32]),
+		register_node!(graphene_core::vector::MeasureNode<_>, input: VectorData, params: [bool]),
+		register_node!(graphene_core::vector::MeasuredLengthNode, input: VectorMeasurements, params: []),
+		register_node!(graphene_core::vector::MeasuredAreaNode, input: VectorMeasurements, params: []),
+		register_node!(graphene_core::vector::MeasuredSubpathLengthsNode, input: VectorMeasurements, params: []),
+		register_node!(graphene_core::vector::MeasuredSubpathAreasNode, input: VectorMeasurements, params: []),
 		register_node!(graphene_core::vector::generator_nodes::UnitCircleGenerator, input: (), params: []),
 		register_node!(
 			graphene_core::vector::generator_nodes::PathGenerator<_>,
 			input: Vec<graphene_core::vector::bezier_rs::Subpath<graphene_core::uuid::ManipulatorGroupId>>,
 			params: [Vec<graphene_core::uuid::ManipulatorGroupId>]
 		),
-		register_node!(graphene_core::text::TextGenerator<_, _, _>, input: WasmEditorApi, params: [String, graphene_core::text::Font, f64]),
+		register_node!(
+			graphene_core::text::TextGenerator<_, _, _, _, _, _, _, _, _, _, _>,
+			input: WasmEditorApi,
+			params: [String, graphene_core::text::Font, f64, f64, f64, f64, graphene_core::text::TextAlign, Vec<graphene_core::text::Font>, f64, f64, f64]
+		),
 		register_node!(graphene_std::brush::VectorPointsNode, input: VectorData, params: []),
+		register_node!(graphene_std::brush::ScatterNode<_, _, _, _>, input: ImageFrame<Color>, params: [VectorPoints, f64, f64, Color]),
 		register_node!(graphene_core::ExtractImageFrame, input: WasmEditorApi, params: []),
+		register_node!(graphene_core::ImageFrameAvailableNode, input: ImageFrame<Color>, params: []),
+		register_node!(graphene_core::FrameNode, input: WasmEditorApi, params: []),
+		register_node!(graphene_core::animation::AnimationCurveNode<_>, input: f64, params: [graphene_core::animation::AnimationCurve]),
 		register_node!(graphene_core::ConstructLayerNode<_, _, _, _, _, _, _>, input: graphene_core::vector::VectorData, params: [String, BlendMode, f32, bool, bool, bool, graphene_core::GraphicGroup]),
 		register_node!(graphene_core::ConstructLayerNode<_, _, _, _, _, _, _>, input: ImageFrame<Color>, params: [String, BlendMode, f32, bool, bool, bool, graphene_core::GraphicGroup]),
 		register_node!(graphene_core::ConstructLayerNode<_, _, _, _, _, _, _>, input: graphene_core::GraphicGroup, params: [String, BlendMode, f32, bool, bool, bool, graphene_core::GraphicGroup]),
 		register_node!(graphene_core::ConstructLayerNode<_, _, _, _, _, _, _>, input: graphene_core::Artboard, params: [String, BlendMode, f32, bool, bool, bool, graphene_core::GraphicGroup]),
-		register_node!(graphene_core::ConstructArtboardNode<_, _, _, _>, input: graphene_core::GraphicGroup, params: [glam::IVec2, glam::IVec2, Color, bool]),
+		register_node!(graphene_core::ReorderElementNode<_, _>, input: graphene_core::GraphicGroup, params: [u32, u32]),
+		register_node!(graphene_core::StackNode<_, _, _, _>, input: graphene_core::GraphicGroup, params: [graphene_core::vector::VectorData, String, u32, bool]),
+		register_node!(graphene_core::StackNode<_, _, _, _>, input: graphene_core::GraphicGroup, params: [ImageFrame<Color>, String, u32, bool]),
+		register_node!(graphene_core::StackNode<_, _, _, _>, input: graphene_core::GraphicGroup, params: [graphene_core::GraphicGroup, String, u32, bool]),
+		register_node!(graphene_core::StackNode<_, _, _, _>, input: graphene_core::GraphicGroup, params: [graphene_core::Artboard, String, u32, bool]),
+		register_node!(graphene_core::ClipNode<_>, input: graphene_core::GraphicGroup, params: [graphene_core::vector::VectorData]),
+		register_node!(graphene_core::FlattenGroupNode<_>, input: graphene_core::GraphicGroup, params: [f64]),
+		register_node!(graphene_core::UngroupNode, input: graphene_core::GraphicGroup, params: []),
+		register_node!(graphene_core::RasterizeNode<_, _, _>, input: graphene_core::vector::VectorData, params: [f64, Option<Color>, graphene_core::renderer::AntiAliasing]),
+		register_node!(graphene_core::RasterizeNode<_, _, _>, input: graphene_core::GraphicGroup, params: [f64, Option<Color>, graphene_core::renderer::AntiAliasing]),
+		register_node!(graphene_core::ConstructArtboardNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>, input: graphene_core::GraphicGroup, params: [glam::IVec2, glam::IVec2, Color, bool, f32, bool, String, f64, Color, f64, graphene_core::vector::style::FillType, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, Option<graphene_core::Color>)>]),
+		register_node!(graphene_std::raster::ExportArtboardsNode, input: graphene_core::GraphicGroup, params: []),
 	];
 	let mut map: HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstructor>> = HashMap::new();
 	for (id, c, types) in node_types.into_iter().flatten() {